@@ -14,21 +14,34 @@
 
 use std::sync::Arc;
 
+use arrow::pyarrow::PyArrowType;
+use arrow::record_batch::RecordBatch;
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_ast::ast::quote::QuotedString;
 use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::tenant::Tenant;
+use databend_common_storages_memory::MemoryTable;
 use databend_common_users::UserApiProvider;
+use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
 use databend_query::sessions::SessionManager;
 use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
 use databend_query::sql::Planner;
 use pyo3::prelude::*;
+use tokio_stream::StreamExt;
 
 use crate::dataframe::default_box_size;
+use crate::dataframe::scan_table;
 use crate::dataframe::PyDataFrame;
 use crate::utils::wait_for_future;
 use crate::utils::RUNTIME;
@@ -88,8 +101,7 @@ impl PySessionContext {
     }
 
     fn sql(&mut self, sql: &str, py: Python) -> PyResult<PyDataFrame> {
-        let ctx = wait_for_future(py, self.session.create_query_context()).unwrap();
-        let res = wait_for_future(py, plan_sql(&ctx, sql));
+        let res = wait_for_future(py, plan_sql(&self.session, sql));
 
         match res {
             Err(err) => Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
@@ -109,6 +121,50 @@ impl PySessionContext {
         }
     }
 
+    /// Returns a lazy DataFrame reading all rows of the table `name` of the current database.
+    fn table(&mut self, name: &str, py: Python) -> PyResult<PyDataFrame> {
+        let session = self.session.clone();
+        let res = wait_for_future(py, async move {
+            let ctx = session.create_query_context().await?;
+            PyDataFrame::create(ctx, scan_table(name), default_box_size()).await
+        });
+        res.map_err(|err| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Error: {}", err))
+        })
+    }
+
+    /// Register in-memory data as the table `name`, backed by the memory engine.
+    ///
+    /// `data` can be a pyarrow Table or RecordBatch, a pandas or a polars DataFrame.
+    /// An existing table with the same name is replaced.
+    fn register_arrow(&mut self, name: &str, data: &PyAny, py: Python) -> PyResult<()> {
+        let pyarrow = py.import("pyarrow")?;
+        let table_class = pyarrow.getattr("Table")?;
+        let table = if data.is_instance(table_class)? {
+            data
+        } else if data.is_instance(pyarrow.getattr("RecordBatch")?)? {
+            table_class.call_method1("from_batches", (vec![data],))?
+        } else if data.hasattr("to_arrow")? {
+            // polars
+            data.call_method0("to_arrow")?
+        } else {
+            // pandas and anything else pyarrow knows how to convert
+            pyarrow.call_method1("table", (data,))?
+        };
+
+        let schema: PyArrowType<ArrowSchema> = table.getattr("schema")?.extract()?;
+        let batches: Vec<PyArrowType<RecordBatch>> = table.call_method0("to_batches")?.extract()?;
+        let batches = batches.into_iter().map(|b| b.0).collect::<Vec<_>>();
+
+        let res = wait_for_future(
+            py,
+            register_memory_table(&self.session, name, &schema.0, batches),
+        );
+        res.map_err(|err| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Error: {}", err))
+        })
+    }
+
     fn register_parquet(
         &mut self,
         name: &str,
@@ -173,13 +229,18 @@ impl PySessionContext {
         // Example: select * from '/home/sundy/dataset/hits_p/' (file_format => 'parquet', pattern => '.*.parquet') limit 3;
         let sql = if let Some(pattern) = pattern {
             format!(
-                "create view {} as select * from '{}' (file_format => '{}', pattern => '{}')",
-                name, path, file_format, pattern
+                "create view {} as select * from {} (file_format => {}, pattern => {})",
+                QuotedIdent(name, '`'),
+                QuotedString(&path, '\''),
+                QuotedString(file_format, '\''),
+                QuotedString(pattern, '\'')
             )
         } else {
             format!(
-                "create view {} as select * from '{}' (file_format => '{}')",
-                name, path, file_format
+                "create view {} as select * from {} (file_format => {})",
+                QuotedIdent(name, '`'),
+                QuotedString(&path, '\''),
+                QuotedString(file_format, '\'')
             )
         };
 
@@ -188,8 +249,76 @@ impl PySessionContext {
     }
 }
 
-async fn plan_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<PyDataFrame> {
+async fn plan_sql(session: &Arc<Session>, sql: &str) -> Result<PyDataFrame> {
+    let ctx = session.create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner.plan_sql(sql).await?;
+    Ok(PyDataFrame::new(
+        ctx,
+        extras.statement,
+        plan,
+        default_box_size(),
+    ))
+}
+
+async fn execute_sql(ctx: &Arc<QueryContext>, sql: &str) -> Result<()> {
     let mut planner = Planner::new(ctx.clone());
     let (plan, _) = planner.plan_sql(sql).await?;
-    Ok(PyDataFrame::new(ctx.clone(), plan, default_box_size()))
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx.clone()).await?;
+    stream.collect::<Result<Vec<_>>>().await?;
+    Ok(())
+}
+
+async fn register_memory_table(
+    session: &Arc<Session>,
+    name: &str,
+    schema: &ArrowSchema,
+    batches: Vec<RecordBatch>,
+) -> Result<()> {
+    let data_schema = DataSchema::try_from(schema)?;
+    let columns = data_schema
+        .fields()
+        .iter()
+        .map(|f| {
+            let data_type = f.data_type();
+            let name = QuotedIdent(f.name(), '`');
+            if data_type.is_nullable_or_null() {
+                format!("{} {}", name, data_type.sql_name())
+            } else {
+                format!("{} {} NOT NULL", name, data_type.sql_name())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let ctx = session.create_query_context().await?;
+    let sql = format!(
+        "CREATE OR REPLACE TABLE {} ({}) ENGINE = Memory",
+        QuotedIdent(name, '`'),
+        columns.join(", ")
+    );
+    execute_sql(&ctx, &sql).await?;
+
+    let ctx = session.create_query_context().await?;
+    let table = ctx
+        .get_table(
+            &ctx.get_current_catalog(),
+            &ctx.get_current_database(),
+            name,
+        )
+        .await?;
+    let memory_table = table
+        .as_any()
+        .downcast_ref::<MemoryTable>()
+        .ok_or_else(|| ErrorCode::Internal(format!("table {name} is not a memory table")))?;
+
+    // Read the schema back so the columns get exactly the types of the table.
+    let table_schema = DataSchema::from(table.schema());
+    let blocks = batches
+        .iter()
+        .filter(|batch| batch.num_rows() > 0)
+        .map(|batch| DataBlock::from_record_batch(&table_schema, batch).map(|(block, _)| block))
+        .collect::<Result<Vec<_>>>()?;
+    memory_table.update(blocks);
+    Ok(())
 }
@@ -12,16 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use arrow::pyarrow::PyArrowType;
 use arrow::pyarrow::ToPyArrow;
 use arrow_schema::Schema as ArrowSchema;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Indirection;
+use databend_common_ast::ast::Join;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::expr::expr;
+use databend_common_ast::parser::query::order_by_expr;
+use databend_common_ast::parser::query::select_target;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::IResult;
+use databend_common_ast::parser::Input;
+use databend_common_ast::parser::ParseMode;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
 use databend_query::interpreters::InterpreterFactory;
 use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
 use databend_query::sql::plans::Plan;
+use databend_query::sql::Planner;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyTuple;
 use tokio_stream::StreamExt;
@@ -38,28 +71,212 @@ pub(crate) struct PyBoxSize {
     pub(crate) bs_max_col_width: usize,
 }
 
+/// A lazily evaluated query.
+///
+/// Builder methods like `select` or `filter` don't run anything, they wrap the
+/// query of the current frame as a subquery of a new query AST and plan it,
+/// so mistakes are reported by the builder call. The query is only executed
+/// once the frame is collected.
 #[pyclass(name = "DataFrame", module = "databend", subclass)]
 #[derive(Clone)]
 pub(crate) struct PyDataFrame {
     ctx: Arc<QueryContext>,
-    pub(crate) df: Plan,
+    stmt: Statement,
+    plan: Plan,
     display_width: PyBoxSize,
 }
 
 impl PyDataFrame {
-    /// creates a new PyDataFrame
-    pub fn new(ctx: Arc<QueryContext>, df: Plan, display_width: PyBoxSize) -> Self {
+    pub fn new(
+        ctx: Arc<QueryContext>,
+        stmt: Statement,
+        plan: Plan,
+        display_width: PyBoxSize,
+    ) -> Self {
         Self {
             ctx,
-            df,
+            stmt,
+            plan,
             display_width,
         }
     }
 
-    async fn df_collect(&self) -> Result<Vec<DataBlock>> {
-        let interpreter = InterpreterFactory::get(self.ctx.clone(), &self.df).await?;
+    /// Plans `stmt` in `ctx`.
+    pub async fn create(
+        ctx: Arc<QueryContext>,
+        stmt: Statement,
+        display_width: PyBoxSize,
+    ) -> Result<Self> {
+        let mut planner = Planner::new(ctx.clone());
+        let plan = planner.plan_stmt(&stmt).await?;
+        Ok(Self::new(ctx, stmt, plan, display_width))
+    }
+
+    /// Returns a new frame for `query`, sharing the context of this one.
+    fn derive(&self, py: Python, query: Query) -> PyResult<Self> {
+        let stmt = Statement::Query(Box::new(query));
+        wait_for_future(
+            py,
+            Self::create(self.ctx.clone(), stmt, self.display_width.clone()),
+        )
+        .map_err(plan_error)
+    }
+
+    /// The query of this frame as a derived table named `alias`.
+    fn subquery(&self, alias: &str) -> PyResult<TableReference> {
+        let Statement::Query(query) = &self.stmt else {
+            return Err(PyValueError::new_err(format!(
+                "only the result of a query can be transformed, got: {}",
+                self.stmt
+            )));
+        };
+        Ok(TableReference::Subquery {
+            span: None,
+            lateral: false,
+            subquery: query.clone(),
+            alias: Some(TableAlias {
+                name: ident(alias),
+                columns: vec![],
+            }),
+            pivot: None,
+            unpivot: None,
+        })
+    }
+
+    /// Parses `text` with `parser`, in the SQL dialect of the session.
+    fn parse<O>(&self, text: &str, parser: impl FnMut(Input) -> IResult<O>) -> PyResult<O> {
+        let res: Result<O> = try {
+            let dialect = self.ctx.get_settings().get_sql_dialect()?;
+            let tokens = tokenize_sql(text)?;
+            run_parser(&tokens, dialect, ParseMode::Default, false, parser)?
+        };
+        res.map_err(|err| PyValueError::new_err(format!("invalid expression {text:?}: {err}")))
+    }
+
+    fn parse_all<O>(
+        &self,
+        texts: &[String],
+        mut parser: impl FnMut(Input) -> IResult<O>,
+    ) -> PyResult<Vec<O>> {
+        texts
+            .iter()
+            .map(|text| self.parse(text, &mut parser))
+            .collect()
+    }
+
+    fn column_names(&self) -> Vec<String> {
+        let schema = self.plan.schema();
+        schema.fields().iter().map(|f| f.name().clone()).collect()
+    }
+
+    async fn df_execute(&self) -> Result<(DataSchemaRef, SendableDataBlockStream)> {
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &self.plan).await?;
         let stream = interpreter.execute(self.ctx.clone()).await?;
-        stream.collect::<Result<Vec<_>>>().await
+        Ok((self.plan.schema(), stream))
+    }
+
+    async fn df_collect(&self) -> Result<(DataSchemaRef, Vec<DataBlock>)> {
+        let (schema, stream) = self.df_execute().await?;
+        let blocks = stream.collect::<Result<Vec<_>>>().await?;
+        Ok((schema, blocks))
+    }
+}
+
+fn plan_error(err: ErrorCode) -> PyErr {
+    PyRuntimeError::new_err(format!("DataFrame plan error: {:?}", err))
+}
+
+fn collect_error(err: ErrorCode) -> PyErr {
+    PyRuntimeError::new_err(format!("DataFrame collect error: {:?}", err))
+}
+
+fn extract_exprs(exprs: &PyTuple) -> PyResult<Vec<String>> {
+    let exprs: Vec<String> = exprs.extract()?;
+    if exprs.is_empty() {
+        return Err(PyValueError::new_err("at least one expression is required"));
+    }
+    Ok(exprs)
+}
+
+fn ident(name: &str) -> Identifier {
+    Identifier::from_name_with_quoted(None, name, Some('`'))
+}
+
+fn column(table: &str, name: &str) -> Expr {
+    Expr::ColumnRef {
+        span: None,
+        column: ColumnRef {
+            database: None,
+            table: Some(ident(table)),
+            column: ColumnID::Name(ident(name)),
+        },
+    }
+}
+
+/// `table.*`
+fn star(table: &str) -> SelectTarget {
+    SelectTarget::StarColumns {
+        qualified: vec![
+            Indirection::Identifier(ident(table)),
+            Indirection::Star(None),
+        ],
+        column_filter: None,
+    }
+}
+
+fn select(select_list: Vec<SelectTarget>, from: TableReference) -> SelectStmt {
+    SelectStmt {
+        span: None,
+        hints: None,
+        distinct: false,
+        top_n: None,
+        select_list,
+        from: vec![from],
+        selection: None,
+        group_by: None,
+        having: None,
+        window_list: None,
+        qualify: None,
+    }
+}
+
+fn query(select: SelectStmt) -> Query {
+    Query {
+        span: None,
+        with: None,
+        body: SetExpr::Select(Box::new(select)),
+        order_by: vec![],
+        limit: vec![],
+        offset: None,
+        ignore_result: false,
+    }
+}
+
+/// `SELECT * FROM name`, reading the table `name` of the current database.
+pub(crate) fn scan_table(name: &str) -> Statement {
+    let table = TableReference::Table {
+        span: None,
+        catalog: None,
+        database: None,
+        table: ident(name),
+        alias: None,
+        temporal: None,
+        with_options: None,
+        pivot: None,
+        unpivot: None,
+        sample: None,
+    };
+    let star = SelectTarget::StarColumns {
+        qualified: vec![Indirection::Star(None)],
+        column_filter: None,
+    };
+    Statement::Query(Box::new(query(select(vec![star], table))))
+}
+
+fn number(value: u64) -> Expr {
+    Expr::Literal {
+        span: None,
+        value: Literal::UInt64(value),
     }
 }
 
@@ -84,19 +301,152 @@ impl PyDataFrame {
     }
 
     pub fn collect(&self, py: Python) -> PyResult<PyDataBlocks> {
-        let blocks = wait_for_future(py, self.df_collect());
+        let res = wait_for_future(py, self.df_collect());
         let display_width = self.get_box();
-        let blocks = blocks.map_err(|err| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("DataFrame collect error: {:?}", err))
-        })?;
+        let (schema, blocks) = res.map_err(collect_error)?;
 
         Ok(PyDataBlocks {
             blocks,
-            schema: self.df.schema(),
+            schema,
             display_width,
         })
     }
 
+    /// Execute the query and return an iterator of pyarrow RecordBatches,
+    /// blocks are pulled from the query one at a time instead of being collected.
+    fn iter_batches(&self, py: Python) -> PyResult<PyRecordBatchIterator> {
+        let (schema, stream) = wait_for_future(py, self.df_execute()).map_err(collect_error)?;
+        Ok(PyRecordBatchIterator { schema, stream })
+    }
+
+    /// The SQL text this frame evaluates.
+    fn to_sql(&self) -> String {
+        self.stmt.to_string()
+    }
+
+    /// Project the given expressions, e.g. `df.select("a", "b + 1 AS c")`.
+    #[pyo3(signature = (*exprs))]
+    fn select(&self, exprs: &PyTuple, py: Python) -> PyResult<Self> {
+        let select_list = self.parse_all(&extract_exprs(exprs)?, select_target)?;
+        self.derive(py, query(select(select_list, self.subquery("_t")?)))
+    }
+
+    /// Keep the rows matching `predicate`, e.g. `df.filter("a > 1")`.
+    fn filter(&self, predicate: &str, py: Python) -> PyResult<Self> {
+        let mut stmt = select(vec![star("_t")], self.subquery("_t")?);
+        stmt.selection = Some(self.parse(predicate, expr)?);
+        self.derive(py, query(stmt))
+    }
+
+    /// Join with another frame. The two sides are aliased as `l` and `r`,
+    /// e.g. `a.join(b, "l.id = r.id", how="left")`.
+    ///
+    /// `how` is one of `inner`, `left`, `right`, `full`, `semi`, `anti` or `cross`,
+    /// `on` can be omitted only for a cross join.
+    ///
+    /// The result has the columns of `l` followed by the columns of `r`, a column
+    /// of `r` whose name is taken by `l` is renamed with the prefix `r_`.
+    /// Semi and anti joins only return the columns of `l`.
+    #[pyo3(signature = (other, on = None, how = "inner"))]
+    fn join(&self, other: &PyDataFrame, on: Option<&str>, how: &str, py: Python) -> PyResult<Self> {
+        let op = match how.to_lowercase().as_str() {
+            "inner" => JoinOperator::Inner,
+            "left" => JoinOperator::LeftOuter,
+            "right" => JoinOperator::RightOuter,
+            "full" | "outer" => JoinOperator::FullOuter,
+            "semi" => JoinOperator::LeftSemi,
+            "anti" => JoinOperator::LeftAnti,
+            "cross" => JoinOperator::CrossJoin,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "unsupported join type: {how}"
+                )));
+            }
+        };
+
+        let condition = match (&op, on) {
+            (JoinOperator::CrossJoin, None) => JoinCondition::None,
+            (JoinOperator::CrossJoin, Some(_)) => {
+                return Err(PyValueError::new_err("cross join doesn't take a condition"));
+            }
+            (_, Some(on)) => JoinCondition::On(Box::new(self.parse(on, expr)?)),
+            (_, None) => {
+                return Err(PyValueError::new_err(format!(
+                    "{how} join requires a condition"
+                )));
+            }
+        };
+
+        let mut select_list = vec![star("l")];
+        if !matches!(op, JoinOperator::LeftSemi | JoinOperator::LeftAnti) {
+            let mut names = self.column_names().into_iter().collect::<HashSet<_>>();
+            for name in other.column_names() {
+                let mut alias = name.clone();
+                while names.contains(&alias) {
+                    alias = format!("r_{alias}");
+                }
+                select_list.push(SelectTarget::AliasedExpr {
+                    expr: Box::new(column("r", &name)),
+                    alias: (alias != name).then(|| ident(&alias)),
+                });
+                names.insert(alias);
+            }
+        }
+
+        let join = TableReference::Join {
+            span: None,
+            join: Join {
+                op,
+                condition,
+                match_condition: None,
+                left: Box::new(self.subquery("l")?),
+                right: Box::new(other.subquery("r")?),
+            },
+        };
+        self.derive(py, query(select(select_list, join)))
+    }
+
+    /// Group by `keys` and compute `aggs`, e.g. `df.group_by(["a"], ["sum(b) AS s"])`.
+    /// With empty `keys` the aggregates are computed over all rows.
+    fn group_by(&self, keys: Vec<String>, aggs: Vec<String>, py: Python) -> PyResult<Self> {
+        if aggs.is_empty() && keys.is_empty() {
+            return Err(PyValueError::new_err(
+                "group_by requires at least one key or aggregate",
+            ));
+        }
+        let keys = self.parse_all(&keys, expr)?;
+        let mut select_list = keys
+            .iter()
+            .map(|key| SelectTarget::AliasedExpr {
+                expr: Box::new(key.clone()),
+                alias: None,
+            })
+            .collect::<Vec<_>>();
+        select_list.extend(self.parse_all(&aggs, select_target)?);
+
+        let mut stmt = select(select_list, self.subquery("_t")?);
+        if !keys.is_empty() {
+            stmt.group_by = Some(GroupBy::Normal(keys));
+        }
+        self.derive(py, query(stmt))
+    }
+
+    /// Sort by the given expressions, e.g. `df.sort("a DESC", "b")`.
+    #[pyo3(signature = (*exprs))]
+    fn sort(&self, exprs: &PyTuple, py: Python) -> PyResult<Self> {
+        let mut query = query(select(vec![star("_t")], self.subquery("_t")?));
+        query.order_by = self.parse_all(&extract_exprs(exprs)?, order_by_expr)?;
+        self.derive(py, query)
+    }
+
+    #[pyo3(signature = (num, offset = 0))]
+    fn limit(&self, num: u64, offset: u64, py: Python) -> PyResult<Self> {
+        let mut query = query(select(vec![star("_t")], self.subquery("_t")?));
+        query.limit = vec![number(num)];
+        query.offset = Some(number(offset));
+        self.derive(py, query)
+    }
+
     pub fn get_box(&self) -> PyBoxSize {
         self.display_width.clone()
     }
@@ -113,24 +463,22 @@ impl PyDataFrame {
         self.display_width.bs_max_col_width = max_col_width;
     }
 
-    pub fn schema(&self) -> PySchema {
-        PySchema {
-            schema: self.df.schema(),
-        }
+    pub fn schema(&self) -> PyResult<PySchema> {
+        Ok(PySchema {
+            schema: self.plan.schema(),
+        })
     }
 
     pub fn to_py_arrow(&self, py: Python) -> PyResult<Vec<PyObject>> {
-        let blocks = wait_for_future(py, self.df_collect());
-        let blocks = blocks.map_err(|err| {
-            pyo3::exceptions::PyRuntimeError::new_err(format!("DataFrame collect error: {:?}", err))
-        })?;
+        let res = wait_for_future(py, self.df_collect());
+        let (schema, blocks) = res.map_err(collect_error)?;
 
         blocks
             .into_iter()
             .map(|block| {
                 block
-                    .to_record_batch_with_dataschema(self.df.schema().as_ref())
-                    .unwrap()
+                    .to_record_batch_with_dataschema(schema.as_ref())
+                    .map_err(collect_error)?
                     .to_pyarrow(py)
             })
             .collect()
@@ -140,7 +488,7 @@ impl PyDataFrame {
     /// Collect the batches and pass to Arrow Table
     pub fn to_arrow_table(&self, py: Python) -> PyResult<PyObject> {
         let batches = self.to_py_arrow(py)?.to_object(py);
-        let schema = ArrowSchema::from(self.df.schema().as_ref());
+        let schema = ArrowSchema::from(self.plan.schema().as_ref());
         let schema = PyArrowType(schema);
         let schema = schema.into_py(py);
 
@@ -179,6 +527,33 @@ impl PyDataFrame {
     }
 }
 
+/// Iterator returned by `DataFrame.iter_batches()`.
+#[pyclass(name = "RecordBatchIterator", module = "databend")]
+pub(crate) struct PyRecordBatchIterator {
+    schema: DataSchemaRef,
+    stream: SendableDataBlockStream,
+}
+
+#[pymethods]
+impl PyRecordBatchIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>, py: Python) -> PyResult<Option<PyObject>> {
+        let next = wait_for_future(py, slf.stream.next());
+        match next {
+            None => Ok(None),
+            Some(block) => {
+                let batch = block
+                    .and_then(|b| b.to_record_batch_with_dataschema(slf.schema.as_ref()))
+                    .map_err(collect_error)?;
+                Ok(Some(batch.to_pyarrow(py)?))
+            }
+        }
+    }
+}
+
 pub(crate) fn default_box_size() -> PyBoxSize {
     PyBoxSize {
         bs_max_display_rows: 40,
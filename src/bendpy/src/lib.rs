@@ -65,5 +65,7 @@ fn databend(_py: Python, m: &PyModule) -> PyResult<()> {
     });
 
     m.add_class::<context::PySessionContext>()?;
+    m.add_class::<dataframe::PyDataFrame>()?;
+    m.add_class::<dataframe::PyRecordBatchIterator>()?;
     Ok(())
 }
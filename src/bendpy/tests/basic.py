@@ -16,6 +16,7 @@
 from databend import SessionContext
 import pandas as pd
 import polars
+import pytest
 
 class TestBasic:
     ctx = SessionContext()
@@ -43,3 +44,58 @@ class TestBasic:
 
         df = self.ctx.sql("select sum(a) x, max(b) y, max(d) z from aa where c").to_polars()
         assert df.to_pandas().values.tolist() == [[90.0, '9', 9.0]]
+
+    def test_register_arrow(self):
+        self.ctx.register_arrow("pd_t", pd.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}))
+        df = self.ctx.sql("select a, b from pd_t where a > 1 order by a").to_pandas()
+        assert df.values.tolist() == [[2, 'y'], [3, 'z']]
+
+        self.ctx.register_arrow("pl_t", polars.DataFrame({"a": [1, 2], "c": [10.0, 20.0]}))
+        df = self.ctx.sql("select sum(c) from pl_t").to_pandas()
+        assert df.values.tolist() == [[30.0]]
+
+        # registering again replaces the data
+        self.ctx.register_arrow("pl_t", polars.DataFrame({"a": [5], "c": [1.0]}))
+        df = self.ctx.sql("select a, c from pl_t").to_pandas()
+        assert df.values.tolist() == [[5, 1.0]]
+
+    def test_lazy_dataframe(self):
+        self.ctx.register_arrow("users", pd.DataFrame({"id": [1, 2, 3], "name": ["a", "b", "c"]}))
+        self.ctx.register_arrow("orders", pd.DataFrame({"user_id": [1, 1, 2, 3], "amount": [5, 15, 30, 1]}))
+
+        df = (
+            self.ctx.table("orders")
+            .filter("amount > 2")
+            .join(self.ctx.table("users"), "l.user_id = r.id")
+            .group_by(["name"], ["sum(amount) AS total"])
+            .sort("total DESC")
+            .limit(1)
+        )
+        assert df.to_pandas().values.tolist() == [['b', 30]]
+
+        df = self.ctx.table("users").select("id", "name").filter("id = 3")
+        assert df.to_pandas().values.tolist() == [[3, 'c']]
+
+        df = self.ctx.table("users").join(self.ctx.table("orders"), "l.id = r.user_id", how="anti")
+        assert df.to_pandas().values.tolist() == []
+
+        df = self.ctx.table("users").join(self.ctx.table("users"), "l.id = r.id").sort("id")
+        assert df.to_pandas().columns.tolist() == ["id", "name", "r_id", "r_name"]
+        assert df.to_pandas().values.tolist() == [[1, 'a', 1, 'a'], [2, 'b', 2, 'b'], [3, 'c', 3, 'c']]
+
+        # mistakes are reported by the call that made them
+        with pytest.raises(Exception):
+            self.ctx.table("users").filter("no_such_column > 1")
+        with pytest.raises(Exception):
+            self.ctx.table("users").filter("id = 1) UNION ALL (SELECT 1, 'x'")
+
+    def test_quoted_names(self):
+        self.ctx.register_arrow("my table", pd.DataFrame({"a b": [1, 2]}))
+        df = self.ctx.table("my table").select("`a b`")
+        assert df.to_pandas().values.tolist() == [[1], [2]]
+
+    def test_iter_batches(self):
+        total = 0
+        for batch in self.ctx.sql("select number from numbers(100000)").iter_batches():
+            total += batch.num_rows
+        assert total == 100000
@@ -12,22 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
+use anyhow::anyhow;
 use anyhow::Result;
-use futures::SinkExt;
+use bytes::Bytes;
+use chrono::Utc;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use log::info;
 use opendal::Operator;
 
+use crate::manifest::BackupManifest;
+use crate::manifest::BackupObject;
+use crate::manifest::MetaDeltaBuilder;
+use crate::manifest::MetaSegment;
+use crate::manifest::MetaSegmentKind;
+use crate::manifest::MAX_META_DELTA_CHAIN;
 use crate::storage::init_query;
 use crate::storage::load_bendsave_storage;
 use crate::storage::load_databend_meta;
 use crate::storage::load_query_config;
 use crate::storage::load_query_storage;
 use crate::storage::verify_query_license;
-use crate::utils::storage_copy;
-use crate::utils::DATABEND_META_BACKUP_PATH;
+use crate::utils::copy_object;
+use crate::utils::ChecksumWriter;
 
-pub async fn backup(from: &str, to: &str) -> Result<()> {
+/// Backup the cluster into `to`.
+///
+/// Unless `full` is set, the backup is based on the latest manifest at `to`:
+/// only objects and meta entries changed since then are copied.
+pub async fn backup(from: &str, to: &str, full: bool) -> Result<()> {
     let query_cfg = load_query_config(from)?;
     init_query(&query_cfg)?;
     verify_query_license(&query_cfg).await?;
@@ -35,26 +50,149 @@ pub async fn backup(from: &str, to: &str) -> Result<()> {
 
     let bendsave_storage = load_bendsave_storage(to).await?;
 
+    let parent = if full {
+        None
+    } else {
+        BackupManifest::load_or_latest(&bendsave_storage, None).await?
+    };
+    let created_at = Utc::now();
+    let id = BackupManifest::new_id(created_at);
+    if bendsave_storage
+        .exists(&BackupManifest::location(&id))
+        .await?
+    {
+        return Err(anyhow!("backup {id} already exists"));
+    }
+    match &parent {
+        Some(parent) => info!("incremental backup {id} based on {}", parent.id),
+        None => info!("full backup {id}"),
+    }
+
     // backup metadata first.
-    backup_meta(bendsave_storage.clone()).await?;
-    storage_copy(databend_storage, bendsave_storage).await?;
+    let meta = backup_meta(&bendsave_storage, &id, parent.as_ref()).await?;
+    let objects =
+        backup_objects(&databend_storage, &bendsave_storage, &id, parent.as_ref()).await?;
+
+    let manifest = BackupManifest {
+        id,
+        parent: parent.map(|p| p.id),
+        created_at,
+        meta,
+        objects,
+    };
+    // The manifest is written last, a backup without manifest is never used.
+    manifest.save(&bendsave_storage).await?;
 
-    info!("databend backup has been finished");
+    info!("databend backup {} has been finished", manifest.id);
     Ok(())
 }
 
 /// Backup the entire databend meta to epochfs.
-pub async fn backup_meta(op: Operator) -> Result<()> {
+///
+/// If there is a parent manifest, only the entries changed since it are written.
+/// The exported meta is streamed into the segment, only the meta of the parent is
+/// loaded to build a delta.
+pub async fn backup_meta(
+    op: &Operator,
+    id: &str,
+    parent: Option<&BackupManifest>,
+) -> Result<Vec<MetaSegment>> {
     let (_client_handle, stream) = load_databend_meta().await?;
-    let mut stream = stream.map_err(std::io::Error::other);
+    let mut stream = Box::pin(stream);
 
-    let mut file = op
-        .writer_with(DATABEND_META_BACKUP_PATH)
-        .chunk(8 * 1024 * 1024)
-        .await?
-        .into_bytes_sink();
-    file.send_all(&mut stream).await?;
-    file.close().await?;
-    info!("databend meta has been backed up");
-    Ok(())
+    let (mut segments, prev) = match parent {
+        Some(parent) if parent.meta_delta_chain() < MAX_META_DELTA_CHAIN => {
+            (parent.meta.clone(), Some(parent.load_meta(op).await?))
+        }
+        _ => (vec![], None),
+    };
+    let mut delta = prev.as_deref().map(MetaDeltaBuilder::new);
+
+    let location = BackupManifest::meta_location(id);
+    let mut writer = ChecksumWriter::create(op, &location).await?;
+    while let Some(chunk) = stream.try_next().await? {
+        match &mut delta {
+            None => writer.write(chunk).await?,
+            Some(delta) => {
+                // Every chunk consists of whole lines.
+                let mut added = String::new();
+                for line in std::str::from_utf8(&chunk)?.lines() {
+                    delta.push(line, &mut added);
+                }
+                writer.write(Bytes::from(added)).await?;
+            }
+        }
+    }
+    let kind = match delta {
+        None => MetaSegmentKind::Full,
+        Some(delta) => {
+            writer.write(Bytes::from(delta.finish())).await?;
+            MetaSegmentKind::Delta
+        }
+    };
+    let (size, checksum) = writer.close().await?;
+    segments.push(MetaSegment {
+        location: location.clone(),
+        kind,
+        size,
+        checksum,
+    });
+
+    info!("databend meta has been backed up as {kind:?} segment {location}");
+    Ok(segments)
+}
+
+/// Copy the objects of databend storage that are not in the parent manifest.
+pub async fn backup_objects(
+    src: &Operator,
+    dst: &Operator,
+    id: &str,
+    parent: Option<&BackupManifest>,
+) -> Result<Vec<BackupObject>> {
+    let prev: HashMap<&str, &BackupObject> = parent
+        .map(|p| p.objects.iter().map(|o| (o.path.as_str(), o)).collect())
+        .unwrap_or_default();
+
+    let mut objects = vec![];
+    let mut copied = 0;
+    let mut list = src.lister_with("/").recursive(true).await?;
+    while let Some(entry) = list.next().await.transpose()? {
+        if entry.metadata().is_dir() {
+            continue;
+        }
+
+        let src_meta = src.stat(entry.path()).await?;
+        let etag = src_meta.etag().map(|v| v.to_string());
+        let last_modified = src_meta.last_modified().map(|v| v.to_string());
+
+        // Fuse objects are immutable, reuse the copy of previous backups.
+        if let Some(prev) = prev.get(entry.path()) {
+            if prev.size == src_meta.content_length()
+                && prev.etag == etag
+                && prev.last_modified == last_modified
+            {
+                objects.push((*prev).clone());
+                continue;
+            }
+        }
+
+        let location = BackupManifest::data_location(id, entry.path());
+        let (size, checksum) = copy_object(src, entry.path(), dst, &location).await?;
+        objects.push(BackupObject {
+            path: entry.path().to_string(),
+            location,
+            size,
+            etag,
+            last_modified,
+            checksum,
+        });
+        copied += 1;
+        info!("file {} has been copied", entry.path());
+    }
+
+    info!(
+        "{copied} objects copied, {} objects reused from previous backups",
+        objects.len() - copied
+    );
+    Ok(objects)
 }
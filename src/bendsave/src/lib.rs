@@ -15,7 +15,12 @@
 mod storage;
 pub mod utils;
 
+pub mod manifest;
+
 mod backup;
 pub use backup::backup;
 mod restore;
 pub use restore::restore;
+pub use restore::restore_selective;
+mod verify;
+pub use verify::verify;
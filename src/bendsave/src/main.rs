@@ -17,6 +17,8 @@ use clap::Parser;
 use clap::Subcommand;
 use databend_bendsave::backup;
 use databend_bendsave::restore;
+use databend_bendsave::restore_selective;
+use databend_bendsave::verify;
 use logforth::append;
 use logforth::filter::EnvFilter;
 use logforth::Dispatch;
@@ -40,23 +42,46 @@ enum Commands {
         /// Backup destination
         #[arg(long)]
         to: String,
+        /// Copy everything instead of only the changes since the latest backup
+        #[arg(long, default_value_t = false)]
+        full: bool,
     },
 
     /// Restore a Databend cluster from a backup
     Restore {
-        /// Backup manifest file path
+        /// Backup location
         #[arg(long)]
         from: String,
+        /// Backup manifest id to restore, defaults to the latest one
+        #[arg(long)]
+        manifest: Option<String>,
         /// Target configuration file path of databend query
         #[arg(long)]
         to_query: String,
-        /// Target configuration file path of databend meta
+        /// Target configuration file path of databend meta, required unless
+        /// restoring a single database or table
+        #[arg(long)]
+        to_meta: Option<String>,
+        /// Only restore this database into the running cluster
         #[arg(long)]
-        to_meta: String,
+        database: Option<String>,
+        /// Only restore this table of `--database` into the running cluster
+        #[arg(long, requires = "database")]
+        table: Option<String>,
         /// Confirm restoration and perform it immediately
         #[arg(long, default_value_t = false)]
         confirm: bool,
     },
+
+    /// Check that all files of a backup exist and match their checksums
+    Verify {
+        /// Backup location
+        #[arg(long)]
+        from: String,
+        /// Backup manifest id to verify, defaults to the latest one
+        #[arg(long)]
+        manifest: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -72,29 +97,66 @@ async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::Backup { from, to } => {
+        Commands::Backup { from, to, full } => {
             println!("Backing up from {} to {}", from, to);
-            backup(from, to).await?;
+            backup(from, to, *full).await?;
         }
         Commands::Restore {
             from,
+            manifest,
             to_query,
             to_meta,
+            database,
+            table,
             confirm,
         } => {
-            if *confirm {
-                println!(
-                    "Restoring from {} to query {} and meta {} with confirmation",
-                    from, to_query, to_meta
-                );
-                restore(from, to_query, to_meta).await?;
-            } else {
-                println!(
-                    "Dry-run restore from {} at query {} and meta {}",
-                    from, to_query, to_meta
-                );
+            let manifest = manifest.as_deref();
+            match (database, to_meta) {
+                (Some(database), _) => {
+                    let target = match table {
+                        Some(table) => format!("{database}.{table}"),
+                        None => database.clone(),
+                    };
+                    if *confirm {
+                        println!(
+                            "Restoring {} from {} to query {} with confirmation",
+                            target, from, to_query
+                        );
+                        restore_selective(from, manifest, to_query, database, table.as_deref())
+                            .await?;
+                    } else {
+                        println!(
+                            "Dry-run restore {} from {} at query {}",
+                            target, from, to_query
+                        );
+                    }
+                }
+                (None, Some(to_meta)) => {
+                    if *confirm {
+                        println!(
+                            "Restoring from {} to query {} and meta {} with confirmation",
+                            from, to_query, to_meta
+                        );
+                        restore(from, manifest, to_query, to_meta).await?;
+                    } else {
+                        println!(
+                            "Dry-run restore from {} at query {} and meta {}",
+                            from, to_query, to_meta
+                        );
+                    }
+                }
+                (None, None) => {
+                    return Err(anyhow::anyhow!(
+                        "--to-meta is required to restore the whole cluster"
+                    ));
+                }
             }
         }
+        Commands::Verify { from, manifest } => {
+            println!("Verifying backup at {}", from);
+            verify(from, manifest.as_deref()).await?;
+            println!("Backup at {} is valid", from);
+        }
     }
 
     Ok(())
@@ -0,0 +1,301 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use anyhow::anyhow;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use futures::TryStreamExt;
+use log::debug;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::utils::checksum;
+
+/// The directory that holds all manifests of a backup location.
+pub static MANIFEST_DIR: &str = "manifests/";
+
+/// The directory that holds the meta segments of all backups.
+pub static META_DIR: &str = "meta/";
+
+/// The directory that holds the data objects of all backups.
+pub static DATA_DIR: &str = "data/";
+
+/// Write a full meta segment once the delta chain grows beyond this length,
+/// so that restoring doesn't need to replay too many segments.
+pub const MAX_META_DELTA_CHAIN: usize = 32;
+
+/// A manifest describes everything needed to restore a cluster as of one
+/// backup run.
+///
+/// Objects that didn't change since the parent manifest are not copied again,
+/// the manifest refers to the location written by the earlier run instead.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BackupManifest {
+    /// The id of this backup, also the file name of the manifest.
+    pub id: String,
+    /// The backup this one is based on, `None` for a full backup.
+    pub parent: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Meta segments to apply in order, the first one is always a full export.
+    pub meta: Vec<MetaSegment>,
+    /// All objects of the databend storage at backup time.
+    pub objects: Vec<BackupObject>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MetaSegment {
+    /// Path of the segment in the backup storage.
+    pub location: String,
+    pub kind: MetaSegmentKind,
+    pub size: u64,
+    pub checksum: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetaSegmentKind {
+    /// One exported meta entry per line.
+    Full,
+    /// Lines prefixed by `+` are added, lines prefixed by `-` are removed
+    /// compared with the meta of the parent manifest.
+    Delta,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BackupObject {
+    /// Path of the object in the databend storage.
+    pub path: String,
+    /// Path of the object in the backup storage.
+    pub location: String,
+    pub size: u64,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// Hex encoded sha256 of the object content.
+    pub checksum: String,
+}
+
+impl BackupManifest {
+    /// Generate a new manifest id, ids sort in the order they are created.
+    pub fn new_id(now: DateTime<Utc>) -> String {
+        now.format("%Y%m%d_%H%M%S").to_string()
+    }
+
+    pub fn location(id: &str) -> String {
+        format!("{MANIFEST_DIR}{id}.manifest")
+    }
+
+    pub fn data_location(id: &str, path: &str) -> String {
+        format!("{DATA_DIR}{id}/{path}")
+    }
+
+    pub fn meta_location(id: &str) -> String {
+        format!("{META_DIR}{id}.db")
+    }
+
+    /// Returns the number of delta segments on top of the last full segment.
+    pub fn meta_delta_chain(&self) -> usize {
+        self.meta
+            .iter()
+            .rev()
+            .take_while(|s| s.kind == MetaSegmentKind::Delta)
+            .count()
+    }
+
+    pub async fn load(op: &Operator, id: &str) -> Result<Self> {
+        let bs = op.read(&Self::location(id)).await?;
+        let manifest = serde_json::from_slice(&bs.to_vec())?;
+        Ok(manifest)
+    }
+
+    pub async fn save(&self, op: &Operator) -> Result<()> {
+        let bs = serde_json::to_vec_pretty(self)?;
+        op.write(&Self::location(&self.id), bs).await?;
+        Ok(())
+    }
+
+    /// List the ids of all manifests in ascending order.
+    pub async fn list(op: &Operator) -> Result<Vec<String>> {
+        let entries: Vec<_> = op.lister(MANIFEST_DIR).await?.try_collect().await?;
+        let mut ids: Vec<String> = entries
+            .iter()
+            .filter_map(|e| e.name().strip_suffix(".manifest"))
+            .map(|id| id.to_string())
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+
+    /// Load the given manifest, or the latest one if `id` is `None`.
+    ///
+    /// Returns `None` if the backup location doesn't have any manifest,
+    /// which is the case for backups taken before manifests were introduced.
+    pub async fn load_or_latest(op: &Operator, id: Option<&str>) -> Result<Option<Self>> {
+        if let Some(id) = id {
+            return Ok(Some(Self::load(op, id).await?));
+        }
+        match Self::list(op).await?.last() {
+            Some(id) => Ok(Some(Self::load(op, id).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read all meta segments and rebuild the exported meta lines.
+    pub async fn load_meta(&self, op: &Operator) -> Result<Vec<String>> {
+        let mut lines = vec![];
+        for segment in &self.meta {
+            let bs = op.read(&segment.location).await?.to_vec();
+            if checksum(&bs) != segment.checksum {
+                return Err(anyhow!(
+                    "meta segment {} doesn't match its checksum",
+                    segment.location
+                ));
+            }
+            let content = String::from_utf8(bs)?;
+            match segment.kind {
+                MetaSegmentKind::Full => {
+                    lines = content.lines().map(|l| l.to_string()).collect();
+                }
+                MetaSegmentKind::Delta => apply_meta_delta(&mut lines, &content)?,
+            }
+            debug!("meta segment {} has been loaded", segment.location);
+        }
+        Ok(lines)
+    }
+}
+
+/// Builds the delta segment that turns `prev` into the current meta, which is
+/// pushed line by line so that it never has to be held in memory.
+pub struct MetaDeltaBuilder<'a> {
+    prev: &'a [String],
+    prev_set: HashSet<&'a str>,
+    kept: HashSet<&'a str>,
+}
+
+impl<'a> MetaDeltaBuilder<'a> {
+    pub fn new(prev: &'a [String]) -> Self {
+        Self {
+            prev,
+            prev_set: prev.iter().map(|l| l.as_str()).collect(),
+            kept: HashSet::new(),
+        }
+    }
+
+    /// Push a line of the current meta, appending it to `delta` if it's added.
+    pub fn push(&mut self, line: &str, delta: &mut String) {
+        match self.prev_set.get(line) {
+            Some(prev) => {
+                self.kept.insert(prev);
+            }
+            None => {
+                delta.push('+');
+                delta.push_str(line);
+                delta.push('\n');
+            }
+        }
+    }
+
+    /// Returns the delta lines of the lines removed from `prev`.
+    pub fn finish(self) -> String {
+        let mut delta = String::new();
+        for line in self.prev.iter().filter(|l| !self.kept.contains(l.as_str())) {
+            delta.push('-');
+            delta.push_str(line);
+            delta.push('\n');
+        }
+        delta
+    }
+}
+
+/// Compute the delta segment that turns `prev` into `curr`.
+pub fn build_meta_delta(prev: &[String], curr: &[String]) -> String {
+    let mut builder = MetaDeltaBuilder::new(prev);
+    let mut delta = String::new();
+    for line in curr {
+        builder.push(line, &mut delta);
+    }
+    delta.push_str(&builder.finish());
+    delta
+}
+
+/// Apply a delta segment built by [`build_meta_delta`] on `lines`.
+pub fn apply_meta_delta(lines: &mut Vec<String>, delta: &str) -> Result<()> {
+    let mut removed = HashSet::new();
+    let mut added = vec![];
+    for line in delta.lines() {
+        if let Some(line) = line.strip_prefix('-') {
+            removed.insert(line);
+        } else if let Some(line) = line.strip_prefix('+') {
+            added.push(line.to_string());
+        } else {
+            return Err(anyhow!("invalid meta delta line: {line}"));
+        }
+    }
+    lines.retain(|l| !removed.contains(l.as_str()));
+    lines.extend(added);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meta_delta() -> Result<()> {
+        let prev: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let curr: Vec<String> = ["a", "c", "d"].iter().map(|s| s.to_string()).collect();
+
+        let delta = build_meta_delta(&prev, &curr);
+        assert_eq!(delta, "+d\n-b\n");
+
+        let mut lines = prev.clone();
+        apply_meta_delta(&mut lines, &delta)?;
+        assert_eq!(lines, curr);
+
+        let mut lines = prev.clone();
+        apply_meta_delta(&mut lines, &build_meta_delta(&prev, &prev))?;
+        assert_eq!(lines, prev);
+
+        assert!(apply_meta_delta(&mut lines, "b\n").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_meta_delta_chain() {
+        let segment = |kind| MetaSegment {
+            location: "meta/x.db".to_string(),
+            kind,
+            size: 0,
+            checksum: "".to_string(),
+        };
+        let manifest = BackupManifest {
+            id: "20250115_201500".to_string(),
+            parent: None,
+            created_at: Utc::now(),
+            meta: vec![
+                segment(MetaSegmentKind::Full),
+                segment(MetaSegmentKind::Delta),
+                segment(MetaSegmentKind::Delta),
+            ],
+            objects: vec![],
+        };
+        assert_eq!(manifest.meta_delta_chain(), 2);
+        assert_eq!(
+            BackupManifest::location(&manifest.id),
+            "manifests/20250115_201500.manifest"
+        );
+    }
+}
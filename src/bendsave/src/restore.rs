@@ -12,23 +12,59 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
 use anyhow::Result;
+use databend_common_meta_api::deserialize_struct;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseId;
+use databend_common_meta_app::schema::DatabaseIdHistoryIdent;
+use databend_common_meta_app::schema::DatabaseIdToName;
+use databend_common_meta_app::schema::TableId;
+use databend_common_meta_app::schema::TableIdHistoryIdent;
+use databend_common_meta_app::schema::TableIdToName;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_client::ClientHandle;
+use databend_common_meta_client::MetaGrpcClient;
 use databend_common_meta_control::args::ImportArgs;
 use databend_common_meta_control::import::import_data;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use databend_storages_common_table_meta::meta::parse_storage_prefix;
 use futures::StreamExt;
 use log::info;
 use opendal::Operator;
 use tokio::io::AsyncWriteExt;
 
+use crate::manifest::BackupManifest;
+use crate::manifest::BackupObject;
 use crate::storage::init_query;
 use crate::storage::load_bendsave_storage;
 use crate::storage::load_meta_config;
 use crate::storage::load_query_config;
 use crate::storage::load_query_storage;
+use crate::utils::copy_object;
 use crate::utils::storage_copy;
 use crate::utils::DATABEND_META_BACKUP_PATH;
 
-pub async fn restore(from: &str, to_query: &str, to_meta: &str) -> Result<()> {
+/// The max number of puts in one transaction to advance the id generators.
+const MAX_ADVANCE_ID_OPS: u64 = 1000;
+
+/// Restore the whole cluster from the given manifest, or the latest one.
+pub async fn restore(
+    from: &str,
+    manifest: Option<&str>,
+    to_query: &str,
+    to_meta: &str,
+) -> Result<()> {
     let bendsave_storage = load_bendsave_storage(from).await?;
 
     let query_cfg = load_query_config(to_query)?;
@@ -37,8 +73,247 @@ pub async fn restore(from: &str, to_query: &str, to_meta: &str) -> Result<()> {
 
     let meta_config = load_meta_config(to_meta)?;
 
-    storage_copy(bendsave_storage.clone(), databend_storage).await?;
-    restore_meta(bendsave_storage, &meta_config).await?;
+    let Some(manifest) = BackupManifest::load_or_latest(&bendsave_storage, manifest).await? else {
+        // Backups taken before manifests were introduced store everything at the root.
+        storage_copy(bendsave_storage.clone(), databend_storage).await?;
+        restore_meta(bendsave_storage, &meta_config).await?;
+        return Ok(());
+    };
+    info!("restore from backup {}", manifest.id);
+
+    restore_objects(
+        &bendsave_storage,
+        &databend_storage,
+        manifest.objects.iter(),
+    )
+    .await?;
+    let lines = manifest.load_meta(&bendsave_storage).await?;
+    write_local_meta(lines.join("\n").as_bytes()).await?;
+    import_meta(&meta_config).await?;
+    Ok(())
+}
+
+/// Restore a single database, or a single table of it, into a running cluster.
+///
+/// The data objects are copied back first, then the meta keys of the database
+/// or table are written in one transaction, so the restored tables never become
+/// visible without their data. The transaction fails if any of the keys is
+/// changed after it has been checked.
+pub async fn restore_selective(
+    from: &str,
+    manifest: Option<&str>,
+    to_query: &str,
+    database: &str,
+    table: Option<&str>,
+) -> Result<()> {
+    let bendsave_storage = load_bendsave_storage(from).await?;
+
+    let query_cfg = load_query_config(to_query)?;
+    init_query(&query_cfg)?;
+    let databend_storage = load_query_storage(&query_cfg)?;
+
+    let manifest = BackupManifest::load_or_latest(&bendsave_storage, manifest)
+        .await?
+        .ok_or_else(|| anyhow!("selective restore requires a backup with manifest"))?;
+    let kvs = load_generic_kvs(&manifest.load_meta(&bendsave_storage).await?)?;
+
+    let client = MetaGrpcClient::try_new(&query_cfg.meta.to_meta_grpc_client_conf())?;
+    let tenant = &query_cfg.query.tenant_id;
+
+    let db_name_key = DatabaseNameIdent::new(tenant, database).to_string_key();
+    let db_id = backup_id(&kvs, &db_name_key)?
+        .ok_or_else(|| anyhow!("database {database} not found in backup {}", manifest.id))?;
+    // The seqs of the live keys, to write them only if they are not changed meanwhile.
+    let mut seqs = BTreeMap::new();
+    let live_db_id = live_id(&client, &db_name_key, &mut seqs).await?;
+
+    let mut keys = vec![];
+    let mut prefixes = vec![];
+    let mut max_id = db_id;
+    match table {
+        None => {
+            if live_db_id.is_some_and(|id| id != db_id) {
+                return Err(anyhow!(
+                    "database {database} in the target cluster is not the one in backup, drop or rename it first"
+                ));
+            }
+            keys.push(db_name_key);
+            keys.push(DatabaseId::new(db_id).to_string_key());
+            keys.push(DatabaseIdToName::new(db_id).to_string_key());
+            keys.push(DatabaseIdHistoryIdent::new(tenant, database).to_string_key());
+            for key in kvs.keys() {
+                let Ok(name) = DBIdTableName::from_str_key(key) else {
+                    continue;
+                };
+                if name.db_id != db_id {
+                    continue;
+                }
+                let table_id = backup_id(&kvs, key)?.unwrap_or_default();
+                max_id = max_id.max(table_id);
+                prefixes.push(table_prefix(&kvs, table_id)?);
+                keys.extend(table_keys(db_id, &name.table_name, table_id));
+            }
+        }
+        Some(table) => {
+            if live_db_id != Some(db_id) {
+                return Err(anyhow!(
+                    "database {database} in the target cluster is not the one in backup, restore the whole database instead"
+                ));
+            }
+            let table_name_key = DBIdTableName::new(db_id, table).to_string_key();
+            let table_id = backup_id(&kvs, &table_name_key)?.ok_or_else(|| {
+                anyhow!(
+                    "table {database}.{table} not found in backup {}",
+                    manifest.id
+                )
+            })?;
+            if live_id(&client, &table_name_key, &mut seqs)
+                .await?
+                .is_some_and(|id| id != table_id)
+            {
+                return Err(anyhow!(
+                    "table {database}.{table} in the target cluster is not the one in backup, drop or rename it first"
+                ));
+            }
+            max_id = max_id.max(table_id);
+            prefixes.push(table_prefix(&kvs, table_id)?);
+            keys.extend(table_keys(db_id, table, table_id));
+        }
+    }
+
+    let objects = manifest
+        .objects
+        .iter()
+        .filter(|o| prefixes.iter().any(|p| o.path.starts_with(p.as_str())));
+    restore_objects(&bendsave_storage, &databend_storage, objects).await?;
+
+    let mut conditions = vec![];
+    let mut ops = vec![];
+    for key in &keys {
+        let Some(value) = kvs.get(key) else {
+            continue;
+        };
+        let seq = match seqs.get(key) {
+            Some(seq) => *seq,
+            None => client.get_kv(key).await?.map_or(0, |v| v.seq),
+        };
+        conditions.push(TxnCondition::eq_seq(key, seq));
+        ops.push(TxnOp::put(key, value.clone()));
+    }
+    let reply = client.transaction(TxnRequest::new(conditions, ops)).await?;
+    if !reply.success {
+        return Err(anyhow!(
+            "restore meta failed, {database} has been changed during the restore, please retry"
+        ));
+    }
+    advance_id_generators(&client, max_id).await?;
+
+    info!(
+        "{database}{} has been restored from backup {}",
+        table.map(|t| format!(".{t}")).unwrap_or_default(),
+        manifest.id
+    );
+    Ok(())
+}
+
+/// The meta keys that make up a table.
+fn table_keys(db_id: u64, table: &str, table_id: u64) -> Vec<String> {
+    vec![
+        DBIdTableName::new(db_id, table).to_string_key(),
+        TableId::new(table_id).to_string_key(),
+        TableIdToName { table_id }.to_string_key(),
+        TableIdHistoryIdent {
+            database_id: db_id,
+            table_name: table.to_string(),
+        }
+        .to_string_key(),
+    ]
+}
+
+/// The storage prefix of a table's objects, with a trailing `/`.
+fn table_prefix(kvs: &BTreeMap<String, Vec<u8>>, table_id: u64) -> Result<String> {
+    let key = TableId::new(table_id).to_string_key();
+    let value = kvs
+        .get(&key)
+        .ok_or_else(|| anyhow!("table meta {key} not found in backup"))?;
+    let meta: TableMeta = deserialize_struct(value)?;
+    let prefix = parse_storage_prefix(&meta.options, table_id)?;
+    Ok(format!("{}/", prefix.trim_end_matches('/')))
+}
+
+/// Collect the generic kv entries from exported meta lines.
+fn load_generic_kvs(lines: &[String]) -> Result<BTreeMap<String, Vec<u8>>> {
+    let mut kvs = BTreeMap::new();
+    for line in lines {
+        let (_tree, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+        if let RaftStoreEntry::GenericKV { key, value } = entry {
+            kvs.insert(key, value.data);
+        }
+    }
+    Ok(kvs)
+}
+
+/// Ids are stored as json encoded u64.
+fn backup_id(kvs: &BTreeMap<String, Vec<u8>>, key: &str) -> Result<Option<u64>> {
+    match kvs.get(key) {
+        Some(v) => Ok(Some(serde_json::from_slice(v)?)),
+        None => Ok(None),
+    }
+}
+
+async fn live_id(
+    client: &ClientHandle,
+    key: &str,
+    seqs: &mut BTreeMap<String, u64>,
+) -> Result<Option<u64>> {
+    let value = client.get_kv(key).await?;
+    seqs.insert(key.to_string(), value.as_ref().map_or(0, |v| v.seq));
+    match value {
+        Some(v) => Ok(Some(serde_json::from_slice(&v.data)?)),
+        None => Ok(None),
+    }
+}
+
+/// The ids are allocated from the global seq of the meta service. Advance it
+/// beyond the restored ids, so that the ids allocated later never collide with
+/// them, each put of a generator key increases the seq by one.
+async fn advance_id_generators(client: &ClientHandle, max_id: u64) -> Result<()> {
+    let key = IdGenerator::table_id().to_string_key();
+    let mut seq = fetch_id(client, IdGenerator::table_id()).await?;
+    while seq <= max_id {
+        let n = (max_id - seq).min(MAX_ADVANCE_ID_OPS);
+        let ops = (0..n).map(|_| TxnOp::put(&key, vec![])).collect();
+        client.transaction(TxnRequest::unconditional(ops)).await?;
+        seq = fetch_id(client, IdGenerator::table_id()).await?;
+    }
+    info!("id generators have been advanced to {seq}");
+    Ok(())
+}
+
+/// Copy the objects back to their original path and check their checksums.
+pub async fn restore_objects<'a>(
+    efs: &Operator,
+    databend_storage: &Operator,
+    objects: impl Iterator<Item = &'a BackupObject>,
+) -> Result<()> {
+    let mut count = 0;
+    for object in objects {
+        let (size, checksum) =
+            copy_object(efs, &object.location, databend_storage, &object.path).await?;
+        if size != object.size || checksum != object.checksum {
+            return Err(anyhow!(
+                "object {} doesn't match the manifest, expect size {} checksum {}, got size {} checksum {}",
+                object.location,
+                object.size,
+                object.checksum,
+                size,
+                checksum
+            ));
+        }
+        count += 1;
+        info!("file {} has been restored", object.path);
+    }
+    info!("{count} objects have been restored");
     Ok(())
 }
 
@@ -58,6 +333,18 @@ pub async fn restore_meta(efs: Operator, meta_cfg: &databend_meta::configs::Conf
     local_file.sync_all().await?;
     local_file.shutdown().await?;
 
+    import_meta(meta_cfg).await
+}
+
+async fn write_local_meta(content: &[u8]) -> Result<()> {
+    let mut local_file = tokio::fs::File::create_new(DATABEND_META_BACKUP_PATH).await?;
+    local_file.write_all(content).await?;
+    local_file.sync_all().await?;
+    local_file.shutdown().await?;
+    Ok(())
+}
+
+async fn import_meta(meta_cfg: &databend_meta::configs::Config) -> Result<()> {
     let import_args = ImportArgs {
         raft_dir: Some(meta_cfg.raft_config.raft_dir.to_string()),
         db: DATABEND_META_BACKUP_PATH.to_string(),
@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use anyhow::Result;
+use bytes::Bytes;
 use futures::SinkExt;
 use futures::StreamExt;
 use log::info;
 use opendal::Operator;
+use opendal::Writer;
+use sha2::Digest;
+use sha2::Sha256;
 
 /// The backup path for databend meta.
 pub static DATABEND_META_BACKUP_PATH: &str = "databend_meta.db";
@@ -59,3 +63,76 @@ pub async fn storage_copy(src: Operator, dst: Operator) -> Result<()> {
     info!("storage copy has been finished");
     Ok(())
 }
+
+/// Returns the hex encoded sha256 of the given content.
+pub fn checksum(bs: &[u8]) -> String {
+    hex::encode(Sha256::digest(bs))
+}
+
+/// Copy one object between operators, returns the copied size and checksum.
+pub async fn copy_object(
+    src: &Operator,
+    src_path: &str,
+    dst: &Operator,
+    dst_path: &str,
+) -> Result<(u64, String)> {
+    let mut stream = src
+        .reader_with(src_path)
+        .chunk(8 * 1024 * 1024)
+        .await?
+        .into_bytes_stream(..)
+        .await?;
+    let mut file = ChecksumWriter::create(dst, dst_path).await?;
+    while let Some(buf) = stream.next().await.transpose()? {
+        file.write(buf).await?;
+    }
+    file.close().await
+}
+
+/// Writes an object in chunks, computing its size and checksum along the way.
+pub struct ChecksumWriter {
+    writer: Writer,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl ChecksumWriter {
+    pub async fn create(op: &Operator, path: &str) -> Result<Self> {
+        Ok(Self {
+            writer: op.writer_with(path).chunk(8 * 1024 * 1024).await?,
+            hasher: Sha256::new(),
+            size: 0,
+        })
+    }
+
+    pub async fn write(&mut self, buf: Bytes) -> Result<()> {
+        self.hasher.update(&buf);
+        self.size += buf.len() as u64;
+        self.writer.write(buf).await?;
+        Ok(())
+    }
+
+    /// Returns the size and checksum of the written object.
+    pub async fn close(mut self) -> Result<(u64, String)> {
+        self.writer.close().await?;
+        Ok((self.size, hex::encode(self.hasher.finalize())))
+    }
+}
+
+/// Read the object and return its size and checksum.
+pub async fn object_checksum(op: &Operator, path: &str) -> Result<(u64, String)> {
+    let mut stream = op
+        .reader_with(path)
+        .chunk(8 * 1024 * 1024)
+        .await?
+        .into_bytes_stream(..)
+        .await?;
+
+    let mut hasher = Sha256::new();
+    let mut size = 0;
+    while let Some(buf) = stream.next().await.transpose()? {
+        hasher.update(&buf);
+        size += buf.len() as u64;
+    }
+    Ok((size, hex::encode(hasher.finalize())))
+}
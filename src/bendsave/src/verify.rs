@@ -0,0 +1,122 @@
+// Copyright 2023 Databend Cloud
+//
+// Licensed under the Elastic License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.elastic.co/licensing/elastic-license
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::anyhow;
+use anyhow::Result;
+use log::error;
+use log::info;
+use opendal::Operator;
+
+use crate::manifest::BackupManifest;
+use crate::storage::load_bendsave_storage;
+use crate::utils::object_checksum;
+
+/// Check that every meta segment and object of a manifest exists in the
+/// backup storage and matches its size and checksum, without restoring.
+pub async fn verify(from: &str, manifest: Option<&str>) -> Result<()> {
+    let bendsave_storage = load_bendsave_storage(from).await?;
+    let manifest = BackupManifest::load_or_latest(&bendsave_storage, manifest)
+        .await?
+        .ok_or_else(|| anyhow!("no backup manifest found in {from}"))?;
+
+    let files = manifest
+        .meta
+        .iter()
+        .map(|s| (s.location.as_str(), s.size, s.checksum.as_str()))
+        .chain(
+            manifest
+                .objects
+                .iter()
+                .map(|o| (o.location.as_str(), o.size, o.checksum.as_str())),
+        );
+
+    let mut total = 0;
+    let mut failed = 0;
+    for (location, size, checksum) in files {
+        total += 1;
+        if let Err(err) = verify_file(&bendsave_storage, location, size, checksum).await {
+            error!("{err}");
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        return Err(anyhow!(
+            "backup {} is broken: {failed} of {total} files failed verification",
+            manifest.id
+        ));
+    }
+    info!(
+        "backup {} has been verified, {total} files checked",
+        manifest.id
+    );
+    Ok(())
+}
+
+async fn verify_file(op: &Operator, location: &str, size: u64, checksum: &str) -> Result<()> {
+    let (actual_size, actual_checksum) = object_checksum(op, location)
+        .await
+        .map_err(|e| anyhow!("file {location} can't be read: {e}"))?;
+    if actual_size != size || actual_checksum != checksum {
+        return Err(anyhow!(
+            "file {location} doesn't match the manifest, expect size {size} checksum {checksum}, got size {actual_size} checksum {actual_checksum}"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use opendal::services::Memory;
+
+    use super::*;
+    use crate::manifest::BackupObject;
+    use crate::utils::checksum;
+
+    #[tokio::test]
+    async fn test_verify_file() -> Result<()> {
+        let op = Operator::new(Memory::default())?.finish();
+        op.write("data/1/a", "hello").await?;
+
+        let object = BackupObject {
+            path: "a".to_string(),
+            location: "data/1/a".to_string(),
+            size: 5,
+            etag: None,
+            last_modified: None,
+            checksum: checksum(b"hello"),
+        };
+        verify_file(&op, &object.location, object.size, &object.checksum).await?;
+        assert!(verify_file(&op, &object.location, object.size, "x")
+            .await
+            .is_err());
+        assert!(verify_file(&op, "data/1/b", 5, &object.checksum)
+            .await
+            .is_err());
+
+        let manifest = BackupManifest {
+            id: BackupManifest::new_id(Utc::now()),
+            parent: None,
+            created_at: Utc::now(),
+            meta: vec![],
+            objects: vec![object],
+        };
+        manifest.save(&op).await?;
+        assert_eq!(BackupManifest::list(&op).await?, vec![manifest.id.clone()]);
+        let loaded = BackupManifest::load_or_latest(&op, None).await?;
+        assert_eq!(loaded, Some(manifest));
+        Ok(())
+    }
+}
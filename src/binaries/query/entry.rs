@@ -32,8 +32,10 @@ use databend_common_version::DATABEND_GIT_SEMVER;
 use databend_common_version::DATABEND_GIT_SHA;
 use databend_common_version::DATABEND_SEMVER;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::dynamic_table::DynamicTableScheduler;
 use databend_query::local;
 use databend_query::persistent_log::GlobalPersistentLog;
+use databend_query::pipe::PipeScheduler;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
 use databend_query::servers::metrics::MetricService;
@@ -42,8 +44,10 @@ use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::task::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -178,6 +182,28 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.mysql_handler_tcp_keepalive_timeout_secs;
+
+        let mut handler =
+            PostgresHandler::create(tcp_keepalive_timeout_secs).with_context(make_error)?;
+        let listening = handler
+            .start(listening.parse().with_context(make_error)?)
+            .await
+            .with_context(make_error)?;
+        shutdown_handle.add_service("PostgresHandler", handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -U root -h {} -p {}",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -287,6 +313,10 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         println!("    persistentlog: {}", conf.log.persistentlog);
     }
 
+    DynamicTableScheduler::instance().initialized();
+    PipeScheduler::instance().initialized();
+    TaskScheduler::instance().initialized();
+
     println!();
     println!(
         "Meta: {}",
@@ -371,6 +401,15 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U ${{USER}} -h {} -p {}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
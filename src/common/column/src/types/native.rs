@@ -392,6 +392,99 @@ impl NativeType for months_days_micros {
     }
 }
 
+/// The in-memory representation of the "TimestampTz" logical type, the timestamp
+/// in microseconds since the epoch in UTC and the offset of its time zone in seconds.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    Eq,
+    Zeroable,
+    Pod,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct timestamp_tz(pub i128);
+
+// Values of the same instant are equal, regardless of their time zones.
+impl Hash for timestamp_tz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp().hash(state)
+    }
+}
+
+impl PartialEq for timestamp_tz {
+    fn eq(&self, other: &Self) -> bool {
+        self.timestamp() == other.timestamp()
+    }
+}
+
+impl PartialOrd for timestamp_tz {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for timestamp_tz {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.timestamp().cmp(&other.timestamp())
+    }
+}
+
+impl timestamp_tz {
+    pub fn new(timestamp: i64, seconds_offset: i32) -> Self {
+        let timestamp_bits = (timestamp as i128) << 64;
+        // See `months_days_micros::new`, the offset is kept as the raw bit pattern.
+        let offset_bits = (seconds_offset as u32) as i128;
+
+        Self(timestamp_bits | offset_bits)
+    }
+
+    /// The microseconds since the epoch in UTC.
+    pub fn timestamp(&self) -> i64 {
+        (self.0 >> 64) as i64
+    }
+
+    /// The offset of the time zone from UTC in seconds.
+    pub fn seconds_offset(&self) -> i32 {
+        (self.0 & 0xFFFFFFFF) as u32 as i32
+    }
+
+    /// The microseconds since the epoch in the local time of the time zone.
+    pub fn local_timestamp(&self) -> i64 {
+        self.timestamp() + self.seconds_offset() as i64 * 1_000_000
+    }
+}
+
+impl NativeType for timestamp_tz {
+    const PRIMITIVE: PrimitiveType = PrimitiveType::TimestampTz;
+    type Bytes = [u8; 16];
+    #[inline]
+    fn to_le_bytes(&self) -> Self::Bytes {
+        self.0.to_le_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(&self) -> Self::Bytes {
+        self.0.to_be_bytes()
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Self(i128::from_le_bytes(bytes))
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self(i128::from_be_bytes(bytes))
+    }
+}
+
 impl std::fmt::Display for days_ms {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "{}d {}ms", self.days(), self.milliseconds())
@@ -410,6 +503,12 @@ impl std::fmt::Display for months_days_micros {
     }
 }
 
+impl std::fmt::Display for timestamp_tz {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}micros {}s", self.timestamp(), self.seconds_offset())
+    }
+}
+
 impl Neg for days_ms {
     type Output = Self;
 
@@ -160,6 +160,11 @@ build_exceptions! {
     // virtual column
     VirtualColumnTooMany(1128),
     VirtualColumnIdOutBound(1129),
+    // partition
+    InvalidPartitionKeys(1130),
+    // The table is not a partitioned table.
+    UnpartitionedTable(1131),
+    ColumnReferencedByPartitionKey(1132),
 
     // Data Related Errors
 
@@ -241,6 +246,8 @@ build_exceptions! {
     DuplicatedUpsertFiles(2014),
     TableAlreadyLocked(2015),
     TableLockExpired(2016),
+    UnknownTableTag(2017),
+    TableTagAlreadyExists(2018),
 
     // User api error codes.
     UnknownUser(2201),
@@ -375,6 +382,16 @@ build_exceptions! {
     // dynamic error codes.
     IllegalDynamicTable(2740),
 
+    // Pipe error codes.
+    UnknownPipe(2750),
+    PipeAlreadyExists(2751),
+    IllegalPipe(2752),
+
+    // Task error codes.
+    UnknownTask(2760),
+    TaskAlreadyExists(2761),
+    IllegalTask(2762),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
@@ -385,7 +402,7 @@ build_exceptions! {
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
 
-    // Script error codes.
+    // Script error codes, [20000, 20999] are reserved for the errors raised by RAISE.
     ScriptSemanticError(3001),
     ScriptExecutionError(3002),
 
@@ -77,6 +77,8 @@ pub enum Feature {
     SystemManagement,
     #[serde(alias = "ngram_index", alias = "NGRAM_INDEX")]
     NgramIndex,
+    #[serde(alias = "vector_index", alias = "VECTOR_INDEX")]
+    VectorIndex,
     #[serde(alias = "workload_group", alias = "WORKLOAD_GROUP")]
     WorkloadGroup,
     #[serde(other)]
@@ -127,6 +129,7 @@ impl fmt::Display for Feature {
             Feature::SystemManagement => write!(f, "system_management"),
             Feature::HilbertClustering => write!(f, "hilbert_clustering"),
             Feature::NgramIndex => write!(f, "ngram_index"),
+            Feature::VectorIndex => write!(f, "vector_index"),
             Feature::WorkloadGroup => write!(f, "workload_group"),
             Feature::Unknown => write!(f, "unknown"),
         }
@@ -176,7 +179,8 @@ impl Feature {
             | (Feature::AttacheTable, Feature::AttacheTable)
             | (Feature::StorageEncryption, Feature::StorageEncryption)
             | (Feature::HilbertClustering, Feature::HilbertClustering)
-            | (Feature::NgramIndex, Feature::NgramIndex) => Ok(true),
+            | (Feature::NgramIndex, Feature::NgramIndex)
+            | (Feature::VectorIndex, Feature::VectorIndex) => Ok(true),
             (_, _) => Ok(false),
         }
     }
@@ -350,6 +354,11 @@ mod tests {
             serde_json::from_str::<Feature>("\"NgramIndex\"").unwrap()
         );
 
+        assert_eq!(
+            Feature::VectorIndex,
+            serde_json::from_str::<Feature>("\"vector_index\"").unwrap()
+        );
+
         assert_eq!(
             Feature::Unknown,
             serde_json::from_str::<Feature>("\"ssss\"").unwrap()
@@ -384,11 +393,12 @@ mod tests {
                 Feature::AmendTable,
                 Feature::HilbertClustering,
                 Feature::NgramIndex,
+                Feature::VectorIndex,
             ]),
         };
 
         assert_eq!(
-            "LicenseInfo{ type: enterprise, org: databend, tenants: [databend_tenant,foo], features: [aggregate_index,amend_table,attach_table,compute_quota(threads_num: 1, memory_usage: 1),computed_column,data_mask,hilbert_clustering,inverted_index,license_info,ngram_index,storage_encryption,storage_quota(storage_usage: 1),stream,vacuum,virtual_column] }",
+            "LicenseInfo{ type: enterprise, org: databend, tenants: [databend_tenant,foo], features: [aggregate_index,amend_table,attach_table,compute_quota(threads_num: 1, memory_usage: 1),computed_column,data_mask,hilbert_clustering,inverted_index,license_info,ngram_index,storage_encryption,storage_quota(storage_usage: 1),stream,vacuum,vector_index,virtual_column] }",
             license_info.to_string()
         );
     }
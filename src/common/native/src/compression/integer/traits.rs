@@ -17,6 +17,7 @@ use std::hash::Hash;
 
 use databend_common_column::types::i256;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_column::types::NativeType;
 
 pub trait IntegerType: NativeType + PartialOrd + Hash + Eq {
@@ -59,3 +60,11 @@ impl IntegerType for months_days_micros {
     }
     const USE_COMMON_COMPRESSION: bool = true;
 }
+
+// pub struct timestamp_tz(pub i128);
+impl IntegerType for timestamp_tz {
+    fn compare_i64(&self, i: i64) -> Ordering {
+        (self.0 as i64).cmp(&i)
+    }
+    const USE_COMMON_COMPRESSION: bool = true;
+}
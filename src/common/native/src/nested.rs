@@ -110,7 +110,10 @@ pub fn to_nested(column: &Column) -> Result<Vec<Vec<Nested>>> {
 pub fn is_nested_type(t: &TableDataType) -> bool {
     matches!(
         t,
-        TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+        TableDataType::Tuple { .. }
+            | TableDataType::Array(_)
+            | TableDataType::Map(_)
+            | TableDataType::Vector(_)
     )
 }
 
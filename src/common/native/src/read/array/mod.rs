@@ -33,5 +33,7 @@ mod list;
 pub use list::*;
 mod interval;
 mod map;
+mod timestamp_tz;
 pub use interval::*;
 pub use map::*;
+pub use timestamp_tz::*;
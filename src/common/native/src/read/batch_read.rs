@@ -14,6 +14,7 @@
 
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::NumberType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::MAX_DECIMAL128_PRECISION;
 use databend_common_expression::Column;
@@ -124,6 +125,25 @@ pub fn read_nested_column<R: NativeReadBuf>(
                 page_metas.pop().unwrap(),
             )?
         }
+        Time => {
+            init.push(InitNested::Primitive(is_nullable));
+            read_nested_integer::<TimeType, _, _>(
+                &mut readers.pop().unwrap(),
+                data_type.clone(),
+                init,
+                page_metas.pop().unwrap(),
+            )?
+        }
+        TimestampTz => {
+            init.push(InitNested::Primitive(is_nullable));
+
+            read_nested_timestamp_tz::<_>(
+                &mut readers.pop().unwrap(),
+                data_type.clone(),
+                init,
+                page_metas.pop().unwrap(),
+            )?
+        }
         t if t.is_physical_binary() => {
             init.push(InitNested::Primitive(is_nullable));
             read_nested_binary::<_>(
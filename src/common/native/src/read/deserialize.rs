@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use databend_common_expression::types::DateType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::MAX_DECIMAL128_PRECISION;
 use databend_common_expression::Column;
@@ -159,6 +161,22 @@ where
                 init,
             ))
         }
+        TableDataType::Time => {
+            init.push(InitNested::Primitive(is_nullable));
+            DynIter::new(IntegerNestedIter::<_, TimeType, i64>::new(
+                readers.pop().unwrap(),
+                data_type.clone(),
+                init,
+            ))
+        }
+        TableDataType::TimestampTz => {
+            init.push(InitNested::Primitive(is_nullable));
+            DynIter::new(TimestampTzNestedIter::<_>::new(
+                readers.pop().unwrap(),
+                data_type.clone(),
+                init,
+            ))
+        }
         TableDataType::Decimal(t) if t.precision() > MAX_DECIMAL128_PRECISION => {
             init.push(InitNested::Primitive(is_nullable));
             DynIter::new(DecimalNestedIter::<
@@ -199,6 +217,12 @@ where
             let iter = deserialize_nested(readers, inner.as_ref().clone(), init)?;
             DynIter::new(ListIterator::new(iter, data_type.clone()))
         }
+        TableDataType::Vector(_) => {
+            init.push(InitNested::List(is_nullable));
+            let inner = TableDataType::Number(NumberDataType::Float32);
+            let iter = deserialize_nested(readers, inner, init)?;
+            DynIter::new(ListIterator::new(iter, data_type.clone()))
+        }
         TableDataType::Map(inner) => {
             init.push(InitNested::List(is_nullable));
             let iter = deserialize_nested(readers, inner.as_ref().clone(), init)?;
@@ -73,6 +73,13 @@ pub fn write<W: Write>(
             let column: Buffer<i128> = unsafe { std::mem::transmute(column) };
             write_primitive::<i128, W>(w, &column, validity, write_options, scratch)
         }
+        Column::Time(column) => {
+            write_primitive::<i64, W>(w, &column, validity, write_options, scratch)
+        }
+        Column::TimestampTz(column) => {
+            let column: Buffer<i128> = unsafe { std::mem::transmute(column) };
+            write_primitive::<i128, W>(w, &column, validity, write_options, scratch)
+        }
         Column::Binary(b)
         | Column::Bitmap(b)
         | Column::Variant(b)
@@ -39,7 +39,10 @@ impl Datum {
             Scalar::Number(NumberScalar::Int8(v)) => Some(Datum::Int(v as i64)),
             Scalar::Number(NumberScalar::Int16(v)) => Some(Datum::Int(v as i64)),
             Scalar::Number(NumberScalar::Int32(v)) | Scalar::Date(v) => Some(Datum::Int(v as i64)),
-            Scalar::Number(NumberScalar::Int64(v)) | Scalar::Timestamp(v) => Some(Datum::Int(v)),
+            Scalar::Number(NumberScalar::Int64(v)) | Scalar::Timestamp(v) | Scalar::Time(v) => {
+                Some(Datum::Int(v))
+            }
+            Scalar::TimestampTz(v) => Some(Datum::Int(v.timestamp())),
             Scalar::Number(NumberScalar::UInt8(v)) => Some(Datum::UInt(v as u64)),
             Scalar::Number(NumberScalar::UInt16(v)) => Some(Datum::UInt(v as u64)),
             Scalar::Number(NumberScalar::UInt32(v)) => Some(Datum::UInt(v as u64)),
@@ -0,0 +1,357 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IVF-Flat index of fixed-dimension vectors.
+//!
+//! The vectors are clustered by k-means into `nlists` lists, a search only scans
+//! the lists whose centroids are nearest to the query vector.
+
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::cosine_distance;
+use crate::l2_distance;
+
+const KMEANS_MAX_ITERATIONS: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    /// Name of the scalar function that computes the distance.
+    pub fn func_name(&self) -> &'static str {
+        match self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        }
+    }
+
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> Result<f32> {
+        let distance = match self {
+            VectorDistance::Cosine => cosine_distance(from, to)?,
+            VectorDistance::L2 => l2_distance(from, to)?,
+        };
+        // Cosine distance of zero vectors is NaN, sort them after all the others.
+        Ok(if distance.is_nan() {
+            f32::MAX
+        } else {
+            distance
+        })
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            VectorDistance::Cosine => 0,
+            VectorDistance::L2 => 1,
+        }
+    }
+
+    fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0 => Ok(VectorDistance::Cosine),
+            1 => Ok(VectorDistance::L2),
+            _ => Err(ErrorCode::StorageOther(format!(
+                "Invalid vector index distance {v}"
+            ))),
+        }
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistance::Cosine),
+            "l2" => Ok(VectorDistance::L2),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid vector distance {s}, expect `cosine` or `l2`"
+            ))),
+        }
+    }
+}
+
+/// A list of the IVF index, the rows and their vectors assigned to the same centroid.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct IvfList {
+    rows: Vec<u32>,
+    // `rows.len() * dimension` values.
+    vectors: Vec<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvfIndex {
+    dimension: usize,
+    distance: VectorDistance,
+    // `lists.len() * dimension` values.
+    centroids: Vec<f32>,
+    lists: Vec<IvfList>,
+}
+
+impl IvfIndex {
+    pub const VERSION: u8 = 1;
+
+    /// Build the index of `vectors`, each item is the row number in block and the vector.
+    ///
+    /// If `nlists` is not specified, the square root of the number of vectors is used.
+    pub fn build(
+        dimension: usize,
+        distance: VectorDistance,
+        vectors: &[(u32, &[f32])],
+        nlists: Option<usize>,
+    ) -> Result<Self> {
+        if let Some((_, vector)) = vectors.iter().find(|(_, v)| v.len() != dimension) {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {} != {}",
+                vector.len(),
+                dimension
+            )));
+        }
+
+        let nlists = if vectors.is_empty() {
+            0
+        } else {
+            nlists
+                .unwrap_or_else(|| (vectors.len() as f64).sqrt().ceil() as usize)
+                .clamp(1, vectors.len())
+        };
+
+        // Deterministic initialization, take evenly spaced vectors as the initial centroids.
+        let mut centroids = Vec::with_capacity(nlists * dimension);
+        for i in 0..nlists {
+            centroids.extend_from_slice(vectors[i * vectors.len() / nlists].1);
+        }
+
+        let mut assignments = vec![0; vectors.len()];
+        for _ in 0..KMEANS_MAX_ITERATIONS {
+            let mut changed = false;
+            for (i, (_, vector)) in vectors.iter().enumerate() {
+                let nearest = nearest_centroid(&centroids, dimension, distance, vector)?;
+                if nearest != assignments[i] {
+                    assignments[i] = nearest;
+                    changed = true;
+                }
+            }
+
+            let mut sums = vec![0f32; nlists * dimension];
+            let mut counts = vec![0usize; nlists];
+            for (i, (_, vector)) in vectors.iter().enumerate() {
+                let list = assignments[i];
+                counts[list] += 1;
+                for (sum, v) in sums[list * dimension..(list + 1) * dimension]
+                    .iter_mut()
+                    .zip(vector.iter())
+                {
+                    *sum += *v;
+                }
+            }
+            for (list, count) in counts.iter().enumerate() {
+                // Empty lists keep the previous centroid.
+                if *count > 0 {
+                    for d in 0..dimension {
+                        centroids[list * dimension + d] =
+                            sums[list * dimension + d] / *count as f32;
+                    }
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        let mut lists = vec![IvfList::default(); nlists];
+        for (i, (row, vector)) in vectors.iter().enumerate() {
+            let list = &mut lists[assignments[i]];
+            list.rows.push(*row);
+            list.vectors.extend_from_slice(vector);
+        }
+
+        Ok(IvfIndex {
+            dimension,
+            distance,
+            centroids,
+            lists,
+        })
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.lists.iter().map(|list| list.rows.len()).sum()
+    }
+
+    /// Search the `k` nearest rows of `query`, returns the rows and the distances in ascending order.
+    ///
+    /// At least `nprobe` lists are scanned, more lists are scanned if they contain less than `k` rows.
+    pub fn search(&self, query: &[f32], k: usize, nprobe: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {} != {}",
+                query.len(),
+                self.dimension
+            )));
+        }
+
+        let mut probes = Vec::with_capacity(self.lists.len());
+        for (i, centroid) in self.centroids.chunks(self.dimension.max(1)).enumerate() {
+            probes.push((i, self.distance.distance(centroid, query)?));
+        }
+        probes.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let mut candidates = Vec::new();
+        for (probed, (list, _)) in probes.into_iter().enumerate() {
+            if probed >= nprobe && candidates.len() >= k {
+                break;
+            }
+            let list = &self.lists[list];
+            for (row, vector) in list
+                .rows
+                .iter()
+                .zip(list.vectors.chunks(self.dimension.max(1)))
+            {
+                candidates.push((*row, self.distance.distance(vector, query)?));
+            }
+        }
+        candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+        candidates.truncate(k);
+        Ok(candidates)
+    }
+
+    /// Serialize the index, all the numbers are in little endian.
+    ///
+    /// `version: u8 | distance: u8 | dimension: u32 | nlists: u32 | centroids: [f32]
+    ///  | nlists * (nrows: u32 | rows: [u32] | vectors: [f32])`
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(
+            10 + self.centroids.len() * 4
+                + self
+                    .lists
+                    .iter()
+                    .map(|list| 4 + list.rows.len() * 4 + list.vectors.len() * 4)
+                    .sum::<usize>(),
+        );
+        buf.push(Self::VERSION);
+        buf.push(self.distance.to_u8());
+        buf.extend_from_slice(&(self.dimension as u32).to_le_bytes());
+        buf.extend_from_slice(&(self.lists.len() as u32).to_le_bytes());
+        for v in self.centroids.iter() {
+            buf.extend_from_slice(&v.to_le_bytes());
+        }
+        for list in self.lists.iter() {
+            buf.extend_from_slice(&(list.rows.len() as u32).to_le_bytes());
+            for row in list.rows.iter() {
+                buf.extend_from_slice(&row.to_le_bytes());
+            }
+            for v in list.vectors.iter() {
+                buf.extend_from_slice(&v.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader { bytes, pos: 0 };
+        let version = reader.read_u8()?;
+        if version != Self::VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported vector index version {version}"
+            )));
+        }
+        let distance = VectorDistance::from_u8(reader.read_u8()?)?;
+        let dimension = reader.read_u32()? as usize;
+        let nlists = reader.read_u32()? as usize;
+        let centroids = reader.read_f32s(nlists * dimension)?;
+        let mut lists = Vec::with_capacity(nlists);
+        for _ in 0..nlists {
+            let nrows = reader.read_u32()? as usize;
+            let mut rows = Vec::with_capacity(nrows);
+            for _ in 0..nrows {
+                rows.push(reader.read_u32()?);
+            }
+            let vectors = reader.read_f32s(nrows * dimension)?;
+            lists.push(IvfList { rows, vectors });
+        }
+        Ok(IvfIndex {
+            dimension,
+            distance,
+            centroids,
+            lists,
+        })
+    }
+}
+
+fn nearest_centroid(
+    centroids: &[f32],
+    dimension: usize,
+    distance: VectorDistance,
+    vector: &[f32],
+) -> Result<usize> {
+    let mut nearest = 0;
+    let mut nearest_distance = f32::MAX;
+    for (i, centroid) in centroids.chunks(dimension.max(1)).enumerate() {
+        let d = distance.distance(centroid, vector)?;
+        if d < nearest_distance {
+            nearest = i;
+            nearest_distance = d;
+        }
+    }
+    Ok(nearest)
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl ByteReader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        if self.pos + n > self.bytes.len() {
+            return Err(ErrorCode::StorageOther(
+                "Invalid vector index data, unexpected end of data",
+            ));
+        }
+        let slice = &self.bytes[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(slice)
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    fn read_f32s(&mut self, n: usize) -> Result<Vec<f32>> {
+        let bytes = self.take(n * 4)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::IvfIndex;
+use databend_common_vector::VectorDistance;
+
+fn gen_vectors(n: usize) -> Vec<Vec<f32>> {
+    (0..n)
+        .map(|i| {
+            let x = (i % 10) as f32;
+            let y = (i / 10) as f32;
+            vec![x, y, x + y]
+        })
+        .collect()
+}
+
+#[test]
+fn test_ivf_search() {
+    let vectors = gen_vectors(100);
+    let items = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i as u32, v.as_slice()))
+        .collect::<Vec<_>>();
+    let index = IvfIndex::build(3, VectorDistance::L2, &items, Some(8)).unwrap();
+    assert_eq!(index.num_rows(), 100);
+
+    // Scan all the lists, the result is exact.
+    let result = index.search(&[3.0, 4.0, 7.0], 3, 8).unwrap();
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[0], (43, 0.0));
+    assert!(result[1].1 <= result[2].1);
+
+    // More lists than `nprobe` are scanned if there are not enough rows.
+    let result = index.search(&[3.0, 4.0, 7.0], 100, 1).unwrap();
+    assert_eq!(result.len(), 100);
+
+    assert!(index.search(&[3.0, 4.0], 3, 8).is_err());
+}
+
+#[test]
+fn test_ivf_serialize() {
+    let vectors = gen_vectors(50);
+    let items = vectors
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (i as u32, v.as_slice()))
+        .collect::<Vec<_>>();
+    let index = IvfIndex::build(3, VectorDistance::Cosine, &items, None).unwrap();
+    let bytes = index.to_bytes();
+    let decoded = IvfIndex::from_bytes(&bytes).unwrap();
+    assert_eq!(index, decoded);
+    assert!(IvfIndex::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    let empty = IvfIndex::build(3, VectorDistance::Cosine, &[], None).unwrap();
+    let decoded = IvfIndex::from_bytes(&empty.to_bytes()).unwrap();
+    assert_eq!(empty, decoded);
+    assert!(decoded.search(&[1.0, 2.0, 3.0], 3, 1).unwrap().is_empty());
+}
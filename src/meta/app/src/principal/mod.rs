@@ -19,6 +19,7 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
 pub mod role_ident;
 mod role_info;
@@ -42,12 +43,16 @@ pub mod client_session_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
+pub mod pipe_ident;
 pub mod procedure;
 pub mod procedure_id_ident;
 pub mod procedure_id_to_name;
 pub mod procedure_identity;
 pub mod procedure_name_ident;
 pub mod stage_file_ident;
+pub mod task;
+pub mod task_ident;
+pub mod task_run_ident;
 pub mod tenant_ownership_object_ident;
 pub mod tenant_user_ident;
 pub mod user_defined_file_format_ident;
@@ -64,6 +69,8 @@ pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use pipe::PipeInfo;
+pub use pipe_ident::PipeIdent;
 pub use principal_identity::PrincipalIdentity;
 pub use procedure::CreateProcedureReply;
 pub use procedure::CreateProcedureReq;
@@ -86,6 +93,10 @@ pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
 pub use stage_file_ident::StageFileIdent;
 pub use stage_file_path::StageFilePath;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task_ident::TaskIdent;
+pub use task_run_ident::TaskRunIdent;
 pub use tenant_ownership_object_ident::TenantOwnershipObjectIdent;
 pub use tenant_user_ident::TenantUserIdent;
 pub use udf_ident::UdfIdent;
@@ -99,6 +110,8 @@ pub use user_defined_function::UDAFScript;
 pub use user_defined_function::UDFDefinition;
 pub use user_defined_function::UDFScript;
 pub use user_defined_function::UDFServer;
+pub use user_defined_function::UDTFScript;
+pub use user_defined_function::UDTFServer;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
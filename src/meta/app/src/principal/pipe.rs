@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A pipe loads the new files of a stage into a table by a `COPY INTO <table>` statement.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The `COPY INTO <table>` statement run by the pipe.
+    pub copy_statement: String,
+    /// Whether the stage is watched and new files are loaded automatically.
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: DateTime<Utc>,
+
+    /// The time of the last load of the pipe.
+    pub last_load_on: Option<DateTime<Utc>>,
+    /// The number of files loaded by the last load.
+    pub last_load_files: u64,
+    /// The error of the last load, `None` if it succeeded.
+    pub last_load_error: Option<String>,
+
+    /// The role that owns the pipe, the pipe is loaded with its privileges.
+    pub owner: String,
+}
+
+impl PipeInfo {
+    pub fn new(
+        name: &str,
+        copy_statement: String,
+        auto_ingest: bool,
+        comment: String,
+        owner: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            name: name.to_string(),
+            copy_statement,
+            auto_ingest,
+            execution_paused: false,
+            comment,
+            created_on: now,
+            updated_on: now,
+            last_load_on: None,
+            last_load_files: 0,
+            last_load_error: None,
+            owner,
+        }
+    }
+}
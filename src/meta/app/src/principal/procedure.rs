@@ -45,6 +45,9 @@ pub struct ProcedureIdent {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProcedureMeta {
     pub return_types: Vec<DataType>,
+    /// The column names of `RETURNS TABLE(...)`, empty for the procedures that return a scalar
+    /// or were created before the names were recorded.
+    pub return_names: Vec<String>,
     pub arg_names: Vec<String>,
     pub created_on: DateTime<Utc>,
     pub updated_on: DateTime<Utc>,
@@ -57,6 +60,7 @@ impl Default for ProcedureMeta {
     fn default() -> Self {
         ProcedureMeta {
             return_types: vec![],
+            return_names: vec![],
             arg_names: vec![],
             created_on: Utc::now(),
             updated_on: Utc::now(),
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, num_derive::FromPrimitive,
+)]
+pub enum ScheduleType {
+    IntervalType = 0,
+    CronType = 1,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleOptions {
+    /// The interval in seconds.
+    pub interval: Option<i32>,
+    pub cron: Option<String>,
+    pub time_zone: Option<String>,
+    pub schedule_type: ScheduleType,
+    pub milliseconds_interval: Option<u64>,
+}
+
+impl Display for ScheduleOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                write!(f, "INTERVAL {} SECOND", self.interval.unwrap_or_default())?;
+                if let Some(ms) = self.milliseconds_interval {
+                    write!(f, " {} MILLISECOND", ms)?;
+                }
+                Ok(())
+            }
+            ScheduleType::CronType => {
+                write!(f, "CRON {}", self.cron.as_deref().unwrap_or_default())?;
+                if let Some(time_zone) = &self.time_zone {
+                    write!(f, " TIMEZONE {}", time_zone)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct WarehouseOptions {
+    pub warehouse: Option<String>,
+    pub using_warehouse_size: Option<String>,
+}
+
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, num_derive::FromPrimitive,
+)]
+pub enum Status {
+    Suspended = 0,
+    Started = 1,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Status::Suspended => write!(f, "Suspended"),
+            Status::Started => write!(f, "Started"),
+        }
+    }
+}
+
+/// A task runs its SQL by a schedule, or after its predecessor tasks in a task graph.
+///
+/// It's used by the built-in task scheduler when the cloud control is not enabled.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Task {
+    pub task_id: u64,
+    pub task_name: String,
+    pub query_text: String,
+    /// The statements of a script task, empty for a single statement task.
+    pub script_sqls: Vec<String>,
+    pub when_condition: Option<String>,
+    /// The predecessor tasks, a task with predecessors has no schedule.
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    /// The role that owns the task, the task runs with this role.
+    pub owner: String,
+    pub schedule_options: Option<ScheduleOptions>,
+    pub warehouse_options: Option<WarehouseOptions>,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    pub error_integration: Option<String>,
+    pub status: Status,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub session_params: BTreeMap<String, String>,
+}
+
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, num_derive::FromPrimitive,
+)]
+pub enum State {
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            State::Scheduled => write!(f, "SCHEDULED"),
+            State::Executing => write!(f, "EXECUTING"),
+            State::Succeeded => write!(f, "SUCCEEDED"),
+            State::Failed => write!(f, "FAILED"),
+            State::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// A run of a task, kept in the history of the task for a while.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TaskRun {
+    /// The task at the time of the run.
+    pub task: Task,
+    pub run_id: String,
+    pub attempt_number: i32,
+    pub state: State,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    /// The root task of the task graph that the run belongs to.
+    pub root_task_id: u64,
+    pub query_id: String,
+}
@@ -59,12 +59,36 @@ pub struct UDAFScript {
     pub runtime_version: String,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDTFServer {
+    pub address: String,
+    pub handler: String,
+    pub headers: BTreeMap<String, String>,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    // table function output columns
+    pub return_fields: Vec<DataField>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDTFScript {
+    pub code: String,
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    // table function output columns
+    pub return_fields: Vec<DataField>,
+    pub runtime_version: String,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
     UDFScript(UDFScript),
     UDAFScript(UDAFScript),
+    UDTFServer(UDTFServer),
+    UDTFScript(UDTFScript),
 }
 
 impl UDFDefinition {
@@ -74,6 +98,8 @@ impl UDFDefinition {
             Self::UDFServer(_) => "UDFServer",
             Self::UDFScript(_) => "UDFScript",
             Self::UDAFScript(_) => "UDAFScript",
+            Self::UDTFServer(_) => "UDTFServer",
+            Self::UDTFScript(_) => "UDTFScript",
         }
     }
 
@@ -83,6 +109,17 @@ impl UDFDefinition {
             Self::UDFServer(_) => false,
             Self::UDFScript(_) => false,
             Self::UDAFScript(_) => true,
+            Self::UDTFServer(_) => false,
+            Self::UDTFScript(_) => false,
+        }
+    }
+
+    /// Returns the output columns if this is a table function.
+    pub fn table_fields(&self) -> Option<&[DataField]> {
+        match self {
+            Self::UDTFServer(x) => Some(&x.return_fields),
+            Self::UDTFScript(x) => Some(&x.return_fields),
+            _ => None,
         }
     }
 
@@ -92,6 +129,8 @@ impl UDFDefinition {
             Self::UDFServer(x) => x.language.as_str(),
             Self::UDFScript(x) => x.language.as_str(),
             Self::UDAFScript(x) => x.language.as_str(),
+            Self::UDTFServer(x) => x.language.as_str(),
+            Self::UDTFScript(x) => x.language.as_str(),
         }
     }
 }
@@ -261,6 +300,66 @@ impl Display for UDFDefinition {
                 }
                 write!(f, " }} RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} AS $${code}$$")?;
             }
+            UDFDefinition::UDTFServer(UDTFServer {
+                address,
+                arg_types,
+                return_fields,
+                handler,
+                headers,
+                language,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ") RETURNS TABLE (")?;
+                for (i, item) in return_fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", item.name(), item.data_type())?;
+                }
+                write!(f, ") LANGUAGE {language} HANDLER = {handler}")?;
+                if !headers.is_empty() {
+                    write!(f, " HEADERS = (")?;
+                    for (i, (key, value)) in headers.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{key} = {value}")?;
+                    }
+                    write!(f, ")")?;
+                }
+                write!(f, " ADDRESS = {address}")?;
+            }
+            UDFDefinition::UDTFScript(UDTFScript {
+                code,
+                arg_types,
+                return_fields,
+                handler,
+                language,
+                runtime_version,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ") RETURNS TABLE (")?;
+                for (i, item) in return_fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} {}", item.name(), item.data_type())?;
+                }
+                write!(
+                    f,
+                    ") LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler} AS $${code}$$"
+                )?;
+            }
         }
         Ok(())
     }
@@ -49,6 +49,9 @@ pub struct DictionaryMeta {
     pub primary_column_ids: Vec<u32>,
     /// A general comment string that can be used to provide additional notes or information about the dictionary.
     pub comment: String,
+    /// The number of seconds a local snapshot of the dictionary data is kept before it is refreshed.
+    /// If it is None, the data is read from the source by each query.
+    pub lifetime: Option<u64>,
     /// The timestamp indicating when the dictionary was created, in Coordinated Universal Time (UTC).
     pub created_on: DateTime<Utc>,
     /// if used in CreateDictionaryReq,
@@ -76,6 +79,7 @@ impl Default for DictionaryMeta {
             created_on: Utc::now(),
             updated_on: None,
             comment: "".to_string(),
+            lifetime: None,
             field_comments: BTreeMap::new(),
         }
     }
@@ -103,9 +107,13 @@ impl DictionaryMeta {
             .options
             .get("db")
             .ok_or_else(|| ErrorCode::BadArguments("Miss option `db`"))?;
+        let scheme = match self.source.as_str() {
+            "postgresql" => "postgres",
+            _ => "mysql",
+        };
         Ok(format!(
-            "mysql://{}:{}@{}:{}/{}",
-            username, password, host, port, db
+            "{}://{}:{}@{}:{}/{}",
+            scheme, username, password, host, port, db
         ))
     }
 }
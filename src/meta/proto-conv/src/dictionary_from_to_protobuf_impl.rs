@@ -43,6 +43,7 @@ impl FromToProto for mt::DictionaryMeta {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             primary_column_ids: p.primary_column_ids,
             comment: p.comment,
+            lifetime: p.lifetime,
             created_on: DateTime::<Utc>::from_pb(p.created_on)?,
             updated_on: match p.updated_on {
                 Some(update_on) => Some(DateTime::<Utc>::from_pb(update_on)?),
@@ -65,6 +66,7 @@ impl FromToProto for mt::DictionaryMeta {
                 None => None,
             },
             comment: self.comment.clone(),
+            lifetime: self.lifetime,
             schema: Some(self.schema.to_pb()?),
             field_comments: self.field_comments.clone(),
         };
@@ -70,6 +70,7 @@ impl FromToProto for mt::principal::ProcedureMeta {
 
         let v = Self {
             return_types,
+            return_names: p.return_names,
             arg_names: p.arg_names.clone(),
             created_on: DateTime::<Utc>::from_pb(p.created_on)?,
             updated_on: DateTime::<Utc>::from_pb(p.updated_on)?,
@@ -98,6 +99,7 @@ impl FromToProto for mt::principal::ProcedureMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
             return_types,
+            return_names: self.return_names.clone(),
             arg_names: self.arg_names.clone(),
             created_on: self.created_on.to_pb()?,
             updated_on: self.updated_on.to_pb()?,
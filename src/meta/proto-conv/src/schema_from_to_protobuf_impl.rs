@@ -267,6 +267,12 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::VectorT(v) => {
+                        reader_check_msg(v.ver, v.min_reader_ver)?;
+                        ex::TableDataType::Vector(v.dimension)
+                    }
+                    Dt24::TimeT(_) => ex::TableDataType::Time,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
                 };
                 Ok(x)
             }
@@ -330,6 +336,16 @@ impl FromToProto for ex::TableDataType {
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Geography => new_pb_dt24(Dt24::GeographyT(pb::Empty {})),
+            TableDataType::Vector(dimension) => {
+                let x = pb::Vector {
+                    ver: VER,
+                    min_reader_ver: MIN_READER_VER,
+                    dimension: *dimension,
+                };
+                new_pb_dt24(Dt24::VectorT(x))
+            }
+            TableDataType::Time => new_pb_dt24(Dt24::TimeT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
         };
         Ok(x)
     }
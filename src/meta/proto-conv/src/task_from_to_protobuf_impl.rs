@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+use num::FromPrimitive;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::task::ScheduleOptions {
+    type PB = pb::TaskScheduleOptions;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TaskScheduleOptions) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            interval: p.interval,
+            cron: p.cron,
+            time_zone: p.time_zone,
+            schedule_type: FromPrimitive::from_i32(p.schedule_type).ok_or_else(|| {
+                Incompatible::new(format!("invalid ScheduleType: {}", p.schedule_type))
+            })?,
+            milliseconds_interval: p.milliseconds_interval,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::TaskScheduleOptions, Incompatible> {
+        Ok(pb::TaskScheduleOptions {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            interval: self.interval,
+            cron: self.cron.clone(),
+            time_zone: self.time_zone.clone(),
+            schedule_type: self.schedule_type as i32,
+            milliseconds_interval: self.milliseconds_interval,
+        })
+    }
+}
+
+impl FromToProto for mt::task::WarehouseOptions {
+    type PB = pb::TaskWarehouseOptions;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TaskWarehouseOptions) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            warehouse: p.warehouse,
+            using_warehouse_size: p.using_warehouse_size,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::TaskWarehouseOptions, Incompatible> {
+        Ok(pb::TaskWarehouseOptions {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            warehouse: self.warehouse.clone(),
+            using_warehouse_size: self.using_warehouse_size.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::Task {
+    type PB = pb::Task;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Task) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            task_id: p.task_id,
+            task_name: p.task_name,
+            query_text: p.query_text,
+            script_sqls: p.script_sqls,
+            when_condition: p.when_condition,
+            after: p.after,
+            comment: p.comment,
+            owner: p.owner,
+            schedule_options: match p.schedule_options {
+                Some(s) => Some(mt::task::ScheduleOptions::from_pb(s)?),
+                None => None,
+            },
+            warehouse_options: match p.warehouse_options {
+                Some(w) => Some(mt::task::WarehouseOptions::from_pb(w)?),
+                None => None,
+            },
+            next_scheduled_at: match p.next_scheduled_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            suspend_task_after_num_failures: p.suspend_task_after_num_failures,
+            error_integration: p.error_integration,
+            status: FromPrimitive::from_i32(p.status)
+                .ok_or_else(|| Incompatible::new(format!("invalid Status: {}", p.status)))?,
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            updated_at: DateTime::<Utc>::from_pb(p.updated_at)?,
+            last_suspended_at: match p.last_suspended_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            session_params: p.session_params,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Task, Incompatible> {
+        Ok(pb::Task {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task_id: self.task_id,
+            task_name: self.task_name.clone(),
+            query_text: self.query_text.clone(),
+            script_sqls: self.script_sqls.clone(),
+            when_condition: self.when_condition.clone(),
+            after: self.after.clone(),
+            comment: self.comment.clone(),
+            owner: self.owner.clone(),
+            schedule_options: match &self.schedule_options {
+                Some(s) => Some(s.to_pb()?),
+                None => None,
+            },
+            warehouse_options: match &self.warehouse_options {
+                Some(w) => Some(w.to_pb()?),
+                None => None,
+            },
+            next_scheduled_at: match &self.next_scheduled_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            suspend_task_after_num_failures: self.suspend_task_after_num_failures,
+            error_integration: self.error_integration.clone(),
+            status: self.status as i32,
+            created_at: self.created_at.to_pb()?,
+            updated_at: self.updated_at.to_pb()?,
+            last_suspended_at: match &self.last_suspended_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            session_params: self.session_params.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::TaskRun {
+    type PB = pb::TaskRun;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TaskRun) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let task = p
+            .task
+            .ok_or_else(|| Incompatible::new("TaskRun.task can not be None".to_string()))?;
+        Ok(Self {
+            task: mt::Task::from_pb(task)?,
+            run_id: p.run_id,
+            attempt_number: p.attempt_number,
+            state: FromPrimitive::from_i32(p.state)
+                .ok_or_else(|| Incompatible::new(format!("invalid State: {}", p.state)))?,
+            scheduled_at: DateTime::<Utc>::from_pb(p.scheduled_at)?,
+            completed_at: match p.completed_at {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            error_code: p.error_code,
+            error_message: p.error_message,
+            root_task_id: p.root_task_id,
+            query_id: p.query_id,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::TaskRun, Incompatible> {
+        Ok(pb::TaskRun {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task: Some(self.task.to_pb()?),
+            run_id: self.run_id.clone(),
+            attempt_number: self.attempt_number,
+            state: self.state as i32,
+            scheduled_at: self.scheduled_at.to_pb()?,
+            completed_at: match &self.completed_at {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+            root_task_id: self.root_task_id,
+            query_id: self.query_id.clone(),
+        })
+    }
+}
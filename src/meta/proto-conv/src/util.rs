@@ -155,6 +155,14 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (123, "2025-03-27: Add: add compression in user.proto/ParquetFileFormatParam"),
     (124, "2025-04-01: Add: add headers in udf.proto/UDFServer"),
     (125, "2025-04-16: Add: add index_type in table.proto/TableIndex"),
+    (126, "2025-04-25: Add: add vector_t in datatype.proto/DataType, add VECTOR in table.proto/TableIndex.IndexType"),
+    (127, "2025-05-06: Add: add lifetime in dictionary.proto/DictionaryMeta"),
+    (128, "2025-05-09: Add: add time_t and timestamp_tz_t in datatype.proto/DataType"),
+    (129, "2025-05-12: Add: pipe.proto/PipeInfo"),
+    (130, "2025-05-15: Add: task.proto/Task, TaskRun"),
+    (131, "2025-05-20: Add: udf.proto: add UDTFServer and UDTFScript"),
+    (132, "2025-05-22: Add: procedure.proto/ProcedureMeta: return_names"),
+    (133, "2025-05-26: Add: pipe.proto/PipeInfo: owner"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -117,3 +117,11 @@ mod v122_virtual_schema;
 mod v123_parquet_format_params;
 mod v124_udf_server_headers;
 mod v125_table_index;
+mod v126_vector_index;
+mod v127_dictionary_lifetime;
+mod v128_time_types;
+mod v129_pipe_info;
+mod v130_task;
+mod v131_udtf;
+mod v132_procedure_return_names;
+mod v133_pipe_owner;
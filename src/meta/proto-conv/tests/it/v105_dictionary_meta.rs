@@ -74,6 +74,7 @@ fn test_decode_v105_dictionary_meta() -> anyhow::Result<()> {
         },
         primary_column_ids: vec![0],
         comment: "comment_example".to_string(),
+        lifetime: None,
         created_on: Utc.with_ymd_and_hms(2024, 8, 5, 7, 0, 0).unwrap(),
         updated_on: None,
     };
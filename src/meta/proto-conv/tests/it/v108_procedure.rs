@@ -31,6 +31,7 @@ fn v108_procedure_meta() -> anyhow::Result<()> {
 
     let want = || mt::ProcedureMeta {
         return_types: vec![DataType::String],
+        return_names: vec![],
         arg_names: vec![],
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
         updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
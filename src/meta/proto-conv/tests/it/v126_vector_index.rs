@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
+use fastrace::func_name;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v126_vector_schema() -> anyhow::Result<()> {
+    let table_schema_v126 = vec![
+        10, 28, 10, 1, 97, 26, 17, 154, 2, 8, 34, 0, 160, 6, 126, 168, 6, 24, 160, 6, 126, 168, 6,
+        24, 160, 6, 126, 168, 6, 24, 10, 30, 10, 1, 118, 26, 17, 146, 3, 8, 8, 3, 160, 6, 126, 168,
+        6, 24, 160, 6, 126, 168, 6, 24, 32, 1, 160, 6, 126, 168, 6, 24, 24, 2, 160, 6, 126, 168, 6,
+        24,
+    ];
+
+    let fields = vec![
+        TableField::new("a", TableDataType::Number(NumberDataType::UInt64)),
+        TableField::new("v", TableDataType::Vector(3)),
+    ];
+
+    let want = || TableSchema::new(fields.clone());
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_schema_v126.as_slice(), 126, want())?;
+    Ok(())
+}
+
+#[test]
+fn test_decode_v126_vector_index() -> anyhow::Result<()> {
+    let table_index_v126 = vec![
+        10, 4, 105, 100, 120, 49, 18, 1, 1, 24, 1, 34, 32, 102, 49, 48, 98, 50, 51, 48, 49, 53, 51,
+        101, 49, 52, 102, 50, 99, 56, 52, 54, 48, 51, 57, 53, 56, 100, 55, 102, 56, 54, 52, 102,
+        56, 42, 11, 10, 6, 110, 112, 114, 111, 98, 101, 18, 1, 52, 48, 2, 160, 6, 126, 168, 6, 24,
+    ];
+    let want = || TableIndex {
+        index_type: TableIndexType::Vector,
+        name: "idx1".to_string(),
+        column_ids: vec![1],
+        sync_creation: true,
+        version: "f10b230153e14f2c84603958d7f864f8".to_string(),
+        options: btreemap! {s("nprobe") => s("4")},
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_index_v126.as_slice(), 126, want())?;
+    Ok(())
+}
+
+fn s(ss: impl ToString) -> String {
+    ss.to_string()
+}
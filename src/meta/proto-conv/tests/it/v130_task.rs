@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_meta_app::principal::task::ScheduleOptions;
+use databend_common_meta_app::principal::task::ScheduleType;
+use databend_common_meta_app::principal::task::State;
+use databend_common_meta_app::principal::task::Status;
+use databend_common_meta_app::principal::task::WarehouseOptions;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v130_task_run() -> anyhow::Result<()> {
+    let task_run_v130 = vec![
+        10, 202, 1, 8, 7, 18, 2, 116, 49, 26, 22, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32,
+        116, 32, 83, 69, 76, 69, 67, 84, 32, 49, 42, 5, 49, 32, 61, 32, 49, 58, 1, 99, 66, 2, 114,
+        49, 74, 37, 18, 11, 48, 32, 48, 32, 57, 32, 42, 32, 42, 32, 42, 26, 13, 65, 115, 105, 97,
+        47, 83, 104, 97, 110, 103, 104, 97, 105, 32, 1, 160, 6, 130, 1, 168, 6, 24, 82, 12, 10, 3,
+        119, 104, 49, 160, 6, 130, 1, 168, 6, 24, 90, 23, 50, 48, 49, 52, 45, 49, 49, 45, 51, 48,
+        32, 49, 50, 58, 48, 48, 58, 49, 49, 32, 85, 84, 67, 96, 3, 112, 1, 122, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 130, 1, 23, 50,
+        48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67,
+        146, 1, 15, 10, 8, 116, 105, 109, 101, 122, 111, 110, 101, 18, 3, 85, 84, 67, 160, 6, 130,
+        1, 168, 6, 24, 18, 2, 49, 48, 24, 1, 32, 3, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 51, 48,
+        32, 49, 50, 58, 48, 48, 58, 49, 49, 32, 85, 84, 67, 50, 23, 50, 48, 49, 52, 45, 49, 49, 45,
+        51, 48, 32, 49, 50, 58, 48, 48, 58, 49, 50, 32, 85, 84, 67, 56, 238, 7, 66, 15, 100, 105,
+        118, 105, 100, 101, 100, 32, 98, 121, 32, 122, 101, 114, 111, 72, 7, 82, 3, 113, 105, 100,
+        160, 6, 130, 1, 168, 6, 24,
+    ];
+
+    let want = || mt::TaskRun {
+        task: mt::Task {
+            task_id: 7,
+            task_name: "t1".to_string(),
+            query_text: "INSERT INTO t SELECT 1".to_string(),
+            script_sqls: vec![],
+            when_condition: Some("1 = 1".to_string()),
+            after: vec![],
+            comment: Some("c".to_string()),
+            owner: "r1".to_string(),
+            schedule_options: Some(ScheduleOptions {
+                interval: None,
+                cron: Some("0 0 9 * * *".to_string()),
+                time_zone: Some("Asia/Shanghai".to_string()),
+                schedule_type: ScheduleType::CronType,
+                milliseconds_interval: None,
+            }),
+            warehouse_options: Some(WarehouseOptions {
+                warehouse: Some("wh1".to_string()),
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: Some(Utc.with_ymd_and_hms(2014, 11, 30, 12, 0, 11).unwrap()),
+            suspend_task_after_num_failures: Some(3),
+            error_integration: None,
+            status: Status::Started,
+            created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+            last_suspended_at: None,
+            session_params: BTreeMap::from([("timezone".to_string(), "UTC".to_string())]),
+        },
+        run_id: "10".to_string(),
+        attempt_number: 1,
+        state: State::Failed,
+        scheduled_at: Utc.with_ymd_and_hms(2014, 11, 30, 12, 0, 11).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2014, 11, 30, 12, 0, 12).unwrap()),
+        error_code: 1006,
+        error_message: Some("divided by zero".to_string()),
+        root_task_id: 7,
+        query_id: "qid".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_run_v130.as_slice(), 130, want())?;
+    Ok(())
+}
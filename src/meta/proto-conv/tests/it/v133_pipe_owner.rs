@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v133_pipe_owner() -> anyhow::Result<()> {
+    let pipe_owner_v133 = vec![
+        10, 2, 112, 49, 18, 19, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116, 32, 70, 82, 79, 77,
+        32, 64, 115, 24, 1, 42, 1, 99, 50, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50,
+        58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32,
+        49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 66, 23, 50, 48, 49, 52, 45, 49, 49, 45, 51,
+        48, 32, 49, 50, 58, 48, 48, 58, 49, 49, 32, 85, 84, 67, 72, 3, 90, 5, 114, 111, 108, 101,
+        49, 160, 6, 133, 1, 168, 6, 24,
+    ];
+
+    let want = || mt::PipeInfo {
+        name: "p1".to_string(),
+        copy_statement: "COPY INTO t FROM @s".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "c".to_string(),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+        last_load_on: Some(Utc.with_ymd_and_hms(2014, 11, 30, 12, 0, 11).unwrap()),
+        last_load_files: 3,
+        last_load_error: None,
+        owner: "role1".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), pipe_owner_v133.as_slice(), 133, want())?;
+    Ok(())
+}
@@ -1090,6 +1090,9 @@ pub enum TypeName {
     Geometry,
     Geography,
     Interval,
+    Vector(u64),
+    Time,
+    TimestampTz,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -1221,6 +1224,15 @@ impl Display for TypeName {
             TypeName::Interval => {
                 write!(f, "INTERVAL")?;
             }
+            TypeName::Vector(dimension) => {
+                write!(f, "VECTOR({})", dimension)?;
+            }
+            TypeName::Time => {
+                write!(f, "TIME")?;
+            }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMPTZ")?;
+            }
         }
         Ok(())
     }
@@ -1793,6 +1805,9 @@ impl ExprReplacer {
             TimeTravelPoint::Offset(offset) => {
                 self.replace_expr(offset);
             }
+            TimeTravelPoint::Version(version) => {
+                self.replace_expr(version);
+            }
             _ => (),
         }
     }
@@ -565,11 +565,13 @@ pub enum TimeTravelPoint {
     Snapshot(String),
     Timestamp(Box<Expr>),
     Offset(Box<Expr>),
+    Version(Box<Expr>),
     Stream {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         name: Identifier,
     },
+    Tag(Identifier),
 }
 
 impl Display for TimeTravelPoint {
@@ -584,6 +586,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Offset(num) => {
                 write!(f, "(OFFSET => {num})")?;
             }
+            TimeTravelPoint::Version(version) => {
+                write!(f, "(VERSION => {version})")?;
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
@@ -596,6 +601,9 @@ impl Display for TimeTravelPoint {
                 )?;
                 write!(f, ")")?;
             }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, "(TAG => {name})")?;
+            }
         }
 
         Ok(())
@@ -1040,8 +1048,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION ({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -1095,6 +1112,8 @@ impl Display for TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // `MATCH_CONDITION (expr)` of ASOF joins
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -1112,6 +1131,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // ASOF joins must carry a `MATCH_CONDITION`, `JoinCondition` may only contain equi-predicates
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -38,6 +38,7 @@ pub struct CreateDictionaryStmt {
     pub primary_keys: Vec<Identifier>,
     pub source_name: Identifier,
     pub source_options: BTreeMap<String, String>,
+    pub lifetime: Option<u64>,
     pub comment: Option<String>,
 }
 
@@ -74,6 +75,9 @@ impl Display for CreateDictionaryStmt {
         }
         write!(f, ")")?;
         write!(f, ")")?;
+        if let Some(lifetime) = self.lifetime {
+            write!(f, " LIFETIME({lifetime})")?;
+        }
         if let Some(comment) = &self.comment {
             write!(f, "COMMENT {} ", QuotedString(comment.clone(), '\''))?;
         }
@@ -37,6 +37,7 @@ pub struct InsertStmt {
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
+    pub partition: Option<Vec<Expr>>,
 }
 
 impl Display for InsertStmt {
@@ -60,6 +61,11 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if let Some(partition) = &self.partition {
+            write!(f, " PARTITION (")?;
+            write_comma_separated_list(f, partition)?;
+            write!(f, ")")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -29,6 +29,7 @@ mod insert;
 mod insert_multi_table;
 mod kill;
 mod lock;
+mod materialized_view;
 mod merge_into;
 mod network_policy;
 mod notification;
@@ -75,6 +76,7 @@ pub use insert::*;
 pub use insert_multi_table::*;
 pub use kill::*;
 pub use lock::*;
+pub use materialized_view::*;
 pub use merge_into::*;
 pub use network_policy::*;
 pub use notification::*;
@@ -27,6 +27,7 @@ pub struct ScriptBlock {
     pub span: Span,
     pub declares: Vec<DeclareItem>,
     pub body: Vec<ScriptStatement>,
+    pub exception_handlers: Vec<ExceptionHandler>,
 }
 
 impl Display for ScriptBlock {
@@ -47,6 +48,16 @@ impl Display for ScriptBlock {
                 indent::indent_all_by(INDENT_DEPTH, format!("{};", stmt))
             )?;
         }
+        if !self.exception_handlers.is_empty() {
+            writeln!(f, "EXCEPTION")?;
+            for handler in &self.exception_handlers {
+                writeln!(
+                    f,
+                    "{}",
+                    indent::indent_all_by(INDENT_DEPTH, format!("{handler}"))
+                )?;
+            }
+        }
         writeln!(f, "END;")?;
         Ok(())
     }
@@ -97,6 +108,54 @@ impl Display for DeclareSet {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExceptionHandler {
+    pub span: Span,
+    pub conditions: Vec<ExceptionCondition>,
+    pub body: Vec<ScriptStatement>,
+}
+
+impl Display for ExceptionHandler {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "WHEN ")?;
+        for (i, condition) in self.conditions.iter().enumerate() {
+            if i > 0 {
+                write!(f, " OR ")?;
+            }
+            write!(f, "{condition}")?;
+        }
+        writeln!(f, " THEN")?;
+        for (i, stmt) in self.body.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{}",
+                indent::indent_all_by(INDENT_DEPTH, format!("{stmt};"))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExceptionCondition {
+    /// Matches the errors with the error code.
+    Code(u64),
+    /// Matches all the errors.
+    Other,
+}
+
+impl Display for ExceptionCondition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExceptionCondition::Code(code) => write!(f, "{code}"),
+            ExceptionCondition::Other => write!(f, "OTHER"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[allow(clippy::large_enum_variant)]
 pub enum ReturnItem {
@@ -197,6 +256,16 @@ pub enum ScriptStatement {
         results: Vec<Vec<ScriptStatement>>,
         else_result: Option<Vec<ScriptStatement>>,
     },
+    Block {
+        span: Span,
+        body: Vec<ScriptStatement>,
+        exception_handlers: Vec<ExceptionHandler>,
+    },
+    Raise {
+        span: Span,
+        code: Option<Expr>,
+        message: Option<Expr>,
+    },
 }
 
 impl Display for ScriptStatement {
@@ -424,6 +493,41 @@ impl Display for ScriptStatement {
                 }
                 write!(f, "END IF")
             }
+            ScriptStatement::Block {
+                body,
+                exception_handlers,
+                ..
+            } => {
+                writeln!(f, "BEGIN")?;
+                for stmt in body {
+                    writeln!(
+                        f,
+                        "{}",
+                        indent::indent_all_by(INDENT_DEPTH, format!("{stmt};"))
+                    )?;
+                }
+                if !exception_handlers.is_empty() {
+                    writeln!(f, "EXCEPTION")?;
+                    for handler in exception_handlers {
+                        writeln!(
+                            f,
+                            "{}",
+                            indent::indent_all_by(INDENT_DEPTH, format!("{handler}"))
+                        )?;
+                    }
+                }
+                write!(f, "END")
+            }
+            ScriptStatement::Raise { code, message, .. } => {
+                write!(f, "RAISE")?;
+                if let Some(code) = code {
+                    write!(f, " {code}")?;
+                }
+                if let Some(message) = message {
+                    write!(f, ", {message}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -222,6 +222,8 @@ pub enum Statement {
     RefreshInvertedIndex(RefreshInvertedIndexStmt),
     CreateNgramIndex(CreateNgramIndexStmt),
     DropNgramIndex(DropNgramIndexStmt),
+    CreateVectorIndex(CreateVectorIndexStmt),
+    DropVectorIndex(DropVectorIndexStmt),
 
     // VirtualColumns
     RefreshVirtualColumn(RefreshVirtualColumnStmt),
@@ -336,6 +338,12 @@ pub enum Statement {
     ShowTasks(ShowTasksStmt),
 
     CreateDynamicTable(CreateDynamicTableStmt),
+    RefreshDynamicTable(RefreshDynamicTableStmt),
+
+    // materialized views
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+    DropMaterializedView(DropMaterializedViewStmt),
 
     // pipes
     CreatePipe(CreatePipeStmt),
@@ -542,6 +550,8 @@ impl Statement {
             | Statement::DropInvertedIndex(..)
             | Statement::CreateNgramIndex(..)
             | Statement::DropNgramIndex(..)
+            | Statement::CreateVectorIndex(..)
+            | Statement::DropVectorIndex(..)
             | Statement::CreateUser(..)
             | Statement::DropUser { .. }
             | Statement::CreateRole { .. }
@@ -567,6 +577,10 @@ impl Statement {
             | Statement::AlterTask(..)
             | Statement::DropTask(..)
             | Statement::CreateDynamicTable(..)
+            | Statement::RefreshDynamicTable(..)
+            | Statement::CreateMaterializedView(..)
+            | Statement::RefreshMaterializedView(..)
+            | Statement::DropMaterializedView(..)
             | Statement::DropPipe(..)
             | Statement::AlterPipe(..)
             | Statement::CreateNotification(..)
@@ -824,6 +838,8 @@ impl Display for Statement {
             Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNgramIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropNgramIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropVectorIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::ShowVirtualColumns(stmt) => write!(f, "{stmt}")?,
@@ -1005,6 +1021,10 @@ impl Display for Statement {
             Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
             Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshDynamicTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaterializedView(stmt) => write!(f, "{stmt}")?,
             Statement::SetPriority {
                 priority,
                 object_id,
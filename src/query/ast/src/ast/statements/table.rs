@@ -185,7 +185,7 @@ pub struct CreateTableStmt {
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Option<ClusterOption>,
     pub table_options: BTreeMap<String, String>,
-    pub iceberg_table_partition: Option<Vec<Identifier>>,
+    pub table_partition: Option<Vec<Expr>>,
     pub table_properties: Option<BTreeMap<String, String>>,
     pub as_query: Option<Box<Query>>,
     pub table_type: TableType,
@@ -244,9 +244,9 @@ impl Display for CreateTableStmt {
             write_space_separated_string_map(f, &self.table_options)?;
         }
 
-        if let Some(iceberg_table_partition) = &self.iceberg_table_partition {
+        if let Some(table_partition) = &self.table_partition {
             write!(f, " PARTITION BY(")?;
-            write_comma_separated_list(f, iceberg_table_partition)?;
+            write_comma_separated_list(f, table_partition)?;
             write!(f, ")")?;
         }
 
@@ -302,18 +302,30 @@ pub enum CreateTableSource {
         Vec<ColumnDefinition>,
         Option<Vec<InvertedIndexDefinition>>,
         Option<Vec<NgramIndexDefinition>>,
+        Option<Vec<VectorIndexDefinition>>,
     ),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns, inverted_indexes, ngram_indexes) => {
+            CreateTableSource::Columns(
+                columns,
+                inverted_indexes,
+                ngram_indexes,
+                vector_indexes,
+            ) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
                 if let Some(inverted_indexes) = inverted_indexes {
@@ -324,6 +336,10 @@ impl Display for CreateTableSource {
                     write!(f, ", ")?;
                     write_comma_separated_list(f, ngram_indexes)?;
                 }
+                if let Some(vector_indexes) = vector_indexes {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, vector_indexes)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -334,6 +350,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -451,6 +480,9 @@ pub enum AlterTableAction {
         cluster_by: ClusterOption,
     },
     DropTableClusterKey,
+    DropPartition {
+        values: Vec<Expr>,
+    },
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -459,6 +491,17 @@ pub enum AlterTableAction {
     FlashbackTo {
         point: TimeTravelPoint,
     },
+    CreateTag {
+        tag: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+        #[drive(skip)]
+        retain: Option<Duration>,
+    },
+    DropTag {
+        #[drive(skip)]
+        if_exists: bool,
+        tag: Identifier,
+    },
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
@@ -523,6 +566,31 @@ impl Display for AlterTableAction {
             AlterTableAction::FlashbackTo { point } => {
                 write!(f, "FLASHBACK TO {}", point)?;
             }
+            AlterTableAction::CreateTag {
+                tag,
+                travel_point,
+                retain,
+            } => {
+                write!(f, "CREATE TAG {tag}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                if let Some(retain) = retain {
+                    write!(f, " RETAIN {} SECONDS", retain.as_secs())?;
+                }
+            }
+            AlterTableAction::DropPartition { values } => {
+                write!(f, "DROP PARTITION (")?;
+                write_comma_separated_list(f, values)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropTag { if_exists, tag } => {
+                write!(f, "DROP TAG ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "{tag}")?;
+            }
             AlterTableAction::UnsetOptions {
                 targets: unset_targets,
             } => {
@@ -986,11 +1054,34 @@ impl Display for NgramIndexDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct VectorIndexDefinition {
+    pub index_name: Identifier,
+    pub column: Identifier,
+    pub index_options: BTreeMap<String, String>,
+}
+
+impl Display for VectorIndexDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VECTOR INDEX")?;
+        write!(f, " {}", self.index_name)?;
+        write!(f, " ({})", self.column)?;
+
+        if !self.index_options.is_empty() {
+            write!(f, " ")?;
+            write_space_separated_string_map(f, &self.index_options)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateDefinition {
     Column(ColumnDefinition),
     InvertedIndex(InvertedIndexDefinition),
     NgramIndex(NgramIndexDefinition),
+    VectorIndex(VectorIndexDefinition),
 }
 
 impl Display for CreateDefinition {
@@ -1005,6 +1096,9 @@ impl Display for CreateDefinition {
             CreateDefinition::NgramIndex(ngram_index_def) => {
                 write!(f, "{}", ngram_index_def)?;
             }
+            CreateDefinition::VectorIndex(vector_index_def) => {
+                write!(f, "{}", vector_index_def)?;
+            }
         }
         Ok(())
     }
@@ -63,6 +63,22 @@ pub enum UDFDefinition {
         language: String,
         runtime_version: String,
     },
+    UDTFServer {
+        arg_types: Vec<TypeName>,
+        return_fields: Vec<UDTFReturnField>,
+        address: String,
+        handler: String,
+        headers: BTreeMap<String, String>,
+        language: String,
+    },
+    UDTFScript {
+        arg_types: Vec<TypeName>,
+        return_fields: Vec<UDTFReturnField>,
+        code: String,
+        handler: String,
+        language: String,
+        runtime_version: String,
+    },
 }
 
 impl Display for UDFDefinition {
@@ -159,6 +175,48 @@ impl Display for UDFDefinition {
                     " }} RETURNS {return_type} LANGUAGE {language} AS $$\n{code}\n$$"
                 )?;
             }
+            UDFDefinition::UDTFServer {
+                arg_types,
+                return_fields,
+                address,
+                handler,
+                headers,
+                language,
+            } => {
+                write!(f, "( ")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(f, " ) RETURNS TABLE (")?;
+                write_comma_separated_list(f, return_fields)?;
+                write!(f, ") LANGUAGE {language} HANDLER = '{handler}'")?;
+                if !headers.is_empty() {
+                    write!(f, " HEADERS = (")?;
+                    for (i, (key, value)) in headers.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "'{key}' = '{value}'")?;
+                    }
+                    write!(f, ")")?;
+                }
+                write!(f, " ADDRESS = '{address}'")?;
+            }
+            UDFDefinition::UDTFScript {
+                arg_types,
+                return_fields,
+                code,
+                handler,
+                language,
+                runtime_version: _,
+            } => {
+                write!(f, "( ")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(f, " ) RETURNS TABLE (")?;
+                write_comma_separated_list(f, return_fields)?;
+                write!(
+                    f,
+                    ") LANGUAGE {language} HANDLER = '{handler}' AS $$\n{code}\n$$"
+                )?;
+            }
         }
         Ok(())
     }
@@ -177,6 +235,19 @@ impl Display for UDAFStateField {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct UDTFReturnField {
+    pub name: Identifier,
+    pub type_name: TypeName,
+}
+
+impl Display for UDTFReturnField {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.type_name)?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateUDFStmt {
     pub create_option: CreateOption,
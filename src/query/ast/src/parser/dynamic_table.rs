@@ -22,6 +22,7 @@ use crate::ast::ClusterOption;
 use crate::ast::ClusterType;
 use crate::ast::CreateDynamicTableStmt;
 use crate::ast::InitializeMode;
+use crate::ast::RefreshDynamicTableStmt;
 use crate::ast::RefreshMode;
 use crate::ast::Statement;
 use crate::ast::TargetLag;
@@ -53,6 +54,7 @@ pub fn dynamic_table(i: Input) -> IResult<Statement> {
   [ COMMENT = '<string_literal>' ]
 AS
   <sql>`"
+        | #refresh_dynamic_table : "`ALTER DYNAMIC TABLE [<database>.]<table> REFRESH`"
     )(i)
 }
 
@@ -105,6 +107,21 @@ fn create_dynamic_table(i: Input) -> IResult<Statement> {
     )(i)
 }
 
+fn refresh_dynamic_table(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            ALTER ~ DYNAMIC ~ TABLE ~ #dot_separated_idents_1_to_3 ~ REFRESH
+        },
+        |(_, _, _, (catalog, database, table), _)| {
+            Statement::RefreshDynamicTable(RefreshDynamicTableStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    )(i)
+}
+
 fn dynamic_table_options(
     i: Input,
 ) -> IResult<(
@@ -120,11 +137,6 @@ fn dynamic_table_options(
         |(_, _, target_lag)| target_lag,
     );
 
-    let refresh_mode = alt((
-        value(RefreshMode::Auto, rule! { AUTO }),
-        value(RefreshMode::Full, rule! { FULL }),
-        value(RefreshMode::Incremental, rule! { INCREMENTAL }),
-    ));
     let refresh_mode_opt = map(
         rule! {
             (REFRESH_MODE ~ "=" ~ #refresh_mode)?
@@ -151,7 +163,15 @@ fn dynamic_table_options(
     ))(i)
 }
 
-fn target_lag(i: Input) -> IResult<TargetLag> {
+pub(crate) fn refresh_mode(i: Input) -> IResult<RefreshMode> {
+    alt((
+        value(RefreshMode::Auto, rule! { AUTO }),
+        value(RefreshMode::Full, rule! { FULL }),
+        value(RefreshMode::Incremental, rule! { INCREMENTAL }),
+    ))(i)
+}
+
+pub(crate) fn target_lag(i: Input) -> IResult<TargetLag> {
     let interval_sec = map(
         rule! {
              #literal_u64 ~ SECOND
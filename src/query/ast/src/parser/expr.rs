@@ -1771,6 +1771,17 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_time = map(
+        rule! { TIME ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
+        |(_, _)| TypeName::Time,
+    );
+    let ty_timestamp_tz = alt((
+        value(TypeName::TimestampTz, rule! { TIMESTAMPTZ | TIMESTAMP_TZ }),
+        value(
+            TypeName::TimestampTz,
+            rule! { TIMESTAMP ~ ( "(" ~ #literal_u64 ~ ")" )? ~ WITH ~ TIME ~ ZONE },
+        ),
+    ));
     let ty_binary = value(
         TypeName::Binary,
         rule! { ( BINARY | VARBINARY | LONGBLOB | MEDIUMBLOB |  TINYBLOB| BLOB ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
@@ -1782,6 +1793,17 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_geography = value(TypeName::Geography, rule! { GEOGRAPHY });
+    let ty_vector = map_res(
+        rule! { VECTOR ~ "(" ~ ^#literal_u64 ~ ^")" },
+        |(_, _, dimension, _)| {
+            if dimension == 0 {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "vector dimension must be greater than 0",
+                )));
+            }
+            Ok(TypeName::Vector(dimension))
+        },
+    );
     map_res(
         alt((
             rule! {
@@ -1806,7 +1828,9 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             },
             rule! {
             ( #ty_date
+            | #ty_timestamp_tz
             | #ty_datetime
+            | #ty_time
             | #ty_interval
             | #ty_numeric
             | #ty_binary
@@ -1814,6 +1838,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_variant
             | #ty_geometry
             | #ty_geography
+            | #ty_vector
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
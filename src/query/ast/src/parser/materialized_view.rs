@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use nom::branch::permutation;
+use nom::combinator::map;
+use nom_rule::rule;
+
+use crate::ast::CreateMaterializedViewStmt;
+use crate::ast::DropMaterializedViewStmt;
+use crate::ast::RefreshMaterializedViewStmt;
+use crate::ast::RefreshMode;
+use crate::ast::Statement;
+use crate::ast::TargetLag;
+use crate::parser::common::dot_separated_idents_1_to_3;
+use crate::parser::common::map_res;
+use crate::parser::common::IResult;
+use crate::parser::common::*;
+use crate::parser::dynamic_table::refresh_mode;
+use crate::parser::dynamic_table::target_lag;
+use crate::parser::query::query;
+use crate::parser::statement::parse_create_option;
+use crate::parser::token::TokenKind::*;
+use crate::parser::Input;
+
+pub fn materialized_view(i: Input) -> IResult<Statement> {
+    rule!(
+        #create_materialized_view : "`CREATE [OR REPLACE] MATERIALIZED VIEW [ IF NOT EXISTS ] [<database>.]<view>
+  [ TARGET_LAG = { <num> { SECOND | MINUTE | HOUR | DAY } | DOWNSTREAM } ]
+  [ REFRESH_MODE = { AUTO | FULL | INCREMENTAL } ]
+AS
+  <sql>`"
+        | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+        | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+    )(i)
+}
+
+fn create_materialized_view(i: Input) -> IResult<Statement> {
+    map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ #materialized_view_options
+            ~ AS ~ ^#query
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, view),
+            (target_lag, refresh_mode),
+            _,
+            query,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateMaterializedView(
+                CreateMaterializedViewStmt {
+                    create_option,
+                    catalog,
+                    database,
+                    view,
+                    // Refreshed manually by default.
+                    target_lag: target_lag.unwrap_or(TargetLag::Downstream),
+                    refresh_mode: refresh_mode.unwrap_or(RefreshMode::Auto),
+                    query: Box::new(query),
+                },
+            ))
+        },
+    )(i)
+}
+
+fn refresh_materialized_view(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    )(i)
+}
+
+fn drop_materialized_view(i: Input) -> IResult<Statement> {
+    map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    )(i)
+}
+
+fn materialized_view_options(i: Input) -> IResult<(Option<TargetLag>, Option<RefreshMode>)> {
+    let target_lag_opt = map(
+        rule! {
+            (TARGET_LAG ~ "=" ~ #target_lag)?
+        },
+        |v| v.map(|v| v.2),
+    );
+    let refresh_mode_opt = map(
+        rule! {
+            (REFRESH_MODE ~ "=" ~ #refresh_mode)?
+        },
+        |v| v.map(|v| v.2),
+    );
+
+    permutation((target_lag_opt, refresh_mode_opt))(i)
+}
@@ -19,6 +19,7 @@ pub mod dynamic_table;
 mod error;
 pub mod expr;
 mod input;
+pub mod materialized_view;
 #[allow(clippy::module_inception)]
 mod parser;
 pub mod query;
@@ -29,20 +29,53 @@ pub fn script_block(i: Input) -> IResult<ScriptBlock> {
             ( DECLARE ~ #semicolon_terminated_list1(declare_item) )?
             ~ BEGIN
             ~ #semicolon_terminated_list1(script_stmt)
+            ~ ( EXCEPTION ~ ^#exception_handler+ )?
             ~ END
             ~ ";"
         }),
-        |(span, (declares, _, body, _, _))| {
+        |(span, (declares, _, body, exception_handlers, _, _))| {
             let declares = declares.map(|(_, declare)| declare).unwrap_or_default();
+            let exception_handlers = exception_handlers
+                .map(|(_, handlers)| handlers)
+                .unwrap_or_default();
             ScriptBlock {
                 span: transform_span(span.tokens),
                 declares,
                 body,
+                exception_handlers,
             }
         },
     )(i)
 }
 
+pub fn exception_handler(i: Input) -> IResult<ExceptionHandler> {
+    map(
+        consumed(rule! {
+            WHEN ~ ^#exception_condition ~ ( OR ~ ^#exception_condition )*
+            ~ ^THEN ~ ^#semicolon_terminated_list1(script_stmt)
+        }),
+        |(span, (_, condition, other_conditions, _, body))| {
+            let mut conditions = vec![condition];
+            conditions.extend(other_conditions.into_iter().map(|(_, cond)| cond));
+            ExceptionHandler {
+                span: transform_span(span.tokens),
+                conditions,
+                body,
+            }
+        },
+    )(i)
+}
+
+pub fn exception_condition(i: Input) -> IResult<ExceptionCondition> {
+    let code = map(literal_u64, ExceptionCondition::Code);
+    let other = map(rule! { OTHER }, |_| ExceptionCondition::Other);
+
+    rule!(
+        #code
+        | #other
+    )(i)
+}
+
 pub fn declare_item(i: Input) -> IResult<DeclareItem> {
     let declare_var = map(declare_var, DeclareItem::Var);
     let declare_set = map(declare_set, DeclareItem::Set);
@@ -299,9 +332,42 @@ pub fn script_stmt(i: Input) -> IResult<ScriptStatement> {
         },
     );
 
+    let block_stmt = map(
+        consumed(rule! {
+            BEGIN ~ #semicolon_terminated_list1(script_stmt)
+            ~ ( EXCEPTION ~ ^#exception_handler+ )?
+            ~ ^END
+        }),
+        |(span, (_, body, exception_handlers, _))| ScriptStatement::Block {
+            span: transform_span(span.tokens),
+            body,
+            exception_handlers: exception_handlers
+                .map(|(_, handlers)| handlers)
+                .unwrap_or_default(),
+        },
+    );
+    let raise_stmt = map(
+        consumed(rule! {
+            RAISE ~ ( #expr ~ ( "," ~ ^#expr )? )?
+        }),
+        |(span, (_, args))| {
+            let (code, message) = match args {
+                Some((code, message)) => (Some(code), message.map(|(_, message)| message)),
+                None => (None, None),
+            };
+            ScriptStatement::Raise {
+                span: transform_span(span.tokens),
+                code,
+                message,
+            }
+        },
+    );
+
     rule!(
         #let_stmt_stmt
         | #let_var_stmt
+        | #block_stmt
+        | #raise_stmt
         | #run_stmt
         | #assign_stmt
         | #return_set_stmt
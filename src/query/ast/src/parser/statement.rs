@@ -34,6 +34,7 @@ use crate::parser::dynamic_table::dynamic_table;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::input::Input;
+use crate::parser::materialized_view::materialized_view;
 use crate::parser::query::*;
 use crate::parser::stage::*;
 use crate::parser::stream::stream_table;
@@ -963,7 +964,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
-            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( PROPERTIES ~  #connection_options )?
             ~ ( AS ~ ^#query )?
         },
@@ -979,7 +980,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             uri_location,
             opt_cluster_by,
             opt_table_options,
-            opt_iceberg_table_partition_by,
+            opt_table_partition_by,
             opt_table_properties,
             opt_as_query,
         )| {
@@ -1004,8 +1005,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     cluster_exprs: exprs,
                 }),
                 table_options: opt_table_options.unwrap_or_default(),
-                iceberg_table_partition: opt_iceberg_table_partition_by
-                    .map(|(_, _, _, cols, _)| cols),
+                table_partition: opt_table_partition_by.map(|(_, _, _, exprs, _)| exprs),
                 table_properties: opt_table_properties.map(|(_, properties)| properties),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
                 table_type,
@@ -1174,6 +1174,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ^SOURCE ~ ^"(" ~ ^#ident ~ ^"("
             ~ ( #table_option )?
             ~ ^")" ~ ^")"
+            ~ ( LIFETIME ~ ^"(" ~ ^#literal_u64 ~ ^")" )?
             ~ ( COMMENT ~ ^#literal_string )?
         },
         |(
@@ -1195,6 +1196,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             opt_source_options,
             _,
             _,
+            opt_lifetime,
             opt_comment,
         )| {
             let create_option =
@@ -1208,6 +1210,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 primary_keys,
                 source_name,
                 source_options: opt_source_options.unwrap_or_default(),
+                lifetime: opt_lifetime.map(|(_, _, lifetime, _)| lifetime),
                 comment: opt_comment.map(|(_, comment)| comment),
             }))
         },
@@ -1546,6 +1549,61 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let create_vector_index = map_res(
+        rule! {
+            CREATE
+            ~ ( OR ~ ^REPLACE )?
+            ~ VECTOR ~ INDEX
+            ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#ident ~ ^")"
+            ~ ( #table_option )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            opt_index_options,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                create_option,
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                index_options: opt_index_options.unwrap_or_default(),
+            }))
+        },
+    );
+
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropVectorIndex(DropVectorIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_virtual_column = map(
         rule! {
             REFRESH ~ VIRTUAL ~ COLUMN ~ FOR ~ #dot_separated_idents_1_to_3
@@ -2621,7 +2679,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         ),
         // dictionary
         rule!(
-            #create_dictionary : "`CREATE [OR REPLACE] DICTIONARY [IF NOT EXISTS] <dictionary_name> [(<column>, ...)] PRIMARY KEY [<primary_key>, ...] SOURCE (<source_name> ([<source_options>])) [COMMENT <comment>] `"
+            #create_dictionary : "`CREATE [OR REPLACE] DICTIONARY [IF NOT EXISTS] <dictionary_name> [(<column>, ...)] PRIMARY KEY [<primary_key>, ...] SOURCE (<source_name> ([<source_options>])) [LIFETIME(<seconds>)] [COMMENT <comment>] `"
             | #drop_dictionary : "`DROP DICTIONARY [IF EXISTS] <dictionary_name>`"
             | #show_create_dictionary : "`SHOW CREATE DICTIONARY <dictionary_name> `"
             | #show_dictionaries : "`SHOW DICTIONARIES [<show_option>, ...]`"
@@ -2641,6 +2699,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
             | #create_ngram_index: "`CREATE [OR REPLACE] NGRAM INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
             | #drop_ngram_index: "`DROP NGRAM INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #create_vector_index: "`CREATE [OR REPLACE] VECTOR INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>)`"
+            | #drop_vector_index: "`DROP VECTOR INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
             | #refresh_virtual_column: "`REFRESH VIRTUAL COLUMN FOR [<database>.]<table>`"
             | #show_virtual_columns : "`SHOW VIRTUAL COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #sequence
@@ -2700,10 +2760,11 @@ AS
          | #desc_task : "`DESC | DESCRIBE TASK <name>`"
          | #execute_task: "`EXECUTE TASK <name>`"
         ),
-        // stream, dynamic tables.
+        // stream, dynamic tables, materialized views.
         rule!(
             #stream_table
             | #dynamic_table
+            | #materialized_view
         ),
         rule!(
             #create_pipe : "`CREATE PIPE [ IF NOT EXISTS ] <name>
@@ -2775,6 +2836,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
             rule! {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
+                ~ ( PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2785,6 +2847,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 overwrite,
                 _,
                 (catalog, database, table),
+                opt_partition,
                 opt_columns,
                 source,
             )| {
@@ -2799,6 +2862,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    partition: opt_partition.map(|(_, _, values, _)| values),
                 })
             },
         )(i)
@@ -3273,6 +3337,22 @@ pub fn ngram_index_def(i: Input) -> IResult<NgramIndexDefinition> {
     )(i)
 }
 
+pub fn vector_index_def(i: Input) -> IResult<VectorIndexDefinition> {
+    map(
+        rule! {
+            VECTOR ~ ^INDEX
+            ~ #ident
+            ~ ^"(" ~ ^#ident ~ ^")"
+            ~ ( #table_option )?
+        },
+        |(_, _, index_name, _, column, _, opt_index_options)| VectorIndexDefinition {
+            index_name,
+            column,
+            index_options: opt_index_options.unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn create_def(i: Input) -> IResult<CreateDefinition> {
     alt((
         map(rule! { #column_def }, CreateDefinition::Column),
@@ -3281,6 +3361,7 @@ pub fn create_def(i: Input) -> IResult<CreateDefinition> {
             CreateDefinition::InvertedIndex,
         ),
         map(rule! { #ngram_index_def }, CreateDefinition::NgramIndex),
+        map(rule! { #vector_index_def }, CreateDefinition::VectorIndex),
     ))(i)
 }
 
@@ -3666,6 +3747,7 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             let mut columns = Vec::with_capacity(create_defs.len());
             let mut inverted_indexes = Vec::new();
             let mut ngram_indexes = Vec::new();
+            let mut vector_indexes = Vec::new();
             for create_def in create_defs {
                 match create_def {
                     CreateDefinition::Column(column) => {
@@ -3677,6 +3759,9 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
                     CreateDefinition::NgramIndex(ngram_index) => {
                         ngram_indexes.push(ngram_index);
                     }
+                    CreateDefinition::VectorIndex(vector_index) => {
+                        vector_indexes.push(vector_index);
+                    }
                 }
             }
             let opt_inverted_indexes = if !inverted_indexes.is_empty() {
@@ -3689,7 +3774,17 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             } else {
                 None
             };
-            CreateTableSource::Columns(columns, opt_inverted_indexes, opt_ngram_indexes)
+            let opt_vector_indexes = if !vector_indexes.is_empty() {
+                Some(vector_indexes)
+            } else {
+                None
+            };
+            CreateTableSource::Columns(
+                columns,
+                opt_inverted_indexes,
+                opt_ngram_indexes,
+                opt_vector_indexes,
+            )
         },
     );
     let like = map(
@@ -3702,10 +3797,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#at_snapshot_or_ts )?
+        },
+        |(_, (catalog, database, table), opt_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_point.map(|(_, point)| point),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3899,6 +4006,13 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
     );
 
+    let drop_partition = map(
+        rule! {
+            DROP ~ PARTITION ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, values, _)| AlterTableAction::DropPartition { values },
+    );
+
     let drop_table_cluster_key = map(
         rule! {
             DROP ~ CLUSTER ~ KEY
@@ -3924,6 +4038,27 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, point)| AlterTableAction::FlashbackTo { point },
     );
 
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ #ident ~ ( AT ~ ^#at_snapshot_or_ts )? ~ ( RETAIN ~ #literal_duration )?
+        },
+        |(_, _, tag, opt_point, opt_retain)| AlterTableAction::CreateTag {
+            tag,
+            travel_point: opt_point.map(|(_, point)| point),
+            retain: opt_retain.map(|(_, retain)| retain),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, tag)| AlterTableAction::DropTag {
+            if_exists: opt_if_exists.is_some(),
+            tag,
+        },
+    );
+
     let set_table_options = map(
         rule! {
             SET ~ OPTIONS ~ "(" ~ #set_table_option ~ ")"
@@ -3948,6 +4083,9 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #drop_partition
+        | #create_tag
+        | #drop_tag
         | #rename_table
         | #rename_column
         | #modify_table_comment
@@ -4726,6 +4864,17 @@ pub fn udaf_state_field(i: Input) -> IResult<UDAFStateField> {
     )(i)
 }
 
+pub fn udtf_return_field(i: Input) -> IResult<UDTFReturnField> {
+    map(
+        rule! {
+            #ident
+            ~ #type_name
+            : "`<column name> <type>`"
+        },
+        |(name, type_name)| UDTFReturnField { name, type_name },
+    )(i)
+}
+
 pub fn udf_header(i: Input) -> IResult<(String, String)> {
     map(
         rule! {
@@ -4817,6 +4966,56 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
         },
     );
 
+    let udtf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(type_name) ~ ")"
+            ~ RETURNS ~ TABLE ~ "(" ~ #comma_separated_list1(udtf_return_field) ~ ")"
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ ( HEADERS ~ ^"=" ~ "(" ~ #comma_separated_list0(udf_header) ~ ")" )?
+            ~ #udf_script_or_address
+        },
+        |(
+            _,
+            arg_types,
+            _,
+            _,
+            _,
+            _,
+            return_fields,
+            _,
+            _,
+            language,
+            _,
+            _,
+            handler,
+            headers,
+            address_or_code,
+        )| {
+            if address_or_code.1 {
+                UDFDefinition::UDTFScript {
+                    arg_types,
+                    return_fields,
+                    code: address_or_code.0,
+                    handler,
+                    language: language.to_string(),
+                    runtime_version: "".to_string(),
+                }
+            } else {
+                UDFDefinition::UDTFServer {
+                    arg_types,
+                    return_fields,
+                    address: address_or_code.0,
+                    handler,
+                    language: language.to_string(),
+                    headers: headers
+                        .map(|(_, _, _, headers, _)| BTreeMap::from_iter(headers))
+                        .unwrap_or_default(),
+                }
+            }
+        },
+    );
+
     let udaf = map(
         rule! {
             "(" ~ #comma_separated_list0(type_name) ~ ")"
@@ -4870,6 +5069,7 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     rule!(
         #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
         | #udaf: "(<arg_type>, ...) STATE {<state_field>, ...} RETURNS <return_type> LANGUAGE <language> { ADDRESS=<udf_server_address> | AS <language_codes> } "
+        | #udtf: "(<arg_type>, ...) RETURNS TABLE (<column_name> <column_type>, ...) LANGUAGE <language> HANDLER=<handler> { ADDRESS=<udf_server_address> | AS <language_codes> } "
         | #udf: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> { ADDRESS=<udf_server_address> | AS <language_codes> } "
 
     )(i)
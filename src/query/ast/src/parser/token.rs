@@ -377,6 +377,8 @@ pub enum TokenKind {
     ASC,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ASYNC", ignore(ascii_case))]
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
@@ -437,6 +439,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -605,6 +609,8 @@ pub enum TokenKind {
     ERROR_ON_COLUMN_COUNT_MISMATCH,
     #[token("ESCAPE", ignore(ascii_case))]
     ESCAPE,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
     #[token("EXCEPTION_BACKTRACE", ignore(ascii_case))]
     EXCEPTION_BACKTRACE,
     #[token("EXISTS", ignore(ascii_case))]
@@ -802,6 +808,8 @@ pub enum TokenKind {
     LEFT,
     #[token("LET", ignore(ascii_case))]
     LET,
+    #[token("LIFETIME", ignore(ascii_case))]
+    LIFETIME,
     #[token("LIKE", ignore(ascii_case))]
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
@@ -896,6 +904,8 @@ pub enum TokenKind {
     ORC,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -966,6 +976,8 @@ pub enum TokenKind {
     QUOTE,
     #[token("QUOTED_IDENTIFIERS", ignore(ascii_case))]
     QUOTED_IDENTIFIERS,
+    #[token("RAISE", ignore(ascii_case))]
+    RAISE,
     #[token("RANGE", ignore(ascii_case))]
     RANGE,
     #[token("RAWDEFLATE", ignore(ascii_case))]
@@ -996,6 +1008,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -1164,6 +1178,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1184,8 +1200,14 @@ pub enum TokenKind {
     THEN,
     #[token("THURSDAY", ignore(ascii_case))]
     THURSDAY,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
+    #[token("TIMESTAMPTZ", ignore(ascii_case))]
+    TIMESTAMPTZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -1260,8 +1282,12 @@ pub enum TokenKind {
     VARIANT,
     #[token("VARIABLE", ignore(ascii_case))]
     VARIABLE,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
+    #[token("VERSION", ignore(ascii_case))]
+    VERSION,
     #[token("GRAPHICAL", ignore(ascii_case))]
     GRAPHICAL,
     #[token("VIEW", ignore(ascii_case))]
@@ -1290,6 +1316,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZONE", ignore(ascii_case))]
+    ZONE,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -1782,6 +1810,8 @@ impl TokenKind {
             // | TokenKind::NOTNULL
             | TokenKind::OFFSET
             | TokenKind::ON
+            | TokenKind::ASOF
+            | TokenKind::MATCH_CONDITION
             | TokenKind::OF
             | TokenKind::ORDER
             | TokenKind::OVER
@@ -125,6 +125,8 @@ fn test_statement() {
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
         r#"CREATE OR REPLACE NGRAM INDEX idx2 ON t1 (a, b);"#,
+        r#"CREATE OR REPLACE VECTOR INDEX idx2 ON t1 (b) distance='cosine' nlists=2;"#,
+        r#"DROP VECTOR INDEX IF EXISTS idx2 ON t1;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create table a (c1 decimal(38), c2 int) partition by (c1, c2) PROPERTIES ("read.split.target-size"='134217728', "read.split.metadata-target-size"='33554432');"#,
@@ -138,8 +140,11 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table if not exists a.b (a string, b string, inverted index idx1 (a,b) tokenizer='chinese');"#,
         r#"create table if not exists a.b (a string, b string, ngram index idx1 (a,b) gram_size=5);"#,
+        r#"create table if not exists a.b (a int, b vector(3), vector index idx1 (b) distance='cosine');"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table a.b clone c.d;"#,
+        r#"create or replace table t clone t2 at (snapshot => '9828b23f74664ff3806f44bbc1925ea5');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"truncate table a;"#,
         r#"truncate table "a".b;"#,
@@ -292,6 +297,8 @@ fn test_statement() {
         r#"ALTER TABLE t MODIFY a int;"#,
         r#"ALTER TABLE t MODIFY COLUMN a DROP STORED;"#,
         r#"ALTER TABLE t SET OPTIONS(SNAPSHOT_LOCATION='1/7/_ss/101fd790dbbe4238a31a8f2e2f856179_v4.mpk',block_per_segment = 500);"#,
+        r#"ALTER TABLE t CREATE TAG v1 AT (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') RETAIN 2 DAYS;"#,
+        r#"ALTER TABLE t DROP TAG IF EXISTS v1;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
@@ -675,6 +682,10 @@ fn test_statement() {
             AS
                 SELECT avg(a), d FROM db.t GROUP BY d
         "#,
+        r#"ALTER DYNAMIC TABLE db.MyDynamic REFRESH"#,
+        // materialized views
+        r#"REFRESH MATERIALIZED VIEW db.mv_sales"#,
+        r#"DROP MATERIALIZED VIEW IF EXISTS mv_sales"#,
         // tasks
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 MINUTE SUSPEND_TASK_AFTER_NUM_FAILURES = 3 ERROR_INTEGRATION = 'notification_name' COMMENT = 'This is test task 1' DATABASE = 'target', TIMEZONE = 'America/Los Angeles' AS SELECT * FROM MyTable1"#,
         r#"CREATE TASK IF NOT EXISTS MyTask1 WAREHOUSE = 'MyWarehouse' SCHEDULE = 15 SECOND SUSPEND_TASK_AFTER_NUM_FAILURES = 3 COMMENT = 'This is test task 1' AS SELECT * FROM MyTable1"#,
@@ -818,6 +829,8 @@ fn test_statement() {
         r#"CREATE FUNCTION IF NOT EXISTS my_agg (INT) STATE { s STRING } RETURNS BOOLEAN LANGUAGE javascript ADDRESS = 'http://0.0.0.0:8815';"#,
         r#"CREATE FUNCTION IF NOT EXISTS my_agg (INT) STATE { s STRING, i INT NOT NULL } RETURNS BOOLEAN LANGUAGE javascript AS 'some code';"#,
         r#"ALTER FUNCTION my_agg (INT) STATE { s STRING } RETURNS BOOLEAN LANGUAGE javascript AS 'some code';"#,
+        r#"CREATE FUNCTION split_kv (STRING) RETURNS TABLE (k STRING, v INT) LANGUAGE python HANDLER = 'split_kv' AS 'some code';"#,
+        r#"CREATE OR REPLACE FUNCTION fetch_rows (INT) RETURNS TABLE (id INT, payload VARIANT) LANGUAGE python HANDLER = 'fetch_rows' HEADERS = ('X-Authorization' = '123') ADDRESS = 'http://0.0.0.0:8815';"#,
         r#"
             EXECUTE IMMEDIATE
             $$
@@ -1091,6 +1104,8 @@ fn test_query() {
         r#"select * exclude c1, b.* exclude (c2, c3, c4) from customer inner join orders on a = b limit 1"#,
         r#"select columns('abc'), columns(a -> length(a) = 3) from t"#,
         r#"select * from customer at(offset => -10 * 30)"#,
+        r#"select * from customer at(version => 3)"#,
+        r#"select * from t at (tag => v1)"#,
         r#"select * from customer changes(information => default) at (stream => s) order by a, b"#,
         r#"select * from customer with consume as s"#,
         r#"select * from t12_0004 at (TIMESTAMP => 'xxxx') as t"#,
@@ -1452,6 +1467,18 @@ fn test_script() {
         r#"select :a + 1"#,
         r#"select IDENTIFIER(:b)"#,
         r#"select a.IDENTIFIER(:b).c + minus(:d)"#,
+        r#"RAISE"#,
+        r#"RAISE 20001, 'custom failure'"#,
+        r#"
+            BEGIN
+                RAISE 20001;
+            EXCEPTION
+                WHEN 20001 OR 20002 THEN
+                    RETURN sqlcode;
+                WHEN OTHER THEN
+                    RAISE;
+            END
+        "#,
     ];
 
     for case in cases {
@@ -1481,6 +1508,14 @@ fn test_script() {
                 END FOR;
             END;
         "#,
+        r#"
+            BEGIN
+                RAISE 20001, 'custom failure';
+            EXCEPTION
+                WHEN OTHER THEN
+                    RETURN sqlerrm;
+            END;
+        "#,
     ];
 
     for case in cases {
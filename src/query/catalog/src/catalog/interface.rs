@@ -19,6 +19,7 @@ use std::sync::Arc;
 use databend_common_ast::ast::Engine;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::ProcedureMeta;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
@@ -510,6 +511,18 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         false
     }
 
+    // Get the table function that calls the procedure and returns its result as a table.
+    fn get_procedure_table_function(
+        &self,
+        _func_name: &str,
+        _procedure_meta: ProcedureMeta,
+        _tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        Err(ErrorCode::Unimplemented(
+            "'get_procedure_table_function' not implemented",
+        ))
+    }
+
     // List all table functions' names.
     fn list_table_functions(&self) -> Vec<String> {
         unimplemented!()
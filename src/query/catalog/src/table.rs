@@ -46,6 +46,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::ClusterType;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use databend_storages_common_table_meta::table_id_ranges::is_temp_table_id;
 
@@ -159,6 +160,35 @@ pub trait Table: Sync + Send {
         Some(ast_exprs)
     }
 
+    fn partition_key_meta(&self) -> Option<String> {
+        self.options().get(OPT_KEY_PARTITION_BY).cloned()
+    }
+
+    fn is_partitioned(&self) -> bool {
+        self.partition_key_meta().is_some()
+    }
+
+    fn resolve_partition_keys(&self, ctx: Arc<dyn TableContext>) -> Result<Option<Vec<Expr>>> {
+        let Some(partition_key_str) = self.partition_key_meta() else {
+            return Ok(None);
+        };
+        let tokens = tokenize_sql(&partition_key_str)?;
+        let sql_dialect = ctx.get_settings().get_sql_dialect().unwrap_or_default();
+        let mut ast_exprs = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+        if ast_exprs.len() != 1 {
+            return Err(ErrorCode::InvalidPartitionKeys(format!(
+                "Invalid partition keys `{}` of table '{}'",
+                partition_key_str,
+                self.name()
+            )));
+        }
+        // unwrap tuple.
+        if let Expr::Tuple { exprs, .. } = &ast_exprs[0] {
+            ast_exprs = exprs.clone();
+        }
+        Ok(Some(ast_exprs))
+    }
+
     fn change_tracking_enabled(&self) -> bool {
         false
     }
@@ -561,7 +591,12 @@ pub enum TimeNavigation {
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    /// Version number of the table log, used by the engines which track the table
+    /// history with a monotonic version, e.g. Delta Lake.
+    Version(u64),
     StreamInfo(TableInfo),
+    /// Named snapshot of the table, created by `ALTER TABLE ... CREATE TAG`.
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -28,6 +28,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
@@ -427,6 +429,15 @@ pub trait TableContext: Send + Sync {
     fn reset_broadcast_id(&self) {
         unimplemented!()
     }
+
+    /// Run the script of a procedure with the named arguments, and return its result as a table.
+    async fn call_procedure(
+        &self,
+        _script: &str,
+        _args: Vec<(String, Scalar)>,
+    ) -> Result<(DataSchemaRef, DataBlock)> {
+        unimplemented!()
+    }
 }
 
 pub type AbortChecker = Arc<dyn CheckAbort + Send + Sync>;
@@ -1452,6 +1452,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "5433")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1789,6 +1795,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
             global_statement_queue: self.global_statement_queue,
@@ -1882,6 +1890,8 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
             global_statement_queue: inner.global_statement_queue,
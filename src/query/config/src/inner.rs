@@ -167,6 +167,8 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
     pub global_statement_queue: bool,
@@ -270,6 +272,8 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
             max_active_sessions: 256,
             max_running_queries: 8,
             global_statement_queue: false,
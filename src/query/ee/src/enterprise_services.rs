@@ -29,6 +29,7 @@ use crate::storage_encryption::RealStorageEncryptionHandler;
 use crate::storage_quota::RealStorageQuotaHandler;
 use crate::storages::fuse::operations::RealVacuumHandler;
 use crate::stream::RealStreamHandler;
+use crate::vector_index::RealVectorIndexHandler;
 use crate::virtual_column::RealVirtualColumnHandler;
 
 pub struct EnterpriseServices;
@@ -49,6 +50,7 @@ impl EnterpriseServices {
         init_resources_management(&cfg).await?;
         RealHilbertClusteringHandler::init()?;
         RealNgramIndexHandler::init()?;
+        RealVectorIndexHandler::init()?;
         Ok(())
     }
 }
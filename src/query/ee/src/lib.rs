@@ -27,4 +27,5 @@ pub mod storage_quota;
 pub mod storages;
 pub mod stream;
 pub mod test_kits;
+pub mod vector_index;
 pub mod virtual_column;
@@ -21,8 +21,10 @@ use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_fuse::FUSE_TBL_CLONE_REFERENCE_PREFIX;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropFileInfo;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropTablesResult;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_REFERENCES;
 use futures_util::TryStreamExt;
 use log::error;
 use log::info;
@@ -38,7 +40,9 @@ pub async fn do_vacuum_drop_table(
     for (table_info, operator) in tables {
         let result =
             vacuum_drop_single_table(&table_info, operator, dry_run_limit, &mut list_files).await;
-        if result.is_err() {
+        // A table still referenced by its clones is skipped, and its meta is kept like the
+        // tables failed to be vacuumed, so that it could be vacuumed later.
+        if !matches!(result, Ok(true)) {
             let table_id = table_info.ident.table_id;
             failed_tables.insert(table_id);
         }
@@ -50,12 +54,13 @@ pub async fn do_vacuum_drop_table(
     })
 }
 
+/// Returns false if the table is skipped because it is referenced by cloned tables.
 async fn vacuum_drop_single_table(
     table_info: &TableInfo,
     operator: Operator,
     dry_run_limit: Option<usize>,
     list_files: &mut Vec<VacuumDropFileInfo>,
-) -> Result<()> {
+) -> Result<bool> {
     let dir = format!(
         "{}/",
         FuseTable::parse_storage_prefix_from_table_info(table_info)?
@@ -70,6 +75,21 @@ async fn vacuum_drop_single_table(
 
     let start = Instant::now();
 
+    // the files of the table are still used by its clones, keep them (and the table meta)
+    // until all the clones are vacuumed.
+    let reference_dir = format!("{}{}/", dir, FUSE_TBL_CLONE_REFERENCE_PREFIX);
+    let references = operator.list(&reference_dir).await?;
+    if references
+        .iter()
+        .any(|entry| entry.metadata().mode() == EntryMode::FILE)
+    {
+        info!(
+            "vacuum drop table {:?} skipped, it is referenced by cloned tables",
+            table_info.name
+        );
+        return Ok(false);
+    }
+
     match dry_run_limit {
         None => {
             let result = operator.remove_all(&dir).await;
@@ -77,6 +97,13 @@ async fn vacuum_drop_single_table(
                 error!("failed to remove all in directory {}: {}", dir, err);
             }
             result?;
+
+            // release the files of the tables which this one is cloned from.
+            if let Some(references) = table_info.options().get(OPT_KEY_CLONE_REFERENCES) {
+                for reference in references.split(',') {
+                    operator.delete(reference).await?;
+                }
+            }
         }
         Some(dry_run_limit) => {
             let mut ds = operator.lister_with(&dir).recursive(true).await?;
@@ -122,7 +149,7 @@ async fn vacuum_drop_single_table(
         dir,
         start.elapsed()
     );
-    Ok(())
+    Ok(true)
 }
 
 #[async_backtrace::framed]
@@ -28,6 +28,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
 use databend_common_meta_app::schema::LeastVisibleTime;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::io::SegmentsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
@@ -231,9 +232,11 @@ pub async fn do_vacuum2(
     ));
 
     let start = std::time::Instant::now();
+    // Segments and blocks referenced by the clones or the tags of this table are kept.
+    let protected_locations = fuse_table.read_protected_locations(&ctx).await?;
     let segments_to_gc: Vec<String> = segments_before_gc_root
         .into_iter()
-        .filter(|s| !gc_root_segments.contains(s))
+        .filter(|s| !gc_root_segments.contains(s) && protected_locations.can_purge(s))
         .collect();
     ctx.set_status_info(&format!(
         "Filter segments to gc for table {} takes {:?}, segments_to_gc: {:?}",
@@ -249,8 +252,14 @@ pub async fn do_vacuum2(
         .read_segments::<Arc<CompactSegmentInfo>>(&gc_root.segments, false)
         .await?;
     let mut gc_root_blocks = HashSet::new();
+    let mut gc_root_deletion_vectors = HashSet::new();
     for segment in segments {
-        gc_root_blocks.extend(segment?.block_metas()?.iter().map(|b| b.location.0.clone()));
+        for block_meta in segment?.block_metas()?.iter() {
+            gc_root_blocks.insert(block_meta.location.0.clone());
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                gc_root_deletion_vectors.insert(deletion_vector.location.0.clone());
+            }
+        }
     }
     ctx.set_status_info(&format!(
         "read segments for table {} takes {:?}",
@@ -283,7 +292,7 @@ pub async fn do_vacuum2(
     let start = std::time::Instant::now();
     let blocks_to_gc: Vec<String> = blocks_before_gc_root
         .into_iter()
-        .filter(|b| !gc_root_blocks.contains(b))
+        .filter(|b| !gc_root_blocks.contains(b) && protected_locations.can_purge(b))
         .collect();
     ctx.set_status_info(&format!(
         "Filter blocks to gc for table {} takes {:?}, blocks_to_gc: {:?}",
@@ -292,6 +301,31 @@ pub async fn do_vacuum2(
         slice_summary(&blocks_to_gc)
     ));
 
+    let start = std::time::Instant::now();
+    // The object keys of deletion vectors are not prefixed by `VACUUM2_OBJECT_KEY_PREFIX`, so all
+    // of them are listed, and only the ones written long enough before the gc root are candidates.
+    let deletion_vectors_to_gc: Vec<String> = list_until_timestamp(
+        fuse_table,
+        fuse_table
+            .meta_location_generator()
+            .deletion_vector_location_prefix(),
+        gc_root_timestamp,
+        false,
+        Some(gc_root_meta_ts),
+    )
+    .await?
+    .into_iter()
+    .map(|v| v.path().to_owned())
+    .filter(|d| !gc_root_deletion_vectors.contains(d) && protected_locations.can_purge(d))
+    .collect();
+    ctx.set_status_info(&format!(
+        "list deletion vectors to gc for table {} takes {:?}, deletion_vector_dir: {:?}, deletion_vectors_to_gc: {:?}",
+        fuse_table.get_table_info().desc,
+        start.elapsed(),
+        fuse_table.meta_location_generator().deletion_vector_location_prefix(),
+        slice_summary(&deletion_vectors_to_gc)
+    ));
+
     let start = std::time::Instant::now();
     let catalog = ctx.get_default_catalog()?;
     let table_agg_index_ids = catalog
@@ -313,13 +347,20 @@ pub async fn do_vacuum2(
             );
         }
         for idx in inverted_indexes.values() {
-            indexes_to_gc.push(
-                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+            indexes_to_gc.push(match idx.index_type {
+                TableIndexType::Vector => {
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        loc,
+                        idx.name.as_str(),
+                        idx.version.as_str(),
+                    )
+                }
+                _ => TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
                     loc,
                     idx.name.as_str(),
                     idx.version.as_str(),
                 ),
-            );
+            });
         }
         indexes_to_gc
             .push(TableMetaLocationGenerator::gen_bloom_index_location_from_block_location(loc));
@@ -336,6 +377,7 @@ pub async fn do_vacuum2(
     let subject_files_to_gc: Vec<_> = segments_to_gc
         .into_iter()
         .chain(blocks_to_gc.into_iter())
+        .chain(deletion_vectors_to_gc.into_iter())
         .collect();
     let op = Files::create(ctx.clone(), fuse_table.get_operator());
 
@@ -355,6 +397,11 @@ pub async fn do_vacuum2(
     //   stored in the meta-server, should be utilized to determine snapshot visibility and
     //   resolve potential issues.
 
+    // the tagged snapshots are kept.
+    let snapshots_to_gc: Vec<_> = snapshots_to_gc
+        .into_iter()
+        .filter(|s| protected_locations.can_purge(s))
+        .collect();
     if let Some(snapshot_cache) = CacheManager::instance().get_table_snapshot_cache() {
         for path in &snapshots_to_gc {
             snapshot_cache.evict(path);
@@ -26,6 +26,7 @@ use crate::license::RealLicenseManager;
 use crate::ngram_index::RealNgramIndexHandler;
 use crate::storages::fuse::operations::RealVacuumHandler;
 use crate::stream::RealStreamHandler;
+use crate::vector_index::RealVectorIndexHandler;
 use crate::virtual_column::RealVirtualColumnHandler;
 
 pub struct MockServices;
@@ -41,6 +42,7 @@ impl MockServices {
         RealStreamHandler::init()?;
         RealInvertedIndexHandler::init()?;
         RealNgramIndexHandler::init()?;
+        RealVectorIndexHandler::init()?;
         Ok(())
     }
 }
@@ -15,6 +15,7 @@
 use databend_common_base::base::OrderedFloat;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::buffer::Buffer;
+use databend_common_column::types::timestamp_tz;
 use databend_common_column::types::Index;
 use databend_common_exception::Result;
 
@@ -37,7 +38,9 @@ use crate::types::NumberDataType;
 use crate::types::NumberScalar;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::visitor::ValueVisitor;
@@ -82,6 +85,10 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
         DataType::Boolean => combine_group_hash_type_column::<IS_FIRST, BooleanType>(c, values),
         DataType::Timestamp => combine_group_hash_type_column::<IS_FIRST, TimestampType>(c, values),
         DataType::Date => combine_group_hash_type_column::<IS_FIRST, DateType>(c, values),
+        DataType::Time => combine_group_hash_type_column::<IS_FIRST, TimeType>(c, values),
+        DataType::TimestampTz => {
+            combine_group_hash_type_column::<IS_FIRST, TimestampTzType>(c, values)
+        }
         DataType::Binary => combine_group_hash_string_column::<IS_FIRST, BinaryType>(c, values),
         DataType::String => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
         DataType::Bitmap => combine_group_hash_string_column::<IS_FIRST, BitmapType>(c, values),
@@ -203,6 +210,8 @@ where I: Index
             },
             Scalar::Timestamp(v) => v.agg_hash(),
             Scalar::Date(v) => v.agg_hash(),
+            Scalar::Time(v) => v.agg_hash(),
+            Scalar::TimestampTz(v) => v.agg_hash(),
             Scalar::Boolean(v) => v.agg_hash(),
             Scalar::Binary(v) => v.agg_hash(),
             Scalar::String(v) => v.as_bytes().agg_hash(),
@@ -243,6 +252,15 @@ where I: Index
         self.visit_number(buffer)
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_number(buffer)
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        let buffer = buffer.as_ref();
+        self.visit_indices(|i| buffer[i.to_usize()].agg_hash())
+    }
+
     fn visit_any_decimal(&mut self, column: DecimalColumn) -> Result<()> {
         with_decimal_type!(|DECIMAL_TYPE| match column {
             DecimalColumn::DECIMAL_TYPE(buffer, _) => {
@@ -433,6 +451,14 @@ impl AggHash for i256 {
     }
 }
 
+// The same instant in different time zones is hashed to the same value.
+impl AggHash for timestamp_tz {
+    #[inline(always)]
+    fn agg_hash(&self) -> u64 {
+        self.timestamp().agg_hash()
+    }
+}
+
 impl AggHash for OrderedFloat<f32> {
     #[inline(always)]
     fn agg_hash(&self) -> u64 {
@@ -33,7 +33,9 @@ use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::StringColumn;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -54,6 +56,8 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         DataType::Timestamp => 8,
         DataType::Date => 4,
         DataType::Interval => 16,
+        DataType::Time => 8,
+        DataType::TimestampTz => 16,
         // use address instead
         DataType::Binary
         | DataType::String
@@ -141,6 +145,16 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Time(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
+        Column::TimestampTz(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -305,6 +319,30 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Time(_) => row_match_column_type::<TimeType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
+        Column::TimestampTz(_) => row_match_column_type::<TimestampTzType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::String(v) => row_match_string_column(
             v,
             validity,
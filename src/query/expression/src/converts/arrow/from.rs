@@ -25,6 +25,7 @@ use databend_common_column::binview::StringColumn;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::buffer::Buffer;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
@@ -34,7 +35,10 @@ use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOGRAPHY;
 use super::ARROW_EXT_TYPE_GEOMETRY;
 use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use super::ARROW_EXT_TYPE_VARIANT;
+use super::ARROW_EXT_TYPE_VECTOR;
+use super::ARROW_EXT_VECTOR_DIMENSION_KEY;
 use super::EXTENSION_KEY;
 use crate::types::AnyType;
 use crate::types::ArrayColumn;
@@ -79,6 +83,20 @@ impl TryFrom<&Field> for TableField {
             ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
             ARROW_EXT_TYPE_GEOGRAPHY => TableDataType::Geography,
             ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+            ARROW_EXT_TYPE_TIMESTAMP_TZ => TableDataType::TimestampTz,
+            ARROW_EXT_TYPE_VECTOR => {
+                let dimension = arrow_f
+                    .metadata()
+                    .get(ARROW_EXT_VECTOR_DIMENSION_KEY)
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(format!(
+                            "Invalid vector field {}: missing dimension",
+                            arrow_f.name()
+                        ))
+                    })?;
+                TableDataType::Vector(dimension)
+            }
             _ => match arrow_f.data_type() {
                 ArrowDataType::Null => TableDataType::Null,
                 ArrowDataType::Boolean => TableDataType::Boolean,
@@ -114,6 +132,7 @@ impl TryFrom<&Field> for TableField {
                 ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
                 ArrowDataType::Date32 => TableDataType::Date,
                 ArrowDataType::Date64 => TableDataType::Date,
+                ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
                 ArrowDataType::List(field) => {
                     let inner_type = TableField::try_from(field.as_ref())?;
                     TableDataType::Array(Box::new(inner_type.data_type))
@@ -289,6 +308,19 @@ impl Column {
                     array.to_data().buffers()[0].clone().into();
                 Column::Interval(buffer)
             }
+            DataType::Time => {
+                let array = arrow_cast::cast(
+                    array.as_ref(),
+                    &ArrowDataType::Time64(arrow_schema::TimeUnit::Microsecond),
+                )?;
+                let buffer: Buffer<i64> = array.to_data().buffers()[0].clone().into();
+                Column::Time(buffer)
+            }
+            DataType::TimestampTz => {
+                let array = arrow_cast::cast(array.as_ref(), &ArrowDataType::Decimal128(38, 0))?;
+                let buffer: Buffer<timestamp_tz> = array.to_data().buffers()[0].clone().into();
+                Column::TimestampTz(buffer)
+            }
             DataType::Nullable(_) => {
                 let validity = match array.nulls() {
                     Some(nulls) => Bitmap::from_null_buffer(nulls.clone()),
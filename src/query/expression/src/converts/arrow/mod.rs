@@ -23,3 +23,6 @@ pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 pub const ARROW_EXT_TYPE_GEOGRAPHY: &str = "Geography";
 pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
+pub const ARROW_EXT_TYPE_VECTOR: &str = "Vector";
+pub const ARROW_EXT_VECTOR_DIMENSION_KEY: &str = "VectorDimension";
@@ -34,7 +34,10 @@ use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOGRAPHY;
 use super::ARROW_EXT_TYPE_GEOMETRY;
 use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
 use super::ARROW_EXT_TYPE_VARIANT;
+use super::ARROW_EXT_TYPE_VECTOR;
+use super::ARROW_EXT_VECTOR_DIMENSION_KEY;
 use super::EXTENSION_KEY;
 use crate::infer_table_schema;
 use crate::types::DataType;
@@ -195,6 +198,23 @@ impl From<&TableField> for Field {
                 );
                 ArrowDataType::Decimal128(38, 0)
             }
+            TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
+            TableDataType::TimestampTz => {
+                metadata.insert(
+                    EXTENSION_KEY.to_string(),
+                    ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+                );
+                ArrowDataType::Decimal128(38, 0)
+            }
+            TableDataType::Vector(dimension) => {
+                metadata.insert(EXTENSION_KEY.to_string(), ARROW_EXT_TYPE_VECTOR.to_string());
+                metadata.insert(
+                    ARROW_EXT_VECTOR_DIMENSION_KEY.to_string(),
+                    dimension.to_string(),
+                );
+                let f = TableField::new("_array", TableDataType::Number(NumberDataType::Float32));
+                ArrowDataType::LargeList(Arc::new(Field::from(&f)))
+            }
         };
 
         Field::new(f.name(), ty, f.is_nullable()).with_metadata(metadata)
@@ -302,6 +322,8 @@ impl From<&Column> for ArrayData {
             Column::Timestamp(col) => buffer_to_array_data((col.clone(), arrow_type)),
             Column::Date(col) => buffer_to_array_data((col.clone(), arrow_type)),
             Column::Interval(col) => buffer_to_array_data((col.clone(), arrow_type)),
+            Column::Time(col) => buffer_to_array_data((col.clone(), arrow_type)),
+            Column::TimestampTz(col) => buffer_to_array_data((col.clone(), arrow_type)),
             Column::Array(col) => {
                 let child_data = ArrayData::from(&col.underlying_column());
                 let builder = ArrayDataBuilder::new(arrow_type)
@@ -39,6 +39,8 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
         Scalar::Interval(_) => unimplemented!("Interval type is not supported"),
+        Scalar::Time(x) => DataValue::Int64(*x),
+        Scalar::TimestampTz(x) => DataValue::Int64(x.timestamp()),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
         Scalar::Variant(x) => DataValue::String(x.clone()),
         Scalar::Geometry(x) => DataValue::String(x.clone()),
@@ -16,6 +16,7 @@
 // This crate keeps some Index codes for compatibility, it's locked by bincode of meta's v3 version
 
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use enum_as_inner::EnumAsInner;
@@ -41,6 +42,8 @@ pub enum IndexScalar {
     Tuple(Vec<IndexScalar>),
     BinaryV2(Vec<u8>),
     Variant(Vec<u8>),
+    Time(i64),
+    TimestampTz(timestamp_tz),
 }
 
 impl TryFrom<IndexScalar> for Scalar {
@@ -54,6 +57,8 @@ impl TryFrom<IndexScalar> for Scalar {
             IndexScalar::Timestamp(ts) => Scalar::Timestamp(ts),
             IndexScalar::Date(date) => Scalar::Date(date),
             IndexScalar::Interval(interval) => Scalar::Interval(interval),
+            IndexScalar::Time(t) => Scalar::Time(t),
+            IndexScalar::TimestampTz(t) => Scalar::TimestampTz(t),
             IndexScalar::Boolean(b) => Scalar::Boolean(b),
             IndexScalar::String(s) => Scalar::String(String::from_utf8(s).map_err(|e| {
                 ErrorCode::InvalidUtf8String(format!("invalid utf8 data for string type: {}", e))
@@ -81,6 +86,8 @@ impl TryFrom<Scalar> for IndexScalar {
             Scalar::Timestamp(ts) => IndexScalar::Timestamp(ts),
             Scalar::Date(date) => IndexScalar::Date(date),
             Scalar::Interval(interval) => IndexScalar::Interval(interval),
+            Scalar::Time(t) => IndexScalar::Time(t),
+            Scalar::TimestampTz(t) => IndexScalar::TimestampTz(t),
             Scalar::Boolean(b) => IndexScalar::Boolean(b),
             Scalar::String(string) => IndexScalar::String(string.as_bytes().to_vec()),
             Scalar::Binary(s) => IndexScalar::BinaryV2(s),
@@ -31,7 +31,9 @@ use crate::types::EmptyArrayType;
 use crate::types::NullableType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::VariantType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -152,6 +154,30 @@ impl Selector<'_> {
                 select_strategy,
                 count,
             ),
+            DataType::Time => self.select_type_values_cmp::<TimeType>(
+                &op,
+                left,
+                right,
+                validity,
+                true_selection,
+                false_selection,
+                mutable_true_idx,
+                mutable_false_idx,
+                select_strategy,
+                count,
+            ),
+            DataType::TimestampTz => self.select_type_values_cmp::<TimestampTzType>(
+                &op,
+                left,
+                right,
+                validity,
+                true_selection,
+                false_selection,
+                mutable_true_idx,
+                mutable_false_idx,
+                select_strategy,
+                count,
+            ),
             DataType::String => self.select_type_values_cmp::<StringType>(
                 &op,
                 left,
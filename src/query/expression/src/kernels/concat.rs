@@ -38,7 +38,9 @@ use crate::types::DecimalType;
 use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -172,6 +174,20 @@ impl Column {
                 );
                 Column::Interval(buffer)
             }
+            Column::Time(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| TimeType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::Time(buffer)
+            }
+            Column::TimestampTz(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| TimestampTzType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::TimestampTz(buffer)
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -18,6 +18,7 @@ use databend_common_column::bitmap::Bitmap;
 use databend_common_column::bitmap::MutableBitmap;
 use databend_common_column::bitmap::TrueIdxIter;
 use databend_common_column::buffer::Buffer;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::Result;
 use string::StringColumnBuilder;
 
@@ -252,6 +253,20 @@ impl ValueVisitor for FilterVisitor<'_> {
         Ok(())
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.result = Some(Value::Column(TimeType::upcast_column(
+            self.filter_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        self.result = Some(Value::Column(TimestampTzType::upcast_column(
+            self.filter_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
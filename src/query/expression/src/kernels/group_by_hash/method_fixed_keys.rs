@@ -555,6 +555,24 @@ fn fixed_hash(keys_vec: &mut KeysVec, col_index: usize, column: &Column) -> Resu
                 }
             }
         },
+        Column::Time(c) => match bitmap {
+            Some(bitmap) => {
+                for (row, (value, valid)) in c.iter().zip(bitmap.iter()).enumerate() {
+                    if valid {
+                        let slice = keys_vec.value(row, col_index);
+                        value.marshal(slice);
+                    } else {
+                        keys_vec.set_null(row, col_index);
+                    }
+                }
+            }
+            None => {
+                for (row, value) in c.iter().enumerate() {
+                    let slice = keys_vec.value(row, col_index);
+                    value.marshal(slice);
+                }
+            }
+        },
         Column::Decimal(c) => {
             with_decimal_mapped_type!(|DECIMAL_TYPE| match c {
                 DecimalColumn::DECIMAL_TYPE(c, _) => {
@@ -296,6 +296,10 @@ impl ValueVisitor for SortCompare {
         self.visit_number(buffer)
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_number(buffer)
+    }
+
     fn visit_string(&mut self, column: StringColumn) -> Result<()> {
         assert!(column.len() == self.rows);
         self.generic_sort(
@@ -303,6 +303,14 @@ impl Column {
                 let builder = IntervalType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<IntervalType>(columns, builder, indices)
             }
+            Column::Time(_) => {
+                let builder = TimeType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimeType>(columns, builder, indices)
+            }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimestampTzType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -532,6 +540,20 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Interval(columns)
             }
+            Column::Time(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimeType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Time(columns)
+            }
+            Column::TimestampTz(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimestampTzType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::TimestampTz(columns)
+            }
             Column::Date(_) => {
                 let columns = columns
                     .iter()
@@ -703,6 +725,23 @@ impl Column {
                         .unwrap();
                 Column::Interval(i)
             }
+            ColumnVec::Time(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                let t = <TimeType>::upcast_column(<TimeType>::column_from_vec(builder, &[]))
+                    .into_time()
+                    .unwrap();
+                Column::Time(t)
+            }
+            ColumnVec::TimestampTz(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                let t = <TimestampTzType>::upcast_column(<TimestampTzType>::column_from_vec(
+                    builder,
+                    &[],
+                ))
+                .into_timestamp_tz()
+                .unwrap();
+                Column::TimestampTz(t)
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
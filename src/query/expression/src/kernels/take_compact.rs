@@ -15,6 +15,7 @@
 use binary::BinaryColumnBuilder;
 use databend_common_base::vec_ext::VecExt;
 use databend_common_column::buffer::Buffer;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::Result;
 
 use crate::types::binary::BinaryColumn;
@@ -141,6 +142,20 @@ impl ValueVisitor for TakeCompactVisitor<'_> {
         Ok(())
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.result = Some(Value::Column(TimeType::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        self.result = Some(Value::Column(TimestampTzType::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -19,6 +19,7 @@ use databend_common_base::vec_ext::VecExt;
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::bitmap::MutableBitmap;
 use databend_common_column::buffer::Buffer;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::Result;
 
 use crate::types::binary::BinaryColumn;
@@ -144,6 +145,20 @@ impl ValueVisitor for TakeRangeVisitor<'_> {
         Ok(())
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.result = Some(Value::Column(TimeType::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        self.result = Some(Value::Column(TimestampTzType::upcast_column(
+            self.take_primitive_types(buffer),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
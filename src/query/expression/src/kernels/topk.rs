@@ -51,6 +51,7 @@ impl TopKSorter {
             DataType::String => self.push_column_internal::<StringType>(col, bitmap),
             DataType::Timestamp => self.push_column_internal::<TimestampType>(col, bitmap),
             DataType::Date => self.push_column_internal::<DateType>(col, bitmap),
+            DataType::Time => self.push_column_internal::<TimeType>(col, bitmap),
             _ => {}
         });
     }
@@ -95,6 +96,8 @@ impl TopKSorter {
                 ),
             DataType::Date => self
                 .push_column_with_selection_internal::<DateType, SELECT_ALL>(col, selection, count),
+            DataType::Time => self
+                .push_column_with_selection_internal::<TimeType, SELECT_ALL>(col, selection, count),
             _ => count,
         })
     }
@@ -170,6 +173,7 @@ impl TopKSorter {
             DataType::String => self.never_match_any_internal::<StringType>(col),
             DataType::Timestamp => self.never_match_any_internal::<TimestampType>(col),
             DataType::Date => self.never_match_any_internal::<DateType>(col),
+            DataType::Time => self.never_match_any_internal::<TimeType>(col),
             _ => false,
         })
     }
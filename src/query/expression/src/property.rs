@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use enum_as_inner::EnumAsInner;
 
 use crate::types::boolean::BooleanDomain;
@@ -37,7 +38,9 @@ use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
@@ -121,6 +124,8 @@ pub enum Domain {
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
     Interval(SimpleDomain<months_days_micros>),
+    Time(SimpleDomain<i64>),
+    TimestampTz(SimpleDomain<timestamp_tz>),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -207,6 +212,8 @@ impl Domain {
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
             DataType::Interval => Domain::Interval(IntervalType::full_domain()),
+            DataType::Time => Domain::Time(TimeType::full_domain()),
+            DataType::TimestampTz => Domain::TimestampTz(TimestampTzType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -282,6 +289,16 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Time(this), Domain::Time(other)) => Domain::Time(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::TimestampTz(this), Domain::TimestampTz(other)) => {
+                Domain::TimestampTz(SimpleDomain {
+                    min: this.min.min(other.min),
+                    max: this.max.max(other.max),
+                })
+            }
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -422,6 +439,10 @@ impl Domain {
             Domain::Interval(SimpleDomain { min, max }) if min == max => {
                 Some(Scalar::Interval(*min))
             }
+            Domain::Time(SimpleDomain { min, max }) if min == max => Some(Scalar::Time(*min)),
+            Domain::TimestampTz(SimpleDomain { min, max }) if min == max => {
+                Some(Scalar::TimestampTz(*min))
+            }
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -509,6 +530,10 @@ impl Domain {
             Domain::Interval(SimpleDomain { min, max }) => {
                 (Scalar::Interval(*min), Scalar::Interval(*max))
             }
+            Domain::Time(SimpleDomain { min, max }) => (Scalar::Time(*min), Scalar::Time(*max)),
+            Domain::TimestampTz(SimpleDomain { min, max }) => {
+                (Scalar::TimestampTz(*min), Scalar::TimestampTz(*max))
+            }
             Domain::Nullable(NullableDomain { has_null, value }) => {
                 if let Some(v) = value {
                     let (min, mut max) = v.to_minmax();
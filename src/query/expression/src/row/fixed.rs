@@ -14,6 +14,7 @@
 
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
@@ -107,6 +108,14 @@ impl FixedLengthEncoding for months_days_micros {
     }
 }
 
+impl FixedLengthEncoding for timestamp_tz {
+    type Encoded = [u8; 8];
+
+    fn encode(self) -> [u8; 8] {
+        self.timestamp().encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
@@ -65,6 +66,8 @@ impl RowConverter {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
             | DataType::Date
             | DataType::Binary
             | DataType::String
@@ -123,6 +126,12 @@ impl RowConverter {
                 DataType::Interval => lengths
                     .iter_mut()
                     .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
+                DataType::Time => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i64::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += timestamp_tz::ENCODED_LEN as u64),
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
@@ -261,6 +270,8 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Time(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::TimestampTz(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
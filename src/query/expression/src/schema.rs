@@ -294,6 +294,10 @@ pub enum TableDataType {
     Geometry,
     Geography,
     Interval,
+    /// Fixed-dimension vector of `Float32`, stored as `Array(Float32)`.
+    Vector(u64),
+    Time,
+    TimestampTz,
 }
 
 impl DataSchema {
@@ -1266,6 +1270,8 @@ impl From<&TableDataType> for DataType {
             TableDataType::Binary => DataType::Binary,
             TableDataType::String => DataType::String,
             TableDataType::Interval => DataType::Interval,
+            TableDataType::Time => DataType::Time,
+            TableDataType::TimestampTz => DataType::TimestampTz,
             TableDataType::Number(ty) => DataType::Number(*ty),
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
@@ -1280,6 +1286,9 @@ impl From<&TableDataType> for DataType {
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
             TableDataType::Geography => DataType::Geography,
+            TableDataType::Vector(_) => {
+                DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)))
+            }
         }
     }
 }
@@ -1433,7 +1442,10 @@ impl TableDataType {
                 | TableDataType::Variant
                 | TableDataType::Geometry
                 | TableDataType::Geography
-                | TableDataType::Interval => ty.sql_name(),
+                | TableDataType::Interval
+                | TableDataType::Time
+                | TableDataType::TimestampTz
+                | TableDataType::Vector(_) => ty.sql_name(),
             };
             if is_null {
                 format!("{} NULL", s)
@@ -1575,6 +1587,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
         DataType::Interval => Ok(TableDataType::Interval),
+        DataType::Time => Ok(TableDataType::Time),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
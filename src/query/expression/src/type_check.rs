@@ -789,6 +789,8 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float32",
     "to_float64",
     "to_timestamp",
+    "to_timestamptz",
+    "to_time",
     "to_interval",
     "to_date",
     "to_variant",
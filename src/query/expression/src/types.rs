@@ -31,7 +31,9 @@ pub mod nullable;
 pub mod number;
 pub mod number_class;
 pub mod string;
+pub mod time;
 pub mod timestamp;
+pub mod timestamp_tz;
 pub mod variant;
 
 use std::cmp::Ordering;
@@ -72,7 +74,9 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringColumn;
 pub use self::string::StringType;
+pub use self::time::TimeType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTzType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -114,6 +118,8 @@ pub enum DataType {
     Geometry,
     Interval,
     Geography,
+    Time,
+    TimestampTz,
 
     // Used internally for generic types
     Generic(usize),
@@ -187,6 +193,8 @@ impl DataType {
             | DataType::Timestamp
             | DataType::Date
             | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
@@ -212,6 +220,8 @@ impl DataType {
             | DataType::Timestamp
             | DataType::Date
             | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
@@ -284,6 +294,7 @@ impl DataType {
             | DataType::Number(NumberDataType::Float32)
             | DataType::Number(NumberDataType::Int32) => Ok(4),
             DataType::Timestamp
+            | DataType::Time
             | DataType::Number(NumberDataType::UInt64)
             | DataType::Number(NumberDataType::Float64)
             | DataType::Number(NumberDataType::Int64) => Ok(8),
@@ -0,0 +1,312 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::Range;
+
+use databend_common_column::buffer::Buffer;
+use databend_common_column::types::timestamp_tz;
+use databend_common_exception::ErrorCode;
+use jiff::tz::TimeZone;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::timestamp::timestamp_to_string;
+use crate::types::timestamp::MICROS_PER_SEC;
+use crate::types::timestamp::TIMESTAMP_MAX;
+use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Timestamp with the offset of its own time zone, values of the same instant
+/// are equal even if they are in different time zones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = timestamp_tz;
+    type ScalarRef<'a> = timestamp_tz;
+    type Column = Buffer<timestamp_tz>;
+    type Domain = SimpleDomain<timestamp_tz>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, timestamp_tz>>;
+    type ColumnBuilder = Vec<timestamp_tz>;
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<timestamp_tz>> {
+        domain.as_timestamp_tz().cloned()
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::TimestampTz(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<timestamp_tz>) -> Domain {
+        Domain::TimestampTz(domain)
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Ordering {
+        lhs.cmp(&rhs)
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: timestamp_tz::new(TIMESTAMP_MIN, 0),
+            max: timestamp_tz::new(TIMESTAMP_MAX, 0),
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse the timestamp with an optional offset, e.g. `2024-01-01 12:00:00+08:00`.
+/// If the offset is omitted, the timestamp is in the time zone `tz`.
+pub fn string_to_timestamp_tz(
+    ts_str: impl AsRef<[u8]>,
+    tz: &TimeZone,
+) -> databend_common_exception::Result<timestamp_tz> {
+    let ts_str = std::str::from_utf8(ts_str.as_ref())
+        .map_err(|_| ErrorCode::BadBytes("invalid utf-8 for timestamp"))?
+        .trim();
+    match split_offset(ts_str)? {
+        Some((local, seconds_offset)) => {
+            let local = string_to_timestamp(local.trim_end(), &TimeZone::UTC)?;
+            let timestamp =
+                local.timestamp().as_microsecond() - seconds_offset as i64 * MICROS_PER_SEC;
+            Ok(timestamp_tz::new(timestamp, seconds_offset))
+        }
+        None => {
+            let zoned = string_to_timestamp(ts_str, tz)?;
+            Ok(timestamp_tz::new(
+                zoned.timestamp().as_microsecond(),
+                zoned.offset().seconds(),
+            ))
+        }
+    }
+}
+
+// Split the trailing offset `Z`, `+HH`, `+HHMM` or `+HH:MM` from the timestamp.
+fn split_offset(ts_str: &str) -> databend_common_exception::Result<Option<(&str, i32)>> {
+    // The offset can only follow the time part, which is after the date `YYYY-MM-DD`.
+    let Some(time_part) = ts_str.get(10..) else {
+        return Ok(None);
+    };
+    if time_part.ends_with(['z', 'Z']) {
+        return Ok(Some((&ts_str[..ts_str.len() - 1], 0)));
+    }
+    let Some(pos) = time_part.rfind(['+', '-']) else {
+        return Ok(None);
+    };
+
+    let invalid = || ErrorCode::BadArguments(format!("invalid time zone offset in '{}'", ts_str));
+    let offset = time_part[pos + 1..].replace(':', "");
+    let (hours, minutes) = match offset.len() {
+        2 => (&offset[..], "0"),
+        4 => (&offset[..2], &offset[2..]),
+        _ => return Err(invalid()),
+    };
+    let hours = hours.parse::<i32>().map_err(|_| invalid())?;
+    let minutes = minutes.parse::<i32>().map_err(|_| invalid())?;
+    if hours > 14 || minutes >= 60 {
+        return Err(invalid());
+    }
+
+    let seconds_offset = hours * 3600 + minutes * 60;
+    let seconds_offset = if time_part[pos..].starts_with('-') {
+        -seconds_offset
+    } else {
+        seconds_offset
+    };
+    Ok(Some((&ts_str[..10 + pos], seconds_offset)))
+}
+
+/// Format the timestamp in the local time of its time zone, e.g. `2024-01-01 12:00:00.000000 +08:00`.
+#[inline]
+pub fn timestamp_tz_to_string(ts: timestamp_tz) -> impl Display {
+    let offset = ts.seconds_offset();
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    format!(
+        "{} {}{:02}:{:02}",
+        timestamp_to_string(ts.local_timestamp(), &TimeZone::UTC),
+        sign,
+        offset / 3600,
+        offset % 3600 / 60
+    )
+}
@@ -29,7 +29,9 @@ use super::binary::BinaryColumnBuilder;
 use super::binary::BinaryColumnIter;
 use super::date::date_to_string;
 use super::number::NumberScalar;
+use super::time::time_to_string;
 use super::timestamp::timestamp_to_string;
+use super::timestamp_tz::timestamp_tz_to_string;
 use crate::property::Domain;
 use crate::types::map::KvPair;
 use crate::types::AnyType;
@@ -256,6 +258,10 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: &TimeZone, buf: &mut Vec<u8
             };
             jsonb::Value::Interval(interval)
         }
+        ScalarRef::Time(t) => jsonb::Value::String(time_to_string(t).to_string().into()),
+        ScalarRef::TimestampTz(t) => {
+            jsonb::Value::String(timestamp_tz_to_string(t).to_string().into())
+        }
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             let owned_jsonb = OwnedJsonb::build_array(items.iter().map(RawJsonb::new))
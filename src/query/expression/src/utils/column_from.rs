@@ -72,6 +72,8 @@ impl_from_data! { BitmapType }
 impl_from_data! { GeometryType }
 impl_from_data! { GeographyType }
 impl_from_data! { IntervalType }
+impl_from_data! { TimeType }
+impl_from_data! { TimestampTzType }
 
 impl<'a> FromData<&'a [u8]> for BinaryType {
     fn from_data(d: Vec<&'a [u8]>) -> Column {
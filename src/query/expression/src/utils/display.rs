@@ -59,7 +59,9 @@ use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringDomain;
+use crate::types::time::time_to_string;
 use crate::types::timestamp::timestamp_to_string;
+use crate::types::timestamp_tz::timestamp_tz_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
 use crate::types::NumberClass;
@@ -139,6 +141,8 @@ impl Debug for ScalarRef<'_> {
                 let interval = interval_to_string(i);
                 write!(f, "{interval}")
             }
+            ScalarRef::Time(t) => write!(f, "{}", time_to_string(*t)),
+            ScalarRef::TimestampTz(t) => write!(f, "{}", timestamp_tz_to_string(*t)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -218,6 +222,8 @@ impl Debug for Column {
             Column::Timestamp(col) => f.debug_tuple("Timestamp").field(col).finish(),
             Column::Date(col) => f.debug_tuple("Date").field(col).finish(),
             Column::Interval(col) => write!(f, "{col:?}"),
+            Column::Time(col) => f.debug_tuple("Time").field(col).finish(),
+            Column::TimestampTz(col) => f.debug_tuple("TimestampTz").field(col).finish(),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => fmt_binary(f, "Bitmap", col),
@@ -249,6 +255,8 @@ impl Display for ScalarRef<'_> {
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, &TimeZone::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, &TimeZone::UTC)),
             ScalarRef::Interval(interval) => write!(f, "'{}'", interval_to_string(interval)),
+            ScalarRef::Time(t) => write!(f, "'{}'", time_to_string(*t)),
+            ScalarRef::TimestampTz(t) => write!(f, "'{}'", timestamp_tz_to_string(*t)),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -512,6 +520,8 @@ impl Display for DataType {
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
             DataType::Interval => write!(f, "Interval"),
+            DataType::Time => write!(f, "Time"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -588,8 +598,11 @@ impl Display for TableDataType {
             }
             TableDataType::Variant => write!(f, "Variant"),
             TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::Time => write!(f, "Time"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
             TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Geography => write!(f, "Geography"),
+            TableDataType::Vector(dimension) => write!(f, "Vector({dimension})"),
         }
     }
 }
@@ -1141,6 +1154,8 @@ impl Display for Domain {
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
             Domain::Interval(domain) => write!(f, "{:?}", domain),
+            Domain::Time(domain) => write!(f, "{domain}"),
+            Domain::TimestampTz(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
@@ -237,12 +237,112 @@ impl UDFFlightClient {
         Ok(())
     }
 
+    /// Check the schema of a table function, the server returns the argument
+    /// columns followed by the output columns, optionally preceded by the
+    /// `row` column that maps each output row to its input row.
+    #[async_backtrace::framed]
+    pub async fn check_table_schema(
+        &mut self,
+        func_name: &str,
+        arg_types: &[DataType],
+        return_types: &[DataType],
+    ) -> Result<()> {
+        let descriptor = FlightDescriptor::new_path(vec![func_name.to_string()]);
+        let request = self.make_request(descriptor);
+        let flight_info = self.inner.get_flight_info(request).await?.into_inner();
+        let schema = flight_info
+            .try_decode_schema()
+            .map_err(|err| {
+                ErrorCode::UDFDataError(format!(
+                    "Decode UDF schema failed on UDF function {func_name}: {err}"
+                ))
+            })
+            .and_then(|schema| DataSchema::try_from(&schema))?;
+
+        if schema.fields().len() < arg_types.len() {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF Server returns {} columns on UDF function {func_name}, but it has {} arguments",
+                schema.fields().len(),
+                arg_types.len()
+            )));
+        }
+
+        let (input_fields, mut output_fields) = schema.fields().split_at(arg_types.len());
+        if output_fields.len() == return_types.len() + 1 && output_fields[0].name() == "row" {
+            output_fields = &output_fields[1..];
+        }
+
+        let remote_arg_types = input_fields
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect::<Vec<_>>();
+        let remote_return_types = output_fields
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect::<Vec<_>>();
+        let display = |types: &[DataType]| {
+            types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        if remote_arg_types != arg_types {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF arg types mismatch on UDF function {}, remote arg types: ({}), defined arg types: ({})",
+                func_name,
+                display(&remote_arg_types),
+                display(arg_types)
+            )));
+        }
+
+        if remote_return_types != return_types {
+            return Err(ErrorCode::UDFSchemaMismatch(format!(
+                "UDF return types mismatch on UDF function {}, remote return types: ({}), defined return types: ({})",
+                func_name,
+                display(&remote_return_types),
+                display(return_types)
+            )));
+        }
+
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn do_exchange(
         &mut self,
         func_name: &str,
         input_batch: RecordBatch,
     ) -> Result<RecordBatch> {
+        let batches = self.exchange(func_name, input_batch).await?;
+        if batches.is_empty() {
+            return Err(ErrorCode::EmptyDataFromServer(format!(
+                "Get empty data from UDF Server on UDF function {func_name}"
+            )));
+        }
+
+        let schema = batches[0].schema();
+        concat_batches(&schema, batches.iter())
+            .map_err(|err| ErrorCode::UDFDataError(err.to_string()))
+    }
+
+    /// Call a table function, which may return any number of rows,
+    /// including none, for each input row.
+    #[async_backtrace::framed]
+    pub async fn do_exchange_table(
+        &mut self,
+        func_name: &str,
+        input_batch: RecordBatch,
+    ) -> Result<Vec<RecordBatch>> {
+        self.exchange(func_name, input_batch).await
+    }
+
+    async fn exchange(
+        &mut self,
+        func_name: &str,
+        input_batch: RecordBatch,
+    ) -> Result<Vec<RecordBatch>> {
         let descriptor = FlightDescriptor::new_path(vec![func_name.to_string()]);
         let batch_rows = self.batch_rows;
         let batches = (0..input_batch.num_rows())
@@ -266,16 +366,7 @@ impl UDFFlightClient {
             ))
         });
 
-        let batches: Vec<RecordBatch> = record_batch_stream.try_collect().await?;
-        if batches.is_empty() {
-            return Err(ErrorCode::EmptyDataFromServer(format!(
-                "Get empty data from UDF Server on UDF function {func_name}"
-            )));
-        }
-
-        let schema = batches[0].schema();
-        concat_batches(&schema, batches.iter())
-            .map_err(|err| ErrorCode::UDFDataError(err.to_string()))
+        record_batch_stream.try_collect().await
     }
 }
 
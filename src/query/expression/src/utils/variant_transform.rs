@@ -34,6 +34,8 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Boolean
         | DataType::Binary
         | DataType::Interval
+        | DataType::Time
+        | DataType::TimestampTz
         | DataType::String
         | DataType::Number(_)
         | DataType::Decimal(_)
@@ -79,6 +81,8 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::Timestamp(_)
         | ScalarRef::Date(_)
         | ScalarRef::Interval(_)
+        | ScalarRef::Time(_)
+        | ScalarRef::TimestampTz(_)
         | ScalarRef::Boolean(_)
         | ScalarRef::Binary(_)
         | ScalarRef::String(_)
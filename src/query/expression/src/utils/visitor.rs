@@ -15,6 +15,7 @@
 use databend_common_column::bitmap::Bitmap;
 use databend_common_column::buffer::Buffer;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::Result;
 use decimal::DecimalType;
 use geometry::GeometryType;
@@ -84,6 +85,14 @@ pub trait ValueVisitor {
         self.visit_typed_column::<IntervalType>(buffer)
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_typed_column::<TimeType>(buffer)
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<timestamp_tz>) -> Result<()> {
+        self.visit_typed_column::<TimestampTzType>(buffer)
+    }
+
     fn visit_array(&mut self, column: Box<ArrayColumn<AnyType>>) -> Result<()> {
         self.visit_typed_column::<AnyType>(Column::Array(column))
     }
@@ -138,6 +147,8 @@ pub trait ValueVisitor {
             Column::Timestamp(buffer) => self.visit_timestamp(buffer),
             Column::Date(buffer) => self.visit_date(buffer),
             Column::Interval(buffer) => self.visit_interval(buffer),
+            Column::Time(buffer) => self.visit_time(buffer),
+            Column::TimestampTz(buffer) => self.visit_timestamp_tz(buffer),
             Column::Array(column) => self.visit_array(column),
             Column::Map(column) => self.visit_map(column),
             Column::Tuple(columns) => self.visit_tuple(columns),
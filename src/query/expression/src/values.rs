@@ -29,6 +29,7 @@ use databend_common_column::bitmap::Bitmap;
 use databend_common_column::bitmap::MutableBitmap;
 use databend_common_column::buffer::Buffer;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_io::prelude::BinaryRead;
@@ -80,6 +81,8 @@ use crate::types::number::F32;
 use crate::types::number::F64;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::time::TIME_MAX;
+use crate::types::time::TIME_MIN;
 use crate::types::timestamp::clamp_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
@@ -126,6 +129,8 @@ pub enum Scalar {
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
     Geography(Geography),
+    Time(i64),
+    TimestampTz(timestamp_tz),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -149,6 +154,8 @@ pub enum ScalarRef<'a> {
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
     Geography(GeographyRef<'a>),
+    Time(i64),
+    TimestampTz(timestamp_tz),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -172,6 +179,8 @@ pub enum Column {
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
     Geography(GeographyColumn),
+    Time(Buffer<i64>),
+    TimestampTz(Buffer<timestamp_tz>),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -214,6 +223,8 @@ pub enum ColumnVec {
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
     Geography(Vec<GeographyColumn>),
+    Time(Vec<Buffer<i64>>),
+    TimestampTz(Vec<Buffer<timestamp_tz>>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -237,6 +248,8 @@ pub enum ColumnBuilder {
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
     Geography(BinaryColumnBuilder),
+    Time(Vec<i64>),
+    TimestampTz(Vec<timestamp_tz>),
 }
 
 impl<T: ValueType> Value<T> {
@@ -397,6 +410,8 @@ impl Scalar {
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
             Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
             Scalar::Geography(g) => ScalarRef::Geography(g.as_ref()),
+            Scalar::Time(t) => ScalarRef::Time(*t),
+            Scalar::TimestampTz(t) => ScalarRef::TimestampTz(*t),
         }
     }
 
@@ -440,6 +455,8 @@ impl Scalar {
             DataType::Variant => Scalar::Variant(vec![]),
             DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Geography => Scalar::Geography(Geography::default()),
+            DataType::Time => Scalar::Time(0),
+            DataType::TimestampTz => Scalar::TimestampTz(timestamp_tz::new(0, 0)),
 
             _ => unimplemented!(),
         }
@@ -461,7 +478,9 @@ impl Scalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
-            | Scalar::Geography(_) => false,
+            | Scalar::Geography(_)
+            | Scalar::Time(_)
+            | Scalar::TimestampTz(_) => false,
             Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => true,
         }
     }
@@ -473,6 +492,8 @@ impl Scalar {
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
             Scalar::Interval(i) => i.0.is_positive(),
+            Scalar::Time(t) => *t > 0,
+            Scalar::TimestampTz(t) => t.timestamp() > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
         }
     }
@@ -518,6 +539,8 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
             ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
             ScalarRef::Geography(s) => Scalar::Geography(s.to_owned()),
+            ScalarRef::Time(t) => Scalar::Time(*t),
+            ScalarRef::TimestampTz(t) => Scalar::TimestampTz(*t),
         }
     }
 
@@ -556,6 +579,8 @@ impl ScalarRef<'_> {
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
             ScalarRef::Interval(i) => Domain::Interval(SimpleDomain { min: *i, max: *i }),
+            ScalarRef::Time(t) => Domain::Time(SimpleDomain { min: *t, max: *t }),
+            ScalarRef::TimestampTz(t) => Domain::TimestampTz(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -616,6 +641,8 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(buf) => buf.len(),
             ScalarRef::Geometry(buf) => buf.len(),
             ScalarRef::Geography(s) => s.0.len(),
+            ScalarRef::Time(_) => 8,
+            ScalarRef::TimestampTz(_) => 16,
         }
     }
 
@@ -651,6 +678,8 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(_) => DataType::Variant,
             ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Geography(_) => DataType::Geography,
+            ScalarRef::Time(_) => DataType::Time,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
         }
     }
 
@@ -718,6 +747,8 @@ impl ScalarRef<'_> {
             (ScalarRef::Geometry(_), ScalarRef::Geometry(_)) => Some(DataType::Geometry),
             (ScalarRef::Geography(_), ScalarRef::Geography(_)) => Some(DataType::Geography),
             (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
+            (ScalarRef::Time(_), ScalarRef::Time(_)) => Some(DataType::Time),
+            (ScalarRef::TimestampTz(_), ScalarRef::TimestampTz(_)) => Some(DataType::TimestampTz),
             _ => None,
         }
     }
@@ -738,6 +769,8 @@ impl ScalarRef<'_> {
                 (ScalarRef::Timestamp(_), DataType::Timestamp) => true,
                 (ScalarRef::Interval(_), DataType::Interval) => true,
                 (ScalarRef::Date(_), DataType::Date) => true,
+                (ScalarRef::Time(_), DataType::Time) => true,
+                (ScalarRef::TimestampTz(_), DataType::TimestampTz) => true,
                 (ScalarRef::Bitmap(_), DataType::Bitmap) => true,
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
@@ -804,6 +837,8 @@ impl ScalarRef<'_> {
             ScalarRef::Variant(s) => s.len() * n + (n + 1) * 8,
             ScalarRef::Geometry(s) => s.len() * n + (n + 1) * 8,
             ScalarRef::Geography(s) => s.0.len() * n + (n + 1) * 8,
+            ScalarRef::Time(_) => n * 8,
+            ScalarRef::TimestampTz(_) => n * 16,
         }
     }
 }
@@ -822,6 +857,8 @@ impl PartialOrd for Scalar {
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
             (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::Time(t1), Scalar::Time(t2)) => t1.partial_cmp(t2),
+            (Scalar::TimestampTz(t1), Scalar::TimestampTz(t2)) => t1.partial_cmp(t2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
@@ -875,6 +912,8 @@ impl<'b> PartialOrd<ScalarRef<'b>> for ScalarRef<'_> {
             (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => compare_geometry(g1, g2),
             (ScalarRef::Geography(g1), ScalarRef::Geography(g2)) => g1.partial_cmp(g2),
             (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Time(t1), ScalarRef::Time(t2)) => t1.partial_cmp(t2),
+            (ScalarRef::TimestampTz(t1), ScalarRef::TimestampTz(t2)) => t1.partial_cmp(t2),
 
             // By default, null is biggest in pgsql
             (ScalarRef::Null, _) => Some(Ordering::Greater),
@@ -916,6 +955,8 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
             ScalarRef::Interval(v) => v.0.hash(state),
+            ScalarRef::Time(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -963,6 +1004,10 @@ impl PartialOrd for Column {
             (Column::Interval(col1), Column::Interval(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::Time(col1), Column::Time(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -1034,6 +1079,8 @@ impl Column {
             Column::Variant(col) => col.len(),
             Column::Geometry(col) => col.len(),
             Column::Geography(col) => col.len(),
+            Column::Time(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
         }
     }
 
@@ -1063,6 +1110,8 @@ impl Column {
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
             Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Geography(col) => Some(ScalarRef::Geography(col.index(index)?)),
+            Column::Time(col) => Some(ScalarRef::Time(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
         }
     }
 
@@ -1095,6 +1144,8 @@ impl Column {
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
             Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Geography(col) => ScalarRef::Geography(col.index_unchecked(index)),
+            Column::Time(col) => ScalarRef::Time(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
         }
     }
 
@@ -1152,6 +1203,12 @@ impl Column {
             Column::Variant(col) => Column::Variant(col.slice(range)),
             Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Geography(col) => Column::Geography(col.slice(range)),
+            Column::Time(col) => {
+                Column::Time(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
         }
     }
 
@@ -1216,6 +1273,20 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Time(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Time(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::TimestampTz(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::TimestampTz(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Array(col) => {
                 if col.len() == 0 {
                     Domain::Array(None)
@@ -1295,6 +1366,8 @@ impl Column {
             Column::Variant(_) => DataType::Variant,
             Column::Geometry(_) => DataType::Geometry,
             Column::Geography(_) => DataType::Geography,
+            Column::Time(_) => DataType::Time,
+            Column::TimestampTz(_) => DataType::TimestampTz,
         }
     }
 
@@ -1435,6 +1508,21 @@ impl Column {
                 })
                 .take(len),
             )),
+            DataType::Time => TimeType::from_data(
+                (0..len)
+                    .map(|_| rng.gen_range(TIME_MIN..=TIME_MAX))
+                    .collect::<Vec<i64>>(),
+            ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        timestamp_tz::new(
+                            rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX),
+                            rng.gen_range(-14 * 3600..=14 * 3600),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Nullable(ty) => {
                 let column = Column::random(ty, len, options);
                 let bitmap =
@@ -1586,6 +1674,8 @@ impl Column {
             Column::Variant(col) => col.memory_size(),
             Column::Geometry(col) => col.memory_size(),
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Time(col) => col.len() * 8,
+            Column::TimestampTz(col) => col.len() * 16,
         }
     }
 
@@ -1601,9 +1691,12 @@ impl Column {
             Column::Number(NumberColumn::Int8(col)) => col.len(),
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) | Column::Date(col) => col.len() * 4,
-            Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
+            Column::Number(NumberColumn::Int64(col))
+            | Column::Timestamp(col)
+            | Column::Time(col) => col.len() * 8,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Geography(col) => GeographyType::column_memory_size(col),
             Column::Boolean(c) => c.len(),
@@ -1705,6 +1798,8 @@ impl ColumnBuilder {
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
             Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::Time(col) => ColumnBuilder::Time(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1764,6 +1859,8 @@ impl ColumnBuilder {
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
             ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
+            ScalarRef::Time(t) => ColumnBuilder::Time(vec![*t; n]),
+            ScalarRef::TimestampTz(t) => ColumnBuilder::TimestampTz(vec![*t; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1802,6 +1899,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
             ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::Time(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
@@ -1840,6 +1939,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
             ColumnBuilder::Interval(col) => col.len() * 16,
+            ColumnBuilder::Time(col) => col.len() * 8,
+            ColumnBuilder::TimestampTz(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
@@ -1869,6 +1970,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
             ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::Time(_) => DataType::Time,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -1918,6 +2021,8 @@ impl ColumnBuilder {
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
             DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::Time => ColumnBuilder::Time(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -1999,6 +2104,8 @@ impl ColumnBuilder {
             DataType::Interval => {
                 ColumnBuilder::Interval(vec![months_days_micros::new(0, 0, 0); len])
             }
+            DataType::Time => ColumnBuilder::Time(vec![0; len]),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(vec![timestamp_tz::new(0, 0); len]),
 
             // binary based
             DataType::Binary => ColumnBuilder::Binary(BinaryColumnBuilder::repeat_default(len)),
@@ -2059,6 +2166,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
                 IntervalType::push_item(builder, value)
             }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item(builder, value)
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item(builder, value)
+            }
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 ArrayType::push_item(builder, value);
             }
@@ -2114,6 +2227,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
                 IntervalType::push_item_repeat(builder, *value, n);
             }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item_repeat(builder, *value, n);
+            }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => {
                 DateType::push_item_repeat(builder, *value, n);
             }
@@ -2168,6 +2287,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
             ColumnBuilder::Interval(builder) => builder.push(months_days_micros::new(0, 0, 0)),
+            ColumnBuilder::Time(builder) => builder.push(0),
+            ColumnBuilder::TimestampTz(builder) => builder.push(timestamp_tz::new(0, 0)),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
@@ -2248,6 +2369,14 @@ impl ColumnBuilder {
                 let value = months_days_micros(i128::de_binary(reader));
                 builder.push(value);
             }
+            ColumnBuilder::Time(builder) => {
+                let value: i64 = reader.read_scalar()?;
+                builder.push(value);
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                let value = timestamp_tz(i128::de_binary(reader));
+                builder.push(value);
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -2359,6 +2488,19 @@ impl ColumnBuilder {
                     builder.push(months_days_micros(i128::de_binary(&mut reader)));
                 }
             }
+            ColumnBuilder::Time(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i64 = reader.read_scalar()?;
+                    builder.push(value);
+                }
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(timestamp_tz(i128::de_binary(&mut reader)));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2433,6 +2575,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
             ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::Time(builder) => builder.pop().map(Scalar::Time),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
@@ -2501,6 +2645,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Time(builder), Column::Time(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2548,6 +2698,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(b) => Column::Timestamp(TimestampType::build_column(b)),
             ColumnBuilder::Date(b) => Column::Date(DateType::build_column(b)),
             ColumnBuilder::Interval(b) => Column::Interval(IntervalType::build_column(b)),
+            ColumnBuilder::Time(b) => Column::Time(TimeType::build_column(b)),
+            ColumnBuilder::TimestampTz(b) => Column::TimestampTz(TimestampTzType::build_column(b)),
             ColumnBuilder::Bitmap(b) => Column::Bitmap(BitmapType::build_column(b)),
             ColumnBuilder::Variant(b) => Column::Variant(VariantType::build_column(b)),
             ColumnBuilder::Geometry(b) => Column::Geometry(GeometryType::build_column(b)),
@@ -2579,6 +2731,8 @@ impl ColumnBuilder {
             ColumnBuilder::Timestamp(b) => Scalar::Timestamp(TimestampType::build_scalar(b)),
             ColumnBuilder::Date(b) => Scalar::Date(DateType::build_scalar(b)),
             ColumnBuilder::Interval(b) => Scalar::Interval(IntervalType::build_scalar(b)),
+            ColumnBuilder::Time(b) => Scalar::Time(TimeType::build_scalar(b)),
+            ColumnBuilder::TimestampTz(b) => Scalar::TimestampTz(TimestampTzType::build_scalar(b)),
             ColumnBuilder::Bitmap(b) => Scalar::Bitmap(BitmapType::build_scalar(b)),
             ColumnBuilder::Variant(b) => Scalar::Variant(VariantType::build_scalar(b)),
             ColumnBuilder::Geometry(b) => Scalar::Geometry(GeometryType::build_scalar(b)),
@@ -15,9 +15,12 @@
 use std::io::Cursor;
 
 use bstr::ByteSlice;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use databend_common_io::cursor_ext::read_num_text_exact;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
@@ -57,3 +60,17 @@ pub(crate) fn read_timestamp(
     column.push(ts);
     Ok(())
 }
+
+pub(crate) fn read_time(column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
+    column.push(string_to_time(data)?);
+    Ok(())
+}
+
+pub(crate) fn read_timestamp_tz(
+    column: &mut Vec<timestamp_tz>,
+    data: &[u8],
+    settings: &InputCommonSettings,
+) -> Result<()> {
+    column.push(string_to_timestamp_tz(data, &settings.jiff_timezone)?);
+    Ok(())
+}
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::io::Cursor;
 
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::serialize::read_decimal_from_json;
@@ -28,7 +29,9 @@ use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::MutableBitmap;
 use databend_common_expression::types::NumberColumnBuilder;
@@ -95,6 +98,8 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
             ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
@@ -310,6 +315,26 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_time(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_time(v.as_bytes())?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect time value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<timestamp_tz>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_timestamp_tz(v.as_bytes(), &self.jiff_timezone)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect timestamp value")),
+        }
+    }
+
     fn read_bitmap(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -18,6 +18,7 @@ use std::io::Cursor;
 
 use bstr::ByteSlice;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
@@ -55,7 +56,9 @@ use jsonb::parse_value;
 use lexical_core::FromLexical;
 
 use crate::binary::decode_binary;
+use crate::field_decoder::common::read_time;
 use crate::field_decoder::common::read_timestamp;
+use crate::field_decoder::common::read_timestamp_tz;
 use crate::FileFormatOptionsExt;
 use crate::InputCommonSettings;
 
@@ -132,6 +135,8 @@ impl NestedValues {
             ColumnBuilder::Date(c) => self.read_date(c, reader),
             ColumnBuilder::Interval(c) => self.read_interval(c, reader),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader),
+            ColumnBuilder::Time(c) => self.read_time(c, reader),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader),
             ColumnBuilder::Binary(c) => self.read_binary(c, reader),
             ColumnBuilder::String(c) => self.read_string(c, reader),
             ColumnBuilder::Array(c) => self.read_array(c, reader),
@@ -283,6 +288,26 @@ impl NestedValues {
         read_timestamp(column, &buf, self.common_settings())
     }
 
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        read_time(column, &buf)
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<timestamp_tz>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        read_timestamp_tz(column, &buf, self.common_settings())
+    }
+
     fn read_bitmap<R: AsRef<[u8]>>(
         &self,
         column: &mut BinaryColumnBuilder,
@@ -55,7 +55,9 @@ use lexical_core::FromLexical;
 use num_traits::NumCast;
 
 use crate::binary::decode_binary;
+use crate::field_decoder::common::read_time;
 use crate::field_decoder::common::read_timestamp;
+use crate::field_decoder::common::read_timestamp_tz;
 use crate::field_decoder::FieldDecoder;
 use crate::FileFormatOptionsExt;
 use crate::InputCommonSettings;
@@ -147,6 +149,8 @@ impl SeparatedTextDecoder {
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Interval(c) => self.read_interval(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Time(c) => read_time(c, data),
+            ColumnBuilder::TimestampTz(c) => read_timestamp_tz(c, data, self.common_settings()),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
@@ -139,6 +139,8 @@ impl FieldEncoderCSV {
 
             Column::Date(..)
             | Column::Timestamp(..)
+            | Column::Time(..)
+            | Column::TimestampTz(..)
             | Column::Bitmap(..)
             | Column::Variant(..)
             | Column::Interval(_) => {
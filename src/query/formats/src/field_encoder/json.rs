@@ -73,6 +73,8 @@ impl FieldEncoderJSON {
 
             Column::Date(..)
             | Column::Timestamp(..)
+            | Column::Time(..)
+            | Column::TimestampTz(..)
             | Column::Bitmap(..)
             | Column::Interval(..) => {
                 let mut buf = Vec::new();
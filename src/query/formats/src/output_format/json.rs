@@ -15,6 +15,8 @@
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::interval::interval_to_string;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::time::time_to_string;
+use databend_common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
@@ -105,6 +107,10 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.jiff_timezone.clone());
             serde_json::to_value(strtime::format("%Y-%m-%d %H:%M:%S", &dt).unwrap()).unwrap()
         }
+        ScalarRef::Time(v) => serde_json::to_value(time_to_string(v).to_string()).unwrap(),
+        ScalarRef::TimestampTz(v) => {
+            serde_json::to_value(timestamp_tz_to_string(v).to_string()).unwrap()
+        }
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
@@ -230,6 +230,8 @@ pub fn try_create_aggregate_string_agg_function(
             | DataType::Date
             | DataType::Variant
             | DataType::Interval
+            | DataType::Time
+            | DataType::TimestampTz
     ) {
         return Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
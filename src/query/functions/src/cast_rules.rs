@@ -96,6 +96,8 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
     (DataType::String, DataType::Interval),
+    (DataType::String, DataType::Time),
+    (DataType::String, DataType::TimestampTz),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
     (
@@ -34,7 +34,9 @@ use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringColumn;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTzType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
@@ -63,6 +65,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_time_cmp(registry);
+    register_timestamp_tz_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -242,6 +246,14 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_time_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, TimeType);
+}
+
+fn register_timestamp_tz_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, TimestampTzType);
+}
+
 fn register_interval_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, IntervalType);
 }
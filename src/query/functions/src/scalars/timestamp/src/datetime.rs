@@ -21,6 +21,7 @@ use std::io::Write;
 use chrono::Datelike;
 use chrono::NaiveDate;
 use databend_common_column::types::months_days_micros;
+use databend_common_column::types::timestamp_tz;
 use databend_common_exception::ErrorCode;
 use databend_common_expression::error_to_null;
 use databend_common_expression::serialize::EPOCH_DAYS_FROM_CE;
@@ -32,12 +33,17 @@ use databend_common_expression::types::date::DATE_MIN;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::Int64Type;
+use databend_common_expression::types::number::Number;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::number::UInt16Type;
 use databend_common_expression::types::number::UInt32Type;
 use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::time::string_to_time;
+use databend_common_expression::types::time::time_to_micros;
+use databend_common_expression::types::time::time_to_string;
+use databend_common_expression::types::time::MICROS_PER_DAY;
 use databend_common_expression::types::timestamp::clamp_timestamp;
 use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::timestamp::timestamp_to_string;
@@ -45,6 +51,8 @@ use databend_common_expression::types::timestamp::MICROS_PER_MILLI;
 use databend_common_expression::types::timestamp::MICROS_PER_SEC;
 use databend_common_expression::types::timestamp::TIMESTAMP_MAX;
 use databend_common_expression::types::timestamp::TIMESTAMP_MIN;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
@@ -54,7 +62,9 @@ use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTzType;
 use databend_common_expression::types::F64;
 use databend_common_expression::utils::date_helper::*;
 use databend_common_expression::vectorize_1_arg;
@@ -87,16 +97,27 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_timestamp_to_date(registry);
     register_number_to_date(registry);
 
-    // cast([date | timestamp] AS string)
-    // to_string([date | timestamp])
+    // cast(xx AS time)
+    // to_time(xx)
+    register_to_time(registry);
+
+    // cast(xx AS timestamptz)
+    // to_timestamptz(xx)
+    register_to_timestamp_tz(registry);
+    register_timestamp_tz_to_timestamp_and_date(registry);
+
+    // cast([date | timestamp | time | timestamptz] AS string)
+    // to_string([date | timestamp | time | timestamptz])
     register_to_string(registry);
+    register_time_to_string(registry);
 
     // cast([date | timestamp] AS [uint8 | int8 | ...])
     // to_[uint8 | int8 | ...]([date | timestamp])
     register_to_number(registry);
 
-    // [add | subtract]_[years | months | days | hours | minutes | seconds]([date | timestamp], number)
-    // date_[add | sub]([year | quarter | month | week | day | hour | minute | second], [date | timestamp], number)
+    // [add | subtract]_[years | months | days | hours | minutes | seconds]([date | timestamp | timestamptz], number)
+    // [add | subtract]_[hours | minutes | seconds](time, number)
+    // date_[add | sub]([year | quarter | month | week | day | hour | minute | second], [date | timestamp | timestamptz | time], number)
     // [date | timestamp] [+ | -] interval number [year | quarter | month | week | day | hour | minute | second]
     register_add_functions(registry);
     register_sub_functions(registry);
@@ -112,10 +133,10 @@ pub fn register(registry: &mut FunctionRegistry) {
     // now, today, yesterday, tomorrow
     register_real_time_functions(registry);
 
-    // to_*([date | timestamp]) -> number
+    // to_*([date | timestamp | timestamptz | time]) -> number
     register_to_number_functions(registry);
 
-    // to_*([date | timestamp]) -> [date | timestamp]
+    // to_*([date | timestamp | timestamptz | time]) -> [date | timestamp | timestamptz | time]
     register_rounder_functions(registry);
 
     // [date | timestamp] +/- number
@@ -665,6 +686,160 @@ fn register_timestamp_to_date(registry: &mut FunctionRegistry) {
     }
 }
 
+fn register_to_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_time),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_time),
+    );
+
+    fn eval_string_to_time(val: Value<StringType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<StringType, TimeType>(
+            |val, output, ctx| match string_to_time(val) {
+                Ok(t) => output.push(t),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIME`. {}", e.message()),
+                    );
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    fn eval_timestamp_to_time(val: Value<TimestampType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimeType>(|val, output, ctx| {
+            let zoned = val.to_timestamp(ctx.func_ctx.tz.clone());
+            output.push(time_to_micros(&zoned.time()));
+        })(val, ctx)
+    }
+}
+
+fn register_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamptz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamptz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamptz",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamptz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_timestamp_to_timestamp_tz),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<DateType, TimestampTzType, _, _>(
+        "to_timestamptz",
+        |_, _| FunctionDomain::Full,
+        eval_date_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<DateType, TimestampTzType, _, _>(
+        "try_to_timestamptz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_date_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: Value<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, &ctx.func_ctx.tz) {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIMESTAMPTZ`. {}", e.message()),
+                    );
+                    output.push(timestamp_tz::new(0, 0));
+                }
+            }
+        })(val, ctx)
+    }
+
+    fn eval_timestamp_to_timestamp_tz(
+        val: Value<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            let zoned = val.to_timestamp(ctx.func_ctx.tz.clone());
+            output.push(timestamp_tz::new(val, zoned.offset().seconds()));
+        })(val, ctx)
+    }
+
+    fn eval_date_to_timestamp_tz(
+        val: Value<DateType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<DateType, TimestampTzType>(|val, output, ctx| {
+            let tz = ctx.func_ctx.tz.clone();
+            match val.to_date(tz.clone()).at(0, 0, 0, 0).to_zoned(tz) {
+                Ok(zoned) => output.push(timestamp_tz::new(
+                    zoned.timestamp().as_microsecond(),
+                    zoned.offset().seconds(),
+                )),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot convert date to type `TIMESTAMPTZ`. {}", e),
+                    );
+                    output.push(timestamp_tz::new(0, 0));
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_tz_to_timestamp_and_date(registry: &mut FunctionRegistry) {
+    // The instant is kept, only the time zone is dropped.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, domain| {
+            FunctionDomain::Domain(SimpleDomain {
+                min: domain.min.timestamp(),
+                max: domain.max.timestamp(),
+            })
+        },
+        vectorize_1_arg::<TimestampTzType, TimestampType>(|val, _| val.timestamp()),
+    );
+
+    // The date is the local date in the time zone of the value.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        "to_date",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, DateType>(|val, _| {
+            val.local_timestamp().div_euclid(24 * 3600 * MICROS_PER_SEC) as i32
+        }),
+    );
+}
+
 fn register_number_to_date(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<Int64Type, DateType, _, _>(
         "to_date",
@@ -809,6 +984,26 @@ fn register_to_string(registry: &mut FunctionRegistry) {
     );
 }
 
+fn register_time_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimeType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimeType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", time_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", timestamp_tz_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
 fn register_to_number(registry: &mut FunctionRegistry) {
     registry.register_1_arg::<DateType, NumberType<i64>, _, _>(
         "to_int64",
@@ -1097,6 +1292,160 @@ macro_rules! impl_register_arith_functions {
                     },
                 ),
             );
+
+            // timestamptz: calendar units are applied in the value's own offset.
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_years"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, ctx| {
+                        match eval_timestamp_tz_local(ts, |local| EvalYearsImpl::eval_timestamp(local, TimeZone::UTC, $signed_wrapper!{delta})) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(timestamp_tz::new(0, 0));
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_quarters"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, ctx| {
+                        match eval_timestamp_tz_local(ts, |local| EvalMonthsImpl::eval_timestamp(local, TimeZone::UTC, $signed_wrapper!{delta} * 3)) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(timestamp_tz::new(0, 0));
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_months"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, ctx| {
+                        match eval_timestamp_tz_local(ts, |local| EvalMonthsImpl::eval_timestamp(local, TimeZone::UTC, $signed_wrapper!{delta})) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(timestamp_tz::new(0, 0));
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_days"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, ctx| {
+                        match eval_timestamp_tz_local(ts, |local| Ok(EvalDaysImpl::eval_timestamp(local, $signed_wrapper!{delta}))) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(timestamp_tz::new(0, 0));
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_weeks"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, ctx| {
+                        let delta = 7 * delta;
+                        match eval_timestamp_tz_local(ts, |local| Ok(EvalDaysImpl::eval_timestamp(local, $signed_wrapper!{delta}))) {
+                            Ok(t) => builder.push(t),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e);
+                                builder.push(timestamp_tz::new(0, 0));
+                            },
+                        }
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_hours"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, _| {
+                        builder.push(timestamp_tz::new(
+                            EvalTimesImpl::eval_timestamp(ts.timestamp(), $signed_wrapper!{delta}, FACTOR_HOUR),
+                            ts.seconds_offset(),
+                        ));
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_minutes"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, _| {
+                        builder.push(timestamp_tz::new(
+                            EvalTimesImpl::eval_timestamp(ts.timestamp(), $signed_wrapper!{delta}, FACTOR_MINUTE),
+                            ts.seconds_offset(),
+                        ));
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, TimestampTzType, _, _>(
+                concat!($op, "_seconds"),
+
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<TimestampTzType, Int64Type, TimestampTzType>(
+                    |ts, delta, builder, _| {
+                        builder.push(timestamp_tz::new(
+                            EvalTimesImpl::eval_timestamp(ts.timestamp(), $signed_wrapper!{delta}, FACTOR_SECOND),
+                            ts.seconds_offset(),
+                        ));
+                    },
+                ),
+            );
+
+            // time: wraps around midnight.
+            registry.register_passthrough_nullable_2_arg::<TimeType, Int64Type, TimeType, _, _>(
+                concat!($op, "_hours"),
+
+                |_, _, _| FunctionDomain::Full,
+                vectorize_with_builder_2_arg::<TimeType, Int64Type, TimeType>(
+                    |time, delta, builder, _| {
+                        builder.push(eval_time_add(time, $signed_wrapper!{delta}, FACTOR_HOUR));
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimeType, Int64Type, TimeType, _, _>(
+                concat!($op, "_minutes"),
+
+                |_, _, _| FunctionDomain::Full,
+                vectorize_with_builder_2_arg::<TimeType, Int64Type, TimeType>(
+                    |time, delta, builder, _| {
+                        builder.push(eval_time_add(time, $signed_wrapper!{delta}, FACTOR_MINUTE));
+                    },
+                ),
+            );
+            registry.register_passthrough_nullable_2_arg::<TimeType, Int64Type, TimeType, _, _>(
+                concat!($op, "_seconds"),
+
+                |_, _, _| FunctionDomain::Full,
+                vectorize_with_builder_2_arg::<TimeType, Int64Type, TimeType>(
+                    |time, delta, builder, _| {
+                        builder.push(eval_time_add(time, $signed_wrapper!{delta}, FACTOR_SECOND));
+                    },
+                ),
+            );
         }
     };
 }
@@ -1104,6 +1453,25 @@ macro_rules! impl_register_arith_functions {
 impl_register_arith_functions!(register_add_functions, "add", unsigned_ident);
 impl_register_arith_functions!(register_sub_functions, "subtract", signed_ident);
 
+/// Applies `eval` to the local time of a TIMESTAMPTZ and keeps its offset.
+fn eval_timestamp_tz_local(
+    val: timestamp_tz,
+    eval: impl FnOnce(i64) -> Result<i64, String>,
+) -> Result<timestamp_tz, String> {
+    let offset = val.seconds_offset();
+    let local = eval(val.local_timestamp())?;
+    Ok(timestamp_tz::new(
+        local - offset as i64 * MICROS_PER_SEC,
+        offset,
+    ))
+}
+
+/// Adds `delta` units of `factor` seconds to a TIME, wrapping around midnight.
+fn eval_time_add(time: i64, delta: i64, factor: i64) -> i64 {
+    let micros = delta as i128 * (factor * MICROS_PER_SEC) as i128;
+    (time as i128 + micros).rem_euclid(MICROS_PER_DAY as i128) as i64
+}
+
 fn register_diff_functions(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Int64Type, _, _>(
         "diff_years",
@@ -2147,6 +2515,100 @@ fn register_to_number_functions(registry: &mut FunctionRegistry) {
             datetime.second() as u8
         }),
     );
+    // timestamptz, in the value's own offset
+    timestamp_tz_to_number_helper::<ToYYYYMM, _>(registry, "to_yyyymm");
+    timestamp_tz_to_number_helper::<ToYYYYMMDD, _>(registry, "to_yyyymmdd");
+    timestamp_tz_to_number_helper::<ToYYYYMMDDHH, _>(registry, "to_yyyymmddhh");
+    timestamp_tz_to_number_helper::<ToYYYYMMDDHHMMSS, _>(registry, "to_yyyymmddhhmmss");
+    timestamp_tz_to_number_helper::<ToYear, _>(registry, "to_year");
+    timestamp_tz_to_number_helper::<ToISOYear, _>(registry, "to_iso_year");
+    timestamp_tz_to_number_helper::<ToQuarter, _>(registry, "to_quarter");
+    timestamp_tz_to_number_helper::<ToMonth, _>(registry, "to_month");
+    timestamp_tz_to_number_helper::<ToDayOfYear, _>(registry, "to_day_of_year");
+    timestamp_tz_to_number_helper::<ToDayOfMonth, _>(registry, "to_day_of_month");
+    timestamp_tz_to_number_helper::<ToDayOfWeek, _>(registry, "to_day_of_week");
+    timestamp_tz_to_number_helper::<DayOfWeek, _>(registry, "dayofweek");
+    timestamp_tz_to_number_helper::<ToYYYYWW, _>(registry, "yearweek");
+    timestamp_tz_to_number_helper::<ToMillennium, _>(registry, "millennium");
+    timestamp_tz_to_number_helper::<ToWeekOfYear, _>(registry, "to_week_of_year");
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, Int64Type, _, _>(
+        "to_unix_timestamp",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, Int64Type>(|val, _| {
+            ToNumberImpl::eval_timestamp::<ToUnixTimestamp, _>(val.timestamp(), TimeZone::UTC)
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, Float64Type, _, _>(
+        "epoch",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, Float64Type>(|val, _| {
+            (val.timestamp() as f64 / 1_000_000f64).into()
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            let datetime = val.local_timestamp().to_timestamp(TimeZone::UTC);
+            datetime.hour() as u8
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_minute",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            let datetime = val.local_timestamp().to_timestamp(TimeZone::UTC);
+            datetime.minute() as u8
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, UInt8Type, _, _>(
+        "to_second",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, UInt8Type>(|val, _| {
+            let datetime = val.local_timestamp().to_timestamp(TimeZone::UTC);
+            datetime.second() as u8
+        }),
+    );
+
+    // time
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| {
+            (val / (FACTOR_HOUR * MICROS_PER_SEC)) as u8
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_minute",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| {
+            (val / (FACTOR_MINUTE * MICROS_PER_SEC) % 60) as u8
+        }),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, UInt8Type, _, _>(
+        "to_second",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, UInt8Type>(|val, _| (val / MICROS_PER_SEC % 60) as u8),
+    );
+    registry.register_passthrough_nullable_1_arg::<TimeType, Float64Type, _, _>(
+        "epoch",
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, Float64Type>(|val, _| (val as f64 / 1_000_000f64).into()),
+    );
+}
+
+fn timestamp_tz_to_number_helper<T, R>(registry: &mut FunctionRegistry, name: &str)
+where
+    T: ToNumber<R>,
+    R: Number,
+{
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, NumberType<R>, _, _>(
+        name,
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, NumberType<R>>(|val, _| {
+            ToNumberImpl::eval_timestamp::<T, _>(val.local_timestamp(), TimeZone::UTC)
+        }),
+    );
 }
 
 fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
@@ -2302,6 +2764,25 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
         }),
     );
 
+    // timestamptz -> timestamptz, in the value's own offset
+    timestamp_tz_rounder_helper(registry, "to_start_of_second", Round::Second);
+    timestamp_tz_rounder_helper(registry, "to_start_of_minute", Round::Minute);
+    timestamp_tz_rounder_helper(registry, "to_start_of_five_minutes", Round::FiveMinutes);
+    timestamp_tz_rounder_helper(registry, "to_start_of_ten_minutes", Round::TenMinutes);
+    timestamp_tz_rounder_helper(
+        registry,
+        "to_start_of_fifteen_minutes",
+        Round::FifteenMinutes,
+    );
+    timestamp_tz_rounder_helper(registry, "to_start_of_hour", Round::Hour);
+    timestamp_tz_rounder_helper(registry, "to_start_of_day", Round::Day);
+    timestamp_tz_rounder_helper(registry, "time_slot", Round::TimeSlot);
+
+    // time -> time
+    time_rounder_helper(registry, "to_start_of_second", FACTOR_SECOND);
+    time_rounder_helper(registry, "to_start_of_minute", FACTOR_MINUTE);
+    time_rounder_helper(registry, "to_start_of_hour", FACTOR_HOUR);
+
     // date | timestamp -> date
     rounder_functions_helper::<ToLastMonday>(registry, "to_monday");
     rounder_functions_helper::<ToLastSunday>(registry, "to_start_of_week");
@@ -2362,6 +2843,17 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
             }
         }),
     );
+    registry.register_passthrough_nullable_2_arg::<TimestampTzType, Int64Type, DateType, _, _>(
+        "to_start_of_week",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_2_arg::<TimestampTzType, Int64Type, DateType>(|val, mode, _| {
+            if mode == 0 {
+                DateRounder::eval_timestamp::<ToLastSunday>(val.local_timestamp(), TimeZone::UTC)
+            } else {
+                DateRounder::eval_timestamp::<ToLastMonday>(val.local_timestamp(), TimeZone::UTC)
+            }
+        }),
+    );
 }
 
 fn rounder_functions_helper<T>(registry: &mut FunctionRegistry, name: &str)
@@ -2386,4 +2878,31 @@ where T: ToNumber<i32> {
             DateRounder::eval_timestamp::<T>(val, ctx.func_ctx.tz.clone())
         }),
     );
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, DateType, _, _>(
+        name,
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, DateType>(|val, _| {
+            DateRounder::eval_timestamp::<T>(val.local_timestamp(), TimeZone::UTC)
+        }),
+    );
+}
+
+fn timestamp_tz_rounder_helper(registry: &mut FunctionRegistry, name: &str, round: Round) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampTzType, _, _>(
+        name,
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimestampTzType, TimestampTzType>(move |val, _| {
+            let offset = val.seconds_offset();
+            let local = round_timestamp(val.local_timestamp(), &TimeZone::UTC, round);
+            timestamp_tz::new(local - offset as i64 * MICROS_PER_SEC, offset)
+        }),
+    );
+}
+
+fn time_rounder_helper(registry: &mut FunctionRegistry, name: &str, factor: i64) {
+    registry.register_passthrough_nullable_1_arg::<TimeType, TimeType, _, _>(
+        name,
+        |_, _| FunctionDomain::Full,
+        vectorize_1_arg::<TimeType, TimeType>(move |val, _| val - val % (factor * MICROS_PER_SEC)),
+    );
 }
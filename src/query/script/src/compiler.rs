@@ -15,11 +15,14 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Display;
+use std::ops::RangeInclusive;
 use std::vec;
 
 use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::ExceptionCondition;
+use databend_common_ast::ast::ExceptionHandler;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::Identifier;
@@ -50,6 +53,10 @@ use crate::ir::SetRef;
 use crate::ir::StatementTemplate;
 use crate::ir::VarRef;
 
+/// The error codes reserved for the errors raised by `RAISE`, so a script can't
+/// fake the errors of the system.
+const USER_ERROR_CODES: RangeInclusive<u64> = 20000..=20999;
+
 #[fastrace::trace]
 pub fn compile(code: &[ScriptStatement]) -> Result<Vec<ScriptIR>> {
     if code.is_empty() {
@@ -67,6 +74,8 @@ pub fn compile(code: &[ScriptStatement]) -> Result<Vec<ScriptIR>> {
 struct Compiler {
     ref_allocator: RefAllocator,
     scopes: Vec<Scope>,
+    // The number of exception handlers installed at the current position.
+    handler_depth: usize,
 }
 
 impl Compiler {
@@ -74,6 +83,7 @@ impl Compiler {
         Compiler {
             ref_allocator: RefAllocator::default(),
             scopes: vec![Scope::default()],
+            handler_depth: 0,
         }
     }
 
@@ -208,12 +218,14 @@ impl Compiler {
                     label: Some(label), ..
                 } => {
                     let loop_item = self.lookup_loop(label)?;
+                    output.append(&mut self.pop_handlers_to(&loop_item));
                     output.push(ScriptIR::Goto {
                         to_label: loop_item.break_label,
                     });
                 }
                 ScriptStatement::Break { span, label: None } => {
                     let loop_item = self.current_loop(*span)?;
+                    output.append(&mut self.pop_handlers_to(&loop_item));
                     output.push(ScriptIR::Goto {
                         to_label: loop_item.break_label,
                     });
@@ -222,12 +234,14 @@ impl Compiler {
                     label: Some(label), ..
                 } => {
                     let loop_item = self.lookup_loop(label)?;
+                    output.append(&mut self.pop_handlers_to(&loop_item));
                     output.push(ScriptIR::Goto {
                         to_label: loop_item.continue_label,
                     });
                 }
                 ScriptStatement::Continue { span, label: None } => {
                     let loop_item = self.current_loop(*span)?;
+                    output.append(&mut self.pop_handlers_to(&loop_item));
                     output.push(ScriptIR::Goto {
                         to_label: loop_item.continue_label,
                     });
@@ -264,9 +278,201 @@ impl Compiler {
                         else_result,
                     )?);
                 }
+                ScriptStatement::Block {
+                    span,
+                    body,
+                    exception_handlers,
+                } => {
+                    output.append(&mut self.compile_block(*span, body, exception_handlers)?);
+                }
+                ScriptStatement::Raise {
+                    span,
+                    code: None,
+                    message: None,
+                } => {
+                    // Re-raise the error caught by the current exception handler.
+                    let (code, message) = self.current_exception(*span)?;
+                    output.push(ScriptIR::Raise {
+                        code,
+                        message: Some(message),
+                    });
+                }
+                ScriptStatement::Raise {
+                    span,
+                    code,
+                    message,
+                } => {
+                    let code = code.as_ref().ok_or_else(|| {
+                        ErrorCode::ScriptSemanticError("RAISE requires an error code".to_string())
+                            .set_span(*span)
+                    })?;
+                    if !matches!(
+                        code,
+                        Expr::Literal {
+                            value: Literal::UInt64(code),
+                            ..
+                        } if USER_ERROR_CODES.contains(code)
+                    ) {
+                        return Err(ErrorCode::ScriptSemanticError(format!(
+                            "RAISE code must be a number in [{}, {}]",
+                            USER_ERROR_CODES.start(),
+                            USER_ERROR_CODES.end()
+                        ))
+                        .set_span(code.span()));
+                    }
+                    let code_var =
+                        VarRef::new_internal(code.span(), "raise_code", &mut self.ref_allocator);
+                    output.append(&mut self.compile_expr(code, code_var.clone())?);
+                    let message_var = match message {
+                        Some(message) => {
+                            let message_var = VarRef::new_internal(
+                                message.span(),
+                                "raise_message",
+                                &mut self.ref_allocator,
+                            );
+                            output.append(&mut self.compile_expr(message, message_var.clone())?);
+                            Some(message_var)
+                        }
+                        None => None,
+                    };
+                    output.push(ScriptIR::Raise {
+                        code: code_var,
+                        message: message_var,
+                    });
+                }
+            }
+        }
+
+        Ok(output)
+    }
+
+    fn compile_block(
+        &mut self,
+        span: Span,
+        body: &[ScriptStatement],
+        exception_handlers: &[ExceptionHandler],
+    ) -> Result<Vec<ScriptIR>> {
+        let mut output = vec![];
+
+        self.push_scope();
+
+        if exception_handlers.is_empty() {
+            output.append(&mut self.compile(body)?);
+            self.pop_scope();
+            return Ok(output);
+        }
+
+        let exception_label = LabelRef::new_internal(span, "EXCEPTION", &mut self.ref_allocator);
+        let end_label = LabelRef::new_internal(span, "BLOCK_END", &mut self.ref_allocator);
+
+        // PUSH_HANDLER EXCEPTION
+        output.push(ScriptIR::PushHandler {
+            to_label: exception_label.clone(),
+        });
+
+        // <body>
+        self.handler_depth += 1;
+        output.append(&mut self.compile(body)?);
+        self.handler_depth -= 1;
+
+        // POP_HANDLER
+        output.push(ScriptIR::PopHandler);
+
+        // GOTO BLOCK_END
+        output.push(ScriptIR::Goto {
+            to_label: end_label.clone(),
+        });
+
+        // Label EXCEPTION
+        output.push(ScriptIR::Label {
+            label: exception_label,
+        });
+
+        // READ_ERROR sqlcode, sqlerrm
+        self.push_scope();
+        let code = VarRef::new(span, "sqlcode", &mut self.ref_allocator);
+        let message = VarRef::new(span, "sqlerrm", &mut self.ref_allocator);
+        for name in ["sqlcode", "SQLCODE"] {
+            self.declare_ref(
+                &Identifier::from_name(span, name),
+                RefItem::Var(code.clone()),
+            )?;
+        }
+        for name in ["sqlerrm", "SQLERRM"] {
+            self.declare_ref(
+                &Identifier::from_name(span, name),
+                RefItem::Var(message.clone()),
+            )?;
+        }
+        self.scopes.last_mut().unwrap().exception = Some((code.clone(), message.clone()));
+        output.push(ScriptIR::ReadError {
+            to_code: code.clone(),
+            to_message: message.clone(),
+        });
+
+        // JUMP_IF_ERROR_CODE [<codes>], HANDLER
+        let mut handler_labels = vec![];
+        let mut has_other = false;
+        for handler in exception_handlers {
+            let handler_label =
+                LabelRef::new_internal(handler.span, "HANDLER", &mut self.ref_allocator);
+            if handler.conditions.contains(&ExceptionCondition::Other) {
+                output.push(ScriptIR::Goto {
+                    to_label: handler_label.clone(),
+                });
+                handler_labels.push(handler_label);
+                has_other = true;
+                break;
             }
+            let codes = handler
+                .conditions
+                .iter()
+                .map(|condition| match condition {
+                    ExceptionCondition::Code(code) => u16::try_from(*code).map_err(|_| {
+                        ErrorCode::ScriptSemanticError(format!("invalid error code: {code}"))
+                            .set_span(handler.span)
+                    }),
+                    ExceptionCondition::Other => unreachable!(),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            output.push(ScriptIR::JumpIfErrorCode {
+                codes,
+                to_label: handler_label.clone(),
+            });
+            handler_labels.push(handler_label);
+        }
+
+        // RAISE sqlcode, sqlerrm
+        if !has_other {
+            output.push(ScriptIR::Raise {
+                code,
+                message: Some(message),
+            });
         }
 
+        for (handler, handler_label) in exception_handlers.iter().zip(handler_labels) {
+            // Label HANDLER
+            output.push(ScriptIR::Label {
+                label: handler_label,
+            });
+
+            // <handler>
+            self.push_scope();
+            output.append(&mut self.compile(&handler.body)?);
+            self.pop_scope();
+
+            // GOTO BLOCK_END
+            output.push(ScriptIR::Goto {
+                to_label: end_label.clone(),
+            });
+        }
+        self.pop_scope();
+
+        // Label BLOCK_END
+        output.push(ScriptIR::Label { label: end_label });
+
+        self.pop_scope();
+
         Ok(output)
     }
 
@@ -865,6 +1071,7 @@ impl Compiler {
             name: Some(name.clone()),
             continue_label: continue_label.clone(),
             break_label: break_label.clone(),
+            handler_depth: self.handler_depth,
         };
         self.scopes.last_mut().unwrap().loop_item = Some(loop_item.clone());
         Ok(loop_item)
@@ -877,6 +1084,7 @@ impl Compiler {
             name: None,
             continue_label: continue_label.clone(),
             break_label: break_label.clone(),
+            handler_depth: self.handler_depth,
         };
         self.scopes.last_mut().unwrap().loop_item = Some(loop_item.clone());
         Ok(loop_item)
@@ -946,6 +1154,25 @@ impl Compiler {
         Err(ErrorCode::ScriptSemanticError("not in a loop".to_string()).set_span(span))
     }
 
+    fn current_exception(&self, span: Span) -> Result<(VarRef, VarRef)> {
+        for scope in self.scopes.iter().rev() {
+            if let Some(exception) = &scope.exception {
+                return Ok(exception.clone());
+            }
+        }
+        Err(
+            ErrorCode::ScriptSemanticError("not in an exception handler".to_string())
+                .set_span(span),
+        )
+    }
+
+    // Jumping out of the loop also leaves the exception handlers installed inside the loop.
+    fn pop_handlers_to(&self, loop_item: &LoopItem) -> Vec<ScriptIR> {
+        (loop_item.handler_depth..self.handler_depth)
+            .map(|_| ScriptIR::PopHandler)
+            .collect()
+    }
+
     fn quote_expr(&mut self, expr: &Expr) -> Result<(Vec<ScriptIR>, Expr)> {
         #[derive(VisitorMut)]
         #[visitor(Expr(enter), Identifier(enter))]
@@ -1064,6 +1291,8 @@ impl Compiler {
 struct Scope {
     items: HashMap<RefName, RefItem>,
     loop_item: Option<LoopItem>,
+    // The variables of SQLCODE and SQLERRM in the exception handler.
+    exception: Option<(VarRef, VarRef)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1087,6 +1316,7 @@ struct LoopItem {
     name: Option<RefName>,
     continue_label: LabelRef,
     break_label: LabelRef,
+    handler_depth: usize,
 }
 
 #[derive(Default)]
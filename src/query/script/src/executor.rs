@@ -18,6 +18,7 @@ use databend_common_ast::ast::Expr;
 use databend_common_ast::Span;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_exception::StackTrace;
 
 use crate::ir::ColumnAccess;
 use crate::ir::IterRef;
@@ -37,6 +38,10 @@ pub trait Client {
         -> Result<Self::Var>;
     fn num_rows(&self, block: &Self::Set) -> usize;
     fn is_true(&self, scalar: &Self::Var) -> Result<bool>;
+    fn code_to_var(&self, code: u16) -> Self::Var;
+    fn message_to_var(&self, message: &str) -> Self::Var;
+    fn var_to_code(&self, scalar: &Self::Var) -> Result<u16>;
+    fn var_to_message(&self, scalar: &Self::Var) -> Result<String>;
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +66,8 @@ pub struct Executor<C: Client> {
     sets: HashMap<SetRef, C::Set>,
     iters: HashMap<IterRef, Cursor>,
     label_to_pc: HashMap<LabelRef, usize>,
+    handlers: Vec<LabelRef>,
+    caught_error: Option<ErrorCode>,
     return_value: Option<ReturnValue<C>>,
     pc: usize,
 }
@@ -84,6 +91,8 @@ impl<C: Client> Executor<C> {
             sets: HashMap::new(),
             iters: HashMap::new(),
             label_to_pc,
+            handlers: vec![],
+            caught_error: None,
             return_value: None,
             pc: 0,
         }
@@ -94,7 +103,17 @@ impl<C: Client> Executor<C> {
             if self.pc >= self.code.len() {
                 return Ok(self.return_value.take());
             }
-            self.step().await?;
+            if let Err(err) = self.step().await {
+                // A killed query can't be caught by the script.
+                if err.code() == ErrorCode::ABORTED_QUERY {
+                    return Err(err);
+                }
+                let Some(handler) = self.handlers.pop() else {
+                    return Err(err);
+                };
+                self.caught_error = Some(err);
+                self.goto(&handler)?;
+            }
         }
 
         Err(ErrorCode::ScriptExecutionError(format!(
@@ -181,6 +200,45 @@ impl<C: Client> Executor<C> {
                 self.return_value = Some(ReturnValue::Set(self.get_set(set)?.clone()));
                 self.goto_end();
             }
+            ScriptIR::PushHandler { to_label } => {
+                self.handlers.push(to_label.clone());
+            }
+            ScriptIR::PopHandler => {
+                self.handlers.pop().ok_or_else(|| {
+                    ErrorCode::ScriptExecutionError("no exception handler to pop".to_string())
+                })?;
+            }
+            ScriptIR::ReadError {
+                to_code,
+                to_message,
+            } => {
+                let err = self.get_caught_error()?;
+                let code = self.client.code_to_var(err.code());
+                let message = self.client.message_to_var(&err.message());
+                self.vars.insert(to_code.clone(), code);
+                self.vars.insert(to_message.clone(), message);
+            }
+            ScriptIR::JumpIfErrorCode { codes, to_label } => {
+                let code = self.get_caught_error()?.code();
+                if codes.contains(&code) {
+                    self.goto(to_label)?;
+                }
+            }
+            ScriptIR::Raise { code, message } => {
+                let code = self.client.var_to_code(self.get_var(code)?)?;
+                let message = match message {
+                    Some(message) => self.client.var_to_message(self.get_var(message)?)?,
+                    None => "user-defined exception".to_string(),
+                };
+                return Err(ErrorCode::create(
+                    code,
+                    "ScriptRaisedError",
+                    message,
+                    String::new(),
+                    None,
+                    StackTrace::no_capture(),
+                ));
+            }
         }
 
         self.pc += 1;
@@ -212,6 +270,12 @@ impl<C: Client> Executor<C> {
             .ok_or_else(|| ErrorCode::ScriptExecutionError(format!("unknown iter: {iter}")))
     }
 
+    fn get_caught_error(&self) -> Result<&ErrorCode> {
+        self.caught_error
+            .as_ref()
+            .ok_or_else(|| ErrorCode::ScriptExecutionError("no error is caught".to_string()))
+    }
+
     fn goto(&mut self, label: &LabelRef) -> Result<()> {
         self.pc = *self
             .label_to_pc
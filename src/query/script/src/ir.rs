@@ -93,6 +93,20 @@ pub enum ScriptIR {
     ReturnVar { var: VarRef },
     /// Returns a result set from the script.
     ReturnSet { set: SetRef },
+    /// Installs an exception handler, the errors raised before the handler
+    /// is removed jump to the specified label.
+    PushHandler { to_label: LabelRef },
+    /// Removes the innermost exception handler.
+    PopHandler,
+    /// Reads the code and the message of the caught error to variables.
+    ReadError { to_code: VarRef, to_message: VarRef },
+    /// Jumps to a specified label if the code of the caught error is one of the codes.
+    JumpIfErrorCode { codes: Vec<u16>, to_label: LabelRef },
+    /// Raises an error with the code and the message in the variables.
+    Raise {
+        code: VarRef,
+        message: Option<VarRef>,
+    },
 }
 
 impl Display for ScriptIR {
@@ -123,6 +137,23 @@ impl Display for ScriptIR {
             ScriptIR::Return => write!(f, "RETURN")?,
             ScriptIR::ReturnVar { var } => write!(f, "RETURN {var}")?,
             ScriptIR::ReturnSet { set } => write!(f, "RETURN {set}")?,
+            ScriptIR::PushHandler { to_label } => write!(f, "PUSH_HANDLER {to_label}")?,
+            ScriptIR::PopHandler => write!(f, "POP_HANDLER")?,
+            ScriptIR::ReadError {
+                to_code,
+                to_message,
+            } => write!(f, "READ_ERROR {to_code}, {to_message}")?,
+            ScriptIR::JumpIfErrorCode { codes, to_label } => {
+                write!(f, "JUMP_IF_ERROR_CODE {codes:?}, {to_label}")?
+            }
+            ScriptIR::Raise {
+                code,
+                message: Some(message),
+            } => write!(f, "RAISE {code}, {message}")?,
+            ScriptIR::Raise {
+                code,
+                message: None,
+            } => write!(f, "RAISE {code}")?,
         };
         Ok(())
     }
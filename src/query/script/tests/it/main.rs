@@ -321,6 +321,35 @@ fn test_script() {
             END CASE;
         "#,
     );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                RAISE 20001, 'boom';
+            EXCEPTION
+                WHEN 20002 THEN
+                    RETURN 0;
+                WHEN 20001 THEN
+                    RETURN sqlcode;
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                BEGIN
+                    SELECT 1 + 0 / 0;
+                EXCEPTION
+                    WHEN OTHER THEN
+                        RAISE;
+                END;
+            EXCEPTION
+                WHEN 1006 THEN
+                    RETURN sqlerrm;
+            END;
+        "#,
+    );
 }
 
 #[test]
@@ -464,6 +493,29 @@ fn test_script_error() {
             END FOR;
         "#,
     );
+    run_script(
+        file,
+        r#"
+            BEGIN
+                RAISE 20001, 'boom';
+            EXCEPTION
+                WHEN 20002 THEN
+                    RETURN 0;
+            END;
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            RAISE 3001, 'boom';
+        "#,
+    );
+    run_script(
+        file,
+        r#"
+            RAISE;
+        "#,
+    );
 }
 
 fn mock_client() -> MockClient {
@@ -510,6 +562,18 @@ fn mock_client() -> MockClient {
         .response_when("SELECT 2", MockSet::unnamed(vec![vec![Literal::UInt64(2)]]))
         .response_when("SELECT 3", MockSet::unnamed(vec![vec![Literal::UInt64(3)]]))
         .response_when("SELECT 6", MockSet::unnamed(vec![vec![Literal::UInt64(6)]]))
+        .response_when(
+            "SELECT 20001",
+            MockSet::unnamed(vec![vec![Literal::UInt64(20001)]]),
+        )
+        .response_when(
+            "SELECT 'boom'",
+            MockSet::unnamed(vec![vec![Literal::String("boom".to_string())]]),
+        )
+        .response_when(
+            "SELECT 'division by zero'",
+            MockSet::unnamed(vec![vec![Literal::String("division by zero".to_string())]]),
+        )
         .response_when(
             "SELECT 'ONE'",
             MockSet::unnamed(vec![vec![Literal::String("ONE".to_string())]]),
@@ -696,6 +760,28 @@ impl Client for MockClient {
     fn is_true(&self, scalar: &Self::Var) -> Result<bool> {
         Ok(*scalar == Literal::Boolean(true))
     }
+
+    fn code_to_var(&self, code: u16) -> Self::Var {
+        Literal::UInt64(code as u64)
+    }
+
+    fn message_to_var(&self, message: &str) -> Self::Var {
+        Literal::String(message.to_string())
+    }
+
+    fn var_to_code(&self, scalar: &Self::Var) -> Result<u16> {
+        match scalar {
+            Literal::UInt64(code) => Ok(u16::try_from(*code).unwrap()),
+            _ => panic!("invalid error code {scalar}"),
+        }
+    }
+
+    fn var_to_message(&self, scalar: &Self::Var) -> Result<String> {
+        match scalar {
+            Literal::String(message) => Ok(message.clone()),
+            _ => Ok(scalar.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -25,6 +25,7 @@ use databend_common_catalog::table_function::TableFunction;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::ProcedureMeta;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
@@ -744,6 +745,16 @@ impl Catalog for DatabaseCatalog {
         self.table_function_factory.exists(func_name)
     }
 
+    fn get_procedure_table_function(
+        &self,
+        func_name: &str,
+        procedure_meta: ProcedureMeta,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        self.table_function_factory
+            .get_procedure(func_name, procedure_meta, tbl_args)
+    }
+
     fn list_table_functions(&self) -> Vec<String> {
         self.table_function_factory.list()
     }
@@ -23,6 +23,7 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_function::TableFunction;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::ProcedureMeta;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::least_visible_time_ident::LeastVisibleTimeIdent;
@@ -621,6 +622,16 @@ impl Catalog for SessionCatalog {
         self.inner.exists_table_function(func_name)
     }
 
+    fn get_procedure_table_function(
+        &self,
+        func_name: &str,
+        procedure_meta: ProcedureMeta,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        self.inner
+            .get_procedure_table_function(func_name, procedure_meta, tbl_args)
+    }
+
     // List all table functions' names.
     fn list_table_functions(&self) -> Vec<String> {
         self.inner.list_table_functions()
@@ -0,0 +1,688 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::ChangesInterval;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::execute_commit_statement;
+use databend_common_sql::normalize_identifier;
+use databend_common_sql::IncrementalRefreshChecker;
+use databend_common_sql::IncrementalRefreshKind;
+use databend_common_sql::NameResolutionContext;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_SNAPSHOT_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_TIME;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_SNAPSHOT_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// A source table of a dynamic table.
+struct RefreshSource {
+    catalog: String,
+    database: String,
+    table: String,
+    /// The quoted full name of the source table.
+    name: String,
+    table_id: u64,
+    /// The snapshot of the source table the dynamic table is refreshed to.
+    snapshot_id: String,
+    snapshot_location: String,
+    columns: Vec<String>,
+    change_tracking: bool,
+}
+
+/// Refreshes the data of a dynamic table by its query.
+///
+/// If the query only reads fuse tables, the snapshots of the source tables read by the last
+/// refresh are kept in the table options. When the query can be maintained incrementally and
+/// only one source with change tracking has changed since then, only the changes of that
+/// source are applied. Otherwise the dynamic table is overwritten by the result of the query.
+pub struct DynamicTableRefresher {
+    ctx: Arc<QueryContext>,
+    catalog: String,
+    database: String,
+    table: String,
+}
+
+impl DynamicTableRefresher {
+    pub fn create(ctx: Arc<QueryContext>, catalog: &str, database: &str, table: &str) -> Self {
+        DynamicTableRefresher {
+            ctx,
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn refresh(&self) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.catalog).await?;
+        let table = catalog
+            .get_table(&self.ctx.get_tenant(), &self.database, &self.table)
+            .await?;
+        let options = table.options();
+        let Some(as_query) = options.get(OPT_KEY_AS_QUERY) else {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "{}.{} is not a dynamic table",
+                self.database, self.table
+            )));
+        };
+
+        let mut query = self.parse_query(as_query)?;
+        let kind = match options.get(OPT_KEY_REFRESH_MODE).map(String::as_str) {
+            Some("FULL") => None,
+            _ => IncrementalRefreshChecker::check(&query),
+        };
+        let sources = self.resolve_sources(&query).await?;
+
+        let mut last_source_snapshots = None;
+        if let Some(sources) = &sources {
+            // The sources are read at the same snapshots by all the statements of this refresh,
+            // the sources without any snapshot are empty.
+            set_sources_temporal(
+                &mut query,
+                sources
+                    .iter()
+                    .map(|source| {
+                        (!source.snapshot_id.is_empty()).then(|| {
+                            TemporalClause::TimeTravel(TimeTravelPoint::Snapshot(
+                                source.snapshot_id.clone(),
+                            ))
+                        })
+                    })
+                    .collect(),
+            );
+            last_source_snapshots = self.last_source_snapshots(table.as_ref(), sources).await?;
+        }
+
+        match (&sources, last_source_snapshots, kind) {
+            (Some(sources), Some(last), _)
+                if sources
+                    .iter()
+                    .zip(&last)
+                    .all(|(source, last)| &source.snapshot_id == last) =>
+            {
+                info!(
+                    "dynamic table {}.{} is up to date with snapshots {:?} of the sources",
+                    self.database, self.table, last
+                );
+            }
+            (Some(sources), Some(last), Some(kind)) => {
+                let changed = sources
+                    .iter()
+                    .zip(&last)
+                    .enumerate()
+                    .filter(|(_, (source, last))| &source.snapshot_id != *last)
+                    .map(|(index, _)| index)
+                    .collect::<Vec<_>>();
+                match changed.as_slice() {
+                    [index] if sources[*index].change_tracking && !last[*index].is_empty() => {
+                        self.incremental_refresh(&query, sources, *index, &last[*index], kind)
+                            .await?
+                    }
+                    _ => self.full_refresh(&query).await?,
+                }
+            }
+            _ => self.full_refresh(&query).await?,
+        }
+
+        self.update_refresh_state(sources.as_deref()).await?;
+        if let Some(sources) = &sources {
+            if options.contains_key(OPT_KEY_MATERIALIZED_VIEW) {
+                self.register_materialized_view(table.get_id(), sources)
+                    .await;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_query(&self, sql: &str) -> Result<Query> {
+        let tokens = tokenize_sql(sql)?;
+        let dialect = self.ctx.get_settings().get_sql_dialect()?;
+        match parse_sql(&tokens, dialect)?.0 {
+            Statement::Query(query) => Ok(*query),
+            _ => Err(ErrorCode::IllegalDynamicTable(format!(
+                "invalid query of dynamic table {}.{}: {}",
+                self.database, self.table, sql
+            ))),
+        }
+    }
+
+    /// Returns the source tables of the query in the order they are referenced,
+    /// `None` if the query reads anything other than fuse tables.
+    async fn resolve_sources(&self, query: &Query) -> Result<Option<Vec<RefreshSource>>> {
+        let mut collector = SourceCollector::default();
+        query.drive(&mut collector);
+        if collector.not_support || collector.tables.is_empty() {
+            return Ok(None);
+        }
+
+        let name_resolution_ctx =
+            NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+        let normalize = |ident: &Identifier| normalize_identifier(ident, &name_resolution_ctx).name;
+        let quote = self.quote()?;
+        let mut sources = Vec::with_capacity(collector.tables.len());
+        for (catalog, database, table) in collector.tables {
+            let catalog = catalog
+                .as_ref()
+                .map(normalize)
+                .unwrap_or_else(|| self.catalog.clone());
+            let database = database
+                .as_ref()
+                .map(normalize)
+                .unwrap_or_else(|| self.database.clone());
+            let table = normalize(&table);
+
+            let source = self
+                .ctx
+                .get_catalog(&catalog)
+                .await?
+                .get_table(&self.ctx.get_tenant(), &database, &table)
+                .await?;
+            let Ok(fuse_table) = FuseTable::try_from_table(source.as_ref()) else {
+                return Ok(None);
+            };
+            let snapshot_id = fuse_table
+                .read_table_snapshot()
+                .await?
+                .map(|snapshot| snapshot.snapshot_id.simple().to_string())
+                .unwrap_or_default();
+
+            sources.push(RefreshSource {
+                name: format!("{}.{}.{}", quote(&catalog), quote(&database), quote(&table)),
+                catalog,
+                database,
+                table,
+                table_id: source.get_id(),
+                snapshot_id,
+                snapshot_location: source
+                    .options()
+                    .get(OPT_KEY_SNAPSHOT_LOCATION)
+                    .cloned()
+                    .unwrap_or_default(),
+                columns: source
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|f| quote(f.name()))
+                    .collect(),
+                change_tracking: source.change_tracking_enabled(),
+            });
+        }
+        Ok(Some(sources))
+    }
+
+    /// Returns the snapshots of the sources read by the last refresh. The changes since then
+    /// can be applied only if neither the sources nor the dynamic table has been replaced or
+    /// modified by others after the last refresh.
+    async fn last_source_snapshots(
+        &self,
+        table: &dyn Table,
+        sources: &[RefreshSource],
+    ) -> Result<Option<Vec<String>>> {
+        let options = table.options();
+        let table_ids = sources
+            .iter()
+            .map(|source| source.table_id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        if options.get(OPT_KEY_SOURCE_TABLE_ID) != Some(&table_ids) {
+            return Ok(None);
+        }
+        let Some(last) = options.get(OPT_KEY_SOURCE_SNAPSHOT_ID) else {
+            return Ok(None);
+        };
+        let last = last.split(',').map(str::to_string).collect::<Vec<_>>();
+        if last.len() != sources.len() {
+            return Ok(None);
+        }
+
+        let snapshot_id = current_snapshot_id(table).await?;
+        if options.get(OPT_KEY_REFRESH_SNAPSHOT_ID) != Some(&snapshot_id) {
+            return Ok(None);
+        }
+        Ok(Some(last))
+    }
+
+    async fn full_refresh(&self, query: &Query) -> Result<()> {
+        info!(
+            "full refresh dynamic table {}.{}",
+            self.database, self.table
+        );
+        let target = self.target_name()?;
+        self.execute_sql(&format!("INSERT OVERWRITE {target} {query}"))
+            .await?;
+        Ok(())
+    }
+
+    /// Applies the changes of the source at `index`, the other sources are unchanged.
+    async fn incremental_refresh(
+        &self,
+        query: &Query,
+        sources: &[RefreshSource],
+        index: usize,
+        last_snapshot: &str,
+        kind: IncrementalRefreshKind,
+    ) -> Result<()> {
+        let source = &sources[index];
+        let changes = |append_only| {
+            TemporalClause::Changes(ChangesInterval {
+                append_only,
+                at_point: TimeTravelPoint::Snapshot(last_snapshot.to_string()),
+                end_point: Some(TimeTravelPoint::Snapshot(source.snapshot_id.clone())),
+            })
+        };
+        let target = self.target_name()?;
+
+        match kind {
+            IncrementalRefreshKind::Append => {
+                let deleted_rows = self
+                    .query_count(&format!(
+                        "SELECT count(*) FROM {} {} WHERE change$action = 'DELETE'",
+                        source.name,
+                        changes(false)
+                    ))
+                    .await?;
+                // Rows deleted or updated in the source can not be found in the dynamic table.
+                if deleted_rows > 0 {
+                    return self.full_refresh(query).await;
+                }
+
+                info!(
+                    "append changes of snapshots {}..{} to dynamic table {}.{}",
+                    last_snapshot, source.snapshot_id, self.database, self.table
+                );
+                // Only the columns of the source are read from the changes, so that
+                // `SELECT *` does not contain the change columns.
+                let changes_query = self.parse_query(&format!(
+                    "SELECT {} FROM {} {}",
+                    source.columns.join(", "),
+                    source.name,
+                    changes(true)
+                ))?;
+                let mut query = query.clone();
+                replace_source(&mut query, index, changes_query);
+                self.execute_sql(&format!("INSERT INTO {target} {query}"))
+                    .await?;
+            }
+            IncrementalRefreshKind::Aggregate {
+                source_key,
+                target_key,
+            } => {
+                let null_keys = self
+                    .query_count(&format!(
+                        "SELECT count(*) FROM {} {} WHERE {source_key} IS NULL",
+                        source.name,
+                        changes(false)
+                    ))
+                    .await?;
+                // `IN` never matches the group of NULL.
+                if null_keys > 0 {
+                    return self.full_refresh(query).await;
+                }
+
+                info!(
+                    "recompute groups changed in snapshots {}..{} of dynamic table {}.{}",
+                    last_snapshot, source.snapshot_id, self.database, self.table
+                );
+                let changed_keys = format!(
+                    "SELECT DISTINCT {source_key} FROM {} {}",
+                    source.name,
+                    changes(false)
+                );
+                let mut query = query.clone();
+                if let SetExpr::Select(stmt) = &mut query.body {
+                    let selection = match &stmt.selection {
+                        Some(selection) => {
+                            format!("({selection}) AND {source_key} IN ({changed_keys})")
+                        }
+                        None => format!("{source_key} IN ({changed_keys})"),
+                    };
+                    let tokens = tokenize_sql(&selection)?;
+                    let dialect = self.ctx.get_settings().get_sql_dialect()?;
+                    stmt.selection = Some(parse_expr(&tokens, dialect)?);
+                }
+
+                self.execute_in_txn(&[
+                    format!("DELETE FROM {target} WHERE {target_key} IN ({changed_keys})"),
+                    format!("INSERT INTO {target} {query}"),
+                ])
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Records the snapshots of the sources and the dynamic table after refreshing.
+    async fn update_refresh_state(&self, sources: Option<&[RefreshSource]>) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.catalog).await?;
+        let table = catalog
+            .get_table(&self.ctx.get_tenant(), &self.database, &self.table)
+            .await?;
+
+        let join = |f: fn(&RefreshSource) -> String| {
+            sources.map(|sources| sources.iter().map(f).collect::<Vec<_>>().join(","))
+        };
+        let mut options = HashMap::new();
+        options.insert(
+            OPT_KEY_REFRESH_TIME.to_string(),
+            Some(Utc::now().timestamp_millis().to_string()),
+        );
+        options.insert(
+            OPT_KEY_REFRESH_SNAPSHOT_ID.to_string(),
+            Some(current_snapshot_id(table.as_ref()).await?),
+        );
+        options.insert(
+            OPT_KEY_SOURCE_TABLE_ID.to_string(),
+            join(|source| source.table_id.to_string()),
+        );
+        options.insert(
+            OPT_KEY_SOURCE_SNAPSHOT_ID.to_string(),
+            join(|source| source.snapshot_id.clone()),
+        );
+        options.insert(
+            OPT_KEY_SOURCE_SNAPSHOT_LOCATION.to_string(),
+            join(|source| source.snapshot_location.clone()),
+        );
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options,
+        };
+        catalog
+            .upsert_table_option(&self.ctx.get_tenant(), &self.database, req)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds the materialized view to the options of its sources, so that it can be found
+    /// when planning the queries on the sources. The sources may have been replaced since the
+    /// last refresh, so it is checked every time.
+    async fn register_materialized_view(&self, view_id: u64, sources: &[RefreshSource]) {
+        for source in sources {
+            if let Err(e) = self.register_on_source(view_id, source).await {
+                warn!(
+                    "register materialized view {}.{} on {} failed: {}",
+                    self.database, self.table, source.name, e
+                );
+            }
+        }
+    }
+
+    async fn register_on_source(&self, view_id: u64, source: &RefreshSource) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&source.catalog).await?;
+        let table = catalog
+            .get_table(&self.ctx.get_tenant(), &source.database, &source.table)
+            .await?;
+        let mut views = table
+            .options()
+            .get(OPT_KEY_MATERIALIZED_VIEWS)
+            .map(|v| v.split(',').map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let view_id = view_id.to_string();
+        if views.contains(&view_id) {
+            return Ok(());
+        }
+        views.push(view_id);
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_MATERIALIZED_VIEWS.to_string(),
+                Some(views.join(",")),
+            )]),
+        };
+        catalog
+            .upsert_table_option(&self.ctx.get_tenant(), &source.database, req)
+            .await?;
+        Ok(())
+    }
+
+    /// Runs the statements in a transaction of a new session with the user, the role and the
+    /// settings of the current session, so that the transaction of the current session is
+    /// never touched.
+    async fn execute_in_txn(&self, sqls: &[String]) -> Result<()> {
+        let current = self.ctx.get_current_session();
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(session)?;
+        session
+            .set_authed_user(
+                current.get_current_user()?,
+                current.get_current_role().map(|role| role.name),
+            )
+            .await?;
+        let settings = session.get_settings();
+        for change in current.get_settings().changes().iter() {
+            settings.set_setting(change.key().clone(), change.value().value.as_string())?;
+        }
+
+        let ctx = session.create_query_context().await?;
+        ctx.txn_mgr().lock().begin();
+        for sql in sqls {
+            if let Err(e) = execute_sql(&session, sql).await {
+                info!(
+                    "refresh dynamic table {}.{} failed, rollback: {}",
+                    self.database, self.table, e
+                );
+                ctx.txn_mgr().lock().clear();
+                return Err(e);
+            }
+        }
+        execute_commit_statement(ctx).await
+    }
+
+    async fn execute_sql(&self, sql: &str) -> Result<Vec<DataBlock>> {
+        execute_sql(&self.ctx.get_current_session(), sql).await
+    }
+
+    async fn query_count(&self, sql: &str) -> Result<u64> {
+        let blocks = self.execute_sql(sql).await?;
+        match blocks
+            .first()
+            .and_then(|block| block.get_by_offset(0).value.index(0))
+        {
+            Some(ScalarRef::Number(NumberScalar::UInt64(rows))) => Ok(rows),
+            _ => Ok(0),
+        }
+    }
+
+    fn target_name(&self) -> Result<String> {
+        let quote = self.quote()?;
+        Ok(format!(
+            "{}.{}.{}",
+            quote(&self.catalog),
+            quote(&self.database),
+            quote(&self.table)
+        ))
+    }
+
+    fn quote(&self) -> Result<impl Fn(&str) -> String> {
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        Ok(move |ident: &str| {
+            format!(
+                "{quote}{}{quote}",
+                ident.replace(quote, &format!("{quote}{quote}"))
+            )
+        })
+    }
+}
+
+async fn execute_sql(session: &Arc<Session>, sql: &str) -> Result<Vec<DataBlock>> {
+    let ctx = session.create_query_context().await?;
+    ctx.set_dynamic_table_refresh();
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect::<Vec<_>>().await
+}
+
+async fn current_snapshot_id(table: &dyn Table) -> Result<String> {
+    let fuse_table = FuseTable::try_from_table(table)?;
+    Ok(fuse_table
+        .read_table_snapshot()
+        .await?
+        .map(|snapshot| snapshot.snapshot_id.simple().to_string())
+        .unwrap_or_default())
+}
+
+/// Collects the tables read by a query in the order they are referenced.
+#[derive(Default, Visitor)]
+#[visitor(Query(enter), TableReference(enter))]
+struct SourceCollector {
+    tables: Vec<(Option<Identifier>, Option<Identifier>, Identifier)>,
+    not_support: bool,
+}
+
+impl SourceCollector {
+    fn enter_query(&mut self, query: &Query) {
+        // The names of common table expressions can not be told from tables.
+        if query.with.is_some() {
+            self.not_support = true;
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        match table_ref {
+            TableReference::Table {
+                catalog,
+                database,
+                table,
+                temporal: None,
+                with_options: None,
+                sample: None,
+                ..
+            } => {
+                self.tables
+                    .push((catalog.clone(), database.clone(), table.clone()));
+            }
+            TableReference::Join { .. } | TableReference::Subquery { .. } => {}
+            _ => self.not_support = true,
+        }
+    }
+}
+
+/// Sets the temporal clauses of the tables referenced by a query, in the order of
+/// [`SourceCollector`].
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter))]
+struct SourceTemporalSetter {
+    clauses: Vec<Option<TemporalClause>>,
+    next: usize,
+}
+
+impl SourceTemporalSetter {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table { temporal, .. } = table_ref {
+            *temporal = self.clauses.get(self.next).cloned().flatten();
+            self.next += 1;
+        }
+    }
+}
+
+fn set_sources_temporal(query: &mut Query, clauses: Vec<Option<TemporalClause>>) {
+    query.drive_mut(&mut SourceTemporalSetter { clauses, next: 0 });
+}
+
+/// Replaces the table referenced at `index` by a subquery, which keeps the alias of the table.
+#[derive(VisitorMut)]
+#[visitor(TableReference(enter))]
+struct SourceReplacer {
+    index: usize,
+    subquery: Option<Query>,
+    next: usize,
+}
+
+impl SourceReplacer {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        let TableReference::Table { table, alias, .. } = table_ref else {
+            return;
+        };
+        self.next += 1;
+        if self.next - 1 != self.index {
+            return;
+        }
+        let Some(subquery) = self.subquery.take() else {
+            return;
+        };
+        let alias = alias.clone().unwrap_or_else(|| TableAlias {
+            name: table.clone(),
+            columns: vec![],
+        });
+        *table_ref = TableReference::Subquery {
+            span: None,
+            lateral: false,
+            subquery: Box::new(subquery),
+            alias: Some(alias),
+            pivot: None,
+            unpivot: None,
+        };
+    }
+}
+
+fn replace_source(query: &mut Query, index: usize, subquery: Query) {
+    query.drive_mut(&mut SourceReplacer {
+        index,
+        subquery: Some(subquery),
+        next: 0,
+    });
+}
@@ -0,0 +1,248 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::Utc;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table::Table;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_TIME;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use log::error;
+use log::info;
+use parking_lot::Mutex;
+
+use crate::dynamic_table::DynamicTableRefresher;
+use crate::object_scheduler::ObjectScheduler;
+use crate::sessions::TableContext;
+
+/// Refreshes the dynamic tables whose data is older than their target lag.
+///
+/// The dynamic tables are discovered by listing the tables every `discover_interval`, the ones
+/// created on this node are known at once. The known dynamic tables are checked every
+/// `interval`, and refreshed with the privileges of the role that owns them. A meta semaphore
+/// of each table makes sure that a dynamic table is refreshed by one node at a time.
+pub struct DynamicTableScheduler {
+    scheduler: ObjectScheduler,
+    interval: Duration,
+    discover_interval: Duration,
+    /// The (database, table) names of the dynamic tables with an interval target lag.
+    dynamic_tables: Mutex<BTreeSet<(String, String)>>,
+}
+
+impl DynamicTableScheduler {
+    pub async fn init(cfg: &InnerConfig) -> Result<()> {
+        let instance = Arc::new(Self {
+            scheduler: ObjectScheduler::create(cfg, "dynamic-table").await?,
+            interval: Duration::from_secs(10),
+            discover_interval: Duration::from_secs(300),
+            dynamic_tables: Mutex::new(BTreeSet::new()),
+        });
+        GlobalInstance::set(instance);
+        GlobalIORuntime::instance().try_spawn(
+            async move {
+                if let Err(e) = DynamicTableScheduler::instance().work().await {
+                    error!("dynamic table scheduler exit {}", e);
+                }
+            },
+            Some("dynamic-table-scheduler".to_string()),
+        )?;
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<DynamicTableScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn initialized(&self) {
+        self.scheduler.initialized();
+    }
+
+    /// Schedules a dynamic table of the default catalog created on this node, without
+    /// waiting for the next discovery.
+    pub fn add(&self, database: &str, table: &str) {
+        self.dynamic_tables
+            .lock()
+            .insert((database.to_string(), table.to_string()));
+    }
+
+    async fn work(&self) -> Result<()> {
+        self.scheduler.wait_initialized().await;
+
+        let mut discovered_at: Option<Instant> = None;
+        loop {
+            if discovered_at.is_none_or(|at| at.elapsed() >= self.discover_interval) {
+                match self.discover().await {
+                    Ok(_) => discovered_at = Some(Instant::now()),
+                    Err(e) => error!("dynamic table scheduler discover failed: {}", e),
+                }
+            }
+            if let Err(e) = self.schedule().await {
+                error!("dynamic table scheduler failed: {}", e);
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    /// Lists all the tables to find the dynamic tables to be scheduled.
+    async fn discover(&self) -> Result<()> {
+        let (tenant, catalog) = self.default_catalog().await?;
+        let mut dynamic_tables = BTreeSet::new();
+        for database in catalog.list_databases(&tenant).await? {
+            for table in catalog.list_tables(&tenant, database.name()).await? {
+                if target_lag(table.as_ref()).is_some() {
+                    dynamic_tables.insert((database.name().to_string(), table.name().to_string()));
+                }
+            }
+        }
+        *self.dynamic_tables.lock() = dynamic_tables;
+        Ok(())
+    }
+
+    async fn schedule(&self) -> Result<()> {
+        let (tenant, catalog) = self.default_catalog().await?;
+        let dynamic_tables = self.dynamic_tables.lock().clone();
+        for (database, table) in dynamic_tables {
+            if let Err(e) = self.try_refresh(&tenant, &catalog, &database, &table).await {
+                error!("refresh dynamic table {}.{} failed: {}", database, table, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn try_refresh(
+        &self,
+        tenant: &Tenant,
+        catalog: &Arc<dyn Catalog>,
+        database: &str,
+        name: &str,
+    ) -> Result<()> {
+        let table = match catalog.get_table(tenant, database, name).await {
+            Ok(table) => table,
+            Err(e)
+                if e.code() == ErrorCode::UNKNOWN_TABLE
+                    || e.code() == ErrorCode::UNKNOWN_DATABASE =>
+            {
+                self.remove(database, name);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let Some(target_lag) = target_lag(table.as_ref()) else {
+            self.remove(database, name);
+            return Ok(());
+        };
+        if !is_stale(table.as_ref(), target_lag) {
+            return Ok(());
+        }
+
+        let _permit = self
+            .scheduler
+            .acquire(
+                &format!("dynamic_table_refresh/{}", table.get_id()),
+                target_lag,
+            )
+            .await?;
+        // The table may have been refreshed by another node while waiting.
+        let table = catalog.get_table(tenant, database, name).await?;
+        if !is_stale(table.as_ref(), target_lag) {
+            return Ok(());
+        }
+
+        let role = self
+            .owner_role(tenant, catalog, database, table.get_id())
+            .await?;
+        info!(
+            "refresh dynamic table {}.{} with role {}, target lag {:?}",
+            database, name, role, target_lag
+        );
+        let session = self.scheduler.session(&role).await?;
+        let ctx = session.create_query_context().await?;
+        DynamicTableRefresher::create(ctx, CATALOG_DEFAULT, database, name)
+            .refresh()
+            .await
+    }
+
+    fn remove(&self, database: &str, table: &str) {
+        self.dynamic_tables
+            .lock()
+            .remove(&(database.to_string(), table.to_string()));
+    }
+
+    /// The dynamic table is refreshed with the privileges of the role that owns it, which is
+    /// `account_admin` if the table has no owner.
+    async fn owner_role(
+        &self,
+        tenant: &Tenant,
+        catalog: &Arc<dyn Catalog>,
+        database: &str,
+        table_id: u64,
+    ) -> Result<String> {
+        let db_id = catalog
+            .get_database(tenant, database)
+            .await?
+            .get_db_info()
+            .database_id
+            .db_id;
+        let object = OwnershipObject::Table {
+            catalog_name: CATALOG_DEFAULT.to_string(),
+            db_id,
+            table_id,
+        };
+        let owner = UserApiProvider::instance()
+            .get_ownership(tenant, &object)
+            .await?;
+        Ok(owner.map_or_else(
+            || BUILTIN_ROLE_ACCOUNT_ADMIN.to_string(),
+            |owner| owner.role,
+        ))
+    }
+
+    async fn default_catalog(&self) -> Result<(Tenant, Arc<dyn Catalog>)> {
+        let session = self.scheduler.admin_session().await?;
+        let ctx = session.create_query_context().await?;
+        Ok((ctx.get_tenant(), ctx.get_catalog(CATALOG_DEFAULT).await?))
+    }
+}
+
+/// Only dynamic tables with an interval target lag are scheduled, `DOWNSTREAM` ones are
+/// refreshed manually.
+fn target_lag(table: &dyn Table) -> Option<Duration> {
+    let target_lag = table.options().get(OPT_KEY_TARGET_LAG)?;
+    let secs = target_lag.strip_suffix(" SECOND")?.parse::<u64>().ok()?;
+    Some(Duration::from_secs(secs))
+}
+
+fn is_stale(table: &dyn Table, target_lag: Duration) -> bool {
+    let refresh_time = table
+        .options()
+        .get(OPT_KEY_REFRESH_TIME)
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or_default();
+    Utc::now().timestamp_millis() - refresh_time >= target_lag.as_millis() as i64
+}
@@ -48,8 +48,10 @@ use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::dynamic_table::DynamicTableScheduler;
 use crate::locks::LockManager;
 use crate::persistent_log::GlobalPersistentLog;
+use crate::pipe::PipeScheduler;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
@@ -57,6 +59,7 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::task::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -180,6 +183,10 @@ impl GlobalServices {
             GlobalPersistentLog::init(config).await?;
         }
 
+        DynamicTableScheduler::init(config).await?;
+        PipeScheduler::init(config).await?;
+        TaskScheduler::init(config).await?;
+
         GLOBAL_QUERIES_MANAGER.set_gc_handle(memory_gc_handle);
 
         Ok(())
@@ -89,6 +89,10 @@ impl AccessChecker for ManagementModeAccess {
                 // Dynamic table.
                 | Plan::CreateDynamicTable(_)
 
+                // Pipe.
+                | Plan::CreatePipe(_)
+                | Plan::DropPipe(_)
+
                 // User.
                 | Plan::AlterUser(_)
                 | Plan::CreateUser(_)
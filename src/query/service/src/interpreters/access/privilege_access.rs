@@ -19,6 +19,7 @@ use databend_common_base::base::GlobalInstance;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
@@ -26,6 +27,7 @@ use databend_common_management::WarehouseInfo;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::OwnershipInfo;
 use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::PipeInfo;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserGrantSet;
@@ -50,6 +52,7 @@ use crate::interpreters::access::AccessChecker;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
 use crate::sql::plans::Plan;
+use crate::table_functions::ProcedureTable;
 
 pub struct PrivilegeAccess {
     ctx: Arc<QueryContext>,
@@ -562,6 +565,55 @@ impl PrivilegeAccess {
         }
     }
 
+    /// Objects that record their owner role by name, like the pipes and tasks, can be managed
+    /// by an available role of the current session that owns them, or with the global `Super`
+    /// privilege.
+    async fn validate_owner_role(&self, owner: &str) -> Result<()> {
+        let session = self.ctx.get_current_session();
+        if session
+            .get_all_available_roles()
+            .await?
+            .iter()
+            .any(|role| role.name == owner)
+        {
+            return Ok(());
+        }
+        self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+            .await
+    }
+
+    /// Returns the pipe if it exists, the interpreter reports the unknown pipe.
+    async fn validate_pipe_ownership(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+    ) -> Result<Option<PipeInfo>> {
+        let pipe = match UserApiProvider::instance().get_pipe(tenant, name).await {
+            Ok(pipe) => pipe,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_PIPE => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        self.validate_owner_role(&pipe.owner).await?;
+        Ok(Some(pipe))
+    }
+
+    /// The tasks of the built-in task scheduler are kept in the meta service with their owner,
+    /// the ones of the cloud control are only managed with the global `Super` privilege.
+    async fn validate_task_ownership(&self, tenant: &Tenant, name: &str) -> Result<()> {
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_some() {
+            return self
+                .validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                .await;
+        }
+        let task = match UserApiProvider::instance().get_task(tenant, name).await {
+            Ok(task) => task,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TASK => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        self.validate_owner_role(&task.owner).await
+    }
+
     async fn validate_stage_access(
         &self,
         stage_info: &StageInfo,
@@ -844,7 +896,18 @@ impl AccessChecker for PrivilegeAccess {
                             DataSourceInfo::TableSource(_) | DataSourceInfo::ResultScanSource(_) => {}
                         }
                     }
-                    if table.is_source_of_view()||table.table().is_temp() {
+                    // Calling a procedure requires the same privilege as `CALL PROCEDURE`.
+                    if table.table().as_any().is::<ProcedureTable>() {
+                        self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                            .await?;
+                        continue;
+                    }
+                    // The tables bound for indexes and materialized views are only read
+                    // through the tables of the query.
+                    if table.is_source_of_view()
+                        || table.is_source_of_index()
+                        || table.table().is_temp()
+                    {
                         continue;
                     }
 
@@ -963,6 +1026,9 @@ impl AccessChecker for PrivilegeAccess {
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
+                if let Some(source) = &plan.clone_source {
+                    self.validate_table_access(&source.catalog, &source.database, &source.table, UserPrivilegeType::Select, false, false).await?;
+                }
             }
             Plan::DropTable(plan) => {
                 // For attach table
@@ -1012,6 +1078,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, false, false).await?
             }
+            Plan::CreateTableTag(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableTag(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::RefreshTableCache(_) | Plan::RefreshDatabaseCache(_) => {
                 // Only Iceberg support this plan
                 return Ok(())
@@ -1064,6 +1136,12 @@ impl AccessChecker for PrivilegeAccess {
                 }
                 self.validate_insert_source(ctx, &plan.source).await?;
             }
+            Plan::InsertOverwritePartition(plan) => {
+                // The privileges of the source are checked when the insert statement is executed.
+                for privilege in [UserPrivilegeType::Insert, UserPrivilegeType::Delete] {
+                    self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false, false).await?;
+                }
+            }
             Plan::InsertMultiTable(plan) => {
                 let target_table_privileges = if plan.overwrite {
                     vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete]
@@ -1158,6 +1236,43 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::CreateDynamicTable(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Create, false).await?;
+                let mut planner = Planner::new(self.ctx.clone());
+                let (plan, _) = planner.plan_sql(&plan.as_query).await?;
+                self.check(ctx, &plan).await?
+            }
+            Plan::CreatePipe(plan) => {
+                let mut planner = Planner::new(self.ctx.clone());
+                let (plan, _) = planner.plan_sql(&plan.copy_statement).await?;
+                self.check(ctx, &plan).await?
+            }
+            Plan::AlterPipe(plan) => {
+                if let Some(pipe) = self.validate_pipe_ownership(&plan.tenant, &plan.name).await? {
+                    let mut planner = Planner::new(self.ctx.clone());
+                    let (plan, _) = planner.plan_sql(&pipe.copy_statement).await?;
+                    self.check(ctx, &plan).await?
+                }
+            }
+            Plan::DropPipe(plan) => {
+                self.validate_pipe_ownership(&plan.tenant, &plan.name).await?;
+            }
+            Plan::DescPipe(plan) => {
+                self.validate_pipe_ownership(&plan.tenant, &plan.name).await?;
+            }
+            Plan::AlterTask(plan) => {
+                self.validate_task_ownership(&plan.tenant, &plan.task_name).await?;
+            }
+            Plan::DropTask(plan) => {
+                self.validate_task_ownership(&plan.tenant, &plan.task_name).await?;
+            }
+            Plan::DescribeTask(plan) => {
+                self.validate_task_ownership(&plan.tenant, &plan.task_name).await?;
+            }
+            Plan::ExecuteTask(plan) => {
+                self.validate_task_ownership(&plan.tenant, &plan.task_name).await?;
+            }
+            Plan::RefreshDynamicTable(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Insert, false, false).await?;
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?;
             }
             Plan::CreateUser(_) => {
                 self.validate_access(
@@ -1270,12 +1385,8 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DescNotification(_)
             | Plan::AlterNotification(_)
             | Plan::DescUser(_)
-            | Plan::CreateTask(_)   // TODO: need to build ownership info for task
-            | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
-            | Plan::DescribeTask(_) // TODO: need to build ownership info for task
-            | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
-            | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_)
+            | Plan::CreateTask(_)
+            | Plan::ShowTasks(_)
             | Plan::CreateSequence(_)
             | Plan::DropSequence(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
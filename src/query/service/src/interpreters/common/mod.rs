@@ -16,6 +16,7 @@ mod grant;
 mod metrics;
 mod notification;
 mod query_log;
+mod result_cache;
 mod stream;
 mod table;
 mod task;
@@ -26,10 +27,14 @@ pub mod table_option_validation;
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
+pub use result_cache::plan_result_cache_key;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
+pub use table::check_materialized_view_dml;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_partition_keys;
 pub use task::get_task_client_config;
+pub use task::make_local_schedule_options;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
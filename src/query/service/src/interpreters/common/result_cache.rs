@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::format_normalized_plan;
+use databend_common_sql::optimizer::ir::SExpr;
+use databend_common_sql::BindContext;
+use databend_common_sql::MetadataRef;
+use databend_common_storages_result_cache::gen_result_cache_key;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+
+/// Generates the result cache key of a query from its optimized plan.
+///
+/// Equivalent queries share the key. Falls back to the formatted AST if the plan
+/// can't be normalized.
+///
+/// The snapshot of every table in the metadata is added to the partitions shas of
+/// `ctx`, which are compared with the ones of the cached result when it is read,
+/// so a commit to any of the tables invalidates the result unless
+/// `query_result_cache_allow_inconsistent` is set.
+pub fn plan_result_cache_key(
+    ctx: &dyn TableContext,
+    s_expr: &SExpr,
+    metadata: &MetadataRef,
+    bind_context: &BindContext,
+    formatted_ast: &str,
+) -> Result<String> {
+    let metadata = metadata.read();
+    for entry in metadata.tables() {
+        let table = entry.table();
+        let snapshot = table.options().get(OPT_KEY_SNAPSHOT_LOCATION);
+        ctx.add_partitions_sha(gen_result_cache_key(&format!(
+            "{}.{}@{}",
+            entry.catalog(),
+            table.get_id(),
+            snapshot.map(String::as_str).unwrap_or("")
+        )));
+    }
+
+    let output_columns = bind_context
+        .columns
+        .iter()
+        .map(|column| column.index)
+        .collect::<Vec<_>>();
+    let normalized = format_normalized_plan(&metadata, s_expr, &output_columns);
+    Ok(gen_result_cache_key(
+        normalized.as_deref().unwrap_or(formatted_ast),
+    ))
+}
@@ -14,12 +14,18 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::parse_computed_expr;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW;
+
+use crate::sessions::QueryContext;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +61,38 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// The partition keys are stored as SQL text in the table options,
+/// the columns referenced by them can't be dropped or renamed.
+pub fn check_referenced_partition_keys(
+    ctx: Arc<dyn TableContext>,
+    table: &dyn Table,
+    column: &str,
+) -> Result<()> {
+    let Ok(fuse_table) = FuseTable::try_from_table(table) else {
+        return Ok(());
+    };
+    for key in fuse_table.partition_keys(ctx)? {
+        let expr = key.as_expr(&BUILTIN_FUNCTIONS);
+        if expr.column_refs().contains_key(column) {
+            return Err(ErrorCode::ColumnReferencedByPartitionKey(format!(
+                "column `{}` is referenced by partition key `{}`",
+                column,
+                expr.sql_display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The data of a materialized view is only written by its refresh, so that it always holds the
+/// result of its query at the snapshots recorded by the refresh.
+pub fn check_materialized_view_dml(ctx: &QueryContext, table: &dyn Table) -> Result<()> {
+    if table.options().contains_key(OPT_KEY_MATERIALIZED_VIEW) && !ctx.is_dynamic_table_refresh() {
+        return Err(ErrorCode::IllegalDynamicTable(format!(
+            "Materialized view {} can only be modified by REFRESH MATERIALIZED VIEW",
+            table.name()
+        )));
+    }
+    Ok(())
+}
@@ -21,6 +21,7 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::task;
 
 use crate::sessions::QueryContext;
 
@@ -51,6 +52,26 @@ pub fn make_schedule_options(
     }
 }
 
+/// Same as `make_schedule_options`, for the tasks scheduled without the cloud control plane.
+pub fn make_local_schedule_options(opt: ScheduleOptions) -> task::ScheduleOptions {
+    match opt {
+        ScheduleOptions::IntervalSecs(secs, ms) => task::ScheduleOptions {
+            interval: Some(secs as i32),
+            milliseconds_interval: if ms == 0 { None } else { Some(ms) },
+            cron: None,
+            time_zone: None,
+            schedule_type: task::ScheduleType::IntervalType,
+        },
+        ScheduleOptions::CronExpression(expr, timezone) => task::ScheduleOptions {
+            interval: None,
+            milliseconds_interval: None,
+            cron: Some(expr),
+            time_zone: timezone,
+            schedule_type: task::ScheduleType::CronType,
+        },
+    }
+}
+
 pub fn make_warehouse_options(
     opt: Option<String>,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -41,6 +41,7 @@ use databend_common_pipeline_core::SourcePipeBuilder;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_common_sql::PreparedPlan;
 use databend_common_storages_system::ProfilesLogElement;
 use databend_common_storages_system::ProfilesLogQueue;
 use databend_storages_common_cache::CacheManager;
@@ -220,7 +221,29 @@ pub async fn interpreter_plan_sql(
     sql: &str,
     acquire_queue: bool,
 ) -> Result<(Plan, PlanExtras, AcquireQueueGuard)> {
-    let result = plan_sql(ctx.clone(), sql, acquire_queue).await;
+    interpreter_plan(ctx, sql, None, None, acquire_queue).await
+}
+
+/// Like `interpreter_plan_sql`, but plans a prepared statement with the bound parameters,
+/// the plan is kept in `prepared` and reused by the next execution if it's still valid.
+pub async fn interpreter_plan_prepared(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    extras: PlanExtras,
+    prepared: &mut Option<PreparedPlan>,
+    acquire_queue: bool,
+) -> Result<(Plan, PlanExtras, AcquireQueueGuard)> {
+    interpreter_plan(ctx, sql, Some(extras), Some(prepared), acquire_queue).await
+}
+
+async fn interpreter_plan(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+    extras: Option<PlanExtras>,
+    prepared: Option<&mut Option<PreparedPlan>>,
+    acquire_queue: bool,
+) -> Result<(Plan, PlanExtras, AcquireQueueGuard)> {
+    let result = plan_sql(ctx.clone(), sql, extras, prepared, acquire_queue).await;
     let short_sql = short_sql(
         sql.to_string(),
         ctx.get_settings().get_short_sql_max_length()?,
@@ -243,6 +266,8 @@ pub async fn interpreter_plan_sql(
 async fn plan_sql(
     ctx: Arc<QueryContext>,
     sql: &str,
+    extras: Option<PlanExtras>,
+    prepared: Option<&mut Option<PreparedPlan>>,
     acquire_queue: bool,
 ) -> Result<(Plan, PlanExtras, AcquireQueueGuard)> {
     let mut planner = Planner::new_with_query_executor(
@@ -253,10 +278,13 @@ async fn plan_sql(
     );
 
     // Parse the SQL query, get extract additional information.
-    let extras = planner.parse_sql(sql)?;
+    let extras = match extras {
+        Some(extras) => extras,
+        None => planner.parse_sql(sql)?,
+    };
     if !acquire_queue {
         // If queue guard is not required, plan the statement directly.
-        let plan = planner.plan_stmt(&extras.statement).await?;
+        let plan = plan_stmt(&mut planner, &extras.statement, prepared).await?;
         return Ok((plan, extras, AcquireQueueGuard::create_global(None)));
     }
 
@@ -267,17 +295,28 @@ async fn plan_sql(
         // See PR https://github.com/databendlabs/databend/pull/16632
         let query_entry = QueryEntry::create_entry(&ctx, &extras, true)?;
         let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
-        let plan = planner.plan_stmt(&extras.statement).await?;
+        let plan = plan_stmt(&mut planner, &extras.statement, prepared).await?;
         Ok((plan, extras, guard))
     } else {
         // No lock is needed, plan the statement first, then acquire the queue guard.
-        let plan = planner.plan_stmt(&extras.statement).await?;
+        let plan = plan_stmt(&mut planner, &extras.statement, prepared).await?;
         let query_entry = QueryEntry::create(&ctx, &plan, &extras)?;
         let guard = QueriesQueueManager::instance().acquire(query_entry).await?;
         Ok((plan, extras, guard))
     }
 }
 
+async fn plan_stmt(
+    planner: &mut Planner,
+    stmt: &Statement,
+    prepared: Option<&mut Option<PreparedPlan>>,
+) -> Result<Plan> {
+    match prepared {
+        Some(prepared) => planner.plan_prepared_stmt(stmt, prepared).await,
+        None => planner.plan_stmt(stmt).await,
+    }
+}
+
 fn attach_query_hash(ctx: &Arc<QueryContext>, stmt: &mut Option<Statement>, sql: &str) {
     let (query_hash, query_parameterized_hash) = if let Some(stmt) = stmt {
         let query_hash = format!("{:x}", Md5::digest(stmt.to_string()));
@@ -369,6 +408,7 @@ fn need_acquire_lock(ctx: Arc<QueryContext>, stmt: &Statement) -> bool {
         Statement::AlterTable(AlterTableStmt { action, .. }) => matches!(
             action,
             AlterTableAction::ReclusterTable { .. }
+                | AlterTableAction::DropPartition { .. }
                 | AlterTableAction::ModifyColumn {
                     action: ModifyColumnAction::SetDataType(_),
                 }
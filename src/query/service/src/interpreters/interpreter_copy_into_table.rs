@@ -41,6 +41,7 @@ use log::debug;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -373,6 +374,7 @@ impl Interpreter for CopyIntoTableInterpreter {
             .await?;
 
         to_table.check_mutable()?;
+        check_materialized_view_dml(&self.ctx, to_table.as_ref())?;
 
         if self.plan.no_file_to_copy {
             info!("no file to copy");
@@ -24,6 +24,7 @@ use databend_common_meta_app::schema::UpdateDictionaryReq;
 use databend_common_sql::plans::CreateDictionaryPlan;
 
 use crate::interpreters::Interpreter;
+use crate::pipelines::processors::transforms::evict_dictionary_snapshots;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -82,7 +83,8 @@ impl Interpreter for CreateDictionaryInterpreter {
                             dictionary_meta: dictionary_meta.clone(),
                             dictionary_ident: dictionary_ident.clone(),
                         };
-                        let _reply = catalog.update_dictionary(req).await?;
+                        let reply = catalog.update_dictionary(req).await?;
+                        evict_dictionary_snapshots(reply.dictionary_id);
                         return Ok(PipelineBuildResult::create());
                     }
                 }
@@ -21,6 +21,7 @@ use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_sql::plans::DropDictionaryPlan;
 
 use crate::interpreters::Interpreter;
+use crate::pipelines::processors::transforms::evict_dictionary_snapshots;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -57,7 +58,14 @@ impl Interpreter for DropDictionaryInterpreter {
             tenant,
             DictionaryIdentity::new(db_id, dict_name.to_string()),
         );
+        let dict_id = catalog
+            .get_dictionary(dict_ident.clone())
+            .await?
+            .map(|reply| reply.dictionary_id);
         let reply = catalog.drop_dictionary(dict_ident.clone()).await?;
+        if let Some(dict_id) = dict_id {
+            evict_dictionary_snapshots(dict_id);
+        }
         if self.plan.if_exists || reply.is_some() {
             return Ok(PipelineBuildResult::create());
         } else {
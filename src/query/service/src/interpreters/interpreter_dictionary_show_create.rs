@@ -115,6 +115,7 @@ impl ShowCreateDictionaryInterpreter {
         let source = dictionary.source.clone();
         let source_options = dictionary.options.clone();
         let comment = dictionary.comment.clone();
+        let lifetime = dictionary.lifetime;
         let pk_id_list = dictionary.primary_column_ids.clone();
         let field_comments = dictionary.field_comments.clone();
 
@@ -180,6 +181,10 @@ impl ShowCreateDictionaryInterpreter {
             dict_create_sql.push_str(&res);
             dict_create_sql.push_str("))\n");
         }
+        // Append lifetime.
+        if let Some(lifetime) = lifetime {
+            dict_create_sql.push_str(&format!("LIFETIME({})\n", lifetime));
+        }
         // Append comment.
         {
             if !comment.is_empty() {
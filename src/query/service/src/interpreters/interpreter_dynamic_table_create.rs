@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::InitializeMode;
+use databend_common_ast::ast::TargetLag;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::CreateDynamicTablePlan;
+use databend_common_sql::plans::CreateTablePlan;
+
+use crate::dynamic_table::DynamicTableRefresher;
+use crate::dynamic_table::DynamicTableScheduler;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Creates a dynamic table as a fuse table with the dynamic table options,
+/// and fills it by the query if it is initialized on create.
+pub struct CreateDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateDynamicTablePlan,
+}
+
+impl CreateDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateDynamicTablePlan) -> Result<Self> {
+        Ok(CreateDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "CreateDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        if plan.create_option == CreateOption::CreateIfNotExists {
+            let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+            if catalog
+                .get_table(&plan.tenant, &plan.database, &plan.table)
+                .await
+                .is_ok()
+            {
+                return Ok(PipelineBuildResult::create());
+            }
+        }
+
+        let create_table_plan = CreateTablePlan {
+            create_option: plan.create_option,
+            tenant: plan.tenant.clone(),
+            catalog: plan.catalog.clone(),
+            database: plan.database.clone(),
+            table: plan.table.clone(),
+            schema: plan.schema.clone(),
+            engine: Engine::Fuse,
+            engine_options: Default::default(),
+            storage_params: None,
+            options: plan.options.clone(),
+            table_properties: None,
+            table_partition: None,
+            field_comments: plan.field_comments.clone(),
+            cluster_key: plan.cluster_key.clone(),
+            as_select: None,
+            inverted_indexes: None,
+            ngram_indexes: None,
+            vector_indexes: None,
+            attached_columns: None,
+            clone_source: None,
+        };
+        CreateTableInterpreter::try_create(self.ctx.clone(), create_table_plan)?
+            .execute2()
+            .await?;
+        if plan.catalog == CATALOG_DEFAULT && matches!(plan.target_lag, TargetLag::IntervalSecs(_))
+        {
+            DynamicTableScheduler::instance().add(&plan.database, &plan.table);
+        }
+
+        if plan.initialize == InitializeMode::OnCreate {
+            DynamicTableRefresher::create(
+                self.ctx.clone(),
+                &plan.catalog,
+                &plan.database,
+                &plan.table,
+            )
+            .refresh()
+            .await?;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshDynamicTablePlan;
+
+use crate::dynamic_table::DynamicTableRefresher;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct RefreshDynamicTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshDynamicTablePlan,
+}
+
+impl RefreshDynamicTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshDynamicTablePlan) -> Result<Self> {
+        Ok(RefreshDynamicTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshDynamicTableInterpreter {
+    fn name(&self) -> &str {
+        "RefreshDynamicTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        DynamicTableRefresher::create(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .refresh()
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
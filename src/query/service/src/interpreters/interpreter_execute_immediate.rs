@@ -14,24 +14,15 @@
 
 use std::sync::Arc;
 
-use databend_common_ast::ast::DeclareItem;
-use databend_common_ast::ast::ScriptStatement;
-use databend_common_ast::parser::run_parser;
-use databend_common_ast::parser::script::script_block;
-use databend_common_ast::parser::tokenize_sql;
-use databend_common_ast::parser::ParseMode;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::box_render;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
-use databend_common_script::compile;
-use databend_common_script::Executor;
 use databend_common_script::ReturnValue;
 use databend_common_sql::plans::ExecuteImmediatePlan;
-use databend_common_storages_fuse::TableContext;
 
-use crate::interpreters::util::ScriptClient;
+use crate::interpreters::util::run_script;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -62,35 +53,7 @@ impl Interpreter for ExecuteImmediateInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let res: Result<_> = try {
-            let settings = self.ctx.get_settings();
-            let sql_dialect = settings.get_sql_dialect()?;
-            let tokens = tokenize_sql(&self.plan.script)?;
-            let mut ast = run_parser(
-                &tokens,
-                sql_dialect,
-                ParseMode::Template,
-                false,
-                script_block,
-            )?;
-
-            let mut src = vec![];
-            for declare in ast.declares {
-                match declare {
-                    DeclareItem::Var(declare) => src.push(ScriptStatement::LetVar { declare }),
-                    DeclareItem::Set(declare) => {
-                        src.push(ScriptStatement::LetStatement { declare })
-                    }
-                }
-            }
-            src.append(&mut ast.body);
-            let compiled = compile(&src)?;
-
-            let client = ScriptClient {
-                ctx: self.ctx.clone(),
-            };
-            let mut executor = Executor::load(ast.span, client, compiled);
-            let script_max_steps = settings.get_script_max_steps()?;
-            let result = executor.run(script_max_steps as usize).await?;
+            let result = run_script(&self.ctx, &self.plan.script, vec![]).await?;
 
             match result {
                 Some(ReturnValue::Var(scalar)) => {
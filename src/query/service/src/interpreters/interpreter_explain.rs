@@ -44,7 +44,6 @@ use databend_common_sql::FormatOptions;
 use databend_common_sql::MetadataRef;
 use databend_common_storages_fuse::FuseLazyPartInfo;
 use databend_common_storages_fuse::FuseTable;
-use databend_common_storages_result_cache::gen_result_cache_key;
 use databend_common_storages_result_cache::ResultCacheReader;
 use databend_common_users::UserApiProvider;
 use serde::Serialize;
@@ -52,6 +51,7 @@ use serde_json;
 
 use super::InsertMultiTableInterpreter;
 use super::InterpreterFactory;
+use crate::interpreters::common::plan_result_cache_key;
 use crate::interpreters::interpreter::on_execution_finished;
 use crate::interpreters::interpreter_mutation::build_mutation_info;
 use crate::interpreters::interpreter_mutation::MutationInterpreter;
@@ -324,17 +324,17 @@ impl ExplainInterpreter {
         &self,
         plan: &PhysicalPlan,
         metadata: &MetadataRef,
-        formatted_ast: &Option<String>,
+        result_cache_key: &Option<String>,
     ) -> Result<Vec<DataBlock>> {
         if self.ctx.get_settings().get_enable_query_result_cache()?
             && self.ctx.get_cacheable()
-            && formatted_ast.is_some()
+            && result_cache_key.is_some()
         {
-            let key = gen_result_cache_key(formatted_ast.as_ref().unwrap());
+            let key = result_cache_key.as_ref().unwrap();
             let kv_store = UserApiProvider::instance().get_meta_store_client();
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
-                &key,
+                key,
                 kv_store.clone(),
                 self.ctx
                     .get_settings()
@@ -550,7 +550,13 @@ impl ExplainInterpreter {
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx, formatted_ast.is_none());
         let mut plan = builder.build(s_expr, bind_context.column_set()).await?;
         self.inject_pruned_partitions_stats(&mut plan, metadata)?;
-        self.explain_physical_plan(&plan, metadata, formatted_ast)
+        let result_cache_key = formatted_ast
+            .as_ref()
+            .map(|ast| {
+                plan_result_cache_key(self.ctx.as_ref(), s_expr, metadata, bind_context, ast)
+            })
+            .transpose()?;
+        self.explain_physical_plan(&plan, metadata, &result_cache_key)
             .await
     }
 
@@ -419,7 +419,12 @@ impl InterpreterFactory {
             )?)),
 
             // dynamic tables
-            Plan::CreateDynamicTable(_) => Err(ErrorCode::Unimplemented("todo")),
+            Plan::CreateDynamicTable(plan) => Ok(Arc::new(
+                CreateDynamicTableInterpreter::try_create(ctx, *plan.clone())?,
+            )),
+            Plan::RefreshDynamicTable(plan) => Ok(Arc::new(
+                RefreshDynamicTableInterpreter::try_create(ctx, *plan.clone())?,
+            )),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -468,6 +473,9 @@ impl InterpreterFactory {
             )?)),
 
             Plan::Insert(insert) => InsertInterpreter::try_create(ctx, *insert.clone()),
+            Plan::InsertOverwritePartition(plan) => {
+                InsertOverwritePartitionInterpreter::try_create(ctx, *plan.clone())
+            }
 
             Plan::Replace(replace) => ReplaceInterpreter::try_create(ctx, *replace.clone()),
             Plan::DataMutation { s_expr, schema, .. } => {
@@ -575,6 +583,14 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTableTag(p) => Ok(Arc::new(CreateTableTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropTableTag(p) => Ok(Arc::new(DropTableTagInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
             Plan::CreateDatamaskPolicy(p) => Ok(Arc::new(CreateDataMaskInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -669,6 +685,13 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
             Plan::InsertMultiTable(p) => {
                 Ok(InsertMultiTableInterpreter::try_create(ctx, *p.clone())?)
             }
@@ -36,6 +36,7 @@ use databend_common_sql::NameResolutionContext;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -107,6 +108,7 @@ impl Interpreter for InsertInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        check_materialized_view_dml(&self.ctx, table.as_ref())?;
         let table_meta_timestamps = if table.engine() == "FUSE" {
             let fuse_table =
                 databend_common_storages_fuse::FuseTable::try_from_table(table.as_ref())?;
@@ -51,6 +51,7 @@ use databend_common_sql::ScalarExpr;
 use databend_common_storages_fuse::FuseTable;
 
 use super::HookOperator;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -382,6 +383,7 @@ impl InsertIntoBranches {
     ) -> Result<Vec<SerializableTable>> {
         let mut serializable_tables = vec![];
         for table in &self.tables {
+            check_materialized_view_dml(&ctx, table.as_ref())?;
             let table_info = table.get_table_info();
             let catalog_info = ctx.get_catalog(table_info.catalog()).await?.info();
             let fuse_table = FuseTable::try_from_table(table.as_ref())?;
@@ -0,0 +1,141 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_sql::execute_commit_statement;
+use databend_common_sql::plans::InsertOverwritePartitionPlan;
+use databend_common_sql::Planner;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Replaces one partition of a partitioned table.
+///
+/// The rows of the partition are deleted and the new rows are appended in the same
+/// transaction, so readers see either the old or the new content of the partition.
+pub struct InsertOverwritePartitionInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: InsertOverwritePartitionPlan,
+}
+
+impl InsertOverwritePartitionInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: InsertOverwritePartitionPlan,
+    ) -> Result<InterpreterPtr> {
+        Ok(Arc::new(InsertOverwritePartitionInterpreter { ctx, plan }))
+    }
+
+    async fn execute_sql(&self, sql: &str) -> Result<(Arc<QueryContext>, Vec<DataBlock>)> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        Ok((ctx, blocks))
+    }
+
+    async fn overwrite_partition(&self) -> Result<()> {
+        let quote = |ident: &str| format!("`{}`", ident.replace('`', "``"));
+        let table = format!(
+            "{}.{}.{}",
+            quote(&self.plan.catalog),
+            quote(&self.plan.database),
+            quote(&self.plan.table)
+        );
+        let predicate = &self.plan.partition_predicate;
+
+        self.execute_sql(&format!("DELETE FROM {table} WHERE {predicate}"))
+            .await?;
+
+        let (insert_ctx, _) = self.execute_sql(&self.plan.insert_sql).await?;
+        let write_progress = insert_ctx.get_write_progress_value();
+        self.ctx.get_write_progress().incr(&write_progress);
+
+        // The partition is empty before appending, all the rows in it now come from the source.
+        let (_, blocks) = self
+            .execute_sql(&format!("SELECT count(*) FROM {table} WHERE {predicate}"))
+            .await?;
+        let partition_rows = match blocks
+            .first()
+            .and_then(|block| block.get_by_offset(0).value.index(0))
+        {
+            Some(ScalarRef::Number(NumberScalar::UInt64(rows))) => rows as usize,
+            _ => 0,
+        };
+        if partition_rows != write_progress.rows {
+            return Err(ErrorCode::InvalidPartitionKeys(format!(
+                "{} row(s) inserted into table {} do not belong to partition {}",
+                write_progress.rows.saturating_sub(partition_rows),
+                self.plan.table,
+                predicate
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for InsertOverwritePartitionInterpreter {
+    fn name(&self) -> &str {
+        "InsertOverwritePartitionInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // Outside an explicit transaction, the delete and the append run in an implicit one.
+        let implicit_txn = !self.ctx.txn_mgr().lock().is_active();
+        if implicit_txn {
+            self.ctx.txn_mgr().lock().begin();
+        }
+
+        match self.overwrite_partition().await {
+            Ok(_) if implicit_txn => execute_commit_statement(self.ctx.clone()).await?,
+            Ok(_) => {}
+            Err(e) => {
+                if implicit_txn {
+                    info!(
+                        "overwrite partition of table {} failed, rollback: {}",
+                        self.plan.table, e
+                    );
+                    self.ctx.txn_mgr().lock().clear();
+                }
+                return Err(e);
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -46,6 +46,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use log::info;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
@@ -211,6 +212,7 @@ pub async fn build_mutation_info(
         .await?;
     // Check if the table supports mutation.
     table.check_mutable()?;
+    check_materialized_view_dml(&ctx, table.as_ref())?;
     let fuse_table = table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
         ErrorCode::Unimplemented(format!(
             "table {}, engine type {}, does not support {}",
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterPipeAction;
+use databend_common_sql::plans::AlterPipePlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipe::PipeLoader;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_pipe_execute");
+
+        let plan = self.plan.clone();
+        let user_mgr = UserApiProvider::instance();
+        let res = match plan.action {
+            AlterPipeAction::Set {
+                execution_paused,
+                comment,
+            } => {
+                user_mgr
+                    .update_pipe(&plan.tenant, &plan.name, |mut pipe| {
+                        if let Some(execution_paused) = execution_paused {
+                            pipe.execution_paused = execution_paused;
+                        }
+                        if let Some(comment) = &comment {
+                            pipe.comment = comment.clone();
+                        }
+                        pipe.updated_on = Utc::now();
+                        pipe
+                    })
+                    .await
+            }
+            AlterPipeAction::Refresh {
+                prefix,
+                modified_after,
+            } => match user_mgr.get_pipe(&plan.tenant, &plan.name).await {
+                Ok(pipe) => PipeLoader::create(self.ctx.clone(), pipe)
+                    .refresh(prefix, modified_after)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            },
+        };
+
+        match res {
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_PIPE => {}
+            res => res?,
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -14,26 +14,15 @@
 
 use std::sync::Arc;
 
-use databend_common_ast::ast::DeclareItem;
-use databend_common_ast::ast::DeclareVar;
-use databend_common_ast::ast::Identifier;
-use databend_common_ast::ast::ScriptStatement;
-use databend_common_ast::parser::run_parser;
-use databend_common_ast::parser::script::script_block;
-use databend_common_ast::parser::tokenize_sql;
-use databend_common_ast::parser::ParseMode;
 use databend_common_exception::Result;
 use databend_common_expression::block_debug::box_render;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
-use databend_common_script::compile;
-use databend_common_script::Executor;
 use databend_common_script::ReturnValue;
 use databend_common_sql::plans::CallProcedurePlan;
-use databend_common_storages_fuse::TableContext;
 
-use crate::interpreters::util::ScriptClient;
+use crate::interpreters::util::run_script;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -64,44 +53,14 @@ impl Interpreter for CallProcedureInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let res: Result<_> = try {
-            let mut src = vec![];
-            for (arg, arg_name) in self.plan.args.iter().zip(self.plan.arg_names.iter()) {
-                src.push(ScriptStatement::LetVar {
-                    declare: DeclareVar {
-                        span: None,
-                        name: Identifier::from_name(None, arg_name),
-                        default: arg.clone(),
-                    },
-                });
-            }
-            let settings = self.ctx.get_settings();
-            let sql_dialect = settings.get_sql_dialect()?;
-            let tokens = tokenize_sql(&self.plan.script)?;
-            let mut ast = run_parser(
-                &tokens,
-                sql_dialect,
-                ParseMode::Template,
-                false,
-                script_block,
-            )?;
-
-            for declare in ast.declares {
-                match declare {
-                    DeclareItem::Var(declare) => src.push(ScriptStatement::LetVar { declare }),
-                    DeclareItem::Set(declare) => {
-                        src.push(ScriptStatement::LetStatement { declare })
-                    }
-                }
-            }
-            src.append(&mut ast.body);
-            let compiled = compile(&src)?;
-
-            let client = ScriptClient {
-                ctx: self.ctx.clone(),
-            };
-            let mut executor = Executor::load(ast.span, client, compiled);
-            let script_max_steps = settings.get_script_max_steps()?;
-            let result = executor.run(script_max_steps as usize).await?;
+            let args = self
+                .plan
+                .arg_names
+                .iter()
+                .cloned()
+                .zip(self.plan.args.iter().cloned())
+                .collect();
+            let result = run_script(&self.ctx, &self.plan.script, args).await?;
 
             match result {
                 Some(ReturnValue::Var(scalar)) => {
@@ -53,6 +53,7 @@ use databend_storages_common_table_meta::table::ClusterType;
 use parking_lot::RwLock;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::common::dml_build_update_stream_req;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::HookOperator;
@@ -144,6 +145,7 @@ impl ReplaceInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        check_materialized_view_dml(&self.ctx, table.as_ref())?;
 
         let schema = table.schema();
         let mut on_conflicts = Vec::with_capacity(plan.on_conflict_fields.len());
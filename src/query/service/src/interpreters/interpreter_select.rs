@@ -37,13 +37,13 @@ use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::parse_result_scan_args;
 use databend_common_sql::ColumnBinding;
 use databend_common_sql::MetadataRef;
-use databend_common_storages_result_cache::gen_result_cache_key;
 use databend_common_storages_result_cache::ResultCacheReader;
 use databend_common_storages_result_cache::WriteResultCacheSink;
 use databend_common_users::UserApiProvider;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::plan_result_cache_key;
 use crate::interpreters::common::query_build_update_stream_req;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -293,7 +293,13 @@ impl Interpreter for SelectInterpreter {
             && self.ctx.get_cacheable()
             && self.formatted_ast.is_some()
         {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+            let key = plan_result_cache_key(
+                self.ctx.as_ref(),
+                &self.s_expr,
+                &self.metadata,
+                &self.bind_context,
+                self.formatted_ast.as_ref().unwrap(),
+            )?;
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
@@ -28,6 +28,7 @@ use databend_common_license::license::Feature;
 use databend_common_license::license::Feature::ComputedColumn;
 use databend_common_license::license::Feature::InvertedIndex;
 use databend_common_license::license::Feature::NgramIndex;
+use databend_common_license::license::Feature::VectorIndex;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
@@ -44,11 +45,14 @@ use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::always_callback;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::plans::TableCloneSource;
 use databend_common_sql::DefaultExprBinder;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FuseSegmentFormat;
 use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_VACUUM;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
@@ -130,6 +134,10 @@ impl Interpreter for CreateTableInterpreter {
             LicenseManagerSwitch::instance()
                 .check_enterprise_enabled(self.ctx.get_license_key(), NgramIndex)?;
         }
+        if self.plan.vector_indexes.is_some() {
+            LicenseManagerSwitch::instance()
+                .check_enterprise_enabled(self.ctx.get_license_key(), VectorIndex)?;
+        }
 
         let quota_api = UserApiProvider::instance().tenant_quota_api(tenant);
         let quota = quota_api.get_quota(MatchSeq::GE(0)).await?.data;
@@ -168,9 +176,12 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
-        match &self.plan.as_select {
-            Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
-            None => self.create_table().await,
+        match (&self.plan.as_select, &self.plan.clone_source) {
+            (Some(select_plan_node), _) => {
+                self.create_table_as_select(select_plan_node.clone()).await
+            }
+            (None, Some(clone_source)) => self.create_table_clone(clone_source).await,
+            (None, None) => self.create_table().await,
         }
     }
 }
@@ -186,6 +197,9 @@ impl CreateTableInterpreter {
         let tenant = self.ctx.get_tenant();
 
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if catalog.is_external() {
+            return self.create_external_table_as_select(select_plan).await;
+        }
 
         let mut req = self.build_request(None)?;
 
@@ -207,22 +221,7 @@ impl CreateTableInterpreter {
         let db_id = reply.db_id;
 
         if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
-            // grant the ownership of the table to the current role.
-            let current_role = self.ctx.get_current_role();
-            if let Some(current_role) = current_role {
-                let role_api = UserApiProvider::instance().role_api(&tenant);
-                role_api
-                    .grant_ownership(
-                        &OwnershipObject::Table {
-                            catalog_name: self.plan.catalog.clone(),
-                            db_id,
-                            table_id,
-                        },
-                        &current_role.name,
-                    )
-                    .await?;
-                RoleCacheManager::instance().invalidate_cache(&tenant);
-            }
+            self.grant_table_ownership(db_id, table_id).await?;
         }
 
         // If the table creation query contains column definitions, like 'CREATE TABLE t1(a int) AS SELECT * from t2',
@@ -315,6 +314,141 @@ impl CreateTableInterpreter {
         Ok(pipeline)
     }
 
+    /// `CREATE TABLE ... CLONE`.
+    ///
+    /// Like `create_table_as_select`, a dropped table is created first. Its first snapshot
+    /// references the segments of the source table instead of copying them, and then the
+    /// table is made visible.
+    #[async_backtrace::framed]
+    async fn create_table_clone(
+        &self,
+        clone_source: &TableCloneSource,
+    ) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+
+        let source_table = self
+            .ctx
+            .get_table(
+                &clone_source.catalog,
+                &clone_source.database,
+                &clone_source.table,
+            )
+            .await?;
+        let source_table = FuseTable::try_from_table(source_table.as_ref())?;
+        let source = match &clone_source.navigation {
+            Some(point) => {
+                source_table
+                    .navigate_to_point(point, self.ctx.clone().get_abort_checker())
+                    .await?
+            }
+            None => Arc::new(source_table.clone()),
+        };
+
+        let mut req = self.build_request(None)?;
+        req.as_dropped = true;
+        req.table_meta.drop_on = Some(Utc::now());
+        let table_meta = req.table_meta.clone();
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let table_id = reply.table_id;
+        let table_id_seq = reply
+            .table_id_seq
+            .expect("internal error: table_id_seq must have been set. CLONE(replace) of table");
+        let db_id = reply.db_id;
+        self.grant_table_ownership(db_id, table_id).await?;
+
+        let table_info = TableInfo::new(
+            &self.plan.database,
+            &self.plan.table,
+            TableIdent::new(table_id, table_id_seq),
+            table_meta,
+        );
+        let table = FuseTable::do_create(table_info)?;
+        let references = table.do_clone_from(self.ctx.clone(), &source).await?;
+
+        info!(
+            "create table {}.{} clone of {}.{} success, commit table meta data by table id {}",
+            self.plan.database,
+            self.plan.table,
+            clone_source.database,
+            clone_source.table,
+            table_id
+        );
+        let req = CommitTableMetaReq {
+            name_ident: TableNameIdent {
+                tenant,
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            db_id,
+            table_id,
+            prev_table_id: reply.prev_table_id,
+            orphan_table_name: reply.orphan_table_name,
+        };
+        if let Err(e) = catalog.commit_table_meta(req).await {
+            // the table stays invisible, release the segments of the source table.
+            table.remove_clone_references(&references).await;
+            return Err(e);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
+    /// Grant the ownership of the new table to the current role.
+    #[async_backtrace::framed]
+    async fn grant_table_ownership(&self, db_id: u64, table_id: u64) -> Result<()> {
+        if let Some(current_role) = self.ctx.get_current_role() {
+            let tenant = self.ctx.get_tenant();
+            let role_api = UserApiProvider::instance().role_api(&tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Table {
+                        catalog_name: self.plan.catalog.clone(),
+                        db_id,
+                        table_id,
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&tenant);
+        }
+        Ok(())
+    }
+
+    /// External catalogs (e.g. iceberg) keep the table metadata by themselves, there is no
+    /// dropped table which could be made visible after the insertion. So the table is created
+    /// first, and then the data is inserted into it like a plain `INSERT INTO ... SELECT`.
+    #[async_backtrace::framed]
+    async fn create_external_table_as_select(
+        &self,
+        select_plan: Box<Plan>,
+    ) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let req = self.build_request(None)?;
+        let reply = catalog.create_table(req).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let insert_plan = Insert {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+            schema: self.plan.schema.clone(),
+            overwrite: false,
+            source: InsertInputSource::SelectPlan(select_plan),
+            table_info: None,
+        };
+
+        InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
+            .execute2()
+            .await
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
@@ -424,7 +558,7 @@ impl CreateTableInterpreter {
             FuseSegmentFormat::from_str(segment_format)?;
         }
         let comment = options.remove(OPT_KEY_COMMENT);
-        let indexes = match (&self.plan.inverted_indexes, &self.plan.ngram_indexes) {
+        let mut indexes = match (&self.plan.inverted_indexes, &self.plan.ngram_indexes) {
             (Some(inverted_indexes), Some(ngram_indexes)) => {
                 let mut table_indexes = inverted_indexes.clone();
                 table_indexes.extend(ngram_indexes.clone());
@@ -434,6 +568,9 @@ impl CreateTableInterpreter {
             (None, Some(ngram_indexes)) => ngram_indexes.clone(),
             (None, None) => BTreeMap::default(),
         };
+        if let Some(vector_indexes) = &self.plan.vector_indexes {
+            indexes.extend(vector_indexes.clone());
+        }
 
         let mut table_meta = TableMeta {
             schema: schema.clone(),
@@ -462,6 +599,7 @@ impl CreateTableInterpreter {
 
         // Same as settings of FUSE_OPT_KEY_ENABLE_AUTO_VACUUM, expect value type is unsigned integer
         is_valid_option_of_type::<u32>(&table_meta.options, FUSE_OPT_KEY_ENABLE_AUTO_VACUUM)?;
+        is_valid_option_of_type::<u32>(&table_meta.options, FUSE_OPT_KEY_ENABLE_DELETION_VECTOR)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
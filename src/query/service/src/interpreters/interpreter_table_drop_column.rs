@@ -26,6 +26,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_partition_keys;
 use crate::interpreters::interpreter_table_add_column::commit_table_meta;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -95,6 +96,11 @@ impl Interpreter for DropTableColumnInterpreter {
                 self.plan.column.as_str(),
             )?;
         }
+        check_referenced_partition_keys(
+            self.ctx.clone(),
+            table.as_ref(),
+            self.plan.column.as_str(),
+        )?;
         // If the column is table index column, the column can't be dropped.
         if !table_info.meta.indexes.is_empty() {
             for (index_name, index) in &table_info.meta.indexes {
@@ -25,6 +25,7 @@ use databend_common_sql::plans::CreateTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
 use databend_enterprise_ngram_index::get_ngram_index_handler;
+use databend_enterprise_vector_index::get_vector_index_handler;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -62,6 +63,10 @@ impl Interpreter for CreateTableIndexInterpreter {
                 LicenseManagerSwitch::instance()
                     .check_enterprise_enabled(self.ctx.get_license_key(), Feature::NgramIndex)?;
             }
+            ast::TableIndexType::Vector => {
+                LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::VectorIndex)?;
+            }
             ast::TableIndexType::Aggregating => (),
         }
 
@@ -79,6 +84,7 @@ impl Interpreter for CreateTableIndexInterpreter {
             }
             ast::TableIndexType::Inverted => TableIndexType::Inverted,
             ast::TableIndexType::Ngram => TableIndexType::Ngram,
+            ast::TableIndexType::Vector => TableIndexType::Vector,
         };
 
         let create_index_req = CreateTableIndexReq {
@@ -105,6 +111,12 @@ impl Interpreter for CreateTableIndexInterpreter {
                     .do_create_table_index(catalog, create_index_req)
                     .await?;
             }
+            TableIndexType::Vector => {
+                let handler = get_vector_index_handler();
+                let _ = handler
+                    .do_create_table_index(catalog, create_index_req)
+                    .await?;
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -25,6 +25,7 @@ use databend_common_sql::plans::DropTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
 use databend_enterprise_ngram_index::get_ngram_index_handler;
+use databend_enterprise_vector_index::get_vector_index_handler;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -62,6 +63,10 @@ impl Interpreter for DropTableIndexInterpreter {
                 LicenseManagerSwitch::instance()
                     .check_enterprise_enabled(self.ctx.get_license_key(), Feature::NgramIndex)?;
             }
+            ast::TableIndexType::Vector => {
+                LicenseManagerSwitch::instance()
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::VectorIndex)?;
+            }
             ast::TableIndexType::Aggregating => (),
         }
 
@@ -76,6 +81,7 @@ impl Interpreter for DropTableIndexInterpreter {
             }
             ast::TableIndexType::Inverted => TableIndexType::Inverted,
             ast::TableIndexType::Ngram => TableIndexType::Ngram,
+            ast::TableIndexType::Vector => TableIndexType::Vector,
         };
 
         let drop_index_req = DropTableIndexReq {
@@ -95,6 +101,10 @@ impl Interpreter for DropTableIndexInterpreter {
                 let handler = get_ngram_index_handler();
                 let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
             }
+            TableIndexType::Vector => {
+                let handler = get_vector_index_handler();
+                let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+            }
         }
 
         Ok(PipelineBuildResult::create())
@@ -27,6 +27,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_partition_keys;
 use crate::interpreters::interpreter_table_add_column::commit_table_meta;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
@@ -108,6 +109,12 @@ impl Interpreter for RenameTableColumnInterpreter {
                 )?;
             }
 
+            check_referenced_partition_keys(
+                self.ctx.clone(),
+                table.as_ref(),
+                self.plan.old_column.as_str(),
+            )?;
+
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
             // update table options
@@ -33,19 +33,25 @@ use databend_common_storages_fuse::FuseSegmentFormat;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_VACUUM;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use databend_storages_common_table_meta::meta::column_oriented_segment::AbstractSegment;
 use databend_storages_common_table_meta::meta::column_oriented_segment::ColumnOrientedSegmentBuilder;
 use databend_storages_common_table_meta::meta::column_oriented_segment::SegmentBuilder;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SEGMENT_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 
@@ -122,9 +128,34 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_CLUSTER_TYPE
             )));
         }
+        if self.plan.set_options.contains_key(OPT_KEY_PARTITION_BY) {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_PARTITION_BY
+            )));
+        }
+        for key in [
+            OPT_KEY_AS_QUERY,
+            OPT_KEY_TARGET_LAG,
+            OPT_KEY_REFRESH_MODE,
+            OPT_KEY_INITIALIZE,
+        ] {
+            if self.plan.set_options.contains_key(key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {} for alter table statement",
+                    key
+                )));
+            }
+        }
 
         // Same as settings of FUSE_OPT_KEY_ENABLE_AUTO_VACUUM, expect value type is unsigned integer
         is_valid_option_of_type::<u32>(&self.plan.set_options, FUSE_OPT_KEY_ENABLE_AUTO_VACUUM)?;
+        is_valid_option_of_type::<u32>(
+            &self.plan.set_options,
+            FUSE_OPT_KEY_ENABLE_DELETION_VECTOR,
+        )?;
 
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let database = self.plan.database.as_str();
@@ -238,6 +269,16 @@ async fn set_segment_format(
                     .await?;
                 for segment in segments {
                     let segment = segment?;
+                    // The deleted rows would be visible again, since the column-oriented
+                    // segments can not carry the deletion vectors of the blocks.
+                    if segment.blocks.iter().any(|b| b.deletion_vector.is_some()) {
+                        return Err(ErrorCode::Unimplemented(format!(
+                            "table {} has blocks with deletion vectors, which are not supported by {} '{}', please compact the table first",
+                            table.name(),
+                            OPT_KEY_SEGMENT_FORMAT,
+                            value,
+                        )));
+                    }
                     for block in segment.blocks {
                         segment_builder.add_block(block.as_ref().clone())?;
                     }
@@ -37,6 +37,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -246,6 +247,7 @@ impl ShowCreateTableInterpreter {
                 let index_type = match index_field.index_type {
                     TableIndexType::Inverted => "INVERTED",
                     TableIndexType::Ngram => "NGRAM",
+                    TableIndexType::Vector => "VECTOR",
                 };
                 let mut index_str = format!(
                     "  {} {} INDEX {} ({})",
@@ -301,6 +303,10 @@ impl ShowCreateTableInterpreter {
             });
         }
 
+        if let Some(partition_keys_str) = table_info.options().get(OPT_KEY_PARTITION_BY) {
+            table_create_sql.push_str(format!(" PARTITION BY {}", partition_keys_str).as_str());
+        }
+
         if engine != "ICEBERG" && engine != "DELTA" {
             if let Some(sp) = &table_info.meta.storage_params {
                 table_create_sql.push_str(format!(" '{}' ", sp).as_str());
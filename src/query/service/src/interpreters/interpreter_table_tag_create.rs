@@ -0,0 +1,72 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::Result;
+use databend_common_sql::plans::CreateTableTagPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableTagPlan,
+}
+
+impl CreateTableTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableTagPlan) -> Result<Self> {
+        Ok(CreateTableTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableTagInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot_location = match &plan.navigation {
+            Some(point) => fuse_table
+                .navigate_to_point(point, self.ctx.clone().get_abort_checker())
+                .await?
+                .snapshot_loc(),
+            None => fuse_table.snapshot_loc(),
+        };
+        fuse_table
+            .create_tag(&plan.tag, snapshot_location, plan.retain)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -22,6 +22,7 @@ use databend_common_sql::plans::TruncateTablePlan;
 
 use crate::clusters::ClusterHelper;
 use crate::clusters::FlightParams;
+use crate::interpreters::common::check_materialized_view_dml;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::servers::flight::v1::actions::TRUNCATE_TABLE;
@@ -84,6 +85,9 @@ impl Interpreter for TruncateTableInterpreter {
             .await?;
         // check mutability
         table.check_mutable()?;
+        if self.proxy_to_warehouse {
+            check_materialized_view_dml(&self.ctx, table.as_ref())?;
+        }
 
         if self.proxy_to_warehouse && table.broadcast_truncate_to_warehouse() {
             let warehouse = self.ctx.get_warehouse_cluster().await?;
@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
@@ -26,15 +28,21 @@ use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::task;
+use databend_common_meta_app::principal::task::Status;
+use databend_common_meta_app::principal::Task;
 use databend_common_settings::DefaultSettings;
 use databend_common_settings::SettingScope;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
+use crate::interpreters::common::make_local_schedule_options;
 use crate::interpreters::common::make_schedule_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::task::next_scheduled_at;
 
 #[derive(Debug)]
 pub struct AlterTaskInterpreter {
@@ -140,6 +148,54 @@ impl AlterTaskInterpreter {
         req
     }
 
+    // Alter the task kept in the meta service for the built-in task scheduler.
+    async fn alter_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        match user_api.get_task(&plan.tenant, &plan.task_name).await {
+            Ok(_) => {}
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TASK => return Ok(()),
+            Err(e) => return Err(e),
+        }
+        let now = Utc::now();
+        match &plan.alter_options {
+            AlterTaskOptions::Set {
+                schedule: Some(schedule),
+                ..
+            } => {
+                next_scheduled_at(&make_local_schedule_options(schedule.clone()), now)?;
+            }
+            AlterTaskOptions::Unset { .. } => {
+                return Err(ErrorCode::Unimplemented(
+                    "ALTER TASK UNSET is not supported",
+                ));
+            }
+            AlterTaskOptions::AddAfter(tasks) => {
+                for after in tasks {
+                    user_api.get_task(&plan.tenant, after).await?;
+                }
+            }
+            _ => {}
+        }
+
+        // A task runs with the privileges of its owner, so the role that modifies its SQL
+        // becomes the owner.
+        let owner = self
+            .ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string();
+        // The options are applied to the latest task on every retry, so a concurrent update,
+        // like the run of the task, is not overwritten.
+        let options = plan.alter_options.clone();
+        user_api
+            .update_task(&plan.tenant, &plan.task_name, move |task| {
+                alter_task(task, &options, &owner, now)
+            })
+            .await
+    }
+
     fn validate_session_parameters(&self) -> Result<()> {
         if let AlterTaskOptions::Set {
             session_parameters: Some(session_parameters),
@@ -167,13 +223,12 @@ impl Interpreter for AlterTaskInterpreter {
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.validate_session_parameters()?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
-        self.validate_session_parameters()?;
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
         let req = self.build_request();
@@ -183,3 +238,84 @@ impl Interpreter for AlterTaskInterpreter {
         Ok(PipelineBuildResult::create())
     }
 }
+
+fn alter_task(mut task: Task, options: &AlterTaskOptions, owner: &str, now: DateTime<Utc>) -> Task {
+    match options.clone() {
+        AlterTaskOptions::Resume => {
+            task.status = Status::Started;
+        }
+        AlterTaskOptions::Suspend => {
+            task.status = Status::Suspended;
+            task.last_suspended_at = Some(now);
+        }
+        AlterTaskOptions::Set {
+            schedule,
+            comments,
+            warehouse,
+            suspend_task_after_num_failures,
+            error_integration,
+            session_parameters,
+        } => {
+            if let Some(schedule) = schedule {
+                task.schedule_options = Some(make_local_schedule_options(schedule));
+            }
+            if let Some(comments) = comments {
+                task.comment = Some(comments);
+            }
+            if let Some(warehouse) = warehouse {
+                task.warehouse_options = Some(task::WarehouseOptions {
+                    warehouse: Some(warehouse),
+                    using_warehouse_size: None,
+                });
+            }
+            if let Some(num) = suspend_task_after_num_failures {
+                task.suspend_task_after_num_failures = Some(num);
+            }
+            if let Some(error_integration) = error_integration {
+                task.error_integration = Some(error_integration);
+            }
+            if let Some(session_parameters) = session_parameters {
+                task.session_params = session_parameters;
+            }
+        }
+        AlterTaskOptions::Unset { .. } => {}
+        AlterTaskOptions::ModifyAs(sql) => {
+            match sql {
+                TaskSql::SingleStatement(stmt) => {
+                    task.query_text = stmt;
+                    task.script_sqls = vec![];
+                }
+                TaskSql::ScriptBlock(ref sqls) => {
+                    task.query_text = format!("{}", sql);
+                    task.script_sqls = sqls.clone();
+                }
+            }
+            task.owner = owner.to_string();
+        }
+        AlterTaskOptions::AddAfter(tasks) => {
+            for after in tasks {
+                if !task.after.contains(&after) {
+                    task.after.push(after);
+                }
+            }
+        }
+        AlterTaskOptions::RemoveAfter(tasks) => {
+            task.after.retain(|t| !tasks.contains(t));
+        }
+        AlterTaskOptions::ModifyWhen(sql) => {
+            task.when_condition = Some(sql.to_string());
+            task.owner = owner.to_string();
+        }
+    }
+
+    // Only the started root tasks are scheduled, the others follow their predecessors.
+    // The schedule has been validated when it was set.
+    task.next_scheduled_at = match &task.schedule_options {
+        Some(options) if task.status == Status::Started && task.after.is_empty() => {
+            next_scheduled_at(options, now).ok()
+        }
+        _ => None,
+    };
+    task.updated_at = now;
+    task
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::make_request;
@@ -21,13 +22,18 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb;
 use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::task::Status;
+use databend_common_meta_app::principal::task::WarehouseOptions;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_settings::DefaultSettings;
 use databend_common_settings::SettingScope;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
+use crate::interpreters::common::make_local_schedule_options;
 use crate::interpreters::common::make_schedule_options;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
@@ -86,6 +92,59 @@ impl CreateTaskInterpreter {
         req
     }
 
+    // Keep the task in the meta service, the built-in task scheduler runs it.
+    async fn create_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        for after in plan.after.iter() {
+            user_api.get_task(&plan.tenant, after).await?;
+        }
+        let owner = self
+            .ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string();
+        let (query_text, script_sqls) = match &plan.sql {
+            TaskSql::SingleStatement(stmt) => (stmt.clone(), vec![]),
+            TaskSql::ScriptBlock(sqls) => (format!("{}", plan.sql), sqls.clone()),
+        };
+        let now = Utc::now();
+        let task = Task {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text,
+            script_sqls,
+            when_condition: plan.when_condition,
+            after: plan.after,
+            comment: plan.comment,
+            owner,
+            schedule_options: plan.schedule_opts.map(make_local_schedule_options),
+            warehouse_options: Some(WarehouseOptions {
+                warehouse: plan.warehouse,
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            error_integration: plan.error_integration,
+            // A task is suspended until it is resumed by ALTER TASK.
+            status: Status::Suspended,
+            created_at: now,
+            updated_at: now,
+            last_suspended_at: None,
+            session_params: plan.session_parameters,
+        };
+        let create_option = if plan.if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+        user_api
+            .create_task(&plan.tenant, task, &create_option)
+            .await?;
+        Ok(())
+    }
+
     fn validate_session_parameters(&self) -> Result<()> {
         let session_parameters = self.plan.session_parameters.clone();
         for (key, _) in session_parameters.iter() {
@@ -108,13 +167,12 @@ impl Interpreter for CreateTaskInterpreter {
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.validate_session_parameters()?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.create_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
-        self.validate_session_parameters()?;
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
         let req = self.build_request();
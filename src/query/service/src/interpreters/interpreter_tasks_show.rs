@@ -18,10 +18,11 @@ use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ShowTasksPlan;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_storages_system::task_to_pb;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -77,9 +78,20 @@ impl Interpreter for ShowTasksInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let available_roles = self
+                .ctx
+                .get_current_session()
+                .get_all_available_roles()
+                .await?;
+            let tasks = UserApiProvider::instance()
+                .get_tasks(&self.plan.tenant)
+                .await?
+                .iter()
+                .filter(|t| available_roles.iter().any(|r| r.identity() == t.owner))
+                .map(task_to_pb)
+                .collect();
+            let result = parse_tasks_to_datablock(tasks)?;
+            return PipelineBuildResult::from_blocks(vec![result]);
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
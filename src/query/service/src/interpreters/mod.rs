@@ -49,6 +49,8 @@ mod interpreter_dictionary_show_create;
 mod interpreter_drop_warehouse_cluster;
 mod interpreter_drop_warehouses;
 mod interpreter_drop_workload_group;
+mod interpreter_dynamic_table_create;
+mod interpreter_dynamic_table_refresh;
 mod interpreter_execute_immediate;
 mod interpreter_explain;
 mod interpreter_factory;
@@ -60,6 +62,7 @@ mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
 mod interpreter_insert_multi_table;
+mod interpreter_insert_overwrite_partition;
 mod interpreter_inspect_warehouse;
 mod interpreter_kill;
 mod interpreter_metrics;
@@ -80,6 +83,10 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_desc;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -130,6 +137,8 @@ mod interpreter_table_rename_column;
 mod interpreter_table_revert;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
+mod interpreter_table_tag_create;
+mod interpreter_table_tag_drop;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_unset_options;
@@ -166,9 +175,11 @@ mod interpreter_view_drop;
 mod interpreter_virtual_column_refresh;
 mod util;
 
+pub use access::Accessor;
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
+pub use interpreter::interpreter_plan_prepared;
 pub use interpreter::interpreter_plan_sql;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
@@ -185,12 +196,15 @@ pub use interpreter_database_rename::RenameDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_dictionary_rename::RenameDictionaryInterpreter;
+pub use interpreter_dynamic_table_create::CreateDynamicTableInterpreter;
+pub use interpreter_dynamic_table_refresh::RefreshDynamicTableInterpreter;
 pub use interpreter_execute_immediate::ExecuteImmediateInterpreter;
 pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
+pub use interpreter_insert_overwrite_partition::InsertOverwritePartitionInterpreter;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_mutation::MutationInterpreter;
@@ -206,6 +220,10 @@ pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_pipe_alter::AlterPipeInterpreter;
+pub use interpreter_pipe_create::CreatePipeInterpreter;
+pub use interpreter_pipe_desc::DescPipeInterpreter;
+pub use interpreter_pipe_drop::DropPipeInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_procedure_desc::DescProcedureInterpreter;
@@ -241,6 +259,8 @@ pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
 pub use interpreter_table_show_create::ShowCreateQuerySettings;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
+pub use interpreter_table_tag_create::CreateTableTagInterpreter;
+pub use interpreter_table_tag_drop::DropTableTagInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
@@ -262,3 +282,5 @@ pub use interpreter_view_alter::AlterViewInterpreter;
 pub use interpreter_view_create::CreateViewInterpreter;
 pub use interpreter_view_drop::DropViewInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use util::run_script;
+pub use util::ScriptClient;
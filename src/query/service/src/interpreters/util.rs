@@ -14,19 +14,31 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::DeclareItem;
+use databend_common_ast::ast::DeclareVar;
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::ScriptStatement;
 use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::script::script_block;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
+use databend_common_ast::parser::ParseMode;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::principal::UserInfo;
+use databend_common_script::compile;
 use databend_common_script::ir::ColumnAccess;
 use databend_common_script::Client;
+use databend_common_script::Executor;
+use databend_common_script::ReturnValue;
 use databend_common_sql::Planner;
 use futures_util::TryStreamExt;
 use itertools::Itertools;
@@ -90,6 +102,56 @@ pub struct ScriptClient {
     pub(crate) ctx: Arc<QueryContext>,
 }
 
+/// Run the script of `EXECUTE IMMEDIATE` or a procedure, the arguments of the procedure are
+/// declared as variables before the script.
+pub async fn run_script(
+    ctx: &Arc<QueryContext>,
+    script: &str,
+    args: Vec<(String, Expr)>,
+) -> databend_common_exception::Result<Option<ReturnValue<ScriptClient>>> {
+    let mut src = vec![];
+    for (arg_name, arg) in args {
+        src.push(ScriptStatement::LetVar {
+            declare: DeclareVar {
+                span: None,
+                name: Identifier::from_name(None, arg_name),
+                default: arg,
+            },
+        });
+    }
+    let settings = ctx.get_settings();
+    let sql_dialect = settings.get_sql_dialect()?;
+    let tokens = tokenize_sql(script)?;
+    let mut ast = run_parser(
+        &tokens,
+        sql_dialect,
+        ParseMode::Template,
+        false,
+        script_block,
+    )?;
+
+    for declare in ast.declares {
+        match declare {
+            DeclareItem::Var(declare) => src.push(ScriptStatement::LetVar { declare }),
+            DeclareItem::Set(declare) => src.push(ScriptStatement::LetStatement { declare }),
+        }
+    }
+    src.append(&mut ast.body);
+    if !ast.exception_handlers.is_empty() {
+        src = vec![ScriptStatement::Block {
+            span: ast.span,
+            body: src,
+            exception_handlers: ast.exception_handlers,
+        }];
+    }
+    let compiled = compile(&src)?;
+
+    let client = ScriptClient { ctx: ctx.clone() };
+    let mut executor = Executor::load(ast.span, client, compiled);
+    let script_max_steps = settings.get_script_max_steps()?;
+    executor.run(script_max_steps as usize).await
+}
+
 impl Client for ScriptClient {
     type Var = Scalar;
     type Set = QueryResult;
@@ -185,6 +247,32 @@ impl Client for ScriptClient {
             ))),
         }
     }
+
+    fn code_to_var(&self, code: u16) -> Self::Var {
+        Scalar::Number(NumberScalar::UInt16(code))
+    }
+
+    fn message_to_var(&self, message: &str) -> Self::Var {
+        Scalar::String(message.to_string())
+    }
+
+    fn var_to_code(&self, scalar: &Self::Var) -> databend_common_exception::Result<u16> {
+        match scalar {
+            Scalar::Number(_) => scalar.to_string().parse::<u16>().map_err(|_| {
+                ErrorCode::ScriptExecutionError(format!("invalid error code {scalar}"))
+            }),
+            _ => Err(ErrorCode::ScriptExecutionError(format!(
+                "error code should be a number, but got {scalar}",
+            ))),
+        }
+    }
+
+    fn var_to_message(&self, scalar: &Self::Var) -> databend_common_exception::Result<String> {
+        match scalar {
+            Scalar::String(message) => Ok(message.clone()),
+            _ => Ok(scalar.to_string()),
+        }
+    }
 }
 
 #[derive(serde::Serialize)]
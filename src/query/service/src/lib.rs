@@ -46,10 +46,13 @@ pub mod builtin;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
+pub mod dynamic_table;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
+pub mod object_scheduler;
 pub mod persistent_log;
+pub mod pipe;
 pub mod pipelines;
 pub mod schedulers;
 pub mod servers;
@@ -57,6 +60,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod task;
 pub mod test_kits;
 
 mod global_services;
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_semaphore::acquirer::Permit;
+use databend_common_meta_semaphore::Semaphore;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_store::MetaStoreProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use parking_lot::Mutex;
+
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The common parts of the schedulers that run the background jobs of the objects of the
+/// tenant on every query node, like the refresh of the dynamic tables and the load of the pipes.
+///
+/// A job runs in the session of the role that owns its object, and a meta semaphore of the
+/// object makes sure that the job runs on one node at a time.
+pub struct ObjectScheduler {
+    /// The kind of the scheduled objects, e.g. `pipe`.
+    kind: &'static str,
+    meta_store: MetaStore,
+    tenant_id: String,
+    node_id: String,
+    cluster_id: String,
+    /// The sessions of the owner roles, created once for each role.
+    sessions: Mutex<HashMap<String, Arc<Session>>>,
+    initialized: AtomicBool,
+}
+
+impl ObjectScheduler {
+    pub async fn create(cfg: &InnerConfig, kind: &'static str) -> Result<Self> {
+        let meta_store = MetaStoreProvider::new(cfg.meta.to_meta_grpc_client_conf())
+            .create_meta_store()
+            .await
+            .map_err(|e| {
+                ErrorCode::MetaServiceError(format!(
+                    "Failed to create meta store for {} scheduler: {}",
+                    kind, e
+                ))
+            })?;
+
+        Ok(Self {
+            kind,
+            meta_store,
+            tenant_id: cfg.query.tenant_id.tenant_name().to_string(),
+            node_id: cfg.query.node_id.clone(),
+            cluster_id: cfg.query.cluster_id.clone(),
+            sessions: Mutex::new(HashMap::new()),
+            initialized: AtomicBool::new(false),
+        })
+    }
+
+    pub fn initialized(&self) {
+        self.initialized.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.initialized.load(Ordering::SeqCst)
+    }
+
+    /// Waits all services to be initialized.
+    pub async fn wait_initialized(&self) {
+        while !self.is_initialized() {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Acquires the semaphore of an object, `key` is unique among the objects of the tenant.
+    ///
+    /// No semaphore is needed by the embedded meta store, which is used by a single node.
+    pub async fn acquire(&self, key: &str, lease: Duration) -> Result<Option<Permit>> {
+        let meta_client = match &self.meta_store {
+            MetaStore::R(handle) => handle.clone(),
+            MetaStore::L(_) => return Ok(None),
+        };
+        let meta_key = format!("{}/{}", self.tenant_id, key);
+        let permit = Semaphore::new_acquired(meta_client, meta_key, 1, self.node_id.clone(), lease)
+            .await
+            .map_err(|e| {
+                ErrorCode::MetaServiceError(format!(
+                    "acquire semaphore {} failed from {} scheduler: {}",
+                    key, self.kind, e
+                ))
+            })?;
+        Ok(Some(permit))
+    }
+
+    /// The session of `account_admin`, to list the scheduled objects.
+    pub async fn admin_session(&self) -> Result<Arc<Session>> {
+        self.session(BUILTIN_ROLE_ACCOUNT_ADMIN).await
+    }
+
+    /// The session to run the jobs of the objects owned by `role`.
+    pub async fn session(&self, role: &str) -> Result<Arc<Session>> {
+        if let Some(session) = self.sessions.lock().get(role) {
+            return Ok(session.clone());
+        }
+
+        let session_manager = SessionManager::instance();
+        let dummy_session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(dummy_session)?;
+        let user = UserInfo::new_no_auth(
+            &format!("{}-{}-{}", self.tenant_id, self.cluster_id, self.kind),
+            "0.0.0.0",
+        );
+        session
+            .set_authed_user(user, Some(role.to_string()))
+            .await?;
+        self.sessions
+            .lock()
+            .insert(role.to_string(), session.clone());
+        Ok(session)
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::LiteralStringOrVariable;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_sql::Planner;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Runs the `COPY INTO <table>` statement of a pipe and records the result in the pipe.
+///
+/// The load history of the target table makes sure each file of the stage is loaded only
+/// once, so a load only picks the files that are new since the last one.
+pub struct PipeLoader {
+    ctx: Arc<QueryContext>,
+    pipe: PipeInfo,
+}
+
+impl PipeLoader {
+    pub fn create(ctx: Arc<QueryContext>, pipe: PipeInfo) -> Self {
+        Self { ctx, pipe }
+    }
+
+    /// Loads the given files of the stage location, or all the new files if `files` is `None`.
+    /// Returns the number of files loaded.
+    #[async_backtrace::framed]
+    pub async fn load(&self, files: Option<Vec<String>>) -> Result<u64> {
+        let res = self.copy(files).await;
+        self.record(&res).await?;
+        res
+    }
+
+    /// Loads the files under `prefix` of the stage location which are modified after
+    /// `modified_after`.
+    #[async_backtrace::framed]
+    pub async fn refresh(
+        &self,
+        prefix: Option<String>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<u64> {
+        let files = self.list_files(prefix, modified_after).await?;
+        if files.is_empty() {
+            self.record(&Ok(0)).await?;
+            return Ok(0);
+        }
+        self.load(Some(files)).await
+    }
+
+    fn copy_stmt(&self) -> Result<CopyIntoTableStmt> {
+        let tokens = tokenize_sql(&self.pipe.copy_statement)?;
+        let dialect = self.ctx.get_settings().get_sql_dialect()?;
+        match parse_sql(&tokens, dialect)?.0 {
+            Statement::CopyIntoTable(stmt) => Ok(stmt),
+            _ => Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} is not defined by a COPY INTO <table> statement",
+                self.pipe.name
+            ))),
+        }
+    }
+
+    async fn copy(&self, files: Option<Vec<String>>) -> Result<u64> {
+        let mut stmt = self.copy_stmt()?;
+        if files.is_some() {
+            stmt.files = files;
+        }
+        info!("pipe {} load: {}", self.pipe.name, stmt);
+
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let plan = planner.plan_stmt(&Statement::CopyIntoTable(stmt)).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        // COPY returns one row for each loaded file.
+        Ok(blocks.iter().map(|b| b.num_rows() as u64).sum())
+    }
+
+    async fn list_files(
+        &self,
+        prefix: Option<String>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<Vec<String>> {
+        let stmt = self.copy_stmt()?;
+        let CopyIntoTableSource::Location(FileLocation::Stage(location)) = &stmt.src else {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} must load from a named stage",
+                self.pipe.name
+            )));
+        };
+        let (stage_info, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let pattern = match stmt.pattern {
+            Some(LiteralStringOrVariable::Literal(pattern)) => Some(pattern),
+            _ => None,
+        };
+        let files_info = StageFilesInfo {
+            path: path.clone(),
+            files: None,
+            pattern,
+        };
+        let thread_num = self.ctx.get_settings().get_max_threads()? as usize;
+        let files = files_info.list(&operator, thread_num, None).await?;
+
+        let prefix = prefix.unwrap_or_default();
+        Ok(files
+            .into_iter()
+            .filter(|file| match modified_after {
+                Some(ts) => file.last_modified.is_some_and(|m| m > ts),
+                None => true,
+            })
+            // The files of COPY are relative to the stage location.
+            .filter_map(|file| {
+                file.path
+                    .strip_prefix(path.trim_start_matches('/'))
+                    .map(|file| file.trim_start_matches('/').to_string())
+            })
+            .filter(|file| file.starts_with(prefix.as_str()))
+            .collect())
+    }
+
+    async fn record(&self, res: &Result<u64>) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        let now = Utc::now();
+        let (files, error) = match res {
+            Ok(files) => (*files, None),
+            Err(e) => (0, Some(e.message())),
+        };
+        UserApiProvider::instance()
+            .update_pipe(&tenant, &self.pipe.name, |mut pipe| {
+                pipe.last_load_on = Some(now);
+                pipe.last_load_files = files;
+                pipe.last_load_error = error.clone();
+                pipe
+            })
+            .await
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use log::error;
+use log::info;
+
+use crate::object_scheduler::ObjectScheduler;
+use crate::pipe::PipeLoader;
+use crate::sessions::TableContext;
+
+/// Loads the new files of the auto-ingest pipes.
+///
+/// Every query node polls the stages of the pipes periodically, and the event notifications
+/// of a stage load the notified files at once. A pipe is loaded with the privileges of the
+/// role that owns it, and a meta semaphore of each pipe makes sure that a pipe is loaded by
+/// one node at a time.
+pub struct PipeScheduler {
+    scheduler: ObjectScheduler,
+    interval: Duration,
+}
+
+impl PipeScheduler {
+    pub async fn init(cfg: &InnerConfig) -> Result<()> {
+        let instance = Arc::new(Self {
+            scheduler: ObjectScheduler::create(cfg, "pipe").await?,
+            interval: Duration::from_secs(30),
+        });
+        GlobalInstance::set(instance);
+        GlobalIORuntime::instance().try_spawn(
+            async move {
+                if let Err(e) = PipeScheduler::instance().work().await {
+                    error!("pipe scheduler exit {}", e);
+                }
+            },
+            Some("pipe-scheduler".to_string()),
+        )?;
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PipeScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn initialized(&self) {
+        self.scheduler.initialized();
+    }
+
+    /// Handles an event notification of the stage of a pipe, the notified files, relative to
+    /// the stage location of the pipe, are loaded in background. All the new files are loaded
+    /// if `files` is `None`.
+    pub async fn notify(&self, name: &str, files: Option<Vec<String>>) -> Result<()> {
+        if !self.scheduler.is_initialized() {
+            return Err(ErrorCode::IllegalPipe("pipe scheduler is not initialized"));
+        }
+        let pipe = self.get_pipe(name).await?;
+        if !pipe.auto_ingest || pipe.execution_paused {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} is not an auto-ingest pipe or is paused",
+                name
+            )));
+        }
+
+        GlobalIORuntime::instance().try_spawn(
+            async move {
+                let scheduler = PipeScheduler::instance();
+                if let Err(e) = scheduler.load(pipe, files).await {
+                    error!("pipe notification load failed: {}", e);
+                }
+            },
+            Some("pipe-notification".to_string()),
+        )?;
+        Ok(())
+    }
+
+    async fn work(&self) -> Result<()> {
+        self.scheduler.wait_initialized().await;
+
+        loop {
+            if let Err(e) = self.schedule().await {
+                error!("pipe scheduler failed: {}", e);
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    async fn schedule(&self) -> Result<()> {
+        let session = self.scheduler.admin_session().await?;
+        let ctx = session.create_query_context().await?;
+        let pipes = UserApiProvider::instance()
+            .get_pipes(&ctx.get_tenant())
+            .await?;
+
+        for pipe in pipes {
+            if !pipe.auto_ingest || pipe.execution_paused {
+                continue;
+            }
+            let name = pipe.name.clone();
+            if let Err(e) = self.load(pipe, None).await {
+                error!("pipe {} load failed: {}", name, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_pipe(&self, name: &str) -> Result<PipeInfo> {
+        let session = self.scheduler.admin_session().await?;
+        let ctx = session.create_query_context().await?;
+        UserApiProvider::instance()
+            .get_pipe(&ctx.get_tenant(), name)
+            .await
+    }
+
+    async fn load(&self, pipe: PipeInfo, files: Option<Vec<String>>) -> Result<()> {
+        let _permit = self
+            .scheduler
+            .acquire(&format!("pipe_load/{}", pipe.name), self.interval)
+            .await?;
+        // The pipes created before the owner was recorded are loaded by `account_admin`.
+        let role = match pipe.owner.as_str() {
+            "" => BUILTIN_ROLE_ACCOUNT_ADMIN,
+            owner => owner,
+        };
+        info!("load pipe {} with role {}", pipe.name, role);
+        let session = self.scheduler.session(role).await?;
+        let ctx = session.create_query_context().await?;
+        let name = pipe.name.clone();
+        let files = PipeLoader::create(ctx, pipe).load(files).await?;
+        info!("pipe {} loaded {} files", name, files);
+        Ok(())
+    }
+}
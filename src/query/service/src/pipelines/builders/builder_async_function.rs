@@ -23,7 +23,8 @@ impl PipelineBuilder {
     pub(crate) fn build_async_function(&mut self, async_function: &AsyncFunction) -> Result<()> {
         self.build_pipeline(&async_function.input)?;
 
-        let operators = TransformAsyncFunction::init_operators(&async_function.async_func_descs)?;
+        let operators =
+            TransformAsyncFunction::init_operators(&self.ctx, &async_function.async_func_descs)?;
         self.main_pipeline.add_async_transformer(|| {
             TransformAsyncFunction::new(
                 self.ctx.clone(),
@@ -17,11 +17,14 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::sync::Barrier;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_sinks::Sinker;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::PhysicalPlan;
+use databend_common_storage::DataOperator;
 
+use crate::pipelines::memory_settings::MemorySettingsExt;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinRight;
@@ -33,6 +36,9 @@ use crate::pipelines::processors::HashJoinDesc;
 use crate::pipelines::processors::HashJoinState;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     // Create a new pipeline builder with the same context as the current builder
@@ -178,7 +184,21 @@ impl PipelineBuilder {
     }
 
     pub(crate) fn build_range_join(&mut self, range_join: &RangeJoin) -> Result<()> {
-        let state = Arc::new(RangeJoinState::new(self.ctx.clone(), range_join));
+        let spill_config = SpillerConfig {
+            spiller_type: SpillerType::RangeJoin,
+            location_prefix: self.ctx.query_id_spill_prefix(),
+            disk_spill: None,
+            use_parquet: self.settings.get_spilling_file_format()?.is_parquet(),
+        };
+        let operator = DataOperator::instance().spill_operator();
+        let spiller = Spiller::create(self.ctx.clone(), operator, spill_config)?;
+        let memory_settings = MemorySettings::from_join_settings(&self.ctx)?;
+        let state = Arc::new(RangeJoinState::try_create(
+            self.ctx.clone(),
+            range_join,
+            spiller,
+            memory_settings,
+        )?);
         self.build_range_join_right_side(range_join, state.clone())?;
         self.build_range_join_left_side(range_join, state)?;
         Ok(())
@@ -213,12 +233,10 @@ impl PipelineBuilder {
 
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
-            Ok(ProcessorPtr::create(
-                Sinker::<TransformRangeJoinRight>::create(
-                    input,
-                    TransformRangeJoinRight::create(state.clone()),
-                ),
-            ))
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                TransformRangeJoinRight::create(state.clone()),
+            )))
         })?;
         self.pipelines.push(right_res.main_pipeline.finalize());
         self.pipelines.extend(right_res.sources_pipelines);
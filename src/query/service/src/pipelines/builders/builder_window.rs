@@ -26,6 +26,7 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::executor::physical_plans::Window;
 use databend_common_sql::executor::physical_plans::WindowPartition;
+use databend_common_storage::DataOperator;
 use databend_storages_common_cache::TempDirManager;
 
 use crate::pipelines::memory_settings::MemorySettingsExt;
@@ -38,7 +39,10 @@ use crate::pipelines::processors::transforms::WindowPartitionExchange;
 use crate::pipelines::processors::transforms::WindowPartitionTopNExchange;
 use crate::pipelines::processors::transforms::WindowSortDesc;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
 use crate::spillers::SpillerDiskConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -73,6 +77,25 @@ impl PipelineBuilder {
             self.main_pipeline.try_resize(1)?;
         }
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
+
+        // Spill the blocks of a partition that is too large to be held in memory.
+        let settings = self.ctx.get_settings();
+        let disk_bytes_limit = settings.get_window_partition_spilling_to_disk_bytes_limit()?;
+        let enable_dio = settings.get_enable_dio()?;
+        let disk_spill = TempDirManager::instance()
+            .get_disk_spill_dir(disk_bytes_limit, &self.ctx.get_id())
+            .map(|temp_dir| SpillerDiskConfig::new(temp_dir, enable_dio))
+            .transpose()?;
+        let spill_config = SpillerConfig {
+            spiller_type: SpillerType::Window,
+            location_prefix: self.ctx.query_id_spill_prefix(),
+            disk_spill,
+            use_parquet: settings.get_spilling_file_format()?.is_parquet(),
+        };
+        let operator = DataOperator::instance().spill_operator();
+        let spiller = Spiller::create(self.ctx.clone(), operator, spill_config)?;
+        let memory_settings = MemorySettings::from_window_settings(&self.ctx)?;
+
         // Window
         self.main_pipeline.add_transform(|input, output| {
             // The transform can only be created here, because it cannot be cloned.
@@ -80,14 +103,17 @@ impl PipelineBuilder {
             let transform = if window.window_frame.units.is_rows() {
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u64>::try_create_rows(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u64>::try_create_rows(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_spill(spiller.clone(), memory_settings.clone()),
+                ) as Box<dyn Processor>
             } else {
                 if order_by.len() == 1 {
                     // If the length of order_by is 1, there may be a RANGE frame.
@@ -108,7 +134,8 @@ impl PipelineBuilder {
                                     partition_by.clone(),
                                     order_by.clone(),
                                     (start_bound, end_bound),
-                                )?,
+                                )?
+                                .with_spill(spiller.clone(), memory_settings.clone()),
                             )
                                 as Box<dyn Processor>));
                         }
@@ -120,14 +147,17 @@ impl PipelineBuilder {
                 // So we can use any number type to create the transform.
                 let start_bound = FrameBound::try_from(&window.window_frame.start_bound)?;
                 let end_bound = FrameBound::try_from(&window.window_frame.end_bound)?;
-                Box::new(TransformWindow::<u8>::try_create_range(
-                    input,
-                    output,
-                    func.clone(),
-                    partition_by.clone(),
-                    order_by.clone(),
-                    (start_bound, end_bound),
-                )?) as Box<dyn Processor>
+                Box::new(
+                    TransformWindow::<u8>::try_create_range(
+                        input,
+                        output,
+                        func.clone(),
+                        partition_by.clone(),
+                        order_by.clone(),
+                        (start_bound, end_bound),
+                    )?
+                    .with_spill(spiller.clone(), memory_settings.clone()),
+                ) as Box<dyn Processor>
             };
             Ok(ProcessorPtr::create(transform))
         })?;
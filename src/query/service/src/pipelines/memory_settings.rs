@@ -28,6 +28,8 @@ pub trait MemorySettingsExt: Sized {
     fn from_window_settings(ctx: &QueryContext) -> Result<Self>;
 
     fn from_aggregate_settings(ctx: &QueryContext) -> Result<Self>;
+
+    fn from_recursive_cte_settings(ctx: &QueryContext) -> Result<Self>;
 }
 
 impl MemorySettingsExt for MemorySettings {
@@ -176,6 +178,40 @@ impl MemorySettingsExt for MemorySettings {
             global_memory_tracking: &GLOBAL_MEM_STAT,
         })
     }
+
+    fn from_recursive_cte_settings(ctx: &QueryContext) -> Result<Self> {
+        let settings = ctx.get_settings();
+
+        if settings.get_force_recursive_cte_data_spill()? {
+            return Ok(MemorySettings::always_spill(0));
+        }
+
+        let mut enable_global_level_spill = false;
+        let mut max_memory_usage = settings.get_max_memory_usage()? as usize;
+
+        let max_memory_ratio = settings.get_recursive_cte_spilling_memory_ratio()?;
+        if max_memory_usage != 0 && max_memory_ratio != 0 {
+            enable_global_level_spill = true;
+            let max_memory_ratio = (max_memory_ratio as f64 / 100_f64).min(1_f64);
+            max_memory_usage = (max_memory_usage as f64 * max_memory_ratio) as usize;
+        }
+
+        let max_query_memory_usage = settings.get_max_query_memory_usage()? as usize;
+        let enable_query_level_spill = match settings.get_query_out_of_memory_behavior()? {
+            OutofMemoryBehavior::Throw => false,
+            OutofMemoryBehavior::Spilling => max_query_memory_usage != 0,
+        };
+
+        Ok(MemorySettings {
+            max_memory_usage,
+            max_query_memory_usage,
+            enable_query_level_spill,
+            enable_global_level_spill,
+            spill_unit_size: 0,
+            query_memory_tracking: ctx.get_query_memory_tracking(),
+            global_memory_tracking: &GLOBAL_MEM_STAT,
+        })
+    }
 }
 
 #[cfg(test)]
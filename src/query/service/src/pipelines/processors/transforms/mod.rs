@@ -40,6 +40,7 @@ mod transform_resort_addon_without_source_schema;
 mod transform_srf;
 mod transform_udf_script;
 mod transform_udf_server;
+mod udtf_result;
 mod window;
 
 pub use broadcast::BroadcastSinkProcessor;
@@ -55,6 +56,7 @@ pub use transform_cache_scan::HashJoinCacheState;
 pub use transform_cache_scan::TransformCacheScan;
 pub use transform_cast_schema::TransformCastSchema;
 pub use transform_create_sets::TransformCreateSets;
+pub use transform_dictionary::evict_dictionary_snapshots;
 pub use transform_expression_scan::TransformExpressionScan;
 pub use transform_filter::TransformFilter;
 pub use transform_limit::TransformLimit;
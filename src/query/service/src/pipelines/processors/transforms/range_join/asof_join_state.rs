@@ -0,0 +1,243 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::RangeJoin;
+use databend_common_sql::plans::JoinType;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::pipelines::processors::transforms::range_join::RangeJoinTask;
+
+// Right rows sharing the same equi keys, sorted by the match key in ascending order.
+type AsofGroup = Vec<(Scalar, u32)>;
+
+pub struct AsofJoinState {
+    join_type: JoinType,
+    // Used to fill the right fields of left asof join if the right table is empty.
+    right_data_types: Vec<DataType>,
+    // The whole right table, the matched rows are taken from it.
+    right_block: RwLock<Option<DataBlock>>,
+    // Right rows grouped by the equi keys, rows with null keys never match and are skipped.
+    groups: RwLock<HashMap<Vec<Scalar>, AsofGroup>>,
+    // Used to evaluate the join keys with the settings (e.g. timezone) of the query.
+    func_ctx: FunctionContext,
+}
+
+impl AsofJoinState {
+    pub(crate) fn try_create(range_join: &RangeJoin, func_ctx: FunctionContext) -> Result<Self> {
+        let right_data_types = range_join
+            .right
+            .output_schema()?
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        Ok(AsofJoinState {
+            join_type: range_join.join_type.clone(),
+            right_data_types,
+            right_block: RwLock::new(None),
+            groups: RwLock::new(HashMap::new()),
+            func_ctx,
+        })
+    }
+}
+
+impl RangeJoinState {
+    // Index the right table, every left block is probed by a single task.
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        // The right payloads are moved into the index, they are never spilled.
+        let right_table = std::mem::take(&mut *self.right_table.write());
+        if !right_table.is_empty() {
+            let right_block = DataBlock::concat(&right_table)?;
+            let keys = evaluate_keys(
+                &asof_join_state.func_ctx,
+                &right_block,
+                self.conditions
+                    .iter()
+                    .map(|condition| &condition.right_expr),
+            )?;
+
+            let mut groups: HashMap<Vec<Scalar>, AsofGroup> = HashMap::new();
+            for row in 0..right_block.num_rows() {
+                if let Some((match_key, equi_keys)) = row_keys(&keys, row) {
+                    groups
+                        .entry(equi_keys)
+                        .or_default()
+                        .push((match_key.to_owned(), row as u32));
+                }
+            }
+            for group in groups.values_mut() {
+                group.sort_by(|(left, _), (right, _)| left.cmp(right));
+            }
+
+            *asof_join_state.groups.write() = groups;
+            *asof_join_state.right_block.write() = Some(right_block);
+        }
+
+        let left_table = self.left_table.read();
+        let mut tasks = self.tasks.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+        }
+        Ok(())
+    }
+
+    pub fn asof_join(&self, task: &RangeJoinTask) -> Result<Vec<DataBlock>> {
+        let asof_join_state = self.asof_join_state.as_ref().unwrap();
+        let left_block = task.left.clone();
+        let num_rows = left_block.num_rows();
+
+        let keys = evaluate_keys(
+            &asof_join_state.func_ctx,
+            &left_block,
+            self.conditions.iter().map(|condition| &condition.left_expr),
+        )?;
+        let operator = self.conditions[0].operator.as_str();
+        let groups = asof_join_state.groups.read();
+        let matched = (0..num_rows)
+            .map(|row| {
+                let (match_key, equi_keys) = row_keys(&keys, row)?;
+                let group = groups.get(&equi_keys)?;
+                closest_row(group, &match_key, operator)
+            })
+            .collect::<Vec<_>>();
+
+        let right_block = asof_join_state.right_block.read();
+        let result_block = match asof_join_state.join_type {
+            JoinType::LeftAsof => {
+                let mut result_block = left_block;
+                match right_block.as_ref() {
+                    Some(right_block) => {
+                        for entry in right_block.columns() {
+                            result_block.add_column(take_nullable(entry, &matched, num_rows));
+                        }
+                    }
+                    None => {
+                        for data_type in asof_join_state.right_data_types.iter() {
+                            result_block.add_column(BlockEntry::new(
+                                data_type.wrap_nullable(),
+                                Value::Scalar(Scalar::Null),
+                            ));
+                        }
+                    }
+                }
+                result_block
+            }
+            _ => {
+                let Some(right_block) = right_block.as_ref() else {
+                    return Ok(vec![]);
+                };
+                let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = matched
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(left, right)| right.map(|right| (left as u32, right)))
+                    .unzip();
+                if left_indices.is_empty() {
+                    return Ok(vec![]);
+                }
+                let mut result_block = left_block.take(&left_indices)?;
+                for entry in right_block.take(&right_indices)?.columns() {
+                    result_block.add_column(entry.clone());
+                }
+                result_block
+            }
+        };
+        Ok(vec![result_block])
+    }
+}
+
+fn evaluate_keys<'a>(
+    func_ctx: &FunctionContext,
+    block: &DataBlock,
+    exprs: impl Iterator<Item = &'a RemoteExpr>,
+) -> Result<Vec<Column>> {
+    let evaluator = Evaluator::new(block, func_ctx, &BUILTIN_FUNCTIONS);
+    exprs
+        .map(|expr| {
+            let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+            Ok(evaluator
+                .run(&expr)?
+                .convert_to_full_column(expr.data_type(), block.num_rows()))
+        })
+        .collect()
+}
+
+// The first key is the match key, the others are the equi keys.
+// Returns `None` if any of the keys is null.
+fn row_keys(keys: &[Column], row: usize) -> Option<(ScalarRef, Vec<Scalar>)> {
+    let match_key = keys[0].index(row).filter(|key| !key.is_null())?;
+    let equi_keys = keys[1..]
+        .iter()
+        .map(|column| {
+            column
+                .index(row)
+                .filter(|key| !key.is_null())
+                .map(|key| key.to_owned())
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((match_key, equi_keys))
+}
+
+// Find the closest right row of the left key, `group` is sorted in ascending order.
+fn closest_row(group: &AsofGroup, key: &ScalarRef, operator: &str) -> Option<u32> {
+    match operator {
+        // left >= right: the largest right key that is not greater than the left key
+        "gte" => {
+            let pos = group.partition_point(|(right, _)| right.as_ref() <= *key);
+            pos.checked_sub(1).map(|pos| group[pos].1)
+        }
+        "gt" => {
+            let pos = group.partition_point(|(right, _)| right.as_ref() < *key);
+            pos.checked_sub(1).map(|pos| group[pos].1)
+        }
+        // left <= right: the smallest right key that is not less than the left key
+        "lte" => {
+            let pos = group.partition_point(|(right, _)| right.as_ref() < *key);
+            group.get(pos).map(|(_, row)| *row)
+        }
+        "lt" => {
+            let pos = group.partition_point(|(right, _)| right.as_ref() <= *key);
+            group.get(pos).map(|(_, row)| *row)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn take_nullable(entry: &BlockEntry, matched: &[Option<u32>], num_rows: usize) -> BlockEntry {
+    let data_type = entry.data_type.wrap_nullable();
+    let mut builder = ColumnBuilder::with_capacity(&data_type, num_rows);
+    for row in matched.iter() {
+        match row.and_then(|row| entry.value.index(row as usize)) {
+            Some(scalar) => builder.push(scalar),
+            None => builder.push(ScalarRef::Null),
+        }
+    }
+    BlockEntry::new(data_type, Value::Column(builder.build()))
+}
@@ -39,6 +39,7 @@ use crate::pipelines::processors::transforms::range_join::filter_block;
 use crate::pipelines::processors::transforms::range_join::order_match;
 use crate::pipelines::processors::transforms::range_join::probe_l1;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::pipelines::processors::transforms::range_join::RangeJoinTask;
 
 pub struct IEJoinState {
     l1_data_type: DataType,
@@ -170,10 +171,10 @@ impl IEJoinState {
 }
 
 impl RangeJoinState {
-    pub fn ie_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+    pub fn ie_join(&self, task: &RangeJoinTask) -> Result<Vec<DataBlock>> {
         let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
         let tasks = self.tasks.read();
-        let (left_idx, right_idx) = tasks[task_id];
+        let (left_idx, right_idx) = tasks[task.id];
         let ie_join_state = self.ie_join_state.as_ref().unwrap();
         let left_sorted_blocks = self.left_sorted_blocks.read();
         let right_sorted_blocks = self.right_sorted_blocks.read();
@@ -287,7 +288,7 @@ impl RangeJoinState {
             l1_index_column,
             &p_array,
             bit_array,
-            task_id,
+            task,
         )?])
     }
 
@@ -298,13 +299,11 @@ impl RangeJoinState {
         l1_index_column: Column,
         p_array: &[u64],
         mut bit_array: MutableBitmap,
-        task_id: usize,
+        task: &RangeJoinTask,
     ) -> Result<DataBlock> {
         let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
         let row_offset = self.row_offset.read();
-        let (left_offset, right_offset) = row_offset[task_id];
-        let tasks = self.tasks.read();
-        let (left_idx, right_idx) = tasks[task_id];
+        let (left_offset, right_offset) = row_offset[task.id];
         let len = p_array.len();
         let mut left_buffer = Vec::with_capacity(block_size);
         let mut right_buffer = Vec::with_capacity(block_size);
@@ -359,23 +358,18 @@ impl RangeJoinState {
         if left_buffer.is_empty() {
             return Ok(DataBlock::empty());
         }
-        let left_table = self.left_table.read();
-        let right_table = self.right_table.read();
         let mut indices = Vec::with_capacity(left_buffer.len());
         for res in left_buffer.iter() {
             indices.push((0u32, *res as u32, 1usize));
         }
         let mut left_result_block =
-            DataBlock::take_blocks(&left_table[left_idx..left_idx + 1], &indices, indices.len());
+            DataBlock::take_blocks(std::slice::from_ref(&task.left), &indices, indices.len());
         indices.clear();
         for res in right_buffer.iter() {
             indices.push((0u32, *res as u32, 1usize));
         }
-        let right_result_block = DataBlock::take_blocks(
-            &right_table[right_idx..right_idx + 1],
-            &indices,
-            indices.len(),
-        );
+        let right_result_block =
+            DataBlock::take_blocks(std::slice::from_ref(&task.right), &indices, indices.len());
         // Merge left_result_block and right_result_block
         for col in right_result_block.columns() {
             left_result_block.add_column(col.clone());
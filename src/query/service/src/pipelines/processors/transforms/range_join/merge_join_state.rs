@@ -23,11 +23,12 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use crate::pipelines::processors::transforms::range_join::filter_block;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::pipelines::processors::transforms::range_join::RangeJoinTask;
 
 impl RangeJoinState {
-    pub fn range_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+    pub fn range_join(&self, task: &RangeJoinTask) -> Result<Vec<DataBlock>> {
         let tasks = self.tasks.read();
-        let (left_idx, right_idx) = tasks[task_id];
+        let (left_idx, right_idx) = tasks[task.id];
         let left_sorted_blocks = self.left_sorted_blocks.read();
         let right_sorted_blocks = self.right_sorted_blocks.read();
 
@@ -72,11 +73,9 @@ impl RangeJoinState {
         let mut j = 0;
 
         let row_offset = self.row_offset.read();
-        let (left_offset, right_offset) = row_offset[task_id];
+        let (left_offset, right_offset) = row_offset[task.id];
 
         let mut result_blocks = Vec::with_capacity(left_len);
-        let left_table = self.left_table.read();
-        let right_table = self.right_table.read();
 
         while i < left_len {
             if j == right_len {
@@ -98,7 +97,7 @@ impl RangeJoinState {
                 if let ScalarRef::Number(NumberScalar::Int64(left)) =
                     unsafe { left_idx_col.index_unchecked(i) }
                 {
-                    left_result_block = left_table[left_idx].take_compacted_indices(
+                    left_result_block = task.left.take_compacted_indices(
                         &[(
                             ((left - 1) as usize - left_offset) as u32,
                             (right_len - j) as u32,
@@ -119,7 +118,7 @@ impl RangeJoinState {
                         indices.push((0u32, *res as u32, 1usize));
                     }
                     let right_result_block = DataBlock::take_blocks(
-                        &right_table[right_idx..right_idx + 1],
+                        std::slice::from_ref(&task.right),
                         &indices,
                         indices.len(),
                     );
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::atomic;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
@@ -21,13 +22,15 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockEntry;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Evaluator;
-use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::physical_plans::RangeJoinCondition;
 use databend_common_sql::executor::physical_plans::RangeJoinType;
@@ -35,8 +38,19 @@ use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::pipelines::executor::WatchNotify;
+use crate::pipelines::processors::transforms::range_join::AsofJoinState;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
 use crate::sessions::QueryContext;
+use crate::spillers::Location;
+use crate::spillers::Spiller;
+
+/// A task joins a left block with a right block, the payload blocks are
+/// restored from the spill storage if they have been spilled.
+pub struct RangeJoinTask {
+    pub(crate) id: usize,
+    pub(crate) left: DataBlock,
+    pub(crate) right: DataBlock,
+}
 
 pub struct RangeJoinState {
     pub(crate) ctx: Arc<QueryContext>,
@@ -61,17 +75,38 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // AsofJoin state
+    pub(crate) asof_join_state: Option<AsofJoinState>,
+    // The payload of left/right table is spilled under memory pressure,
+    // the key blocks are always kept in memory.
+    pub(crate) spiller: Spiller,
+    pub(crate) memory_settings: MemorySettings,
+    pub(crate) left_spilled: RwLock<HashMap<usize, Location>>,
+    pub(crate) right_spilled: RwLock<HashMap<usize, Location>>,
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        range_join: &RangeJoin,
+        spiller: Spiller,
+        memory_settings: MemorySettings,
+    ) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
+        let asof_join_state = if matches!(range_join.range_join_type, RangeJoinType::Asof) {
+            Some(AsofJoinState::try_create(
+                range_join,
+                ctx.get_function_context()?,
+            )?)
+        } else {
+            None
+        };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
@@ -88,20 +123,99 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
-        }
+            asof_join_state,
+            spiller,
+            memory_settings,
+            left_spilled: Default::default(),
+            right_spilled: Default::default(),
+        })
     }
 
     pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
         // Sink block to right table
-        let mut right_table = self.right_table.write();
-        right_table.push(block);
-        Ok(())
+        self.sink(block, false)
     }
 
     pub(crate) fn sink_left(&self, block: DataBlock) -> Result<()> {
         // Sink block to left table
-        let mut left_table = self.left_table.write();
-        left_table.push(block);
+        self.sink(block, true)
+    }
+
+    fn sink(&self, block: DataBlock, is_left: bool) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        // Asof join indexes the whole right table, it doesn't need key blocks.
+        let keys_block = match self.asof_join_state {
+            Some(_) => None,
+            None => Some(self.keys_block(&block, is_left)?),
+        };
+        let (table, sorted_blocks) = match is_left {
+            true => (&self.left_table, &self.left_sorted_blocks),
+            false => (&self.right_table, &self.right_sorted_blocks),
+        };
+        // Hold both locks, the payload and the key block must share the same index.
+        let mut table = table.write();
+        let mut sorted_blocks = sorted_blocks.write();
+        table.push(block);
+        if let Some(keys_block) = keys_block {
+            sorted_blocks.push(keys_block);
+        }
+        Ok(())
+    }
+
+    // Generate keys block by join keys
+    // For example, if join keys are [t1.a + t2.b, t1.c], then key blocks will contain two columns: [t1.a + t2.b, t1.c]
+    // We can get the key blocks by evaluating the join keys expressions on the block
+    fn keys_block(&self, block: &DataBlock, is_left: bool) -> Result<DataBlock> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let mut columns = Vec::with_capacity(3);
+        // Append join keys columns
+        for condition in self.conditions.iter() {
+            let expr = match is_left {
+                true => condition.left_expr.as_expr(&BUILTIN_FUNCTIONS),
+                false => condition.right_expr.as_expr(&BUILTIN_FUNCTIONS),
+            };
+            let column = evaluator
+                .run(&expr)?
+                .convert_to_full_column(expr.data_type(), block.num_rows());
+            columns.push(column);
+        }
+        Ok(DataBlock::new_from_columns(columns))
+    }
+
+    pub(crate) fn need_spill(&self, is_left: bool) -> bool {
+        // Asof join indexes the whole right table into a single in-memory block
+        // when partitioning, so only its left payloads are spilled and restored
+        // by the probe tasks.
+        if (!is_left && self.asof_join_state.is_some()) || !self.memory_settings.check_spill() {
+            return false;
+        }
+        let table = match is_left {
+            true => self.left_table.read(),
+            false => self.right_table.read(),
+        };
+        table.iter().any(|block| !block.is_empty())
+    }
+
+    // Spill the payload blocks of one side that are still in memory, an empty block is left in place.
+    pub(crate) async fn spill(&self, is_left: bool) -> Result<()> {
+        let (table, spilled) = match is_left {
+            true => (&self.left_table, &self.left_spilled),
+            false => (&self.right_table, &self.right_spilled),
+        };
+        let blocks = table
+            .write()
+            .iter_mut()
+            .enumerate()
+            .filter(|(_, block)| !block.is_empty())
+            .map(|(idx, block)| (idx, std::mem::replace(block, DataBlock::empty())))
+            .collect::<Vec<_>>();
+        for (idx, block) in blocks {
+            let location = self.spiller.spill(vec![block]).await?;
+            spilled.write().insert(idx, location);
+        }
         Ok(())
     }
 
@@ -131,6 +245,44 @@ impl RangeJoinState {
         *right_sinker_count += 1;
     }
 
+    pub(crate) fn is_spilled_task(&self, task_id: usize) -> bool {
+        let (left_idx, right_idx) = self.tasks.read()[task_id];
+        self.left_spilled.read().contains_key(&left_idx)
+            || (self.asof_join_state.is_none()
+                && self.right_spilled.read().contains_key(&right_idx))
+    }
+
+    pub(crate) fn task(&self, task_id: usize) -> RangeJoinTask {
+        let (left_idx, right_idx) = self.tasks.read()[task_id];
+        let left = self.left_table.read()[left_idx].clone();
+        // Asof join takes the matched rows from the whole right table.
+        let right = match self.asof_join_state {
+            Some(_) => DataBlock::empty(),
+            None => self.right_table.read()[right_idx].clone(),
+        };
+        RangeJoinTask {
+            id: task_id,
+            left,
+            right,
+        }
+    }
+
+    pub(crate) async fn restore_task(&self, task_id: usize) -> Result<RangeJoinTask> {
+        let mut task = self.task(task_id);
+        let (left_idx, right_idx) = self.tasks.read()[task_id];
+        let location = self.left_spilled.read().get(&left_idx).cloned();
+        if let Some(location) = location {
+            task.left = self.spiller.read_spilled_file(&location).await?;
+        }
+        if self.asof_join_state.is_none() {
+            let location = self.right_spilled.read().get(&right_idx).cloned();
+            if let Some(location) = location {
+                task.right = self.spiller.read_spilled_file(&location).await?;
+            }
+        }
+        Ok(task)
+    }
+
     pub fn task_id(&self) -> Option<usize> {
         let task_id = self.finished_tasks.fetch_add(1, atomic::Ordering::SeqCst);
         if task_id >= self.tasks.read().len() as u64 {
@@ -172,13 +324,21 @@ impl RangeJoinState {
     }
 
     pub(crate) fn partition(&self) -> Result<()> {
+        if self.asof_join_state.is_some() {
+            return self.asof_partition();
+        }
+
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let left_table = self.left_table.read();
         // Right table is bigger than left table
         let mut right_table = self.right_table.write();
+        let mut left_sorted_blocks = self.left_sorted_blocks.write();
+        let mut right_sorted_blocks = self.right_sorted_blocks.write();
+        // The spilled right blocks are kept as they are, their indexes are referenced by the spill locations.
         if !left_table.is_empty()
             && !right_table.is_empty()
             && left_table.len() * right_table.len() < max_threads
+            && self.right_spilled.read().is_empty()
         {
             let num_parts = max_threads / left_table.len() + 1;
             // Spit right_table to num_parts equally
@@ -189,74 +349,45 @@ impl RangeJoinState {
             }
             let scatter_blocks = DataBlock::scatter(&merged_right_table, &indices, num_parts)?;
             right_table.clear();
+            right_sorted_blocks.clear();
             for block in scatter_blocks.iter() {
                 if !block.is_empty() {
+                    right_sorted_blocks.push(self.keys_block(block, false)?);
                     right_table.push(block.clone());
                 }
             }
         }
 
-        let mut left_sorted_blocks = self.left_sorted_blocks.write();
-        let mut right_sorted_blocks = self.right_sorted_blocks.write();
-
         let mut current_rows = 0;
-        for left_block in left_table.iter() {
-            // Generate keys block by join keys
-            // For example, if join keys are [t1.a + t2.b, t1.c], then key blocks will contain two columns: [t1.a + t2.b, t1.c]
-            // We can get the key blocks by evaluating the join keys expressions on the block
-            let mut columns = Vec::with_capacity(3);
-            // Append join keys columns
-            for condition in self.conditions.iter() {
-                let func_ctx = FunctionContext::default();
-                let evaluator = Evaluator::new(left_block, &func_ctx, &BUILTIN_FUNCTIONS);
-                let expr = condition.left_expr.as_expr(&BUILTIN_FUNCTIONS);
-                let column = evaluator
-                    .run(&expr)?
-                    .convert_to_full_column(expr.data_type(), left_block.num_rows());
-                columns.push(column);
-            }
+        for keys_block in left_sorted_blocks.iter_mut() {
             // Generate idx column from current_rows to current_rows + block.num_rows()
-            let mut column_builder = ColumnBuilder::with_capacity(
-                &DataType::Number(NumberDataType::Int64),
-                left_block.num_rows(),
-            );
-            for idx in current_rows..(current_rows + left_block.num_rows()) {
+            let num_rows = keys_block.num_rows();
+            let mut column_builder =
+                ColumnBuilder::with_capacity(&DataType::Number(NumberDataType::Int64), num_rows);
+            for idx in current_rows..(current_rows + num_rows) {
                 column_builder.push(ScalarRef::Number(NumberScalar::Int64((idx + 1) as i64)));
             }
-            columns.push(column_builder.build());
-            let keys_block = DataBlock::new_from_columns(columns);
-            left_sorted_blocks.push(keys_block);
-            current_rows += left_block.num_rows();
+            keys_block.add_column(BlockEntry::new(
+                DataType::Number(NumberDataType::Int64),
+                Value::Column(column_builder.build()),
+            ));
+            current_rows += num_rows;
         }
 
         current_rows = 0;
-        for right_block in right_table.iter() {
-            // Generate keys block by join keys
-            // For example, if join keys are [t1.a + t2.b, t1.c], then key blocks will contain two columns: [t1.a + t2.b, t1.c]
-            // We can get the key blocks by evaluating the join keys expressions on the block
-            let mut columns = Vec::with_capacity(3);
-            // Append join keys columns
-            for condition in self.conditions.iter() {
-                let func_ctx = FunctionContext::default();
-                let evaluator = Evaluator::new(right_block, &func_ctx, &BUILTIN_FUNCTIONS);
-                let expr = condition.right_expr.as_expr(&BUILTIN_FUNCTIONS);
-                let column = evaluator
-                    .run(&expr)?
-                    .convert_to_full_column(expr.data_type(), right_block.num_rows());
-                columns.push(column);
-            }
+        for keys_block in right_sorted_blocks.iter_mut() {
             // Generate idx column from current_rows to current_rows + block.num_rows()
-            let mut column_builder = ColumnBuilder::with_capacity(
-                &DataType::Number(NumberDataType::Int64),
-                right_block.num_rows(),
-            );
-            for idx in current_rows..(current_rows + right_block.num_rows()) {
+            let num_rows = keys_block.num_rows();
+            let mut column_builder =
+                ColumnBuilder::with_capacity(&DataType::Number(NumberDataType::Int64), num_rows);
+            for idx in current_rows..(current_rows + num_rows) {
                 column_builder.push(ScalarRef::Number(NumberScalar::Int64(-(idx as i64 + 1))));
             }
-            columns.push(column_builder.build());
-            let keys_block = DataBlock::new_from_columns(columns);
-            right_sorted_blocks.push(keys_block);
-            current_rows += right_block.num_rows();
+            keys_block.add_column(BlockEntry::new(
+                DataType::Number(NumberDataType::Int64),
+                Value::Column(column_builder.build()),
+            ));
+            current_rows += num_rows;
         }
         // Add tasks
         let mut row_offset = self.row_offset.write();
@@ -22,12 +22,15 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
-use databend_common_pipeline_sinks::Sink;
+use databend_common_pipeline_sinks::AsyncSink;
 
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+use crate::pipelines::processors::transforms::range_join::RangeJoinTask;
 
 enum RangeJoinStep {
     Sink,
+    // Spill the left table under memory pressure
+    Spill,
     Merging,
     // Execute ie_join algo,
     Execute,
@@ -41,6 +44,9 @@ pub struct TransformRangeJoinLeft {
     state: Arc<RangeJoinState>,
     step: RangeJoinStep,
     execute_finished: bool,
+    // The task to be executed, and the id of the task whose blocks need to be restored first
+    task: Option<RangeJoinTask>,
+    spilled_task: Option<usize>,
 }
 
 impl TransformRangeJoinLeft {
@@ -58,6 +64,8 @@ impl TransformRangeJoinLeft {
             state: ie_join_state,
             step: RangeJoinStep::Sink,
             execute_finished: false,
+            task: None,
+            spilled_task: None,
         })
     }
 }
@@ -67,6 +75,8 @@ impl Processor for TransformRangeJoinLeft {
     fn name(&self) -> String {
         if self.state.ie_join_state.is_some() {
             "TransformIEJoinLeft".to_string()
+        } else if self.state.asof_join_state.is_some() {
+            "TransformAsofJoinLeft".to_string()
         } else {
             "TransformMergeJoinLeft".to_string()
         }
@@ -82,6 +92,10 @@ impl Processor for TransformRangeJoinLeft {
                 if self.input_data.is_some() {
                     return Ok(Event::Sync);
                 }
+                if self.state.need_spill(true) {
+                    self.step = RangeJoinStep::Spill;
+                    return Ok(Event::Async);
+                }
                 if self.input_port.is_finished() {
                     self.state.left_detach()?;
                     self.step = RangeJoinStep::Merging;
@@ -113,12 +127,25 @@ impl Processor for TransformRangeJoinLeft {
                     return Ok(Event::NeedConsume);
                 }
 
+                if self.task.is_some() {
+                    return Ok(Event::Sync);
+                }
+
                 if !self.execute_finished {
-                    Ok(Event::Sync)
-                } else {
-                    self.output_port.finish();
-                    Ok(Event::Finished)
+                    match self.state.task_id() {
+                        Some(task_id) if self.state.is_spilled_task(task_id) => {
+                            self.spilled_task = Some(task_id);
+                            return Ok(Event::Async);
+                        }
+                        Some(task_id) => {
+                            self.task = Some(self.state.task(task_id));
+                            return Ok(Event::Sync);
+                        }
+                        None => self.execute_finished = true,
+                    }
                 }
+                self.output_port.finish();
+                Ok(Event::Finished)
             }
             _ => unreachable!(),
         }
@@ -132,19 +159,20 @@ impl Processor for TransformRangeJoinLeft {
                 }
             }
             RangeJoinStep::Execute => {
-                let task_id = self.state.task_id();
-                if let Some(task_id) = task_id {
-                    let res = match self.state.ie_join_state {
-                        Some(ref _ie_join_state) => self.state.ie_join(task_id)?,
-                        None => self.state.range_join(task_id)?,
+                if let Some(task) = self.task.take() {
+                    let res = if self.state.asof_join_state.is_some() {
+                        self.state.asof_join(&task)?
+                    } else {
+                        match self.state.ie_join_state {
+                            Some(ref _ie_join_state) => self.state.ie_join(&task)?,
+                            None => self.state.range_join(&task)?,
+                        }
                     };
                     for block in res {
                         if !block.is_empty() {
                             self.output_data_blocks.push_back(block);
                         }
                     }
-                } else {
-                    self.execute_finished = true;
                 }
             }
             _ => unreachable!(),
@@ -154,9 +182,21 @@ impl Processor for TransformRangeJoinLeft {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        if let RangeJoinStep::Merging = self.step {
-            self.state.wait_merge_finish().await?;
-            self.step = RangeJoinStep::Execute;
+        match self.step {
+            RangeJoinStep::Spill => {
+                self.state.spill(true).await?;
+                self.step = RangeJoinStep::Sink;
+            }
+            RangeJoinStep::Merging => {
+                self.state.wait_merge_finish().await?;
+                self.step = RangeJoinStep::Execute;
+            }
+            RangeJoinStep::Execute => {
+                if let Some(task_id) = self.spilled_task.take() {
+                    self.task = Some(self.state.restore_task(task_id).await?);
+                }
+            }
+            _ => unreachable!(),
         }
         Ok(())
     }
@@ -175,15 +215,22 @@ impl TransformRangeJoinRight {
     }
 }
 
-impl Sink for TransformRangeJoinRight {
+#[async_trait::async_trait]
+impl AsyncSink for TransformRangeJoinRight {
     const NAME: &'static str = "TransformRangeJoinRight";
 
-    fn on_finish(&mut self) -> Result<()> {
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
         self.state.right_detach()?;
         Ok(())
     }
 
-    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
-        self.state.sink_right(data_block)
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        self.state.sink_right(data_block)?;
+        if self.state.need_spill(false) {
+            self.state.spill(false).await?;
+        }
+        Ok(false)
     }
 }
@@ -17,6 +17,9 @@ use std::collections::HashMap;
 use std::collections::HashSet;
 use std::string::String;
 use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use std::time::Instant;
 
 use databend_common_column::bitmap::Bitmap;
 use databend_common_exception::ErrorCode;
@@ -39,7 +42,12 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_sql::Planner;
+use futures_util::TryStreamExt;
 use jiff::tz::TimeZone;
+use log::warn;
+use parking_lot::Mutex;
 use redis::aio::ConnectionManager;
 use redis::AsyncCommands;
 use redis::Client;
@@ -47,12 +55,21 @@ use redis::ConnectionInfo;
 use redis::ProtocolVersion;
 use redis::RedisConnectionInfo;
 use sqlx::MySqlPool;
+use sqlx::PgPool;
+use tokio::sync::OnceCell;
 
+use crate::interpreters::InterpreterFactory;
 use crate::pipelines::processors::transforms::TransformAsyncFunction;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
 use crate::sql::executor::physical_plans::AsyncFunctionDesc;
 use crate::sql::plans::AsyncFunctionArgument;
+use crate::sql::plans::DatabendSource;
 use crate::sql::plans::DictGetFunctionArgument;
 use crate::sql::plans::DictionarySource;
+use crate::sql::plans::HttpSource;
 use crate::sql::plans::SqlSource;
 use crate::sql::IndexType;
 
@@ -159,19 +176,64 @@ macro_rules! fetch_all_rows_by_sqlx {
     };
 }
 
+// The key of the snapshot is formatted as a string, and the value is cast to the attribute type.
+type DictionaryValues = HashMap<String, Scalar>;
+
+struct DictionarySnapshot {
+    values: Arc<DictionaryValues>,
+    loaded_at: Instant,
+    refreshing: bool,
+}
+
+struct DictionarySnapshots {
+    meta_seq: u64,
+    // Keyed by the value field and the key and value types of the dict_get function.
+    snapshots: HashMap<String, DictionarySnapshot>,
+}
+
+// Local snapshots of the dictionaries with LIFETIME keyed by the dictionary id,
+// shared by all queries of this node.
+static DICTIONARY_SNAPSHOTS: LazyLock<Mutex<HashMap<u64, DictionarySnapshots>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Evicts the local snapshots of the dictionary, called when it is dropped or replaced.
+pub fn evict_dictionary_snapshots(dict_id: u64) {
+    DICTIONARY_SNAPSHOTS.lock().remove(&dict_id);
+}
+
 pub(crate) enum DictionaryOperator {
     Redis(ConnectionManager),
     Mysql(MySqlPool),
+    Postgresql(PgPool),
+    // The whole HTTP and Databend sources are loaded once by each query if there is no LIFETIME.
+    Http {
+        client: reqwest::Client,
+        values: OnceCell<Arc<DictionaryValues>>,
+    },
+    // The source is read in a dedicated session of the query user, so the background
+    // refresh of the local snapshot does not keep the query context alive.
+    Databend {
+        user: UserInfo,
+        role: Option<String>,
+        values: OnceCell<Arc<DictionaryValues>>,
+    },
 }
 
 impl DictionaryOperator {
     async fn dict_get(
-        &self,
+        self: &Arc<Self>,
         value: &Value<AnyType>,
+        key_type: &DataType,
         data_type: &DataType,
         dict_arg: &DictGetFunctionArgument,
     ) -> Result<Value<AnyType>> {
-        match self {
+        if let Some(lifetime) = dict_arg.lifetime {
+            let values = self
+                .get_snapshot_values(lifetime, key_type, data_type, dict_arg)
+                .await?;
+            return Self::lookup_values(&values, value, data_type, &dict_arg.default_value);
+        }
+        match self.as_ref() {
             DictionaryOperator::Redis(connection) => match value {
                 Value::Scalar(scalar) => {
                     self.get_scalar_value_from_redis(scalar, connection, &dict_arg.default_value)
@@ -225,6 +287,362 @@ impl DictionaryOperator {
                     }
                 }
             }
+            DictionaryOperator::Postgresql(pool) => {
+                let sql_source = dict_arg.dict_source.as_postgresql().unwrap();
+                let keys = match value {
+                    Value::Scalar(scalar) => vec![scalar.as_ref()],
+                    Value::Column(column) => column.iter().collect(),
+                };
+                let keys = keys
+                    .into_iter()
+                    .filter(|key| *key != ScalarRef::Null)
+                    .map(Self::format_postgresql_key)
+                    .collect::<HashSet<_>>();
+                let values = if keys.is_empty() {
+                    DictionaryValues::new()
+                } else {
+                    let sql = format!(
+                        "SELECT CAST({} AS TEXT), CAST({} AS TEXT) FROM {} WHERE {} IN ({})",
+                        sql_source.key_field,
+                        sql_source.value_field,
+                        sql_source.table,
+                        sql_source.key_field,
+                        keys.into_iter().collect::<Vec<_>>().join(",")
+                    );
+                    let rows = sqlx::query_as(&sql).fetch_all(pool).await?;
+                    Self::build_values(rows, key_type, data_type)?
+                };
+                Self::lookup_values(&values, value, data_type, &dict_arg.default_value)
+            }
+            DictionaryOperator::Http { values, .. }
+            | DictionaryOperator::Databend { values, .. } => {
+                let values = values
+                    .get_or_try_init(|| async {
+                        let values = self.load_values(key_type, data_type, dict_arg).await?;
+                        Ok::<_, ErrorCode>(Arc::new(values))
+                    })
+                    .await?;
+                Self::lookup_values(values, value, data_type, &dict_arg.default_value)
+            }
+        }
+    }
+
+    // Returns the local snapshot of the dictionary, the expired snapshot is still returned
+    // while it is refreshed in the background, so a slow source does not stall the queries.
+    async fn get_snapshot_values(
+        self: &Arc<Self>,
+        lifetime: u64,
+        key_type: &DataType,
+        value_type: &DataType,
+        dict_arg: &DictGetFunctionArgument,
+    ) -> Result<Arc<DictionaryValues>> {
+        let dict_id = dict_arg.dict_id;
+        let meta_seq = dict_arg.dict_meta_seq;
+        let snapshot_key = format!("{:?}/{}/{}", dict_arg.dict_source, key_type, value_type);
+        let cacheable = {
+            let mut dictionaries = DICTIONARY_SNAPSHOTS.lock();
+            let dictionary = dictionaries
+                .entry(dict_id)
+                .or_insert_with(|| DictionarySnapshots {
+                    meta_seq,
+                    snapshots: HashMap::new(),
+                });
+            if dictionary.meta_seq < meta_seq {
+                // The dictionary is replaced on another node, the snapshots of the old one are stale.
+                dictionary.meta_seq = meta_seq;
+                dictionary.snapshots.clear();
+            }
+            // The query bound to an older version of the dictionary does not cache its values.
+            if dictionary.meta_seq > meta_seq {
+                false
+            } else if let Some(snapshot) = dictionary.snapshots.get_mut(&snapshot_key) {
+                if !snapshot.refreshing
+                    && snapshot.loaded_at.elapsed() >= Duration::from_secs(lifetime)
+                {
+                    snapshot.refreshing = true;
+                    let operator = self.clone();
+                    let key_type = key_type.clone();
+                    let value_type = value_type.clone();
+                    let dict_arg = dict_arg.clone();
+                    let snapshot_key = snapshot_key.clone();
+                    databend_common_base::runtime::spawn(async move {
+                        let values = operator
+                            .load_values(&key_type, &value_type, &dict_arg)
+                            .await;
+                        let mut dictionaries = DICTIONARY_SNAPSHOTS.lock();
+                        let Some(dictionary) = dictionaries.get_mut(&dict_arg.dict_id) else {
+                            return;
+                        };
+                        if dictionary.meta_seq != dict_arg.dict_meta_seq {
+                            return;
+                        }
+                        if let Some(snapshot) = dictionary.snapshots.get_mut(&snapshot_key) {
+                            snapshot.refreshing = false;
+                            match values {
+                                Ok(values) => {
+                                    snapshot.values = Arc::new(values);
+                                    snapshot.loaded_at = Instant::now();
+                                }
+                                Err(cause) => {
+                                    warn!("Refresh dictionary snapshot failed: {:?}", cause);
+                                }
+                            }
+                        }
+                    });
+                }
+                return Ok(snapshot.values.clone());
+            } else {
+                true
+            }
+        };
+
+        let values = Arc::new(self.load_values(key_type, value_type, dict_arg).await?);
+        if !cacheable {
+            return Ok(values);
+        }
+        let mut dictionaries = DICTIONARY_SNAPSHOTS.lock();
+        // The dictionary may be evicted or replaced while its values are loaded.
+        if let Some(dictionary) = dictionaries.get_mut(&dict_id) {
+            if dictionary.meta_seq == meta_seq {
+                dictionary
+                    .snapshots
+                    .insert(snapshot_key, DictionarySnapshot {
+                        values: values.clone(),
+                        loaded_at: Instant::now(),
+                        refreshing: false,
+                    });
+            }
+        }
+        Ok(values)
+    }
+
+    // Loads all the key-value pairs of the dictionary from the source.
+    async fn load_values(
+        &self,
+        key_type: &DataType,
+        value_type: &DataType,
+        dict_arg: &DictGetFunctionArgument,
+    ) -> Result<DictionaryValues> {
+        let rows = match (self, &dict_arg.dict_source) {
+            (DictionaryOperator::Mysql(pool), DictionarySource::Mysql(sql_source)) => {
+                let sql = format!(
+                    "SELECT CAST({} AS CHAR), CAST({} AS CHAR) FROM {}",
+                    sql_source.key_field, sql_source.value_field, sql_source.table
+                );
+                sqlx::query_as(&sql).fetch_all(pool).await?
+            }
+            (DictionaryOperator::Postgresql(pool), DictionarySource::Postgresql(sql_source)) => {
+                let sql = format!(
+                    "SELECT CAST({} AS TEXT), CAST({} AS TEXT) FROM {}",
+                    sql_source.key_field, sql_source.value_field, sql_source.table
+                );
+                sqlx::query_as(&sql).fetch_all(pool).await?
+            }
+            (DictionaryOperator::Http { client, .. }, DictionarySource::Http(http_source)) => {
+                Self::fetch_http_rows(client, http_source).await?
+            }
+            (
+                DictionaryOperator::Databend { user, role, .. },
+                DictionarySource::Databend(source),
+            ) => Self::fetch_databend_rows(user, role, source).await?,
+            (_, source) => {
+                return Err(ErrorCode::DictionarySourceError(format!(
+                    "Dictionary source {:?} does not support local snapshot",
+                    source
+                )));
+            }
+        };
+        Self::build_values(rows, key_type, value_type)
+    }
+
+    async fn fetch_http_rows(
+        client: &reqwest::Client,
+        http_source: &HttpSource,
+    ) -> Result<Vec<(Option<String>, Option<String>)>> {
+        let mut request = client.get(&http_source.url);
+        if let Some(timeout) = http_source.timeout {
+            request = request.timeout(Duration::from_secs(timeout));
+        }
+        let response = request.send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ErrorCode::DictionarySourceError(format!(
+                "HTTP dictionary source {} responds with status {}",
+                http_source.url, status
+            )));
+        }
+        let body = response.bytes().await?;
+        let rows: Vec<serde_json::Map<String, serde_json::Value>> = serde_json::from_slice(&body)
+            .map_err(|err| {
+            ErrorCode::DictionarySourceError(format!(
+                "HTTP dictionary source {} must respond with a JSON array of objects: {}",
+                http_source.url, err
+            ))
+        })?;
+        Ok(rows
+            .iter()
+            .map(|row| {
+                (
+                    Self::json_value_to_text(row.get(&http_source.key_field)),
+                    Self::json_value_to_text(row.get(&http_source.value_field)),
+                )
+            })
+            .collect())
+    }
+
+    #[inline]
+    fn json_value_to_text(value: Option<&serde_json::Value>) -> Option<String> {
+        match value? {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some(s.clone()),
+            v => Some(v.to_string()),
+        }
+    }
+
+    async fn fetch_databend_rows(
+        user: &UserInfo,
+        role: &Option<String>,
+        source: &DatabendSource,
+    ) -> Result<Vec<(Option<String>, Option<String>)>> {
+        let quote = |ident: &str| format!("`{}`", ident.replace('`', "``"));
+        let sql = format!(
+            "SELECT CAST({} AS STRING), CAST({} AS STRING) FROM {}.{}.{}",
+            quote(&source.key_field),
+            quote(&source.value_field),
+            quote(&source.catalog),
+            quote(&source.database),
+            quote(&source.table)
+        );
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(session)?;
+        session.set_authed_user(user.clone(), role.clone()).await?;
+        let ctx = session.create_query_context().await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+
+        let text = |scalar: ScalarRef| match scalar {
+            ScalarRef::String(s) => Some(s.to_string()),
+            _ => None,
+        };
+        let mut rows = Vec::new();
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let keys = block.get_by_offset(0).to_column(num_rows);
+            let values = block.get_by_offset(1).to_column(num_rows);
+            rows.extend(
+                keys.iter()
+                    .zip(values.iter())
+                    .map(|(k, v)| (text(k), text(v))),
+            );
+        }
+        Ok(rows)
+    }
+
+    // Parses the text of keys and values, the rows with null key or value are ignored.
+    fn build_values(
+        rows: Vec<(Option<String>, Option<String>)>,
+        key_type: &DataType,
+        value_type: &DataType,
+    ) -> Result<DictionaryValues> {
+        let mut values = DictionaryValues::with_capacity(rows.len());
+        for (key, value) in rows {
+            let (Some(key), Some(value)) = (key, value) else {
+                continue;
+            };
+            let key = Self::parse_text(&key, key_type)?;
+            let value = Self::parse_text(&value, value_type)?;
+            values.insert(Self::format_snapshot_key(key.as_ref()), value);
+        }
+        Ok(values)
+    }
+
+    fn parse_text(text: &str, data_type: &DataType) -> Result<Scalar> {
+        let scalar = match data_type.remove_nullable() {
+            DataType::Boolean => match text.to_lowercase().as_str() {
+                "true" | "t" | "1" => Some(Scalar::Boolean(true)),
+                "false" | "f" | "0" => Some(Scalar::Boolean(false)),
+                _ => None,
+            },
+            DataType::String => Some(Scalar::String(text.to_string())),
+            DataType::Number(num_ty) => with_integer_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => text
+                    .trim()
+                    .parse::<NUM_TYPE>()
+                    .ok()
+                    .map(|v| Scalar::Number(NUM_TYPE::upcast_scalar(v))),
+                NumberDataType::Float32 => text
+                    .trim()
+                    .parse::<f32>()
+                    .ok()
+                    .map(|v| Scalar::Number(NumberScalar::Float32(v.into()))),
+                NumberDataType::Float64 => text
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .map(|v| Scalar::Number(NumberScalar::Float64(v.into()))),
+            }),
+            _ => {
+                return Err(ErrorCode::DictionarySourceError(format!(
+                    "Dictionary snapshot currently does not support value type {data_type}"
+                )));
+            }
+        };
+        scalar.ok_or_else(|| {
+            ErrorCode::DictionarySourceError(format!(
+                "Dictionary source value '{text}' can not be parsed as {data_type}"
+            ))
+        })
+    }
+
+    fn lookup_values(
+        values: &DictionaryValues,
+        value: &Value<AnyType>,
+        data_type: &DataType,
+        default_value: &Scalar,
+    ) -> Result<Value<AnyType>> {
+        match value {
+            Value::Scalar(Scalar::Null) => Ok(Value::Scalar(default_value.clone())),
+            Value::Scalar(scalar) => {
+                let value = values
+                    .get(&Self::format_snapshot_key(scalar.as_ref()))
+                    .cloned()
+                    .unwrap_or(default_value.clone());
+                Ok(Value::Scalar(value))
+            }
+            Value::Column(column) => {
+                let mut builder = ColumnBuilder::with_capacity(data_type, column.len());
+                for key in column.iter() {
+                    let value = match key {
+                        ScalarRef::Null => None,
+                        key => values.get(&Self::format_snapshot_key(key)),
+                    };
+                    match value {
+                        Some(value) => builder.push(value.as_ref()),
+                        None => builder.push(default_value.as_ref()),
+                    }
+                }
+                Ok(Value::Column(builder.build()))
+            }
+        }
+    }
+
+    #[inline]
+    fn format_snapshot_key(key: ScalarRef<'_>) -> String {
+        match key {
+            ScalarRef::String(s) => s.to_string(),
+            _ => format!("{}", key),
+        }
+    }
+
+    #[inline]
+    fn format_postgresql_key(key: ScalarRef<'_>) -> String {
+        match key {
+            ScalarRef::String(s) => format!("'{}'", s.replace('\'', "''")),
+            _ => format!("{}", key),
         }
     }
 
@@ -539,6 +957,7 @@ impl DictionaryOperator {
 
 impl TransformAsyncFunction {
     pub(crate) fn init_operators(
+        ctx: &Arc<QueryContext>,
         async_func_descs: &[AsyncFunctionDesc],
     ) -> Result<BTreeMap<usize, Arc<DictionaryOperator>>> {
         let mut operator_map: HashMap<String, Arc<DictionaryOperator>> = HashMap::new();
@@ -584,6 +1003,34 @@ impl TransformAsyncFunction {
                         operator_map.insert(sql_source.connection_url.clone(), operator.clone());
                         operators.insert(i, operator);
                     }
+                    DictionarySource::Postgresql(sql_source) => {
+                        if let Some(operator) = operator_map.get(&sql_source.connection_url) {
+                            operators.insert(i, operator.clone());
+                            continue;
+                        }
+                        let pg_pool = databend_common_base::runtime::block_on(
+                            sqlx::PgPool::connect(&sql_source.connection_url),
+                        )?;
+                        let operator = Arc::new(DictionaryOperator::Postgresql(pg_pool));
+                        operator_map.insert(sql_source.connection_url.clone(), operator.clone());
+                        operators.insert(i, operator);
+                    }
+                    // Each function has its own operator, the values it loads depend on the value field.
+                    DictionarySource::Http(_) => {
+                        let operator = Arc::new(DictionaryOperator::Http {
+                            client: reqwest::Client::new(),
+                            values: OnceCell::new(),
+                        });
+                        operators.insert(i, operator);
+                    }
+                    DictionarySource::Databend(_) => {
+                        let operator = Arc::new(DictionaryOperator::Databend {
+                            user: ctx.get_current_user()?,
+                            role: ctx.get_current_role().map(|role| role.name),
+                            values: OnceCell::new(),
+                        });
+                        operators.insert(i, operator);
+                    }
                 }
             }
         }
@@ -603,7 +1050,10 @@ impl TransformAsyncFunction {
         // only support one key field.
         let arg_index = arg_indices[0];
         let entry = data_block.get_by_offset(arg_index);
-        let value = op.dict_get(&entry.value, data_type, dict_arg).await?;
+        let key_type = entry.data_type.remove_nullable();
+        let value = op
+            .dict_get(&entry.value, &key_type, data_type, dict_arg)
+            .await?;
         let entry = BlockEntry {
             data_type: data_type.clone(),
             value,
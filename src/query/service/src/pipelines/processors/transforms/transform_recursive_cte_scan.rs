@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
@@ -24,12 +25,18 @@ use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_storages_memory::MemoryTable;
 
+use crate::pipelines::processors::transforms::transform_recursive_cte_source::create_recursive_cte_spiller;
+use crate::pipelines::processors::transforms::transform_recursive_cte_source::RecursiveCteSpilledMeta;
 use crate::sessions::QueryContext;
+use crate::spillers::Spiller;
 
 pub struct TransformRecursiveCteScan {
     ctx: Arc<QueryContext>,
     table: Option<Arc<dyn Table>>,
     table_name: String,
+    // Blocks of the working set may have been spilled by the recursive cte source.
+    spiller: Spiller,
+    blocks: VecDeque<DataBlock>,
 }
 
 impl TransformRecursiveCteScan {
@@ -38,10 +45,13 @@ impl TransformRecursiveCteScan {
         output_port: Arc<OutputPort>,
         table_name: String,
     ) -> Result<ProcessorPtr> {
+        let spiller = create_recursive_cte_spiller(&ctx)?;
         AsyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteScan {
             ctx,
             table: None,
             table_name,
+            spiller,
+            blocks: VecDeque::new(),
         })
     }
 }
@@ -63,23 +73,27 @@ impl AsyncSource for TransformRecursiveCteScan {
                 .await?;
             self.table = Some(table);
         }
-        let memory_table = self
-            .table
-            .as_ref()
-            .unwrap()
-            .as_any()
-            .downcast_ref::<MemoryTable>()
-            .unwrap();
-        let data = memory_table.get_blocks();
-        if data.is_empty() {
-            return Ok(None);
+        if self.blocks.is_empty() {
+            let memory_table = self
+                .table
+                .as_ref()
+                .unwrap()
+                .as_any()
+                .downcast_ref::<MemoryTable>()
+                .unwrap();
+            let data = memory_table.get_blocks();
+            if data.is_empty() {
+                return Ok(None);
+            }
+            memory_table.truncate();
+            self.blocks = data.into();
         }
-        memory_table.truncate();
-        let data = DataBlock::concat(&data)?;
-        if data.is_empty() {
-            Ok(None)
-        } else {
-            Ok(Some(data))
+        while let Some(block) = self.blocks.pop_front() {
+            let data = RecursiveCteSpilledMeta::restore(&self.spiller, block).await?;
+            if !data.is_empty() {
+                return Ok(Some(data));
+            }
         }
+        Ok(None)
     }
 }
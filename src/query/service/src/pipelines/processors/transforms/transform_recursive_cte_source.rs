@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Engine;
@@ -21,6 +24,10 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_schema_type;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::TableField;
@@ -32,11 +39,13 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sources::SyncSource;
 use databend_common_pipeline_sources::SyncSourcer;
+use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::executor::physical_plans::UnionAll;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::plans::DropTablePlan;
 use databend_common_sql::IndexType;
+use databend_common_storage::DataOperator;
 use databend_common_storages_memory::MemoryTable;
 use futures_util::TryStreamExt;
 
@@ -45,11 +54,70 @@ use crate::interpreters::DropTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::memory_settings::MemorySettingsExt;
 use crate::pipelines::processors::transforms::transform_merge_block::project_block;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
+use crate::spillers::Location;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 use crate::stream::PullingExecutorStream;
 
+/// A block of the recursive cte working set that has been spilled, it's kept in the
+/// memory table of cte scan in place of the data.
+#[derive(Clone)]
+pub struct RecursiveCteSpilledMeta {
+    pub location: Location,
+}
+
+impl RecursiveCteSpilledMeta {
+    pub fn create(location: Location) -> BlockMetaInfoPtr {
+        Box::new(RecursiveCteSpilledMeta { location })
+    }
+
+    /// Returns the block itself if it's not spilled, otherwise reads it back from the spiller.
+    pub async fn restore(spiller: &Spiller, mut block: DataBlock) -> Result<DataBlock> {
+        let Some(meta) = block.take_meta() else {
+            return Ok(block);
+        };
+        match RecursiveCteSpilledMeta::downcast_from(meta) {
+            Some(meta) => spiller.read_spilled_file(&meta.location).await,
+            None => Err(ErrorCode::Internal(
+                "Cannot downcast meta to RecursiveCteSpilledMeta",
+            )),
+        }
+    }
+}
+
+local_block_meta_serde!(RecursiveCteSpilledMeta);
+
+impl Debug for RecursiveCteSpilledMeta {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("RecursiveCteSpilledMeta")
+            .field("location", &self.location)
+            .finish()
+    }
+}
+
+#[typetag::serde(name = "recursive_cte_spilled")]
+impl BlockMetaInfo for RecursiveCteSpilledMeta {
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+pub(crate) fn create_recursive_cte_spiller(ctx: &Arc<QueryContext>) -> Result<Spiller> {
+    let spill_config = SpillerConfig {
+        spiller_type: SpillerType::RecursiveCte,
+        location_prefix: ctx.query_id_spill_prefix(),
+        disk_spill: None,
+        use_parquet: ctx.get_settings().get_spilling_file_format()?.is_parquet(),
+    };
+    let operator = DataOperator::instance().spill_operator();
+    Spiller::create(ctx.clone(), operator, spill_config)
+}
+
 // The whole recursive cte as source.
 pub struct TransformRecursiveCteSource {
     ctx: Arc<QueryContext>,
@@ -59,6 +127,12 @@ pub struct TransformRecursiveCteSource {
 
     recursive_step: usize,
     cte_scan_tables: Vec<Arc<dyn Table>>,
+
+    // The working set is spilled block by block under memory pressure.
+    spiller: Spiller,
+    memory_settings: MemorySettings,
+    // The working set of the current round that hasn't been output.
+    output_blocks: VecDeque<DataBlock>,
 }
 
 impl TransformRecursiveCteSource {
@@ -89,6 +163,8 @@ impl TransformRecursiveCteSource {
                 }
             })
             .collect::<Vec<_>>();
+        let spiller = create_recursive_cte_spiller(&ctx)?;
+        let memory_settings = MemorySettings::from_recursive_cte_settings(&ctx)?;
         SyncSourcer::create(ctx.clone(), output_port, TransformRecursiveCteSource {
             ctx,
             union_plan,
@@ -96,6 +172,9 @@ impl TransformRecursiveCteSource {
             right_outputs,
             recursive_step: 0,
             cte_scan_tables: vec![],
+            spiller,
+            memory_settings,
+            output_blocks: VecDeque::new(),
         })
     }
 
@@ -103,7 +182,7 @@ impl TransformRecursiveCteSource {
         ctx: Arc<QueryContext>,
         recursive_step: usize,
         union_plan: UnionAll,
-    ) -> Result<(Vec<DataBlock>, Vec<Arc<dyn Table>>)> {
+    ) -> Result<(PullingExecutorStream, Vec<Arc<dyn Table>>)> {
         if ctx.get_settings().get_max_cte_recursive_depth()? < recursive_step {
             return Err(ErrorCode::Internal("Recursive depth is reached"));
         }
@@ -132,12 +211,52 @@ impl TransformRecursiveCteSource {
         let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
         ctx.set_executor(pulling_executor.get_inner())?;
         Ok((
-            PullingExecutorStream::create(pulling_executor)?
-                .try_collect::<Vec<DataBlock>>()
-                .await?,
+            PullingExecutorStream::create(pulling_executor)?,
             cte_scan_tables,
         ))
     }
+
+    // Execute one round of the recursive cte and collect the projected working set,
+    // blocks are spilled once the memory usage exceeds the limit.
+    async fn collect_working_set(&mut self) -> Result<Vec<DataBlock>> {
+        let (mut stream, cte_scan_tables) = Self::execute_r_cte(
+            self.ctx.clone(),
+            self.recursive_step,
+            self.union_plan.clone(),
+        )
+        .await?;
+        if !cte_scan_tables.is_empty() {
+            self.cte_scan_tables = cte_scan_tables;
+        }
+
+        let func_ctx = self.ctx.get_function_context()?;
+        let left_schema = self.union_plan.left.output_schema()?;
+        let right_schema = self.union_plan.right.output_schema()?;
+        let mut blocks = vec![];
+        while let Some(data) = stream.try_next().await? {
+            if data.is_empty() {
+                continue;
+            }
+            let data = project_block(
+                &func_ctx,
+                data,
+                &left_schema,
+                &right_schema,
+                &self.left_outputs,
+                &self.right_outputs,
+                self.recursive_step == 0,
+            )?;
+            if self.memory_settings.check_spill() {
+                let location = self.spiller.spill(vec![data]).await?;
+                blocks.push(DataBlock::empty_with_meta(RecursiveCteSpilledMeta::create(
+                    location,
+                )));
+            } else {
+                blocks.push(data);
+            }
+        }
+        Ok(blocks)
+    }
 }
 
 #[async_trait::async_trait]
@@ -145,21 +264,15 @@ impl SyncSource for TransformRecursiveCteSource {
     const NAME: &'static str = "TransformRecursiveCteSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if let Some(block) = self.output_blocks.pop_front() {
+            let block = GlobalIORuntime::instance()
+                .block_on(RecursiveCteSpilledMeta::restore(&self.spiller, block))?;
+            return Ok(Some(block));
+        }
+
         let mut res = None;
-        let mut data = DataBlock::empty();
-        match GlobalIORuntime::instance().block_on(Self::execute_r_cte(
-            self.ctx.clone(),
-            self.recursive_step,
-            self.union_plan.clone(),
-        )) {
-            Ok(res) => {
-                if !res.0.is_empty() {
-                    data = DataBlock::concat(&res.0)?;
-                }
-                if !res.1.is_empty() {
-                    self.cte_scan_tables = res.1;
-                }
-            }
+        let blocks = match GlobalIORuntime::instance().block_on(self.collect_working_set()) {
+            Ok(blocks) => blocks,
             Err(e) => {
                 return Err(ErrorCode::Internal(format!(
                     "Failed to execute recursive cte: {:?}",
@@ -169,24 +282,18 @@ impl SyncSource for TransformRecursiveCteSource {
         };
         self.recursive_step += 1;
 
-        let row_size = data.num_rows();
-        if row_size > 0 {
-            let func_ctx = self.ctx.get_function_context()?;
-            data = project_block(
-                &func_ctx,
-                data,
-                &self.union_plan.left.output_schema()?,
-                &self.union_plan.right.output_schema()?,
-                &self.left_outputs,
-                &self.right_outputs,
-                self.recursive_step == 1,
-            )?;
+        if !blocks.is_empty() {
             // Prepare the data of next round recursive.
             for table in self.cte_scan_tables.iter() {
                 let memory_table = table.as_any().downcast_ref::<MemoryTable>().unwrap();
-                memory_table.update(vec![data.clone()]);
+                memory_table.update(blocks.clone());
             }
-            res = Some(data);
+            self.output_blocks = blocks.into();
+            let block = self.output_blocks.pop_front().unwrap();
+            res = Some(
+                GlobalIORuntime::instance()
+                    .block_on(RecursiveCteSpilledMeta::restore(&self.spiller, block))?,
+            );
         } else {
             let ctx = self.ctx.clone();
             let table_names = self.union_plan.cte_scan_names.clone();
@@ -309,7 +416,9 @@ async fn create_memory_table_for_cte_scan(
                 as_select: None,
                 inverted_indexes: None,
                 ngram_indexes: None,
+                vector_indexes: None,
                 attached_columns: None,
+                clone_source: None,
             };
             let create_table_interpreter =
                 CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -17,6 +17,8 @@ use std::collections::BTreeMap;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
+use arrow_array::cast::AsArray;
+use arrow_array::Array;
 use arrow_array::RecordBatch;
 use arrow_udf_runtime::javascript::FunctionOptions;
 use databend_common_base::runtime::GlobalIORuntime;
@@ -40,6 +42,8 @@ use databend_common_sql::plans::UDFType;
 
 use super::runtime_pool::Pool;
 use super::runtime_pool::RuntimeBuilder;
+use super::udtf_result::build_udtf_result;
+use super::udtf_result::UdtfOutput;
 
 pub enum ScriptRuntime {
     JavaScript(JsRuntimePool),
@@ -59,7 +63,7 @@ impl ScriptRuntime {
                     name: func.name.clone(),
                     handler: func.func_name.clone(),
                     code: String::from_utf8(code.to_vec())?,
-                    output_type: func.data_type.as_ref().clone(),
+                    output_field: udf_output_field(&func.name, func),
                     counter: Default::default(),
                 };
                 Ok(Self::JavaScript(JsRuntimePool::new(builder)))
@@ -84,7 +88,7 @@ impl ScriptRuntime {
                     name: func.name.clone(),
                     handler: func.func_name.clone(),
                     code: String::from_utf8(code.to_vec())?,
-                    output_type: func.data_type.as_ref().clone(),
+                    output_field: udf_output_field(&func.name, func),
                     counter: Default::default(),
                 };
                 Ok(Self::Python(python_pool::PyRuntimePool::new(builder)))
@@ -128,9 +132,7 @@ impl ScriptRuntime {
                     .iter()
                     .map(|f| f.as_ref().clone())
                     .collect();
-                let return_type = func.data_type.as_ref().clone();
-                let f = DataField::new(&func.func_name, return_type);
-                let return_f = arrow_schema::Field::from(&f);
+                let return_f = udf_output_field(&func.func_name, func);
 
                 let handle = runtime
                     .find_function(&func.func_name, args_types, return_f)
@@ -151,13 +153,99 @@ impl ScriptRuntime {
         };
         Ok(result_batch)
     }
+
+    /// Call a table function, each output batch contains the `row` column with
+    /// the index of the input row, followed by a struct column of the output fields.
+    pub fn handle_table_execution(
+        &self,
+        func: &UdfFunctionDesc,
+        input_batch: &RecordBatch,
+    ) -> Result<Vec<RecordBatch>> {
+        let batches = match self {
+            ScriptRuntime::JavaScript(pool) => pool.call(|runtime| {
+                GlobalIORuntime::instance().block_on(async move {
+                    let collect = async {
+                        let mut iter = runtime
+                            .call_table_function(&func.name, input_batch, TABLE_FUNCTION_CHUNK_SIZE)
+                            .await?;
+                        let mut batches = vec![];
+                        while let Some(batch) = iter.next().await? {
+                            batches.push(batch);
+                        }
+                        Ok::<_, anyhow::Error>(batches)
+                    };
+                    collect.await.map_err(|err| {
+                        ErrorCode::UDFRuntimeError(format!(
+                            "JavaScript UDTF {:?} execution failed: {err}",
+                            func.name
+                        ))
+                    })
+                })
+            })?,
+            #[cfg(feature = "python-udf")]
+            ScriptRuntime::Python(pool) => pool
+                .call(|runtime| {
+                    runtime
+                        .call_table_function(&func.name, input_batch, TABLE_FUNCTION_CHUNK_SIZE)?
+                        .collect::<std::result::Result<Vec<_>, _>>()
+                })
+                .map_err(|err| {
+                    ErrorCode::UDFRuntimeError(format!(
+                        "Python UDTF {:?} execution failed: {err}",
+                        func.name
+                    ))
+                })?,
+            ScriptRuntime::WebAssembly(runtime) => {
+                let args_types: Vec<_> = input_batch
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|f| f.as_ref().clone())
+                    .collect();
+                let return_f = udf_output_field(&func.func_name, func);
+
+                runtime
+                    .find_function(&func.func_name, args_types, return_f)
+                    .and_then(|handle| {
+                        runtime
+                            .call_table_function(&handle, input_batch)?
+                            .collect::<std::result::Result<Vec<_>, _>>()
+                    })
+                    .map_err(|err| {
+                        ErrorCode::UDFRuntimeError(format!(
+                            "WASM UDTF {:?} execution failed: {err}",
+                            func.func_name
+                        ))
+                    })?
+            }
+        };
+        Ok(batches)
+    }
+}
+
+// Number of output rows returned by a table function in one batch.
+const TABLE_FUNCTION_CHUNK_SIZE: usize = 1024;
+
+/// The output of a table function is a struct of its fields, so that the
+/// runtimes can return multiple columns.
+fn udf_output_field(name: &str, func: &UdfFunctionDesc) -> arrow_schema::Field {
+    match &func.table_fields {
+        Some(fields) => {
+            let fields = fields
+                .iter()
+                .map(|f| arrow_field_from_data_type(&f.name, f.data_type.clone()))
+                .collect::<arrow_schema::Fields>();
+            arrow_schema::Field::new(name, arrow_schema::DataType::Struct(fields), true)
+        }
+        None => arrow_field_from_data_type(name, func.data_type.as_ref().clone()),
+    }
 }
 
 pub struct JsRuntimeBuilder {
     name: String,
     handler: String,
     code: String,
-    output_type: DataType,
+    output_field: arrow_schema::Field,
 
     counter: AtomicUsize,
 }
@@ -184,7 +272,7 @@ impl RuntimeBuilder<arrow_udf_runtime::javascript::Runtime> for JsRuntimeBuilder
                     // we pass the field instead of the data type because arrow-udf-js
                     // now takes the field as an argument here so that it can get any
                     // metadata associated with the field
-                    arrow_field_from_data_type(&self.name, self.output_type.clone()),
+                    self.output_field.clone(),
                     &self.code,
                     FunctionOptions::default()
                         .return_null_on_null_input()
@@ -218,7 +306,7 @@ pub struct PyRuntimeBuilder {
     name: String,
     handler: String,
     code: String,
-    output_type: DataType,
+    output_field: arrow_schema::Field,
 
     counter: AtomicUsize,
 }
@@ -235,7 +323,7 @@ mod python_pool {
             let mut runtime = arrow_udf_runtime::python::Builder::default().build()?;
             runtime.add_function_with_handler(
                 &self.name,
-                arrow_field_from_data_type(&self.name, self.output_type.clone()),
+                self.output_field.clone(),
                 arrow_udf_runtime::CallMode::CalledOnNullInput,
                 &self.code,
                 &self.handler,
@@ -289,6 +377,16 @@ impl Transform for TransformUdfScript {
             let block_entries = self.prepare_block_entries(func, &data_block)?;
             let input_batch = self.create_input_batch(block_entries, num_rows)?;
             let runtime = self.script_runtimes.get(&func.name).unwrap();
+            if func.table_fields.is_some() {
+                let batches = runtime.handle_table_execution(func, &input_batch)?;
+                let outputs = batches
+                    .iter()
+                    .map(|batch| flatten_table_output(func, batch))
+                    .collect::<Result<Vec<_>>>()?;
+                let col = build_udtf_result(func, num_rows, outputs)?;
+                data_block.add_column(col);
+                continue;
+            }
             let result_batch = runtime.handle_execution(func, &input_batch)?;
             self.update_datablock(func, result_batch, &mut data_block)?;
         }
@@ -418,3 +516,177 @@ impl TransformUdfScript {
         Ok(())
     }
 }
+
+fn flatten_table_output(func: &UdfFunctionDesc, batch: &RecordBatch) -> Result<UdtfOutput> {
+    if batch.num_columns() != 2 {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Table function {:?} returned {} columns but expected the row index and a struct",
+            func.name,
+            batch.num_columns()
+        )));
+    }
+    let Some(output) = batch.column(1).as_struct_opt() else {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Table function {:?} returned column with data type {} but expected a struct",
+            func.name,
+            batch.column(1).data_type()
+        )));
+    };
+    Ok(UdtfOutput {
+        row: batch.column(0).clone(),
+        columns: output.columns().to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_expression::types::NumberDataType;
+    use databend_common_expression::types::StringType;
+    use databend_common_expression::FromData;
+    use databend_common_expression::Value;
+    use databend_common_sql::plans::UDFField;
+
+    use super::*;
+    use crate::test_kits::TestFixture;
+
+    fn split_words_desc(language: UDFLanguage, code: &str) -> UdfFunctionDesc {
+        let table_fields = vec![
+            UDFField {
+                name: "idx".to_string(),
+                data_type: DataType::Number(NumberDataType::Int32).wrap_nullable(),
+            },
+            UDFField {
+                name: "word".to_string(),
+                data_type: DataType::String.wrap_nullable(),
+            },
+        ];
+        let tuple = DataType::Tuple(table_fields.iter().map(|f| f.data_type.clone()).collect());
+        UdfFunctionDesc {
+            name: "split_words_udtf".to_string(),
+            func_name: "split_words".to_string(),
+            output_column: 1,
+            arg_indices: vec![0],
+            arg_exprs: vec!["s".to_string()],
+            data_type: Box::new(DataType::Array(Box::new(tuple))),
+            headers: BTreeMap::new(),
+            udf_type: UDFType::Script(UDFScriptCode {
+                language,
+                runtime_version: "".to_string(),
+                code: Arc::new(code.as_bytes().to_vec().into_boxed_slice()),
+            }),
+            table_fields: Some(table_fields),
+        }
+    }
+
+    /// Calls the table function with one input row per string and
+    /// returns the output rows of each input row.
+    fn call_split_words(func: &UdfFunctionDesc, input: Vec<Option<&str>>) -> Result<Vec<String>> {
+        let num_rows = input.len();
+        let schema = DataSchema::new(vec![DataField::new("s", DataType::String.wrap_nullable())]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_opt_data(input)]);
+        let input_batch = block.to_record_batch_with_dataschema(&schema)?;
+
+        let runtime = ScriptRuntime::try_create(func)?;
+        let outputs = runtime
+            .handle_table_execution(func, &input_batch)?
+            .iter()
+            .map(|batch| flatten_table_output(func, batch))
+            .collect::<Result<Vec<_>>>()?;
+        let entry = build_udtf_result(func, num_rows, outputs)?;
+        let Value::Column(column) = entry.value else {
+            unreachable!()
+        };
+        Ok((0..num_rows)
+            .map(|i| column.index(i).unwrap().to_string())
+            .collect())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_js_table_function() -> Result<()> {
+        let _fixture = TestFixture::setup().await?;
+        let func = split_words_desc(
+            UDFLanguage::JavaScript,
+            r#"
+export function* split_words(s) {
+    if (s === null) {
+        return;
+    }
+    let words = s.split(' ').filter(w => w.length > 0);
+    for (let i = 0; i < words.length; i++) {
+        yield { idx: i, word: words[i] };
+    }
+}
+"#,
+        );
+
+        let rows = call_split_words(&func, vec![Some("a b"), Some(""), None, Some("c d e")])?;
+        assert_eq!(rows, vec![
+            "[(0, 'a'), (1, 'b')]",
+            "[]",
+            "[]",
+            "[(0, 'c'), (1, 'd'), (2, 'e')]",
+        ]);
+        Ok(())
+    }
+
+    #[cfg(feature = "python-udf")]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_python_table_function() -> Result<()> {
+        let _fixture = TestFixture::setup().await?;
+        let func = split_words_desc(
+            UDFLanguage::Python,
+            r#"
+class Row:
+    def __init__(self, idx, word):
+        self.idx = idx
+        self.word = word
+
+def split_words(s):
+    if s is None:
+        return
+    for i, word in enumerate(s.split()):
+        yield Row(i, word)
+"#,
+        );
+
+        let rows = call_split_words(&func, vec![Some("a b"), Some(""), None, Some("c d e")])?;
+        assert_eq!(rows, vec![
+            "[(0, 'a'), (1, 'b')]",
+            "[]",
+            "[]",
+            "[(0, 'c'), (1, 'd'), (2, 'e')]",
+        ]);
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_wasm_table_function_missing() -> Result<()> {
+        let _fixture = TestFixture::setup().await?;
+        // An empty module, `(module)` in the text format.
+        let module = b"\0asm\x01\0\0\0";
+        let mut func = split_words_desc(UDFLanguage::WebAssembly, "");
+        func.udf_type = UDFType::Script(UDFScriptCode {
+            language: UDFLanguage::WebAssembly,
+            runtime_version: "".to_string(),
+            code: Arc::new(module.to_vec().into_boxed_slice()),
+        });
+
+        let res = call_split_words(&func, vec![Some("a b")]);
+        assert_eq!(res.unwrap_err().code(), ErrorCode::UDF_RUNTIME_ERROR);
+        Ok(())
+    }
+
+    #[test]
+    fn test_table_output_shape() {
+        let func = split_words_desc(UDFLanguage::JavaScript, "");
+        let schema = Arc::new(arrow_schema::Schema::new(vec![arrow_schema::Field::new(
+            "row",
+            arrow_schema::DataType::Int32,
+            false,
+        )]));
+        let rows: arrow_array::ArrayRef = Arc::new(arrow_array::Int32Array::from(vec![0]));
+        let batch = RecordBatch::try_new(schema, vec![rows]).unwrap();
+        let err = flatten_table_output(&func, &batch).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UDF_DATA_ERROR);
+    }
+}
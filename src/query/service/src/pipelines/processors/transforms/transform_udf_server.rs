@@ -45,6 +45,8 @@ use databend_common_version::UDF_CLIENT_USER_AGENT;
 use tokio::sync::Semaphore;
 use tonic::transport::Endpoint;
 
+use super::udtf_result::build_udtf_result;
+use super::udtf_result::UdtfOutput;
 use crate::sessions::QueryContext;
 
 pub struct TransformUdfServer {
@@ -173,6 +175,40 @@ impl TransformUdfServer {
         record_running_requests_external_start(func.name.clone(), 1);
         record_request_external_batch_rows(func.func_name.clone(), num_rows);
 
+        if func.table_fields.is_some() {
+            let result_batches = client
+                .do_exchange_table(&func.func_name, input_batch.clone())
+                .await;
+
+            let request_duration = instant.elapsed() - connect_duration;
+            record_running_requests_external_finish(func.name.clone(), 1);
+            record_request_external_duration(func.func_name.clone(), request_duration);
+
+            // Each batch of a table function starts with the `row` column,
+            // which is the index of the input row.
+            let outputs = result_batches?
+                .into_iter()
+                .map(|batch| {
+                    let columns = batch.columns();
+                    if columns.is_empty() {
+                        return Err(ErrorCode::UDFDataError(format!(
+                            "UDF server returned no row index for table function {:?}",
+                            func.name
+                        )));
+                    }
+                    Ok(UdtfOutput {
+                        row: columns[0].clone(),
+                        columns: columns[1..].to_vec(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let col = build_udtf_result(&func, num_rows, outputs)?;
+            data_block.add_column(col);
+            drop(permit);
+            return Ok(data_block);
+        }
+
         let result_batch = client
             .do_exchange(&func.func_name, input_batch.clone())
             .await;
@@ -0,0 +1,157 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::types::Int64Type;
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::UInt32Array;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArrayColumn;
+use databend_common_expression::variant_transform::contains_variant;
+use databend_common_expression::variant_transform::transform_variant;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Value;
+use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+
+/// The rows returned by a table-valued UDF for a batch of input rows.
+///
+/// `row` is the index of the input row that produced each output row,
+/// `columns` are the output columns in the order of the table fields.
+pub struct UdtfOutput {
+    pub row: ArrayRef,
+    pub columns: Vec<ArrayRef>,
+}
+
+/// Group the output rows of a table-valued UDF by input row, and build an
+/// `Array(Tuple(..))` column with one array per input row, which is then
+/// unnested by the set-returning function `unnest`.
+pub fn build_udtf_result(
+    func: &UdfFunctionDesc,
+    num_rows: usize,
+    outputs: Vec<UdtfOutput>,
+) -> Result<BlockEntry> {
+    let Some(table_fields) = &func.table_fields else {
+        return Err(ErrorCode::Internal(format!(
+            "Function {:?} is not a table function",
+            func.name
+        )));
+    };
+
+    let mut rows = Vec::new();
+    for output in &outputs {
+        if output.columns.len() != table_fields.len() {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Table function {:?} returned {} columns but expected {}",
+                func.name,
+                output.columns.len(),
+                table_fields.len()
+            )));
+        }
+        collect_row_indices(func, &output.row, num_rows, &mut rows)?;
+    }
+
+    // Output rows of the same input row are kept in the order they were returned.
+    let mut order = (0..rows.len() as u32).collect::<Vec<_>>();
+    order.sort_by_key(|i| rows[*i as usize]);
+    let indices = UInt32Array::from(order);
+
+    let mut counts = vec![0u64; num_rows];
+    for row in rows {
+        counts[row] += 1;
+    }
+
+    let mut fields = Vec::with_capacity(table_fields.len());
+    for (i, field) in table_fields.iter().enumerate() {
+        let column = if outputs.is_empty() {
+            ColumnBuilder::with_capacity(&field.data_type, 0).build()
+        } else {
+            let arrays = outputs
+                .iter()
+                .map(|output| output.columns[i].as_ref())
+                .collect::<Vec<_>>();
+            let array = arrow_select::concat::concat(&arrays)?;
+            let array = arrow_select::take::take(array.as_ref(), &indices, None)?;
+            let column = Column::from_arrow_rs(array, &field.data_type).map_err(|err| {
+                ErrorCode::UDFDataError(format!(
+                    "Table function {:?} returned invalid column {:?}: {err}",
+                    func.name, field.name
+                ))
+            })?;
+            if contains_variant(&field.data_type) {
+                match transform_variant(&Value::Column(column), false)? {
+                    Value::Column(column) => column,
+                    Value::Scalar(_) => unreachable!(),
+                }
+            } else {
+                column
+            }
+        };
+        fields.push(column);
+    }
+
+    let mut offsets = Vec::with_capacity(num_rows + 1);
+    offsets.push(0);
+    let mut offset = 0;
+    for count in counts {
+        offset += count;
+        offsets.push(offset);
+    }
+
+    let column = Column::Array(Box::new(ArrayColumn::new(
+        Column::Tuple(fields),
+        offsets.into(),
+    )));
+    if column.data_type() != *func.data_type {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Function {:?} returned column with data type {:?} but expected {:?}",
+            func.name,
+            column.data_type(),
+            func.data_type
+        )));
+    }
+    Ok(BlockEntry::new(column.data_type(), Value::Column(column)))
+}
+
+fn collect_row_indices(
+    func: &UdfFunctionDesc,
+    row: &ArrayRef,
+    num_rows: usize,
+    rows: &mut Vec<usize>,
+) -> Result<()> {
+    let start = rows.len();
+    if let Some(array) = row.as_primitive_opt::<Int32Type>() {
+        rows.extend(array.values().iter().map(|v| *v as usize));
+    } else if let Some(array) = row.as_primitive_opt::<Int64Type>() {
+        rows.extend(array.values().iter().map(|v| *v as usize));
+    } else {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Table function {:?} returned row index with data type {} but expected Int32 or Int64",
+            func.name,
+            row.data_type()
+        )));
+    }
+
+    if row.null_count() > 0 || rows[start..].iter().any(|row| *row >= num_rows) {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Table function {:?} returned invalid row index, input has {num_rows} rows",
+            func.name
+        )));
+    }
+    Ok(())
+}
@@ -37,6 +37,7 @@ use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_transforms::MemorySettings;
 use databend_common_sql::executor::physical_plans::LagLeadDefault;
 use databend_common_sql::plans::WindowFuncFrameUnits;
 
@@ -44,6 +45,8 @@ use super::frame_bound::FrameBound;
 use super::window_function::WindowFuncAggImpl;
 use super::window_function::WindowFunctionImpl;
 use super::WindowFunctionInfo;
+use crate::spillers::Location;
+use crate::spillers::Spiller;
 
 #[derive(Debug, Clone)]
 pub struct WindowSortDesc {
@@ -81,6 +84,13 @@ impl RowPtr {
 struct WindowBlock {
     block: DataBlock,
     builder: ColumnBuilder,
+    // The columns not used by the window function are pruned from `block` under memory pressure,
+    // if the block has not been output, the whole block is spilled and restored before output.
+    pruned: bool,
+    // All the columns are released if the rows have been accumulated into an unbounded frame,
+    // the block is restored when the current row reaches it.
+    evicted: bool,
+    spilled: Option<Location>,
 }
 
 /// The input [`DataBlock`] of [`TransformWindow`] should be sorted by partition and order by columns.
@@ -158,6 +168,12 @@ pub struct TransformWindow<T: Number> {
     is_empty_frame: bool,
     // If window function is ranking function
     is_ranking: bool,
+
+    // Spill the blocks of an oversized partition. The blocks accumulated into an unbounded frame are
+    // released entirely, for other frames the columns used by the computation are kept in memory.
+    spiller: Option<Spiller>,
+    memory_settings: MemorySettings,
+    used_columns: Vec<usize>,
 }
 
 impl<T: Number> TransformWindow<T> {
@@ -444,7 +460,8 @@ impl<T: Number> TransformWindow<T> {
         while self.next_output_block - self.first_block < self.blocks.len() {
             let block = &mut self.blocks[self.next_output_block - self.first_block];
 
-            if block.block.num_rows() == block.builder.len() {
+            // The spilled block should be restored first.
+            if block.block.num_rows() == block.builder.len() && block.spilled.is_none() {
                 // Can output
                 let mut output = block.block.clone();
                 let data_type = block.builder.data_type();
@@ -479,6 +496,159 @@ impl<T: Number> TransformWindow<T> {
         }
     }
 
+    pub fn with_spill(mut self, spiller: Spiller, memory_settings: MemorySettings) -> Self {
+        let mut used_columns = self.func.arg_indices();
+        used_columns.extend(self.partition_indices.iter().copied());
+        used_columns.extend(self.order_by.iter().map(|desc| desc.offset));
+        used_columns.sort();
+        used_columns.dedup();
+
+        self.spiller = Some(spiller);
+        self.memory_settings = memory_settings;
+        self.used_columns = used_columns;
+        self
+    }
+
+    /// The aggregation of a frame `UNBOUNDED PRECEDING` to `UNBOUNDED FOLLOWING` doesn't
+    /// depend on the current row, so the rows can be accumulated before the partition is ended.
+    fn can_evict_frame(&self) -> bool {
+        self.spiller.is_some()
+            && matches!(self.func, WindowFunctionImpl::Aggregate(_))
+            && matches!(self.start_bound, FrameBound::Preceding(None))
+            && matches!(self.end_bound, FrameBound::Following(None))
+            && !self.is_empty_frame
+    }
+
+    /// The block has been accumulated into the frame and its rows are not read anymore
+    /// until the current row reaches it.
+    fn can_evict(&self, index: usize) -> bool {
+        self.can_evict_frame()
+            && index > self.prev_frame_start.block
+            && index > self.peer_group_start.block
+            && index != self.current_row.block
+            && index < self.prev_frame_end.block
+    }
+
+    fn need_spill(&self) -> bool {
+        self.spiller.is_some()
+            && self.blocks.iter().enumerate().any(|(index, block)| {
+                !block.evicted && (!block.pruned || self.can_evict(self.first_block + index))
+            })
+            && self.memory_settings.check_spill()
+    }
+
+    fn is_current_row_evicted(&self) -> bool {
+        self.current_row < self.partition_end
+            && self
+                .blocks
+                .get(self.current_row.block - self.first_block)
+                .is_some_and(|block| block.evicted)
+    }
+
+    fn need_restore(&self) -> bool {
+        self.is_current_row_evicted()
+            || self
+                .blocks
+                .get(self.next_output_block - self.first_block)
+                .is_some_and(|block| {
+                    block.spilled.is_some() && block.block.num_rows() == block.builder.len()
+                })
+    }
+
+    fn next_state(&self) -> ProcessorState {
+        if !self.outputs.is_empty() {
+            ProcessorState::Output
+        } else if self.need_restore() {
+            ProcessorState::Restore
+        } else if self.need_spill() {
+            ProcessorState::Spill
+        } else {
+            ProcessorState::Consume
+        }
+    }
+
+    /// Release the blocks accumulated into an unbounded frame, and prune the columns
+    /// that are not used by the window function from the others.
+    /// The blocks that have not been output are spilled before releasing.
+    async fn spill(&mut self) -> Result<()> {
+        let spiller = self.spiller.as_ref().unwrap();
+        let evictable = (0..self.blocks.len())
+            .map(|index| self.can_evict(self.first_block + index))
+            .collect::<Vec<_>>();
+        for (index, block) in self.blocks.iter_mut().enumerate() {
+            if block.evicted {
+                continue;
+            }
+
+            if evictable[index] {
+                if self.first_block + index >= self.next_output_block && block.spilled.is_none() {
+                    block.spilled = Some(spiller.spill(vec![block.block.clone()]).await?);
+                }
+                // Keep the rows count, it is used to advance the rows.
+                block.block = DataBlock::new(vec![], block.block.num_rows());
+                block.pruned = true;
+                block.evicted = true;
+                continue;
+            }
+
+            if block.pruned {
+                continue;
+            }
+            block.pruned = true;
+
+            let num_columns = block.block.num_columns();
+            if (0..num_columns).all(|offset| self.used_columns.binary_search(&offset).is_ok()) {
+                // All the columns are used, nothing can be released.
+                continue;
+            }
+
+            let num_rows = block.block.num_rows();
+            let columns = block
+                .block
+                .columns()
+                .iter()
+                .enumerate()
+                .map(
+                    |(offset, entry)| match self.used_columns.binary_search(&offset) {
+                        Ok(_) => entry.clone(),
+                        Err(_) => BlockEntry::new(
+                            entry.data_type.clone(),
+                            Value::Scalar(Scalar::default_value(&entry.data_type)),
+                        ),
+                    },
+                )
+                .collect::<Vec<_>>();
+
+            if self.first_block + index >= self.next_output_block {
+                block.spilled = Some(spiller.spill(vec![block.block.clone()]).await?);
+            }
+            block.block = DataBlock::new(columns, num_rows);
+        }
+        Ok(())
+    }
+
+    async fn restore(&mut self) -> Result<()> {
+        let spiller = self.spiller.as_ref().unwrap();
+        let current_row_evicted = self.is_current_row_evicted();
+        let index = match current_row_evicted {
+            true => self.current_row.block,
+            false => self.next_output_block,
+        };
+        let block = &mut self.blocks[index - self.first_block];
+        if let Some(location) = block.spilled.take() {
+            let data = spiller.read_spilled_file(&location).await?;
+            block.block = data.consume_convert_to_full();
+            block.pruned = false;
+            block.evicted = false;
+        }
+
+        if current_row_evicted {
+            // Continue to compute the rows that are waiting for the block.
+            self.add_block(None)?;
+        }
+        Ok(())
+    }
+
     fn apply_aggregate(&self, agg: &WindowFuncAggImpl) -> Result<()> {
         debug_assert!(self.frame_started);
         debug_assert!(self.frame_ended);
@@ -499,6 +669,16 @@ impl<T: Number> TransformWindow<T> {
             agg.reset();
         }
 
+        self.accumulate_rows(agg, rows_start, rows_end)
+    }
+
+    /// Accumulate the rows in [`rows_start`, `rows_end`) into the aggregate state.
+    fn accumulate_rows(
+        &self,
+        agg: &WindowFuncAggImpl,
+        rows_start: RowPtr,
+        rows_end: RowPtr,
+    ) -> Result<()> {
         let end_block = if rows_end.row == 0 {
             rows_end.block
         } else {
@@ -805,6 +985,9 @@ impl TransformWindow<u64> {
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
+            spiller: None,
+            memory_settings: MemorySettings::disable_spill(),
+            used_columns: vec![],
         })
     }
 }
@@ -882,6 +1065,9 @@ where T: Number + ResultTypeOfUnary
             input_is_finished: false,
             is_empty_frame,
             is_ranking,
+            spiller: None,
+            memory_settings: MemorySettings::disable_spill(),
+            used_columns: vec![],
         })
     }
 
@@ -1025,6 +1211,9 @@ where T: Number + ResultTypeOfUnary
                 self.blocks.push_back(WindowBlock {
                     block: data.consume_convert_to_full(),
                     builder: ColumnBuilder::with_capacity(&self.func.return_type()?, num_rows),
+                    pruned: false,
+                    evicted: false,
+                    spilled: None,
                 });
             }
         }
@@ -1050,6 +1239,11 @@ where T: Number + ResultTypeOfUnary
             });
 
             while self.current_row < self.partition_end {
+                // The block of current row is evicted, it should be restored first.
+                if self.is_current_row_evicted() {
+                    return Ok(());
+                }
+
                 if !self.are_peers(&self.peer_group_start, &self.current_row, false) {
                     self.peer_group_start = self.current_row;
                     self.peer_group_end = self.current_row;
@@ -1109,6 +1303,15 @@ where T: Number + ResultTypeOfUnary
                     if !self.frame_ended {
                         debug_assert!(!self.input_is_finished);
                         debug_assert!(!self.partition_ended);
+
+                        // Accumulate the rows that have been read, then the blocks can be evicted.
+                        if self.can_evict_frame() {
+                            debug_assert!(self.frame_start == self.prev_frame_start);
+                            if let WindowFunctionImpl::Aggregate(agg) = &self.func {
+                                self.accumulate_rows(agg, self.prev_frame_end, self.frame_end)?;
+                            }
+                            self.prev_frame_end = self.frame_end;
+                        }
                         break;
                     }
 
@@ -1274,6 +1477,8 @@ enum ProcessorState {
     Consume,
     AddBlock(Option<DataBlock>),
     Output,
+    Spill,
+    Restore,
 }
 
 #[async_trait::async_trait]
@@ -1328,10 +1533,11 @@ where T: Number + ResultTypeOfUnary
                 let output = self.outputs.pop_front().unwrap();
                 self.output.push_data(Ok(output));
                 if self.outputs.is_empty() {
-                    self.state = ProcessorState::Consume;
+                    self.state = self.next_state();
                 }
                 Ok(Event::NeedConsume)
             }
+            ProcessorState::Spill | ProcessorState::Restore => Ok(Event::Async),
             _ => unreachable!(),
         }
     }
@@ -1342,16 +1548,26 @@ where T: Number + ResultTypeOfUnary
         {
             self.add_block(data)?;
             self.check_outputs();
-            self.state = if self.outputs.is_empty() {
-                ProcessorState::Consume
-            } else {
-                ProcessorState::Output
-            };
+            self.state = self.next_state();
         } else {
             unreachable!()
         }
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.state {
+            ProcessorState::Spill => self.spill().await?,
+            ProcessorState::Restore => {
+                self.restore().await?;
+                self.check_outputs();
+            }
+            _ => unreachable!(),
+        }
+        self.state = self.next_state();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1454,6 +1670,9 @@ mod tests {
         transform.blocks.push_back(WindowBlock {
             block: DataBlock::new_from_columns(vec![column]),
             builder: ColumnBuilder::with_capacity(&data_type, num_rows),
+            pruned: false,
+            evicted: false,
+            spilled: None,
         });
         Ok(transform)
     }
@@ -312,4 +312,17 @@ impl WindowFunctionImpl {
             agg.reset();
         }
     }
+
+    /// The offsets of the input columns that the function reads.
+    pub fn arg_indices(&self) -> Vec<usize> {
+        match self {
+            Self::Aggregate(agg) => agg.args.clone(),
+            Self::LagLead(ll) => match ll.default {
+                LagLeadDefault::Null => vec![ll.arg],
+                LagLeadDefault::Index(col) => vec![ll.arg, col],
+            },
+            Self::NthValue(func) => vec![func.arg],
+            _ => vec![],
+        }
+    }
 }
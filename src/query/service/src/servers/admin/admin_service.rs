@@ -83,6 +83,10 @@ impl AdminService {
                 "v1/queries/:query_id/graph_dump",
                 get(super::v1::query_dump::running_query_dump),
             )
+            .at(
+                "/v1/pipes/:pipe/notify",
+                post(super::v1::pipes::notify_pipe_handler),
+            )
             .at("/debug/home", get(debug_home_handler))
             .at("/debug/pprof/profile", get(debug_pprof_handler))
             .at("/debug/async_tasks/dump", get(debug_dump_stack));
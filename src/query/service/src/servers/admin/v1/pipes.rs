@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_exception::ErrorCode;
+use log::info;
+use poem::web::Json;
+use poem::web::Path;
+use poem::IntoResponse;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::pipe::PipeScheduler;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipeNotification {
+    /// The new files relative to the stage location of the pipe, all the new files of the
+    /// stage are loaded if it's absent.
+    #[serde(default)]
+    pub files: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PipeNotificationResponse {
+    pipe: String,
+    accepted: bool,
+}
+
+// This handler receives the event notifications of the stage of an auto-ingest pipe,
+// the notified files are loaded in background.
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn notify_pipe_handler(
+    Path(pipe): Path<String>,
+    notification: Json<PipeNotification>,
+) -> poem::Result<impl IntoResponse> {
+    info!("notify pipe {}: {:?}", pipe, notification.0);
+
+    PipeScheduler::instance()
+        .notify(&pipe, notification.0.files)
+        .await
+        .map_err(|e| match e.code() {
+            ErrorCode::UNKNOWN_PIPE => poem::error::NotFound(e),
+            ErrorCode::ILLEGAL_PIPE => poem::error::BadRequest(e),
+            _ => poem::error::InternalServerError(e),
+        })?;
+    Ok(Json(PipeNotificationResponse {
+        pipe,
+        accepted: true,
+    }))
+}
@@ -14,23 +14,51 @@
 
 use std::sync::Arc;
 
+use arrow_array::builder::BinaryBuilder;
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
 use arrow_array::RecordBatch;
 use arrow_flight::utils::batches_to_flight_data;
+use arrow_flight::IpcMessage;
+use arrow_flight::SchemaAsIpc;
+use arrow_ipc::writer::IpcWriteOptions;
 use arrow_schema::DataType;
 use arrow_schema::Field;
 use arrow_schema::Schema;
+use arrow_schema::SchemaRef;
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_expression::generate_like_pattern;
+use databend_common_expression::LikePattern;
 use futures_util::stream;
 use log::warn;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const TABLE_TYPE_TABLE: &str = "table";
+const TABLE_TYPE_VIEW: &str = "view";
+
+/// Filters used by `CommandGetTables`, patterns follow the SQL LIKE syntax.
+#[derive(Default)]
+pub(super) struct TableFilter {
+    pub catalog: Option<String>,
+    pub db_schema_filter_pattern: Option<String>,
+    pub table_name_filter_pattern: Option<String>,
+    pub table_types: Vec<String>,
+    pub include_schema: bool,
+}
+
+struct TableEntry {
+    catalog_name: String,
+    db_schema_name: String,
+    table_name: String,
+    table_type: String,
+    table_schema: Option<Vec<u8>>,
+}
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -45,43 +73,79 @@ impl CatalogInfoProvider {
         Ok(Box::pin(stream))
     }
 
-    async fn get_tables_internal(
-        ctx: Arc<dyn TableContext>,
+    fn like_pattern(pattern: &Option<String>) -> Option<LikePattern<'_>> {
+        pattern
+            .as_ref()
+            .map(|p| generate_like_pattern(p.as_bytes(), 0))
+    }
+
+    fn matches(pattern: &Option<LikePattern<'_>>, value: &str) -> bool {
+        pattern
+            .as_ref()
+            .map(|p| p.compare(value.as_bytes()))
+            .unwrap_or(true)
+    }
+
+    async fn list_catalogs(
+        ctx: &Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
-    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>, Vec<String>, Vec<String>)>
-    {
+    ) -> databend_common_exception::Result<Vec<(String, Arc<dyn Catalog>)>> {
         let tenant = ctx.get_tenant();
         let catalog_mgr = CatalogManager::instance();
-        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
-            vec![(
+        if let Some(catalog_name) = catalog_name {
+            Ok(vec![(
                 catalog_name.clone(),
                 catalog_mgr
                     .get_catalog(tenant.tenant_name(), &catalog_name, ctx.session_state())
                     .await?,
-            )]
+            )])
         } else {
-            catalog_mgr
+            Ok(catalog_mgr
                 .list_catalogs(&tenant, ctx.session_state())
                 .await?
                 .iter()
                 .map(|r| (r.name(), r.clone()))
-                .collect()
-        };
+                .collect())
+        }
+    }
+
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
+        let db_pattern = Self::like_pattern(&db_schema_filter_pattern);
 
         let mut catalog_names = vec![];
         let mut database_names = vec![];
-        let mut table_names = vec![];
-        let mut table_types = vec![];
-        let table_type = "table".to_string();
-        for (catalog_name, catalog) in catalogs.into_iter() {
-            let dbs = if let Some(database_name) = &database_name {
-                vec![catalog.get_database(&tenant, database_name).await?]
-            } else {
-                catalog.list_databases(&tenant).await?
-            };
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, catalog_name).await? {
+            for db in catalog.list_databases(&tenant).await? {
+                if Self::matches(&db_pattern, db.name()) {
+                    catalog_names.push(catalog_name.clone());
+                    database_names.push(db.name().to_string());
+                }
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    async fn get_tables_internal(
+        ctx: Arc<dyn TableContext>,
+        filter: &TableFilter,
+    ) -> databend_common_exception::Result<Vec<TableEntry>> {
+        let tenant = ctx.get_tenant();
+        let db_pattern = Self::like_pattern(&filter.db_schema_filter_pattern);
+        let table_pattern = Self::like_pattern(&filter.table_name_filter_pattern);
+
+        let mut entries = vec![];
+        for (catalog_name, catalog) in Self::list_catalogs(&ctx, filter.catalog.clone()).await? {
+            let dbs = catalog.list_databases(&tenant).await?;
             for db in dbs {
                 let db_name = db.name();
+                if !Self::matches(&db_pattern, db_name) {
+                    continue;
+                }
                 let tables = match catalog.list_tables(&tenant, db_name).await {
                     Ok(tables) => tables,
                     Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
@@ -91,41 +155,168 @@ impl CatalogInfoProvider {
                     Err(err) => return Err(err),
                 };
                 for table in tables {
-                    catalog_names.push(catalog_name.clone());
-                    database_names.push(db_name.to_string());
-                    table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    if !Self::matches(&table_pattern, table.name()) {
+                        continue;
+                    }
+                    let table_type = if table.engine().eq_ignore_ascii_case("VIEW") {
+                        TABLE_TYPE_VIEW
+                    } else {
+                        TABLE_TYPE_TABLE
+                    };
+                    if !filter.table_types.is_empty()
+                        && !filter
+                            .table_types
+                            .iter()
+                            .any(|t| t.eq_ignore_ascii_case(table_type))
+                    {
+                        continue;
+                    }
+                    let table_schema = if filter.include_schema {
+                        let schema = Schema::from(table.schema().as_ref());
+                        let IpcMessage(bytes) =
+                            SchemaAsIpc::new(&schema, &IpcWriteOptions::default()).try_into()?;
+                        Some(bytes.to_vec())
+                    } else {
+                        None
+                    };
+                    entries.push(TableEntry {
+                        catalog_name: catalog_name.clone(),
+                        db_schema_name: db_name.to_string(),
+                        table_name: table.name().to_string(),
+                        table_type: table_type.to_string(),
+                        table_schema,
+                    });
                 }
             }
         }
-        Ok((catalog_names, database_names, table_names, table_types))
+        Ok(entries)
     }
 
-    pub(crate) async fn get_tables(
+    pub(crate) async fn get_catalogs(ctx: Arc<dyn TableContext>) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = Self::list_catalogs(&ctx, None)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) async fn get_schemas(
         ctx: Arc<dyn TableContext>,
         catalog_name: Option<String>,
-        database_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
     ) -> Result<DoGetStream, Status> {
         let schema = Arc::new(Schema::new(vec![
-            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("catalog_name", DataType::Utf8, true),
             Field::new("db_schema_name", DataType::Utf8, false),
-            Field::new("table_name", DataType::Utf8, false),
-            Field::new("table_type", DataType::Utf8, false),
         ]));
-        let (catalog_name, db_schema_name, table_name, table_type) =
-            Self::get_tables_internal(ctx.clone(), catalog_name, database_name)
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern)
                 .await
                 .map_err(|e| Status::internal(format!("{e:?}")))?;
         let batch = RecordBatch::try_new(schema, vec![
             Self::string_array(catalog_name),
             Self::string_array(db_schema_name),
-            Self::string_array(table_name),
-            Self::string_array(table_type),
         ])
         .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_tables(
+        ctx: Arc<dyn TableContext>,
+        filter: TableFilter,
+    ) -> Result<DoGetStream, Status> {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, false),
+            Field::new("db_schema_name", DataType::Utf8, false),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if filter.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        let schema = Arc::new(Schema::new(fields));
+        let entries = Self::get_tables_internal(ctx.clone(), &filter)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+
+        let mut columns = vec![
+            Self::string_array(entries.iter().map(|e| e.catalog_name.clone()).collect()),
+            Self::string_array(entries.iter().map(|e| e.db_schema_name.clone()).collect()),
+            Self::string_array(entries.iter().map(|e| e.table_name.clone()).collect()),
+            Self::string_array(entries.iter().map(|e| e.table_type.clone()).collect()),
+        ];
+        if filter.include_schema {
+            let mut builder = BinaryBuilder::new();
+            for entry in &entries {
+                builder.append_value(entry.table_schema.as_deref().unwrap_or_default());
+            }
+            columns.push(Arc::new(builder.finish()));
+        }
+        let batch = RecordBatch::try_new(schema, columns)
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(vec![
+            TABLE_TYPE_TABLE.to_string(),
+            TABLE_TYPE_VIEW.to_string(),
+        ])])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    /// Databend does not enforce primary keys, so the result is always empty.
+    pub(crate) fn get_primary_keys() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        Self::batch_to_get_stream(RecordBatch::new_empty(schema))
+    }
+
+    /// Databend does not enforce foreign keys, so exported keys, imported keys
+    /// and cross references are always empty.
+    pub(crate) fn get_foreign_keys() -> Result<DoGetStream, Status> {
+        Self::batch_to_get_stream(RecordBatch::new_empty(Self::foreign_keys_schema()))
+    }
+
+    fn foreign_keys_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -22,10 +22,12 @@ mod sql_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
-use dashmap::DashMap;
+use catalog::TableFilter;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use futures::Stream;
@@ -34,7 +36,9 @@ use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
 
+use crate::servers::http::v1::query::expirable::Expirable;
 use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
 use crate::sessions::Session;
 
 #[macro_export]
@@ -49,9 +53,29 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// The statements not used for this time are dropped, like the handles that are never
+/// fetched or closed by the client.
+const STATEMENT_MAX_IDLE_TIME: Duration = Duration::from_secs(360);
+
+#[derive(Clone)]
+struct Statement {
+    plan: Arc<(Plan, PlanExtras)>,
+    last_access: Arc<Mutex<Instant>>,
+}
+
+impl Expirable for Statement {
+    fn expire_state(&self) -> ExpiringState {
+        ExpiringState::Idle {
+            idle_time: self.last_access.lock().elapsed(),
+        }
+    }
+
+    fn on_expire(&self) {}
+}
+
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    statements: Mutex<ExpiringMap<Uuid, Statement>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -63,3 +87,29 @@ impl FlightSqlServiceImpl {
         }
     }
 }
+
+impl FlightSqlServiceImpl {
+    fn add_statement(&self, handle: Uuid, plan: (Plan, PlanExtras)) {
+        let statement = Statement {
+            plan: Arc::new(plan),
+            last_access: Arc::new(Mutex::new(Instant::now())),
+        };
+        self.statements
+            .lock()
+            .insert(handle, statement, Some(STATEMENT_MAX_IDLE_TIME));
+    }
+
+    fn get_statement(&self, handle: &Uuid) -> Result<Arc<(Plan, PlanExtras)>, Status> {
+        let statement = self
+            .statements
+            .lock()
+            .get(handle)
+            .ok_or_else(|| Status::not_found(format!("Unknown statement handle {handle}")))?;
+        *statement.last_access.lock() = Instant::now();
+        Ok(statement.plan)
+    }
+
+    fn remove_statement(&self, handle: &Uuid) {
+        self.statements.lock().remove(handle);
+    }
+}
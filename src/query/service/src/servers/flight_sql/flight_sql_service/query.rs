@@ -19,6 +19,9 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
+use arrow_flight::sql::CommandStatementIngest;
+use arrow_flight::sql::TableExistsOption;
+use arrow_flight::sql::TableNotExistOption;
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
 use arrow_ipc::writer;
@@ -28,18 +31,30 @@ use arrow_ipc::MetadataVersion;
 use arrow_schema::Schema as ArrowSchema;
 use async_stream::stream;
 use bytes::Bytes;
+use databend_common_ast::ast::quote::QuotedIdent;
 use databend_common_base::base::tokio;
+use databend_common_catalog::query_kind::QueryKind;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_pipeline_sources::StreamSource;
+use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_sql::get_query_kind;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::InsertValue;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use futures::Stream;
 use futures::StreamExt;
+use parking_lot::Mutex;
 use prost::bytes;
 use serde::Deserialize;
 use serde::Serialize;
@@ -48,7 +63,13 @@ use tonic::Status;
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use crate::interpreters::Accessor;
 use crate::interpreters::InterpreterFactory;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::transforms::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
 
@@ -175,6 +196,178 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    /// Append the Arrow batches of an ADBC bulk ingest into the target table,
+    /// creating or replacing the table first if the ingest options ask for it.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_ingest(
+        &self,
+        session: Arc<Session>,
+        cmd: &CommandStatementIngest,
+        source_schema: DataSchemaRef,
+        blocks: SendableDataBlockStream,
+    ) -> Result<i64> {
+        let context = session.create_query_context().await?;
+        let catalog = cmd
+            .catalog
+            .clone()
+            .unwrap_or_else(|| context.get_current_catalog());
+        let database = cmd
+            .schema
+            .clone()
+            .unwrap_or_else(|| context.get_current_database());
+        self.prepare_ingest_table(&session, &context, cmd, &catalog, &database, &source_schema)
+            .await?;
+
+        // Use a fresh context, the table may have just been (re)created.
+        let context = session.create_query_context().await?;
+        context.attach_query_str(
+            QueryKind::Insert,
+            format!(
+                "INSERT INTO {} /* flight sql ingest */",
+                table_ident(&catalog, &database, &cmd.table)
+            ),
+        );
+        let table = context.get_table(&catalog, &database, &cmd.table).await?;
+        table.check_mutable()?;
+
+        // The batches are appended without an interpreter, so the access of the insert is
+        // checked here.
+        let insert = Plan::Insert(Box::new(Insert {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: cmd.table.clone(),
+            schema: table.schema(),
+            overwrite: false,
+            source: InsertInputSource::Values(InsertValue::Values { rows: vec![] }),
+            table_info: None,
+        }));
+        Accessor::create(context.clone()).check(&insert).await?;
+
+        let table_schema = table.schema();
+        let insert_schema = Arc::new(DataSchema::new(
+            source_schema
+                .fields()
+                .iter()
+                .map(|f| Ok(DataField::from(table_schema.field_with_name(f.name())?)))
+                .collect::<Result<Vec<_>>>()?,
+        ));
+
+        let table_meta_timestamps = if table.engine() == "FUSE" {
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            let snapshot = fuse_table.read_table_snapshot().await?;
+            context.get_table_meta_timestamps(table.as_ref(), snapshot)?
+        } else {
+            Default::default()
+        };
+
+        let mut build_res = PipelineBuildResult::create();
+        let blocks = Mutex::new(Some(blocks));
+        build_res.main_pipeline.add_source(
+            |output| StreamSource::create(context.clone(), blocks.lock().take(), output),
+            1,
+        )?;
+        if insert_schema != source_schema {
+            let func_ctx = context.get_function_context()?;
+            build_res.main_pipeline.try_add_transformer(|| {
+                TransformCastSchema::try_new(
+                    source_schema.clone(),
+                    insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+        }
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            context.clone(),
+            &mut build_res.main_pipeline,
+            table,
+            insert_schema,
+            None,
+            vec![],
+            false,
+            None,
+            table_meta_timestamps,
+        )?;
+
+        build_res.set_max_threads(context.get_settings().get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(context.clone())?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        context.set_executor(executor.get_inner())?;
+        databend_common_base::runtime::spawn_blocking(move || executor.execute())
+            .await
+            .map_err(|e| ErrorCode::Internal(format!("ingest executor panicked: {e}")))??;
+
+        let affected_rows = context.get_write_progress_value().rows;
+        Ok(affected_rows as i64)
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare_ingest_table(
+        &self,
+        session: &Arc<Session>,
+        context: &Arc<QueryContext>,
+        cmd: &CommandStatementIngest,
+        catalog: &str,
+        database: &str,
+        source_schema: &DataSchema,
+    ) -> Result<()> {
+        let exists = match context.get_table(catalog, database, &cmd.table).await {
+            Ok(_) => true,
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => false,
+            Err(e) => return Err(e),
+        };
+        let (if_exists, if_not_exist) = cmd
+            .table_definition_options
+            .as_ref()
+            .map(|o| (o.if_exists(), o.if_not_exist()))
+            .unwrap_or((
+                TableExistsOption::Unspecified,
+                TableNotExistOption::Unspecified,
+            ));
+        let create = match (exists, if_exists, if_not_exist) {
+            (true, TableExistsOption::Fail, _) => {
+                return Err(ErrorCode::TableAlreadyExists(format!(
+                    "Table `{catalog}`.`{database}`.`{}` already exists",
+                    cmd.table
+                )));
+            }
+            (true, TableExistsOption::Replace, _) => "CREATE OR REPLACE",
+            (true, _, _) => return Ok(()),
+            (false, _, TableNotExistOption::Create) => "CREATE",
+            (false, _, _) => {
+                return Err(ErrorCode::UnknownTable(format!(
+                    "Unknown table `{catalog}`.`{database}`.`{}`",
+                    cmd.table
+                )));
+            }
+        };
+
+        let columns = source_schema
+            .fields()
+            .iter()
+            .map(|f| {
+                let data_type = f.data_type();
+                let name = QuotedIdent(f.name(), '`');
+                if data_type.is_nullable_or_null() {
+                    format!("{name} {}", data_type.sql_name())
+                } else {
+                    format!("{name} {} NOT NULL", data_type.sql_name())
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let temporary = if cmd.temporary { " TEMP" } else { "" };
+        let sql = format!(
+            "{create}{temporary} TABLE {} ({columns})",
+            table_ident(catalog, database, &cmd.table)
+        );
+        let (plan, plan_extras) = self.plan_sql(session, &sql).await?;
+        self.execute_update(session.clone(), &plan, &plan_extras)
+            .await?;
+        Ok(())
+    }
+
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
@@ -317,3 +510,12 @@ struct ProgressValue {
     pub write_rows: usize,
     pub write_bytes: usize,
 }
+
+fn table_ident(catalog: &str, database: &str, table: &str) -> String {
+    format!(
+        "{}.{}.{}",
+        QuotedIdent(catalog, '`'),
+        QuotedIdent(database, '`'),
+        QuotedIdent(table, '`')
+    )
+}
@@ -16,6 +16,8 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
 use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::flight_service_server::FlightService;
 use arrow_flight::sql::server::FlightSqlService;
@@ -45,6 +47,7 @@ use arrow_flight::sql::CommandGetTables;
 use arrow_flight::sql::CommandGetXdbcTypeInfo;
 use arrow_flight::sql::CommandPreparedStatementQuery;
 use arrow_flight::sql::CommandPreparedStatementUpdate;
+use arrow_flight::sql::CommandStatementIngest;
 use arrow_flight::sql::CommandStatementQuery;
 use arrow_flight::sql::CommandStatementSubstraitPlan;
 use arrow_flight::sql::CommandStatementUpdate;
@@ -66,8 +69,11 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
+use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use futures::Stream;
+use futures::StreamExt;
 use log::info;
 use prost::Message;
 use tonic::metadata::MetadataValue;
@@ -78,6 +84,7 @@ use tonic::Status;
 use tonic::Streaming;
 
 use super::status;
+use super::TableFilter;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
@@ -125,6 +132,48 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+fn statement_flight_info(
+    data_schema: &DataSchema,
+    ticket: Ticket,
+) -> std::result::Result<Response<FlightInfo>, Status> {
+    let schema = data_schema.into();
+    let loc = Location {
+        uri: "grpc+tcp://127.0.0.1".to_string(),
+    };
+    let endpoint = FlightEndpoint {
+        ticket: Some(ticket),
+        location: vec![loc],
+        expiration_time: None,
+        app_metadata: Default::default(),
+    };
+    let endpoints = vec![endpoint];
+
+    let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = message;
+
+    let flight_desc = FlightDescriptor {
+        r#type: DescriptorType::Cmd.into(),
+        cmd: Default::default(),
+        path: vec![],
+    };
+    let info = FlightInfo {
+        schema: schema_bytes,
+        flight_descriptor: Some(flight_desc),
+        endpoint: endpoints,
+        total_records: -1,
+        total_bytes: -1,
+        ordered: false,
+        app_metadata: Default::default(),
+    };
+    Ok(Response::new(info))
+}
+
+fn decode_statement_handle(handle: &[u8]) -> std::result::Result<Uuid, Status> {
+    Uuid::from_slice(handle).map_err(|e| Status::internal(format!("Error decoding handle: {e}")))
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -197,9 +246,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let plan = self.get_statement(&handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &plan.0, &plan.1)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -212,9 +261,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let data_schema = plan.0.schema();
+
+        // The plan is kept until the client fetches the ticket by do_get_statement.
+        let handle = Uuid::new_v4();
+        self.add_statement(handle, plan);
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let ticket = Ticket {
+            ticket: ticket.as_any().encode_to_vec().into(),
+        };
+        statement_flight_info(&data_schema, ticket)
     }
 
     #[async_backtrace::framed]
@@ -224,50 +288,17 @@ impl FlightSqlService for FlightSqlServiceImpl {
         request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         let _session = self.get_session(&request);
-        let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
-            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+        let handle = decode_statement_handle(cmd.prepared_statement_handle.as_ref())?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
-        let loc = Location {
-            uri: "grpc+tcp://127.0.0.1".to_string(),
-        };
+        let plan = self.get_statement(&handle)?;
         let fetch = FetchResults {
             handle: handle.to_string(),
         };
         let buf = fetch.as_any().encode_to_vec().into();
         let ticket = Ticket { ticket: buf };
-        let endpoint = FlightEndpoint {
-            ticket: Some(ticket),
-            location: vec![loc],
-            expiration_time: None,
-            app_metadata: Default::default(),
-        };
-        let endpoints = vec![endpoint];
-
-        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
-            .try_into()
-            .map_err(|e| status!("Unable to serialize schema", e))?;
-        let IpcMessage(schema_bytes) = message;
-
-        let flight_desc = FlightDescriptor {
-            r#type: DescriptorType::Cmd.into(),
-            cmd: Default::default(),
-            path: vec![],
-        };
-        let info = FlightInfo {
-            schema: schema_bytes,
-            flight_descriptor: Some(flight_desc),
-            endpoint: endpoints,
-            total_records: -1,
-            total_bytes: -1,
-            ordered: false,
-            app_metadata: Default::default(),
-        };
-        let resp = Response::new(info);
-        Ok(resp)
+        statement_flight_info(&plan.0.schema(), ticket)
     }
 
     #[async_backtrace::framed]
@@ -329,48 +360,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -378,42 +405,79 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = decode_statement_handle(ticket.statement_handle.as_ref())?;
+
+        info!("do_get_statement with handle={handle}");
+
+        // Statement tickets are single use, unlike prepared statements.
+        let plan = self.get_statement(&handle)?;
+        self.remove_statement(&handle);
+        let stream = self
+            .execute_query(session, &plan.0, &plan.1)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = decode_statement_handle(query.prepared_statement_handle.as_ref())?;
+
+        info!("do_get_prepared_statement with handle={handle}");
+
+        let plan = self.get_statement(&handle)?;
+        let stream = self
+            .execute_query(session, &plan.0, &plan.1)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_catalogs(context).await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context,
+                query.catalog,
+                query.db_schema_filter_pattern,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -428,9 +492,15 @@ impl FlightSqlService for FlightSqlServiceImpl {
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
+        let filter = TableFilter {
+            catalog: query.catalog,
+            db_schema_filter_pattern: query.db_schema_filter_pattern,
+            table_name_filter_pattern: query.table_name_filter_pattern,
+            table_types: query.table_types,
+            include_schema: query.include_schema,
+        };
         Ok(Response::new(
-            super::CatalogInfoProvider::get_tables(context.clone(), query.catalog.clone(), None)
-                .await?,
+            super::CatalogInfoProvider::get_tables(context.clone(), filter).await?,
         ))
     }
 
@@ -438,10 +508,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -458,21 +529,25 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys()?
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -480,11 +555,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -492,11 +568,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> std::result::Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys()?
         ))
     }
 
@@ -522,6 +599,45 @@ impl FlightSqlService for FlightSqlServiceImpl {
         Ok(res)
     }
 
+    // called by ADBC bulk ingest.
+    #[async_backtrace::framed]
+    async fn do_put_statement_ingest(
+        &self,
+        ticket: CommandStatementIngest,
+        request: Request<PeekableFlightDataStream>,
+    ) -> std::result::Result<i64, Status> {
+        let session = self.get_session(&request)?;
+        info!(
+            "do_put_statement_ingest into table={:?}.{:?}.{}",
+            ticket.catalog, ticket.schema, ticket.table
+        );
+
+        let mut batches = FlightRecordBatchStream::new_from_flight_data(
+            request.into_inner().map(|r| r.map_err(FlightError::from)),
+        );
+        // The schema message is only decoded after polling the stream once.
+        let first = batches.next().await.transpose()?;
+        let Some(arrow_schema) = batches.schema().cloned() else {
+            return Ok(0);
+        };
+        let source_schema = DataSchema::try_from(arrow_schema.as_ref())
+            .map_err(|e| status!("Unsupported ingest schema", e))?;
+        let source_schema = Arc::new(source_schema);
+
+        let schema = source_schema.clone();
+        let blocks = futures::stream::iter(first.map(Ok))
+            .chain(batches)
+            .map(move |batch| {
+                let batch = batch.map_err(|e| ErrorCode::Internal(format!("{e:?}")))?;
+                let (block, _) = DataBlock::from_record_batch(&schema, &batch)?;
+                Ok(block)
+            });
+
+        self.execute_ingest(session, &ticket, source_schema, Box::pin(blocks))
+            .await
+            .map_err(|e| status!("fail to ingest", e))
+    }
+
     #[async_backtrace::framed]
     async fn do_put_prepared_statement_query(
         &self,
@@ -534,9 +650,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let plan = self.get_statement(&handle)?;
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan.0, &plan.1)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -562,9 +678,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let plan = self.get_statement(&handle)?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &plan.0, &plan.1)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -600,7 +716,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.add_statement(handle, plan);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -628,7 +744,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             match Uuid::try_parse(handle) {
                 Ok(handle) => {
                     if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
+                        self.remove_statement(&handle);
                     }
                 }
                 Err(e) => {
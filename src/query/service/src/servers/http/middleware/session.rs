@@ -38,6 +38,7 @@ use fastrace::func_name;
 use headers::authorization::Basic;
 use headers::authorization::Bearer;
 use headers::authorization::Credentials;
+use http::header::ACCEPT;
 use http::header::AUTHORIZATION;
 use http::HeaderMap;
 use http::HeaderValue;
@@ -436,6 +437,12 @@ impl<E> HTTPSessionEndpoint<E> {
             .get(USER_AGENT)
             .map(|id| id.to_str().unwrap().to_string());
 
+        let accept = req
+            .headers()
+            .get(ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+
         let expected_node_id = req
             .headers()
             .get(HEADER_NODE_ID)
@@ -458,6 +465,7 @@ impl<E> HTTPSessionEndpoint<E> {
             expected_node_id,
             deduplicate_label,
             user_agent,
+            accept,
             trace_parent,
             opentelemetry_baggage,
             http_method: req.method().to_string(),
@@ -75,6 +75,8 @@ use crate::servers::http::v1::discovery_nodes;
 use crate::servers::http::v1::login_handler;
 use crate::servers::http::v1::logout_handler;
 use crate::servers::http::v1::query::blocks_serializer::BlocksSerializer;
+use crate::servers::http::v1::query::blocks_serializer::ResultFormat;
+use crate::servers::http::v1::query::blocks_serializer::ARROW_STREAM_CONTENT_TYPE;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::refresh_handler;
 use crate::servers::http::v1::roles::list_roles_handler;
@@ -88,6 +90,9 @@ use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::HttpHandlerKind;
 use crate::sessions::QueryAffect;
 
+/// The key of the Arrow schema metadata which keeps the JSON response without `data`.
+pub const ARROW_RESPONSE_METADATA_KEY: &str = "databend.response";
+
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
 }
@@ -164,7 +169,8 @@ impl QueryResponse {
         id: String,
         r: HttpQueryResponseInternal,
         is_final: bool,
-    ) -> impl IntoResponse {
+        format: ResultFormat,
+    ) -> poem::Response {
         let state = r.state.clone();
         let (data, next_uri) = if is_final {
             (Arc::new(BlocksSerializer::empty()), None)
@@ -214,7 +220,7 @@ impl QueryResponse {
         };
         let rows = data.num_rows();
 
-        Json(QueryResponse {
+        let response = QueryResponse {
             data,
             state: state.state,
             schema: state.schema.clone(),
@@ -232,10 +238,33 @@ impl QueryResponse {
             error: r.state.error.map(QueryError::from_error_code),
             has_result_set: r.state.has_result_set,
             result_timeout_secs: Some(r.result_timeout_secs),
-        })
-        .with_header(HEADER_QUERY_ID, id.clone())
-        .with_header(HEADER_QUERY_STATE, state.state.to_string())
-        .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        };
+        let body = match format {
+            ResultFormat::Json => Json(response).into_response(),
+            ResultFormat::Arrow => match response.to_arrow_ipc() {
+                Ok(body) => body
+                    .with_content_type(ARROW_STREAM_CONTENT_TYPE)
+                    .into_response(),
+                Err(err) => {
+                    return PoemError::from(HttpErrorCode::server_error(err)).into_response();
+                }
+            },
+        };
+        body.with_header(HEADER_QUERY_ID, id.clone())
+            .with_header(HEADER_QUERY_STATE, state.state.to_string())
+            .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+            .into_response()
+    }
+
+    /// Encodes the data as an Arrow IPC stream, the other fields are kept as JSON in the
+    /// schema metadata of the stream.
+    fn to_arrow_ipc(mut self) -> databend_common_exception::Result<Vec<u8>> {
+        let data = std::mem::replace(&mut self.data, Arc::new(BlocksSerializer::empty()));
+        let response = serde_json::to_string(&self)?;
+        data.to_arrow_ipc(HashMap::from([(
+            ARROW_RESPONSE_METADATA_KEY.to_string(),
+            response,
+        )]))
     }
 }
 
@@ -280,7 +309,10 @@ async fn query_final_handler(
                 // it is safe to set these 2 fields to None, because client now check for null/None first.
                 response.session = None;
                 response.state.affect = None;
-                Ok(QueryResponse::from_internal(query_id, response, true))
+                let format = query.result_format(ctx);
+                Ok(QueryResponse::from_internal(
+                    query_id, response, true, format,
+                ))
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
         }
@@ -341,7 +373,10 @@ async fn query_state_handler(
                     let response = query
                         .get_response_state_only()
                         .map_err(HttpErrorCode::server_error)?;
-                    Ok(QueryResponse::from_internal(query_id, response, false))
+                    let format = query.result_format(ctx);
+                    Ok(QueryResponse::from_internal(
+                        query_id, response, false, format,
+                    ))
                 }
             }
             None => Err(query_id_not_found(&query_id, &ctx.node_id)),
@@ -389,7 +424,8 @@ async fn query_page_handler(
                     poem::Error::from_string(err.message(), StatusCode::NOT_FOUND)
                 })?;
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query_id, resp, false))
+                let format = query.result_format(ctx);
+                Ok(QueryResponse::from_internal(query_id, resp, false, format))
             }
         }
     };
@@ -477,7 +513,13 @@ pub(crate) async fn query_handler(
                         &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
                     );
                 query.update_expire_time(false).await;
-                Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
+                let format = query.result_format(ctx);
+                Ok(QueryResponse::from_internal(
+                    query.id.to_string(),
+                    resp,
+                    false,
+                    format,
+                ))
             }
         }
     };
@@ -30,6 +30,7 @@ pub use http_query_handlers::query_route;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryResponseField;
 pub use http_query_handlers::QueryStats;
+pub use http_query_handlers::ARROW_RESPONSE_METADATA_KEY;
 pub use query::blocks_serializer::BlocksSerializer;
 pub use query::ExecuteStateKind;
 pub use query::ExpiringMap;
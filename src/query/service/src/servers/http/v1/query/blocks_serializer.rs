@@ -13,12 +13,23 @@
 // limitations under the License.
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::ops::DerefMut;
+use std::sync::Arc;
 
+use arrow_ipc::writer::StreamWriter;
+use arrow_schema::Schema as ArrowSchema;
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::interval::interval_to_string;
+use databend_common_expression::types::time::time_to_string;
 use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::timestamp_tz::timestamp_tz_to_string;
 use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
 use databend_common_formats::field_encoder::FieldEncoderValues;
 use databend_common_io::ewkb_to_geo;
 use databend_common_io::geo_to_ewkb;
@@ -40,11 +51,45 @@ fn data_is_null(column: &Column, row_index: usize) -> bool {
     }
 }
 
+pub const ARROW_STREAM_CONTENT_TYPE: &str = "application/vnd.apache.arrow.stream";
+
+/// The encoding of the result pages of a http query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultFormat {
+    // Rows of strings in the `data` field of the JSON response.
+    Json,
+    // An Arrow IPC stream, the JSON response without `data` is kept in the schema metadata.
+    Arrow,
+}
+
+impl ResultFormat {
+    pub fn from_setting(value: &str) -> Self {
+        match value {
+            "arrow" => ResultFormat::Arrow,
+            _ => ResultFormat::Json,
+        }
+    }
+
+    pub fn from_accept(accept: &str) -> Option<Self> {
+        accept
+            .split(',')
+            .any(|media_type| {
+                media_type
+                    .split(';')
+                    .next()
+                    .is_some_and(|t| t.trim().eq_ignore_ascii_case(ARROW_STREAM_CONTENT_TYPE))
+            })
+            .then_some(ResultFormat::Arrow)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BlocksSerializer {
     // Vec<Column> for a Block
     columns: Vec<(Vec<Column>, usize)>,
     pub(crate) format: Option<FormatSettings>,
+    // Schema of the result set, used by the Arrow encoding.
+    schema: Option<DataSchemaRef>,
 }
 
 impl BlocksSerializer {
@@ -52,6 +97,7 @@ impl BlocksSerializer {
         Self {
             columns: vec![],
             format: None,
+            schema: None,
         }
     }
 
@@ -59,9 +105,18 @@ impl BlocksSerializer {
         Self {
             columns: vec![],
             format,
+            schema: None,
         }
     }
 
+    pub fn has_schema(&self) -> bool {
+        self.schema.is_some()
+    }
+
+    pub fn set_schema(&mut self, schema: DataSchemaRef) {
+        self.schema = Some(schema);
+    }
+
     pub fn has_format(&self) -> bool {
         self.format.is_some()
     }
@@ -81,6 +136,24 @@ impl BlocksSerializer {
     pub fn num_rows(&self) -> usize {
         self.columns.iter().map(|(_, num_rows)| *num_rows).sum()
     }
+
+    /// Encodes the page as an Arrow IPC stream with one record batch for each block,
+    /// `metadata` is attached to the schema of the stream.
+    pub fn to_arrow_ipc(&self, metadata: HashMap<String, String>) -> Result<Vec<u8>> {
+        let schema = match &self.schema {
+            Some(schema) => infer_table_schema(schema)?,
+            None => infer_table_schema(&DataSchema::empty())?,
+        };
+        let arrow_schema = ArrowSchema::from(&schema).with_metadata(metadata);
+        let mut writer = StreamWriter::try_new(Vec::new(), &arrow_schema)?;
+        for (columns, _) in self.columns.iter() {
+            let batch = DataBlock::new_from_columns(columns.clone()).to_record_batch(&schema)?;
+            let batch = batch.with_schema(Arc::new(arrow_schema.clone()))?;
+            writer.write(&batch)?;
+        }
+        writer.finish()?;
+        Ok(writer.into_inner()?)
+    }
 }
 
 impl serde::Serialize for BlocksSerializer {
@@ -146,6 +219,14 @@ impl RowSerializer<'_> {
                 let v = unsafe { b.get_unchecked(self.row_index) };
                 Some(interval_to_string(v).to_string())
             }
+            Column::Time(b) => {
+                let v = unsafe { b.get_unchecked(self.row_index) };
+                Some(time_to_string(*v).to_string())
+            }
+            Column::TimestampTz(b) => {
+                let v = unsafe { b.get_unchecked(self.row_index) };
+                Some(timestamp_tz_to_string(*v).to_string())
+            }
             Column::Bitmap(_) => Some("<bitmap binary>".to_string()),
             Column::Geometry(c) => {
                 let v = unsafe { c.index_unchecked(self.row_index) };
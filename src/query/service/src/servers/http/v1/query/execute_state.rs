@@ -342,6 +342,7 @@ impl ExecuteState {
         ctx: Arc<QueryContext>,
         block_sender: SizedChannelSender<DataBlock>,
         format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
+        result_schema: Arc<parking_lot::RwLock<Option<DataSchemaRef>>>,
     ) -> Result<(), ExecutionError> {
         let make_error = || format!("failed to start query: {sql}");
 
@@ -362,6 +363,10 @@ impl ExecuteState {
             .await
             .with_context(make_error)?;
         let has_result_set = plan.has_result_set();
+        if has_result_set {
+            let mut guard = result_schema.write();
+            *guard = Some(plan.schema());
+        }
         let schema = if has_result_set {
             // check has_result_set first for safety
             QueryResponseField::from_schema(plan.schema())
@@ -31,6 +31,7 @@ use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::tenant::Tenant;
@@ -54,6 +55,7 @@ use super::RemoveReason;
 use crate::servers::http::error::QueryError;
 use crate::servers::http::v1::http_query_handlers::QueryResponseField;
 use crate::servers::http::v1::query::blocks_serializer::BlocksSerializer;
+use crate::servers::http::v1::query::blocks_serializer::ResultFormat;
 use crate::servers::http::v1::query::execute_state::ExecuteStarting;
 use crate::servers::http::v1::query::execute_state::ExecuteStopped;
 use crate::servers::http::v1::query::execute_state::ExecutorSessionState;
@@ -361,6 +363,9 @@ pub struct HttpQuery {
     /// should fetch the paginated result in a timely manner, and the interval should not
     /// exceed this result_timeout_secs.
     pub(crate) result_timeout_secs: u64,
+    /// The result format negotiated when the query is submitted, a later request may
+    /// override it by its own Accept header.
+    pub(crate) result_format: ResultFormat,
 
     pub(crate) is_txn_mgr_saved: AtomicBool,
 
@@ -465,6 +470,10 @@ impl HttpQuery {
 
         let settings = session.get_settings();
         let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
+        let result_format = match ctx.accept.as_deref().and_then(ResultFormat::from_accept) {
+            Some(result_format) => result_format,
+            None => ResultFormat::from_setting(&settings.get_http_handler_result_format()?),
+        };
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
@@ -520,6 +529,7 @@ impl HttpQuery {
         }));
 
         let format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>> = Default::default();
+        let result_schema: Arc<parking_lot::RwLock<Option<DataSchemaRef>>> = Default::default();
         let tenant = session.get_current_tenant();
         let user_name = session.get_current_user()?.name;
 
@@ -532,6 +542,7 @@ impl HttpQuery {
             req.pagination.max_rows_per_page,
             block_receiver,
             format_settings,
+            result_schema,
         )));
 
         Ok(HttpQuery {
@@ -545,6 +556,7 @@ impl HttpQuery {
             state,
             page_manager: data,
             result_timeout_secs,
+            result_format,
 
             expire_state: Arc::new(Mutex::new(ExpireState::Working)),
 
@@ -557,6 +569,13 @@ impl HttpQuery {
         })
     }
 
+    pub fn result_format(&self, ctx: &HttpQueryContext) -> ResultFormat {
+        ctx.accept
+            .as_deref()
+            .and_then(ResultFormat::from_accept)
+            .unwrap_or(self.result_format)
+    }
+
     #[async_backtrace::framed]
     #[fastrace::trace]
     pub async fn get_response_page(&self, page_no: usize) -> Result<HttpQueryResponseInternal> {
@@ -731,9 +750,12 @@ impl HttpQuery {
 
         let query_state = self.state.clone();
 
-        let query_format_settings = {
+        let (query_format_settings, query_result_schema) = {
             let page_manager = self.page_manager.lock().await;
-            page_manager.format_settings.clone()
+            (
+                page_manager.format_settings.clone(),
+                page_manager.result_schema.clone(),
+            )
         };
 
         GlobalQueryRuntime::instance().runtime().try_spawn(
@@ -745,6 +767,7 @@ impl HttpQuery {
                     query_context.clone(),
                     block_sender.clone(),
                     query_format_settings,
+                    query_result_schema,
                 ))
                 .await
                 .with_context(|| "failed to start query")
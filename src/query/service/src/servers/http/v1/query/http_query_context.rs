@@ -37,6 +37,8 @@ pub struct HttpQueryContext {
     pub expected_node_id: Option<String>,
     pub deduplicate_label: Option<String>,
     pub user_agent: Option<String>,
+    // The Accept header, used to negotiate the result format.
+    pub accept: Option<String>,
     pub trace_parent: Option<String>,
     pub opentelemetry_baggage: Option<Vec<(String, String)>>,
     pub http_method: String,
@@ -22,6 +22,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_io::prelude::FormatSettings;
 use itertools::Itertools;
 use log::debug;
@@ -57,6 +58,7 @@ pub struct PageManager {
     row_buffer: Option<Vec<Column>>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     pub(crate) format_settings: Arc<RwLock<Option<FormatSettings>>>,
+    pub(crate) result_schema: Arc<RwLock<Option<DataSchemaRef>>>,
 }
 
 impl PageManager {
@@ -64,6 +66,7 @@ impl PageManager {
         max_rows_per_page: usize,
         block_receiver: SizedChannelReceiver<DataBlock>,
         format_settings: Arc<RwLock<Option<FormatSettings>>>,
+        result_schema: Arc<RwLock<Option<DataSchemaRef>>>,
     ) -> PageManager {
         PageManager {
             total_rows: 0,
@@ -75,6 +78,7 @@ impl PageManager {
             block_receiver,
             max_rows_per_page,
             format_settings,
+            result_schema,
         }
     }
 
@@ -91,6 +95,9 @@ impl PageManager {
         let next_no = self.total_pages;
         if page_no == next_no {
             let mut serializer = BlocksSerializer::new(self.format_settings.read().clone());
+            if let Some(schema) = self.result_schema.read().clone() {
+                serializer.set_schema(schema);
+            }
             if !self.end {
                 let end = self.collect_new_page(&mut serializer, tp).await?;
                 let num_row = serializer.num_rows();
@@ -142,6 +149,11 @@ impl PageManager {
             let guard = self.format_settings.read();
             serializer.set_format(guard.as_ref().unwrap().clone());
         }
+        if !serializer.has_schema() {
+            if let Some(schema) = self.result_schema.read().clone() {
+                serializer.set_schema(schema);
+            }
+        }
 
         let columns = block
             .columns()
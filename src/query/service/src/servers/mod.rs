@@ -24,6 +24,8 @@ pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
+pub use self::postgres::PostgresConnection;
+pub use self::postgres::PostgresHandler;
 
 pub mod admin;
 pub(crate) mod federated_helper;
@@ -32,4 +34,5 @@ pub mod flight_sql;
 pub mod http;
 pub mod metrics;
 mod mysql;
+mod postgres;
 pub(crate) mod server;
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
@@ -31,6 +33,9 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::PlanExtras;
+use databend_common_sql::Planner;
+use databend_common_sql::PreparedPlan;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
 use databend_common_version::DATABEND_COMMIT_VERSION;
@@ -40,6 +45,9 @@ use futures_util::StreamExt;
 use log::error;
 use log::info;
 use opensrv_mysql::AsyncMysqlShim;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
 use opensrv_mysql::ErrorKind;
 use opensrv_mysql::InitWriter;
 use opensrv_mysql::ParamParser;
@@ -50,9 +58,13 @@ use rand::Rng as _;
 use rand::RngCore;
 use uuid::Uuid;
 
+use crate::interpreters::interpreter_plan_prepared;
 use crate::interpreters::interpreter_plan_sql;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
+use crate::servers::mysql::mysql_statement::param_to_literal;
+use crate::servers::mysql::mysql_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -64,8 +76,13 @@ use crate::sessions::Session;
 use crate::sessions::TableContext;
 use crate::stream::DataBlockStream;
 
+// The max number of prepared statements of a connection, like `max_prepared_stmt_count` of MySQL.
+const MAX_PREPARED_STATEMENTS: usize = 16382;
+
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    statements: HashMap<u32, PreparedStatement>,
+    next_statement_id: u32,
 }
 
 pub struct InteractiveWorker {
@@ -164,20 +181,72 @@ impl<W: AsyncWrite + Send + Sync + Unpin> AsyncMysqlShim<W> for InteractiveWorke
         param: ParamParser<'a>,
         writer: QueryResultWriter<'a, W>,
     ) -> Result<()> {
-        if self.base.session.is_aborting() {
-            writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    "Aborting this connection. because we are try aborting server.".as_bytes(),
-                )
-                .await?;
+        let query_id = Uuid::new_v4().to_string();
+        let sampled =
+            thread_rng().gen_range(0..100) <= self.base.session.get_trace_sample_rate()?;
+        let root = Span::root(func_path!(), SpanContext::random().sampled(sampled))
+            .with_properties(|| self.base.session.to_fastrace_properties());
 
-            return Err(ErrorCode::AbortedSession(
-                "Aborting this connection. because we are try aborting server.",
-            ));
-        }
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        tracking_payload.mem_stat = Some(MemStat::create(query_id.clone()));
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        ThreadTracker::tracking_future(async {
+            if self.base.session.is_aborting() {
+                writer
+                    .error(
+                        ErrorKind::ER_ABORTING_CONNECTION,
+                        "Aborting this connection. because we are try aborting server.".as_bytes(),
+                    )
+                    .await?;
+
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            let Some(query) = self.base.statements.get(&id).map(|stmt| stmt.query.clone()) else {
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_STMT_HANDLER,
+                        format!(
+                            "Unknown prepared statement handler ({}) given to EXECUTE",
+                            id
+                        )
+                        .as_bytes(),
+                    )
+                    .await?;
+                return Ok(());
+            };
+
+            let mut writer = DFQueryResultWriter::create_binary(writer, self.base.session.clone());
+            if !self.keep_alive_task_started {
+                self.start_keep_alive().await
+            }
+
+            let instant = Instant::now();
+            let query_result = self
+                .base
+                .do_execute(query_id, id, param)
+                .await
+                .map_err(|err| err.display_with_sql(&query));
+
+            let format = self.base.session.get_format_settings();
+
+            let mut write_result = writer.write(query_result, &format).await;
 
-        self.base.do_execute(id, param, writer).await
+            if let Err(cause) = write_result {
+                self.base.session.txn_mgr().lock().set_fail();
+                let suffix = format!("(while in prepared statement {})", query);
+                write_result = Err(cause.add_message_back(suffix));
+            }
+            observe_mysql_process_request_duration(instant.elapsed());
+
+            write_result
+        })
+        .in_span(root)
+        .await
     }
 
     /// https://dev.mysql.com/doc/internals/en/com-stmt-close.html
@@ -315,36 +384,107 @@ impl InteractiveWorkerBase {
     #[async_backtrace::framed]
     async fn do_prepare<W: AsyncWrite + Unpin>(
         &mut self,
-        _: &str,
+        query: &str,
         writer: StatementMetaWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        if self.statements.len() >= MAX_PREPARED_STATEMENTS {
+            writer
+                .error(
+                    ErrorKind::ER_MAX_PREPARED_STMT_COUNT_REACHED,
+                    format!(
+                        "Can't create more than {} prepared statements",
+                        MAX_PREPARED_STATEMENTS
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let (statement, columns) = match self.prepare_statement(query).await {
+            Ok(prepared) => prepared,
+            Err(cause) => {
+                writer
+                    .error(ErrorKind::ER_UNKNOWN_ERROR, cause.message().as_bytes())
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        // The parameters are bound as literals, so the type of them is not checked here.
+        let params = (0..statement.num_params())
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut id = self.next_statement_id;
+        while self.statements.contains_key(&id) {
+            id = id.wrapping_add(1).max(1);
+        }
+        self.next_statement_id = id.wrapping_add(1).max(1);
+        self.statements.insert(id, statement);
+        writer.reply(id, &params, &columns).await?;
         Ok(())
     }
 
+    // Parse the statement and describe the result columns of it.
+    async fn prepare_statement(&self, query: &str) -> Result<(PreparedStatement, Vec<Column>)> {
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let extras = planner.parse_sql(query)?;
+        let is_query = matches!(extras.statement, Statement::Query(_));
+        let statement = PreparedStatement::create(query.to_string(), extras);
+
+        // The columns of the result set are described by planning the query with NULL parameters,
+        // clients read the actual columns from the result of execution if this fails.
+        let mut columns = vec![];
+        if is_query {
+            let dialect = context.get_settings().get_sql_dialect()?;
+            let nulls = vec!["NULL".to_string(); statement.num_params()];
+            if let Ok(extras) = statement.bind(&nulls, dialect) {
+                if let Ok(plan) = planner.plan_stmt(&extras.statement).await {
+                    columns = convert_schema(&plan.schema(), true)?;
+                }
+            }
+        }
+        Ok((statement, columns))
+    }
+
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    #[fastrace::trace]
+    async fn do_execute(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
-        writer: QueryResultWriter<'_, W>,
-    ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        query_id: String,
+        id: u32,
+        params: ParamParser<'_>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        let statement = self
+            .statements
+            .get_mut(&id)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Unknown prepared statement {}", id)))?;
+        let params = params
+            .into_iter()
+            .map(param_to_literal)
+            .collect::<Result<Vec<_>>>()?;
+
+        let context = self.session.create_query_context().await?;
+        context.update_init_query_id(query_id);
+        let dialect = context.get_settings().get_sql_dialect()?;
+        let extras = statement.bind(&params, dialect)?;
+        let query = extras.statement.to_string();
+        info!("Prepared query: {}", query);
+
+        Self::run_query(context, &query, Some((extras, &mut statement.plan))).await
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -391,30 +531,42 @@ impl InteractiveWorkerBase {
                 info!("Normal query: {}", query);
                 let context = self.session.create_query_context().await?;
                 context.update_init_query_id(query_id);
-
-                // Use interpreter_plan_sql, we can write the query log if an error occurs.
-                let (plan, _, _guard) = interpreter_plan_sql(context.clone(), query, true).await?;
-
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
-                let has_result_set = plan.has_result_set();
-
-                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
-                let schema = plan.schema();
-                let format = context.get_format_settings()?;
-                Ok((
-                    QueryResult::create(
-                        blocks,
-                        extra_info,
-                        has_result_set,
-                        schema,
-                        query.to_string(),
-                    ),
-                    Some(format),
-                ))
+                Self::run_query(context, query, None).await
             }
         }
     }
 
+    async fn run_query(
+        context: Arc<QueryContext>,
+        query: &str,
+        prepared: Option<(PlanExtras, &mut Option<PreparedPlan>)>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        // Use interpreter_plan_sql, we can write the query log if an error occurs.
+        let (plan, _, _guard) = match prepared {
+            Some((extras, plan)) => {
+                interpreter_plan_prepared(context.clone(), query, extras, plan, true).await?
+            }
+            None => interpreter_plan_sql(context.clone(), query, true).await?,
+        };
+
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let has_result_set = plan.has_result_set();
+
+        let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+        let schema = plan.schema();
+        let format = context.get_format_settings()?;
+        Ok((
+            QueryResult::create(
+                blocks,
+                extra_info,
+                has_result_set,
+                schema,
+                query.to_string(),
+            ),
+            Some(format),
+        ))
+    }
+
     #[async_backtrace::framed]
     #[fastrace::trace]
     async fn exec_query(
@@ -495,7 +647,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                statements: HashMap::new(),
+                next_statement_id: 1,
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
@@ -0,0 +1,264 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_ast::ast::quote::QuotedString;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::PlanExtras;
+use databend_common_sql::PreparedPlan;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use opensrv_mysql::ParamValue;
+use opensrv_mysql::ValueInner;
+
+/// A statement prepared by `COM_STMT_PREPARE`.
+///
+/// The statement is parsed once, each `COM_STMT_EXECUTE` replaces the `?`
+/// placeholders with the parameters and plans the statement, unless the plan
+/// of the last execution can be reused.
+pub struct PreparedStatement {
+    pub query: String,
+    extras: PlanExtras,
+    /// Start positions of the placeholders in the AST, in the order of the query text.
+    placeholders: Vec<u32>,
+    /// Byte ranges of the placeholders in the raw `VALUES` of INSERT and REPLACE,
+    /// which are not parsed into the AST.
+    raw_placeholders: Vec<Range<usize>>,
+    /// The plan of the last execution, reused if the parameters are the same.
+    pub plan: Option<PreparedPlan>,
+}
+
+impl PreparedStatement {
+    pub fn create(query: String, extras: PlanExtras) -> Self {
+        let mut collector = PlaceholderCollector::default();
+        extras.statement.drive(&mut collector);
+        let mut placeholders = collector.positions;
+        placeholders.sort_unstable();
+
+        let raw_placeholders = match raw_values(&extras.statement) {
+            Some(values) => tokenize_sql(values)
+                .map(|tokens| {
+                    tokens
+                        .iter()
+                        .filter(|token| token.kind == TokenKind::Placeholder)
+                        .map(|token| token.span.start as usize..token.span.end as usize)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            None => vec![],
+        };
+
+        PreparedStatement {
+            query,
+            extras,
+            placeholders,
+            raw_placeholders,
+            plan: None,
+        }
+    }
+
+    pub fn num_params(&self) -> usize {
+        self.placeholders.len() + self.raw_placeholders.len()
+    }
+
+    /// Replace the placeholders with the literals of the parameters.
+    pub fn bind(&self, params: &[String], dialect: Dialect) -> Result<PlanExtras> {
+        if params.len() != self.num_params() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement requires {} parameters, but {} are given",
+                self.num_params(),
+                params.len()
+            )));
+        }
+
+        let mut extras = self.extras.clone();
+        let (params, raw_params) = params.split_at(self.placeholders.len());
+        if !params.is_empty() {
+            let exprs = params
+                .iter()
+                .map(|param| {
+                    let tokens = tokenize_sql(param)?;
+                    Ok(parse_expr(&tokens, dialect)?)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let mut binder = PlaceholderBinder {
+                positions: &self.placeholders,
+                exprs,
+            };
+            extras.statement.drive_mut(&mut binder);
+        }
+
+        if !raw_params.is_empty() {
+            if let Some(values) = raw_values_mut(&mut extras.statement) {
+                // Replace from the end, so the ranges of the former placeholders are kept.
+                for (range, param) in self.raw_placeholders.iter().zip(raw_params).rev() {
+                    values.replace_range(range.clone(), param);
+                }
+            }
+        }
+        Ok(extras)
+    }
+}
+
+fn raw_values(stmt: &Statement) -> Option<&String> {
+    match stmt {
+        Statement::Insert(insert) => match &insert.source {
+            InsertSource::RawValues { rest_str, .. } => Some(rest_str),
+            _ => None,
+        },
+        Statement::Replace(replace) => match &replace.source {
+            InsertSource::RawValues { rest_str, .. } => Some(rest_str),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn raw_values_mut(stmt: &mut Statement) -> Option<&mut String> {
+    match stmt {
+        Statement::Insert(insert) => match &mut insert.source {
+            InsertSource::RawValues { rest_str, .. } => Some(rest_str),
+            _ => None,
+        },
+        Statement::Replace(replace) => match &mut replace.source {
+            InsertSource::RawValues { rest_str, .. } => Some(rest_str),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Default, Visitor)]
+#[visitor(Expr(enter))]
+struct PlaceholderCollector {
+    positions: Vec<u32>,
+}
+
+impl PlaceholderCollector {
+    fn enter_expr(&mut self, expr: &Expr) {
+        if let Expr::Placeholder { span } = expr {
+            self.positions
+                .push(span.map(|span| span.start).unwrap_or_default());
+        }
+    }
+}
+
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct PlaceholderBinder<'a> {
+    positions: &'a [u32],
+    exprs: Vec<Expr>,
+}
+
+impl PlaceholderBinder<'_> {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Placeholder { span } = expr {
+            let start = span.map(|span| span.start).unwrap_or_default();
+            if let Ok(index) = self.positions.binary_search(&start) {
+                *expr = self.exprs[index].clone();
+            }
+        }
+    }
+}
+
+/// Converts a parameter of `COM_STMT_EXECUTE` into a SQL literal.
+pub fn param_to_literal(param: ParamValue) -> Result<String> {
+    match param.value.into_inner() {
+        ValueInner::NULL => Ok("NULL".to_string()),
+        ValueInner::Int(v) => Ok(v.to_string()),
+        ValueInner::UInt(v) => Ok(v.to_string()),
+        ValueInner::Double(v) if v.is_finite() => Ok(format!("{:?}", v)),
+        ValueInner::Double(v) => Ok(format!("'{}'::DOUBLE", v)),
+        ValueInner::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(v) => Ok(QuotedString(v, '\'').to_string()),
+            Err(_) => Ok(format!("FROM_HEX('{}')", hex::encode(bytes))),
+        },
+        ValueInner::Date(bytes) | ValueInner::Datetime(bytes) => datetime_literal(bytes),
+        ValueInner::Time(bytes) => time_literal(bytes),
+    }
+}
+
+// The binary encoding of DATE and DATETIME is:
+// length (0, 4, 7 or 11 bytes), year (2), month (1), day (1),
+// hour (1), minute (1), second (1), microsecond (4).
+fn datetime_literal(bytes: &[u8]) -> Result<String> {
+    match bytes.len() {
+        0 => Ok("'1970-01-01'::DATE".to_string()),
+        4 => Ok(format!(
+            "'{:04}-{:02}-{:02}'::DATE",
+            u16::from_le_bytes([bytes[0], bytes[1]]),
+            bytes[2],
+            bytes[3]
+        )),
+        7 | 11 => {
+            let micros = if bytes.len() == 11 {
+                u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]])
+            } else {
+                0
+            };
+            Ok(format!(
+                "'{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}'::TIMESTAMP",
+                u16::from_le_bytes([bytes[0], bytes[1]]),
+                bytes[2],
+                bytes[3],
+                bytes[4],
+                bytes[5],
+                bytes[6],
+                micros
+            ))
+        }
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of DATETIME parameter",
+            len
+        ))),
+    }
+}
+
+// The binary encoding of TIME is:
+// length (0, 8 or 12 bytes), is_negative (1), days (4),
+// hour (1), minute (1), second (1), microsecond (4).
+fn time_literal(bytes: &[u8]) -> Result<String> {
+    match bytes.len() {
+        0 => Ok("'00:00:00'".to_string()),
+        8 | 12 => {
+            let sign = if bytes[0] == 1 { "-" } else { "" };
+            let days = u32::from_le_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+            let hours = u64::from(days) * 24 + u64::from(bytes[5]);
+            let micros = if bytes.len() == 12 {
+                u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]])
+            } else {
+                0
+            };
+            Ok(format!(
+                "'{}{:02}:{:02}:{:02}.{:06}'",
+                sign, hours, bytes[6], bytes[7], micros
+            ))
+        }
+        len => Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of TIME parameter",
+            len
+        ))),
+    }
+}
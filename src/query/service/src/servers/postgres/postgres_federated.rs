@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::postgres::POSTGRES_SERVER_VERSION;
+
+pub struct PostgresFederated {}
+
+impl PostgresFederated {
+    pub fn create() -> Self {
+        PostgresFederated {}
+    }
+
+    // Build block for the show statement.
+    // Format:
+    // |name|
+    // |value|
+    fn show_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block =
+            DataBlock::new_from_columns(vec![StringType::from_data(vec![value.to_string()])]);
+        Some((schema, block))
+    }
+
+    // Check for the session setup commands sent by drivers and tools.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #![allow(clippy::type_complexity)]
+        static MIXED_RULES: LazyLock<Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)>> =
+            LazyLock::new(|| {
+                vec![
+                    // JDBC and psycopg.
+                    (
+                        Regex::new("(?i)^(SET extra_float_digits(.*))").unwrap(),
+                        None,
+                    ),
+                    (Regex::new("(?i)^(SET application_name(.*))").unwrap(), None),
+                    (Regex::new("(?i)^(SET datestyle(.*))").unwrap(), None),
+                    (Regex::new("(?i)^(SET client_encoding(.*))").unwrap(), None),
+                    (
+                        Regex::new("(?i)^(SET client_min_messages(.*))").unwrap(),
+                        None,
+                    ),
+                    (Regex::new("(?i)^(SET search_path(.*))").unwrap(), None),
+                    (
+                        Regex::new("(?i)^(SET SESSION CHARACTERISTICS(.*))").unwrap(),
+                        None,
+                    ),
+                    (Regex::new("(?i)^(SET TRANSACTION(.*))").unwrap(), None),
+                    (
+                        Regex::new("(?i)^(SHOW TRANSACTION ISOLATION LEVEL)").unwrap(),
+                        PostgresFederated::show_block("transaction_isolation", "read committed"),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW server_version)$").unwrap(),
+                        PostgresFederated::show_block("server_version", POSTGRES_SERVER_VERSION),
+                    ),
+                    (
+                        Regex::new("(?i)^(SHOW standard_conforming_strings)$").unwrap(),
+                        PostgresFederated::show_block("standard_conforming_strings", "on"),
+                    ),
+                ]
+            });
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+}
@@ -0,0 +1,839 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::runtime::MemStat;
+use databend_common_base::runtime::ThreadTracker;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use fastrace::func_path;
+use fastrace::prelude::*;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+use rand::Rng;
+use uuid::Uuid;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::servers::postgres::postgres_federated::PostgresFederated;
+use crate::servers::postgres::postgres_statement::bind_parameters;
+use crate::servers::postgres::postgres_statement::split_statements;
+use crate::servers::postgres::postgres_statement::PendingRows;
+use crate::servers::postgres::postgres_statement::Portal;
+use crate::servers::postgres::postgres_statement::PreparedStatement;
+use crate::servers::postgres::protocol::read_auth_message;
+use crate::servers::postgres::protocol::read_message;
+use crate::servers::postgres::protocol::read_startup_message;
+use crate::servers::postgres::protocol::FrontendMessage;
+use crate::servers::postgres::protocol::MessageWriter;
+use crate::servers::postgres::protocol::StartupMessage;
+use crate::servers::postgres::types::encode_field;
+use crate::servers::postgres::types::field_descriptions;
+use crate::servers::postgres::types::format_code;
+use crate::servers::postgres::types::param_to_literal;
+use crate::servers::postgres::types::TEXT_OID;
+use crate::servers::postgres::POSTGRES_SERVER_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+// Flush the buffered messages to the client when they exceed 100KB.
+const FLUSH_THRESHOLD: usize = 100 * 1024;
+
+struct QueryResult {
+    blocks: SendableDataBlockStream,
+    has_result_set: bool,
+    schema: DataSchemaRef,
+    context: Option<Arc<QueryContext>>,
+}
+
+/// Serves a PostgreSQL connection, the simple and extended query protocols
+/// are supported.
+pub struct InteractiveWorker<R, W> {
+    session: Arc<Session>,
+    client_addr: String,
+    reader: R,
+    writer: W,
+    messages: MessageWriter,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    keep_alive_task_started: bool,
+}
+
+impl<R, W> InteractiveWorker<R, W>
+where
+    R: AsyncRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+{
+    pub fn create(session: Arc<Session>, client_addr: String, reader: R, writer: W) -> Self {
+        InteractiveWorker {
+            session,
+            client_addr,
+            reader,
+            writer,
+            messages: MessageWriter::default(),
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            keep_alive_task_started: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(mut self) -> Result<()> {
+        let Some(params) = self.startup().await? else {
+            return Ok(());
+        };
+
+        if let Err(error) = self.authenticate(&params).await {
+            error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                params.get("user").cloned().unwrap_or_default(),
+                self.client_addr,
+                error
+            );
+            return self.fatal(&error).await;
+        }
+
+        if let Err(error) = self.init(&params).await {
+            return self.fatal(&error).await;
+        }
+
+        self.start_keep_alive();
+        self.messages.ready_for_query(self.transaction_status());
+        self.flush().await?;
+
+        let mut ignore_till_sync = false;
+        while let Some(message) = read_message(&mut self.reader).await? {
+            if self.session.is_aborting() {
+                let error = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.fatal(&error).await?;
+                return Err(error);
+            }
+
+            // After an error of the extended query protocol, messages are
+            // discarded until the Sync message.
+            if ignore_till_sync && !matches!(message, FrontendMessage::Sync) {
+                continue;
+            }
+
+            let result = match message {
+                FrontendMessage::Query(query) => {
+                    self.on_query(&query).await;
+                    self.messages.ready_for_query(self.transaction_status());
+                    self.flush().await?;
+                    continue;
+                }
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                } => self.on_parse(name, query, param_types),
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                } => self.on_bind(portal, &statement, &param_formats, params, result_formats),
+                FrontendMessage::Describe { kind, name } => self.on_describe(kind, &name).await,
+                FrontendMessage::Execute { portal, max_rows } => {
+                    self.on_execute(&portal, max_rows).await
+                }
+                FrontendMessage::Close { kind, name } => self.on_close(kind, &name),
+                FrontendMessage::Sync => {
+                    ignore_till_sync = false;
+                    self.messages.ready_for_query(self.transaction_status());
+                    self.flush().await?;
+                    continue;
+                }
+                FrontendMessage::Flush => {
+                    self.flush().await?;
+                    continue;
+                }
+                FrontendMessage::Password(_) => Err(ErrorCode::BadBytes(
+                    "unexpected password message after authentication",
+                )),
+                FrontendMessage::Terminate => break,
+            };
+
+            if let Err(error) = result {
+                self.write_error(&error);
+                ignore_till_sync = true;
+            }
+            if self.messages.len() > FLUSH_THRESHOLD {
+                self.flush().await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the parameters of the startup message, or `None` if the client
+    // cancels a query, which is not supported.
+    async fn startup(&mut self) -> Result<Option<HashMap<String, String>>> {
+        loop {
+            match read_startup_message(&mut self.reader).await? {
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    self.messages.ssl_refused();
+                    self.flush().await?;
+                }
+                StartupMessage::CancelRequest => return Ok(None),
+                StartupMessage::Startup { params } => return Ok(Some(params)),
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, params: &HashMap<String, String>) -> Result<()> {
+        let Some(user_name) = params.get("user") else {
+            return Err(ErrorCode::AuthenticateFailure(
+                "no PostgreSQL user name specified in startup packet",
+            ));
+        };
+
+        let user_api = UserApiProvider::instance();
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(user_name, "%");
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0].to_string();
+        let mut user = user_api
+            .get_user_with_client_ip(&tenant, identity.clone(), Some(&client_ip))
+            .await?;
+
+        // check global network policy if user is not account admin
+        if !user.is_account_admin() {
+            let global_network_policy = self
+                .session
+                .get_settings()
+                .get_network_policy()
+                .unwrap_or_default();
+            if !global_network_policy.is_empty() {
+                user_api
+                    .enforce_network_policy(&tenant, &global_network_policy, Some(&client_ip))
+                    .await?;
+            }
+        }
+
+        // Check password policy for login
+        let need_change = user_api
+            .check_login_password(&tenant, identity.clone(), &user)
+            .await?;
+        if need_change {
+            user.update_auth_need_change_password();
+        }
+
+        let authed = match &user.auth_info {
+            AuthInfo::None => true,
+            AuthInfo::Password {
+                hash_value: h,
+                hash_method: t,
+                ..
+            } => {
+                self.messages.authentication_cleartext_password();
+                self.flush().await?;
+                match read_auth_message(&mut self.reader).await? {
+                    Some(FrontendMessage::Password(password)) => *h == t.hash(password.as_bytes()),
+                    _ => false,
+                }
+            }
+            _ => {
+                return Err(ErrorCode::AuthenticateFailure(
+                    "the authentication type of the user is not supported by PostgreSQL protocol",
+                ));
+            }
+        };
+
+        user_api
+            .update_user_login_result(tenant, identity, authed, &user)
+            .await?;
+        if !authed {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "password authentication failed for user \"{}\"",
+                user_name
+            )));
+        }
+
+        self.session.set_authed_user(user, None).await?;
+        self.messages.authentication_ok();
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn init(&mut self, params: &HashMap<String, String>) -> Result<()> {
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            let init_query = format!("USE `{}`;", database);
+            if let Err(error) = self.execute_to_end(&init_query).await {
+                // Clients use the user name as the database by default, which
+                // may not exist in Databend.
+                if params.get("user") != Some(database) {
+                    return Err(error);
+                }
+            }
+        }
+
+        let timezone = self
+            .session
+            .get_settings()
+            .get_timezone()
+            .unwrap_or_else(|_| "UTC".to_string());
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        for (name, value) in [
+            ("server_version", POSTGRES_SERVER_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+            ("TimeZone", &timezone),
+            ("application_name", &application_name),
+        ] {
+            self.messages.parameter_status(name, value);
+        }
+
+        let mut rng = rand::thread_rng();
+        self.messages
+            .backend_key_data(rng.gen_range(1..i32::MAX), rng.gen());
+        Ok(())
+    }
+
+    async fn on_query(&mut self, query: &str) {
+        let statements = split_statements(query);
+        if statements.is_empty() {
+            self.messages.empty_query_response();
+            return;
+        }
+
+        for statement in statements {
+            let result = match self.do_query(statement).await {
+                Ok(result) => self.write_result(statement, result, &[], true).await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = result {
+                self.write_error(&error);
+                break;
+            }
+        }
+    }
+
+    fn on_parse(&mut self, name: String, query: String, param_types: Vec<u32>) -> Result<()> {
+        if !name.is_empty() && self.statements.contains_key(&name) {
+            return Err(ErrorCode::BadArguments(format!(
+                "prepared statement \"{}\" already exists",
+                name
+            )));
+        }
+        let statement = PreparedStatement::create(query, param_types)?;
+        self.statements.insert(name, statement);
+        self.messages.parse_complete();
+        Ok(())
+    }
+
+    fn on_bind(
+        &mut self,
+        portal: String,
+        statement_name: &str,
+        param_formats: &[i16],
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    ) -> Result<()> {
+        let statement = self.get_statement(statement_name)?;
+        if params.len() != statement.param_types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "bind message supplies {} parameters, but prepared statement \"{}\" requires {}",
+                params.len(),
+                statement_name,
+                statement.param_types.len()
+            )));
+        }
+
+        let literals = params
+            .iter()
+            .zip(statement.param_types.iter())
+            .enumerate()
+            .map(|(index, (value, type_oid))| {
+                let format = format_code(param_formats, index)?;
+                param_to_literal(value.as_deref(), *type_oid, format)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let query = bind_parameters(&statement.query, &literals)?;
+
+        self.portals.insert(portal, Portal {
+            query,
+            result_formats,
+            pending: None,
+        });
+        self.messages.bind_complete();
+        Ok(())
+    }
+
+    async fn on_describe(&mut self, kind: u8, name: &str) -> Result<()> {
+        match kind {
+            b'S' => {
+                let statement = self.get_statement(name)?;
+                let param_types = statement
+                    .param_types
+                    .iter()
+                    .map(|oid| if *oid == 0 { TEXT_OID } else { *oid })
+                    .collect::<Vec<_>>();
+                // The parameters are unknown yet, the result schema is planned
+                // with NULL parameters.
+                let literals = vec!["NULL".to_string(); param_types.len()];
+                let query = bind_parameters(&statement.query, &literals)?;
+                self.messages.parameter_description(&param_types);
+
+                match self.plan_schema(&query).await.ok().flatten() {
+                    Some(schema) => {
+                        let fields = field_descriptions(schema.fields(), &[])?;
+                        self.messages.row_description(&fields);
+                    }
+                    None => self.messages.no_data(),
+                }
+            }
+            b'P' => {
+                let portal = self.get_portal(name)?;
+                let query = portal.query.clone();
+                let result_formats = portal.result_formats.clone();
+                match self.plan_schema(&query).await? {
+                    Some(schema) => {
+                        let fields = field_descriptions(schema.fields(), &result_formats)?;
+                        self.messages.row_description(&fields);
+                    }
+                    None => self.messages.no_data(),
+                }
+            }
+            kind => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid describe message subtype '{}'",
+                    kind as char
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    async fn on_execute(&mut self, portal_name: &str, max_rows: i32) -> Result<()> {
+        let portal = self.get_portal(portal_name)?;
+        if let Some(pending) = portal.pending.take() {
+            return self.write_pending(portal_name, pending, max_rows);
+        }
+
+        let query = portal.query.clone();
+        let result_formats = portal.result_formats.clone();
+        if query.trim().is_empty() {
+            self.messages.empty_query_response();
+            return Ok(());
+        }
+
+        let result = self.do_query(&query).await?;
+        if !result.has_result_set || max_rows <= 0 {
+            return self
+                .write_result(&query, result, &result_formats, false)
+                .await;
+        }
+
+        // The execution may be suspended, buffer the rows for the following
+        // Execute messages.
+        let mut encoder = RowEncoder::create(&self.session, &result.context, result_formats);
+        let mut rows = VecDeque::new();
+        let mut blocks = result.blocks;
+        while let Some(block) = blocks.next().await {
+            encoder.encode_block(block?, |values| {
+                rows.push_back(values.map(|value| value.map(|v| v.to_vec())).collect());
+            })?;
+        }
+        self.write_pending(portal_name, PendingRows { rows, sent: 0 }, max_rows)
+    }
+
+    fn on_close(&mut self, kind: u8, name: &str) -> Result<()> {
+        match kind {
+            b'S' => {
+                self.statements.remove(name);
+            }
+            b'P' => {
+                self.portals.remove(name);
+            }
+            kind => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid close message subtype '{}'",
+                    kind as char
+                )));
+            }
+        }
+        self.messages.close_complete();
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn get_portal(&mut self, name: &str) -> Result<&mut Portal> {
+        self.portals
+            .get_mut(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    // Check the query is a federated or driver setup command.
+    fn federated_server_command_check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        let federated = PostgresFederated::create();
+        federated.check(query)
+    }
+
+    #[async_backtrace::framed]
+    async fn plan_schema(&self, query: &str) -> Result<Option<DataSchemaRef>> {
+        if query.trim().is_empty() {
+            return Ok(None);
+        }
+        if let Some((schema, _)) = self.federated_server_command_check(query) {
+            return Ok((!schema.fields().is_empty()).then_some(schema));
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await?;
+        Ok(plan.has_result_set().then(|| plan.schema()))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_query(&mut self, query: &str) -> Result<QueryResult> {
+        if self.session.is_aborting() {
+            return Err(ErrorCode::AbortedSession(
+                "Aborting this connection. because we are try aborting server.",
+            ));
+        }
+
+        if let Some((schema, data_block)) = self.federated_server_command_check(query) {
+            info!("Federated query: {}", query);
+            return Ok(QueryResult {
+                blocks: DataBlockStream::create(None, vec![data_block]).boxed(),
+                has_result_set: !schema.fields().is_empty(),
+                schema,
+                context: None,
+            });
+        }
+
+        let query_id = Uuid::new_v4().to_string();
+        let mut tracking_payload = ThreadTracker::new_tracking_payload();
+        tracking_payload.query_id = Some(query_id.clone());
+        tracking_payload.mem_stat = Some(MemStat::create(query_id.clone()));
+        let _guard = ThreadTracker::tracking(tracking_payload);
+
+        let root = Span::root(func_path!(), SpanContext::random())
+            .with_properties(|| self.session.to_fastrace_properties());
+        ThreadTracker::tracking_future(async {
+            info!("Normal query: {}", query);
+            let context = self.session.create_query_context().await?;
+            context.update_init_query_id(query_id);
+
+            // Use interpreter_plan_sql, we can write the query log if an error occurs.
+            let (plan, _, _guard) = interpreter_plan_sql(context.clone(), query, true).await?;
+            let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+
+            let blocks = context.try_spawn(
+                {
+                    let ctx = context.clone();
+                    async move { interpreter.execute(ctx).await }
+                        .in_span(Span::enter_with_local_parent(func_path!()))
+                },
+                None,
+            )?;
+            let blocks = blocks.await.map_err_to_code(ErrorCode::TokioError, || {
+                "Cannot join handle from context's runtime"
+            })??;
+
+            Ok::<_, ErrorCode>(QueryResult {
+                blocks,
+                has_result_set: plan.has_result_set(),
+                schema: plan.schema(),
+                context: Some(context),
+            })
+        })
+        .in_span(root)
+        .await
+        .map_err(|err| err.display_with_sql(query))
+    }
+
+    async fn execute_to_end(&mut self, query: &str) -> Result<()> {
+        let mut result = self.do_query(query).await?;
+        while let Some(block) = result.blocks.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    // Writes the rows and the command tag of the result, the row description
+    // is written if `describe` is true, as the simple query protocol requires.
+    async fn write_result(
+        &mut self,
+        query: &str,
+        result: QueryResult,
+        formats: &[i16],
+        describe: bool,
+    ) -> Result<()> {
+        let QueryResult {
+            mut blocks,
+            has_result_set,
+            schema,
+            context,
+        } = result;
+        if !has_result_set {
+            while let Some(block) = blocks.next().await {
+                block.map_err(|err| err.display_with_sql(query))?;
+            }
+            let affected_rows = context
+                .as_ref()
+                .map(|ctx| ctx.get_write_progress_value().rows)
+                .unwrap_or_default();
+            self.messages
+                .command_complete(&command_tag(query, affected_rows));
+            return Ok(());
+        }
+
+        if describe {
+            let fields = field_descriptions(schema.fields(), formats)?;
+            self.messages.row_description(&fields);
+        }
+
+        let mut encoder = RowEncoder::create(&self.session, &context, formats.to_vec());
+        let mut num_rows = 0;
+        while let Some(block) = blocks.next().await {
+            let block = block.map_err(|err| err.display_with_sql(query))?;
+            let messages = &mut self.messages;
+            num_rows += encoder.encode_block(block, |values| messages.data_row(values))?;
+            if self.messages.len() > FLUSH_THRESHOLD {
+                self.flush().await?;
+            }
+        }
+        self.messages
+            .command_complete(&format!("SELECT {}", num_rows));
+        Ok(())
+    }
+
+    fn write_pending(
+        &mut self,
+        portal_name: &str,
+        mut pending: PendingRows,
+        max_rows: i32,
+    ) -> Result<()> {
+        let limit = if max_rows <= 0 {
+            usize::MAX
+        } else {
+            max_rows as usize
+        };
+        for _ in 0..limit {
+            let Some(row) = pending.rows.pop_front() else {
+                break;
+            };
+            self.messages
+                .data_row(row.iter().map(|value| value.as_deref()));
+            pending.sent += 1;
+        }
+
+        if pending.rows.is_empty() {
+            self.messages
+                .command_complete(&format!("SELECT {}", pending.sent));
+        } else {
+            self.messages.portal_suspended();
+            if let Some(portal) = self.portals.get_mut(portal_name) {
+                portal.pending = Some(pending);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_error(&mut self, error: &ErrorCode) {
+        self.session.txn_mgr().lock().set_fail();
+        if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
+            error!("OnQuery Error: {:?}", error);
+        } else {
+            info!("OnQuery Error: {:?}", error);
+        }
+        self.messages
+            .error_response("ERROR", sqlstate(error), &error.message());
+    }
+
+    async fn fatal(&mut self, error: &ErrorCode) -> Result<()> {
+        self.messages
+            .error_response("FATAL", sqlstate(error), &error.message());
+        self.flush().await
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        self.messages.flush(&mut self.writer).await
+    }
+
+    fn transaction_status(&self) -> u8 {
+        let txn_mgr = self.session.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.is_fail() {
+            b'E'
+        } else if txn_mgr.is_active() {
+            b'T'
+        } else {
+            b'I'
+        }
+    }
+
+    fn start_keep_alive(&mut self) {
+        if self.keep_alive_task_started {
+            return;
+        }
+        let session = &self.session;
+        let tenant = session.get_current_tenant();
+        let session_id = session.get_id();
+        let user_name = session
+            .get_current_user()
+            .expect("postgres handler should be authed when call")
+            .name;
+        self.keep_alive_task_started = true;
+
+        databend_common_base::runtime::spawn(async move {
+            loop {
+                UserApiProvider::instance()
+                    .client_session_api(&tenant)
+                    .upsert_client_session_id(
+                        &session_id,
+                        &user_name,
+                        Duration::from_secs(3600 + 600),
+                    )
+                    .await
+                    .ok();
+                tokio::time::sleep(Duration::from_secs(3600)).await;
+            }
+        });
+    }
+}
+
+/// Encodes the rows of the blocks, values are encoded in the text or binary
+/// format requested by the client.
+struct RowEncoder {
+    encoder: FieldEncoderValues,
+    formats: Vec<i16>,
+    buf: Vec<u8>,
+    values: Vec<Option<Range<usize>>>,
+}
+
+impl RowEncoder {
+    fn create(
+        session: &Arc<Session>,
+        context: &Option<Arc<QueryContext>>,
+        formats: Vec<i16>,
+    ) -> Self {
+        let format = match context {
+            Some(ctx) => ctx
+                .get_format_settings()
+                .unwrap_or_else(|_| session.get_format_settings()),
+            None => session.get_format_settings(),
+        };
+        RowEncoder {
+            encoder: FieldEncoderValues::create_for_mysql_handler(
+                format.jiff_timezone.clone(),
+                format.timezone,
+                format.geometry_format,
+            ),
+            formats,
+            buf: vec![],
+            values: vec![],
+        }
+    }
+
+    // Calls `f` with the values of each row, returns the number of rows.
+    fn encode_block<F>(&mut self, block: DataBlock, mut f: F) -> Result<usize>
+    where F: FnMut(&mut dyn ExactSizeIterator<Item = Option<&[u8]>>) {
+        let num_rows = block.num_rows();
+        let columns = block
+            .consume_convert_to_full()
+            .columns()
+            .iter()
+            .map(|column| column.value.clone().into_column().unwrap())
+            .collect::<Vec<Column>>();
+
+        for row_index in 0..num_rows {
+            self.buf.clear();
+            self.values.clear();
+            for (index, column) in columns.iter().enumerate() {
+                let format = format_code(&self.formats, index)?;
+                let start = self.buf.len();
+                let not_null =
+                    encode_field(column, row_index, format, &self.encoder, &mut self.buf)?;
+                self.values.push(not_null.then_some(start..self.buf.len()));
+            }
+            let buf = &self.buf;
+            f(&mut self
+                .values
+                .iter()
+                .map(|value| value.clone().map(|range| &buf[range])));
+        }
+        Ok(num_rows)
+    }
+}
+
+fn command_tag(query: &str, affected_rows: usize) -> String {
+    let words = query
+        .split_whitespace()
+        .take(2)
+        .map(|word| word.trim_end_matches(';').to_uppercase())
+        .collect::<Vec<_>>();
+    match words.first().map(|word| word.as_str()) {
+        Some("INSERT") => format!("INSERT 0 {}", affected_rows),
+        Some(keyword @ ("UPDATE" | "DELETE" | "MERGE" | "COPY")) => {
+            format!("{} {}", keyword, affected_rows)
+        }
+        Some(keyword @ ("CREATE" | "DROP" | "ALTER")) if words.len() > 1 && words[1] != "OR" => {
+            format!("{} {}", keyword, words[1])
+        }
+        Some(keyword) => keyword.to_string(),
+        None => String::new(),
+    }
+}
+
+fn sqlstate(error: &ErrorCode) -> &'static str {
+    match error.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        _ => "XX000",
+    }
+}
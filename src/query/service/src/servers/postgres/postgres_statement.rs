@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// A statement created by the Parse message of the extended query protocol.
+pub struct PreparedStatement {
+    pub query: String,
+    /// Types of the parameters, `0` means unspecified.
+    pub param_types: Vec<u32>,
+}
+
+impl PreparedStatement {
+    pub fn create(query: String, mut param_types: Vec<u32>) -> Result<Self> {
+        let num_params = count_parameters(&query)?;
+        if param_types.len() < num_params {
+            param_types.resize(num_params, 0);
+        }
+        Ok(PreparedStatement { query, param_types })
+    }
+}
+
+/// A statement with bound parameters, created by the Bind message.
+pub struct Portal {
+    pub query: String,
+    pub result_formats: Vec<i16>,
+    /// The remaining rows of a suspended execution, each value is `None` for NULL.
+    pub pending: Option<PendingRows>,
+}
+
+pub struct PendingRows {
+    pub rows: VecDeque<Vec<Option<Vec<u8>>>>,
+    pub sent: usize,
+}
+
+/// Splits a simple query message into statements by the semicolons.
+///
+/// Semicolons in string literals and comments are not separators. If the query
+/// can not be tokenized, it is returned as a whole and the error is reported by
+/// the planner.
+pub fn split_statements(query: &str) -> Vec<&str> {
+    let Ok(tokens) = tokenize_sql(query) else {
+        return vec![query.trim()];
+    };
+
+    let mut statements = vec![];
+    let mut start = None;
+    for token in tokens {
+        match token.kind {
+            TokenKind::SemiColon | TokenKind::EOI => {
+                if let Some(start) = start.take() {
+                    statements.push(query[start..token.span.start as usize].trim());
+                }
+            }
+            _ => {
+                start.get_or_insert(token.span.start as usize);
+            }
+        }
+    }
+    statements
+}
+
+/// Returns the number of parameters, which is the largest `$n` placeholder.
+pub fn count_parameters(query: &str) -> Result<usize> {
+    let mut count = 0;
+    for token in tokenize_sql(query)? {
+        if token.kind == TokenKind::ColumnPosition {
+            count = count.max(placeholder_index(token.text())?);
+        }
+    }
+    Ok(count)
+}
+
+/// Replaces the `$n` placeholders with the literals of the parameters.
+///
+/// Each literal is enclosed in parentheses, so it stays a single operand next
+/// to the surrounding tokens, e.g. `10-$1` with `-5` is not bound as the
+/// comment `10--5`.
+pub fn bind_parameters(query: &str, params: &[String]) -> Result<String> {
+    if params.is_empty() {
+        return Ok(query.to_string());
+    }
+
+    let mut bound = String::with_capacity(query.len());
+    let mut last = 0;
+    for token in tokenize_sql(query)? {
+        if token.kind != TokenKind::ColumnPosition {
+            continue;
+        }
+        let index = placeholder_index(token.text())?;
+        let param = params.get(index.wrapping_sub(1)).ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "there is no parameter ${}, {} parameters are bound",
+                index,
+                params.len()
+            ))
+        })?;
+        bound.push_str(&query[last..token.span.start as usize]);
+        bound.push('(');
+        bound.push_str(param);
+        bound.push(')');
+        last = token.span.end as usize;
+    }
+    bound.push_str(&query[last..]);
+    Ok(bound)
+}
+
+fn placeholder_index(text: &str) -> Result<usize> {
+    text[1..]
+        .parse::<usize>()
+        .map_err(|_| ErrorCode::BadArguments(format!("invalid parameter placeholder {}", text)))
+}
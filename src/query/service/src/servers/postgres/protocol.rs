@@ -0,0 +1,431 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use bytes::BufMut;
+use bytes::BytesMut;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+const MAX_STARTUP_MESSAGE_SIZE: usize = 10 * 1024;
+// The messages before authentication are small, limit their size so that
+// unauthenticated clients can not make the server allocate large buffers.
+const MAX_AUTH_MESSAGE_SIZE: usize = 10 * 1024;
+const MAX_MESSAGE_SIZE: usize = 1024 * 1024 * 1024;
+
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest,
+    Startup { params: HashMap<String, String> },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Sync,
+    Flush,
+    Password(String),
+    Terminate,
+}
+
+/// Reads the first message of the connection, which has no message type.
+pub async fn read_startup_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+    let len = reader.read_i32().await? as usize;
+    if !(8..=MAX_STARTUP_MESSAGE_SIZE).contains(&len) {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid length of startup packet: {}",
+            len
+        )));
+    }
+    let mut body = vec![0; len - 4];
+    reader.read_exact(&mut body).await?;
+
+    let mut body = MessageReader::new(&body);
+    match body.read_i32()? {
+        SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+        GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+        CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+        PROTOCOL_VERSION_3 => {
+            let mut params = HashMap::new();
+            loop {
+                let name = body.read_cstr()?;
+                if name.is_empty() {
+                    break;
+                }
+                let value = body.read_cstr()?;
+                params.insert(name, value);
+            }
+            Ok(StartupMessage::Startup { params })
+        }
+        code => Err(ErrorCode::BadBytes(format!(
+            "unsupported frontend protocol {}.{}",
+            code >> 16,
+            code & 0xffff
+        ))),
+    }
+}
+
+/// Reads a message from the client, returns `None` if the connection is closed.
+pub async fn read_message<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+    read_message_with_limit(reader, MAX_MESSAGE_SIZE).await
+}
+
+/// Reads a message from the client during authentication, before the client is trusted.
+pub async fn read_auth_message<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<Option<FrontendMessage>> {
+    read_message_with_limit(reader, MAX_AUTH_MESSAGE_SIZE).await
+}
+
+async fn read_message_with_limit<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    max_len: usize,
+) -> Result<Option<FrontendMessage>> {
+    let tag = match reader.read_u8().await {
+        Ok(tag) => tag,
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    let len = reader.read_i32().await?;
+    if len < 4 || len as usize > max_len {
+        return Err(ErrorCode::BadBytes(format!(
+            "invalid message length: {}",
+            len
+        )));
+    }
+    // The buffer grows with the bytes actually received instead of the declared length.
+    let body_len = len as usize - 4;
+    let mut body = Vec::with_capacity(body_len.min(MAX_AUTH_MESSAGE_SIZE));
+    (&mut *reader)
+        .take(body_len as u64)
+        .read_to_end(&mut body)
+        .await?;
+    if body.len() != body_len {
+        return Err(ErrorCode::BadBytes(format!(
+            "unexpected end of message, expect {} bytes, got {}",
+            body_len,
+            body.len()
+        )));
+    }
+
+    let mut body = MessageReader::new(&body);
+    let message = match tag {
+        b'Q' => FrontendMessage::Query(body.read_cstr()?),
+        b'P' => {
+            let name = body.read_cstr()?;
+            let query = body.read_cstr()?;
+            let num_types = body.read_i16()?;
+            let param_types = (0..num_types)
+                .map(|_| body.read_i32().map(|oid| oid as u32))
+                .collect::<Result<_>>()?;
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            }
+        }
+        b'B' => {
+            let portal = body.read_cstr()?;
+            let statement = body.read_cstr()?;
+            let num_formats = body.read_i16()?;
+            let param_formats = (0..num_formats)
+                .map(|_| body.read_i16())
+                .collect::<Result<_>>()?;
+            let num_params = body.read_i16()?;
+            let params = (0..num_params)
+                .map(|_| {
+                    let len = body.read_i32()?;
+                    match len {
+                        -1 => Ok(None),
+                        len if len >= 0 => body.read_bytes(len as usize).map(Some),
+                        len => Err(ErrorCode::BadBytes(format!(
+                            "invalid parameter length: {}",
+                            len
+                        ))),
+                    }
+                })
+                .collect::<Result<_>>()?;
+            let num_formats = body.read_i16()?;
+            let result_formats = (0..num_formats)
+                .map(|_| body.read_i16())
+                .collect::<Result<_>>()?;
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            }
+        }
+        b'D' => FrontendMessage::Describe {
+            kind: body.read_u8()?,
+            name: body.read_cstr()?,
+        },
+        b'E' => FrontendMessage::Execute {
+            portal: body.read_cstr()?,
+            max_rows: body.read_i32()?,
+        },
+        b'C' => FrontendMessage::Close {
+            kind: body.read_u8()?,
+            name: body.read_cstr()?,
+        },
+        b'S' => FrontendMessage::Sync,
+        b'H' => FrontendMessage::Flush,
+        b'p' => FrontendMessage::Password(body.read_cstr()?),
+        b'X' => FrontendMessage::Terminate,
+        tag => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported frontend message type '{}'",
+                tag as char
+            )));
+        }
+    };
+    Ok(Some(message))
+}
+
+struct MessageReader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> MessageReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        MessageReader { buf }
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>> {
+        if self.buf.len() < len {
+            return Err(ErrorCode::BadBytes("unexpected end of message"));
+        }
+        let (bytes, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(bytes.to_vec())
+    }
+
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_i16(&mut self) -> Result<i16> {
+        let bytes = self.read_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_i32(&mut self) -> Result<i32> {
+        let bytes = self.read_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_cstr(&mut self) -> Result<String> {
+        let Some(end) = self.buf.iter().position(|b| *b == 0) else {
+            return Err(ErrorCode::BadBytes("unterminated string in message"));
+        };
+        let value = String::from_utf8(self.buf[..end].to_vec())
+            .map_err(|_| ErrorCode::BadBytes("invalid UTF-8 string in message"))?;
+        self.buf = &self.buf[end + 1..];
+        Ok(value)
+    }
+}
+
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub format: i16,
+}
+
+/// Buffers the messages to the client, they are sent on `flush`.
+#[derive(Default)]
+pub struct MessageWriter {
+    buf: BytesMut,
+}
+
+impl MessageWriter {
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub fn ssl_refused(&mut self) {
+        self.buf.put_u8(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.message(b'R', |buf| buf.put_i32(0));
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        self.message(b'R', |buf| buf.put_i32(3));
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.message(b'S', |buf| {
+            put_cstr(buf, name);
+            put_cstr(buf, value);
+        });
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.message(b'K', |buf| {
+            buf.put_i32(process_id);
+            buf.put_i32(secret_key);
+        });
+    }
+
+    pub fn ready_for_query(&mut self, status: u8) {
+        self.message(b'Z', |buf| buf.put_u8(status));
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        self.message(b'T', |buf| {
+            buf.put_i16(fields.len() as i16);
+            for field in fields {
+                put_cstr(buf, &field.name);
+                // table oid and column attribute number
+                buf.put_i32(0);
+                buf.put_i16(0);
+                buf.put_u32(field.type_oid);
+                buf.put_i16(field.type_size);
+                // type modifier
+                buf.put_i32(-1);
+                buf.put_i16(field.format);
+            }
+        });
+    }
+
+    /// Writes a data row, the value is `None` for NULL.
+    pub fn data_row<'a>(&mut self, values: impl ExactSizeIterator<Item = Option<&'a [u8]>>) {
+        self.message(b'D', |buf| {
+            buf.put_i16(values.len() as i16);
+            for value in values {
+                match value {
+                    Some(value) => {
+                        buf.put_i32(value.len() as i32);
+                        buf.put_slice(value);
+                    }
+                    None => buf.put_i32(-1),
+                }
+            }
+        });
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.message(b'C', |buf| put_cstr(buf, tag));
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.message(b'I', |_| {});
+    }
+
+    pub fn error_response(&mut self, severity: &str, code: &str, message: &str) {
+        self.message(b'E', |buf| {
+            buf.put_u8(b'S');
+            put_cstr(buf, severity);
+            buf.put_u8(b'V');
+            put_cstr(buf, severity);
+            buf.put_u8(b'C');
+            put_cstr(buf, code);
+            buf.put_u8(b'M');
+            put_cstr(buf, message);
+            buf.put_u8(0);
+        });
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.message(b'1', |_| {});
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.message(b'2', |_| {});
+    }
+
+    pub fn close_complete(&mut self) {
+        self.message(b'3', |_| {});
+    }
+
+    pub fn no_data(&mut self) {
+        self.message(b'n', |_| {});
+    }
+
+    pub fn parameter_description(&mut self, type_oids: &[u32]) {
+        self.message(b't', |buf| {
+            buf.put_i16(type_oids.len() as i16);
+            for oid in type_oids {
+                buf.put_u32(*oid);
+            }
+        });
+    }
+
+    pub fn portal_suspended(&mut self) {
+        self.message(b's', |_| {});
+    }
+
+    pub async fn flush<W: AsyncWrite + Unpin>(&mut self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.buf).await?;
+        writer.flush().await?;
+        self.buf.clear();
+        Ok(())
+    }
+
+    fn message(&mut self, tag: u8, f: impl FnOnce(&mut BytesMut)) {
+        self.buf.put_u8(tag);
+        let start = self.buf.len();
+        // The length includes itself, filled after the body is written.
+        self.buf.put_i32(0);
+        f(&mut self.buf);
+        let len = (self.buf.len() - start) as i32;
+        self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+}
+
+fn put_cstr(buf: &mut BytesMut, value: &str) {
+    buf.put_slice(value.as_bytes());
+    buf.put_u8(0);
+}
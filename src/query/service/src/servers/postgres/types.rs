@@ -0,0 +1,339 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataField;
+use databend_common_expression::ScalarRef;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::postgres::protocol::FieldDescription;
+
+pub const TEXT_FORMAT: i16 = 0;
+pub const BINARY_FORMAT: i16 = 1;
+
+// Type OIDs, see `pg_type.dat` of PostgreSQL.
+pub const BOOL_OID: u32 = 16;
+pub const BYTEA_OID: u32 = 17;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const JSON_OID: u32 = 114;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIME_OID: u32 = 1083;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const NUMERIC_OID: u32 = 1700;
+
+/// Maps a Databend type to the PostgreSQL type OID and type size.
+///
+/// Unsigned integers are mapped to the next wider signed type, as PostgreSQL
+/// has no unsigned integers. Types without a PostgreSQL counterpart are sent as text.
+pub fn type_oid(data_type: &DataType) -> (u32, i16) {
+    match data_type.remove_nullable() {
+        DataType::Boolean => (BOOL_OID, 1),
+        DataType::Number(number) => match number {
+            NumberDataType::Int8 | NumberDataType::UInt8 | NumberDataType::Int16 => (INT2_OID, 2),
+            NumberDataType::UInt16 | NumberDataType::Int32 => (INT4_OID, 4),
+            NumberDataType::UInt32 | NumberDataType::Int64 => (INT8_OID, 8),
+            NumberDataType::UInt64 => (NUMERIC_OID, -1),
+            NumberDataType::Float32 => (FLOAT4_OID, 4),
+            NumberDataType::Float64 => (FLOAT8_OID, 8),
+        },
+        DataType::Decimal(_) => (NUMERIC_OID, -1),
+        DataType::Binary => (BYTEA_OID, -1),
+        DataType::Date => (DATE_OID, 4),
+        DataType::Timestamp => (TIMESTAMP_OID, 8),
+        DataType::Time => (TIME_OID, 8),
+        DataType::TimestampTz => (TIMESTAMPTZ_OID, 8),
+        DataType::Variant => (JSON_OID, -1),
+        _ => (TEXT_OID, -1),
+    }
+}
+
+/// Builds the row description of the fields, `formats` are the result
+/// formats requested by the client in the Bind message.
+pub fn field_descriptions(fields: &[DataField], formats: &[i16]) -> Result<Vec<FieldDescription>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let (type_oid, type_size) = type_oid(field.data_type());
+            let format = format_code(formats, index)?;
+            Ok(FieldDescription {
+                name: field.name().clone(),
+                type_oid,
+                type_size,
+                format,
+            })
+        })
+        .collect()
+}
+
+/// The format codes of parameters or results can be empty (all text), a single
+/// code for all values, or one code per value.
+pub fn format_code(formats: &[i16], index: usize) -> Result<i16> {
+    let format = match formats.len() {
+        0 => TEXT_FORMAT,
+        1 => formats[0],
+        _ => *formats.get(index).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("missing format code for value {}", index + 1))
+        })?,
+    };
+    match format {
+        TEXT_FORMAT | BINARY_FORMAT => Ok(format),
+        format => Err(ErrorCode::BadArguments(format!(
+            "unsupported format code: {}",
+            format
+        ))),
+    }
+}
+
+/// Encodes the value at `row_index` into `buf`, returns false if the value is NULL.
+pub fn encode_field(
+    column: &Column,
+    row_index: usize,
+    format: i16,
+    encoder: &FieldEncoderValues,
+    buf: &mut Vec<u8>,
+) -> Result<bool> {
+    let value = unsafe { column.index_unchecked(row_index) };
+    if matches!(value, ScalarRef::Null) {
+        return Ok(false);
+    }
+    if format == BINARY_FORMAT {
+        match value {
+            ScalarRef::Number(NumberScalar::UInt64(_)) | ScalarRef::Decimal(_) => {
+                let mut text = vec![];
+                encoder.write_field(column, row_index, &mut text, false);
+                encode_numeric(&String::from_utf8_lossy(&text), buf);
+            }
+            _ => encode_binary(value, buf)?,
+        }
+        return Ok(true);
+    }
+
+    match value {
+        ScalarRef::Boolean(v) => buf.push(if v { b't' } else { b'f' }),
+        ScalarRef::Binary(v) => {
+            buf.extend_from_slice(b"\\x");
+            buf.extend_from_slice(hex::encode(v).as_bytes());
+        }
+        ScalarRef::Bitmap(_) => buf.extend_from_slice(b"<bitmap binary>"),
+        _ => encoder.write_field(column, row_index, buf, false),
+    }
+    Ok(true)
+}
+
+fn encode_binary(value: ScalarRef, buf: &mut Vec<u8>) -> Result<()> {
+    match value {
+        ScalarRef::Boolean(v) => buf.push(v as u8),
+        ScalarRef::Number(number) => match number {
+            NumberScalar::Int8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+            NumberScalar::UInt8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+            NumberScalar::Int16(v) => buf.extend_from_slice(&v.to_be_bytes()),
+            NumberScalar::UInt16(v) => buf.extend_from_slice(&(v as i32).to_be_bytes()),
+            NumberScalar::Int32(v) => buf.extend_from_slice(&v.to_be_bytes()),
+            NumberScalar::UInt32(v) => buf.extend_from_slice(&(v as i64).to_be_bytes()),
+            NumberScalar::Int64(v) => buf.extend_from_slice(&v.to_be_bytes()),
+            NumberScalar::Float32(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+            NumberScalar::Float64(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+            NumberScalar::UInt64(v) => encode_numeric(&v.to_string(), buf),
+        },
+        ScalarRef::String(v) => buf.extend_from_slice(v.as_bytes()),
+        ScalarRef::Binary(v) => buf.extend_from_slice(v),
+        value => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "binary format is not supported for {}",
+                value.infer_data_type()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Encodes a decimal text in the binary format of numeric, which is a list of
+/// base 10000 digits with the weight of the first digit.
+fn encode_numeric(text: &str, buf: &mut Vec<u8>) {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    let int_part = int_part.trim_start_matches('0');
+
+    // Pad both parts with zeros to whole groups of 4 digits.
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let frac_pad = (4 - frac_part.len() % 4) % 4;
+    let padded = format!(
+        "{}{}{}{}",
+        "0".repeat(int_pad),
+        int_part,
+        frac_part,
+        "0".repeat(frac_pad)
+    );
+    let mut digits = padded
+        .as_bytes()
+        .chunks(4)
+        .map(|group| {
+            group
+                .iter()
+                .fold(0i16, |acc, digit| acc * 10 + (digit - b'0') as i16)
+        })
+        .collect::<VecDeque<_>>();
+    let mut weight = ((int_pad + int_part.len()) / 4) as i16 - 1;
+    while digits.front() == Some(&0) {
+        digits.pop_front();
+        weight -= 1;
+    }
+    while digits.back() == Some(&0) {
+        digits.pop_back();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+
+    let sign: u16 = if negative && !digits.is_empty() {
+        0x4000
+    } else {
+        0
+    };
+    buf.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    buf.extend_from_slice(&weight.to_be_bytes());
+    buf.extend_from_slice(&sign.to_be_bytes());
+    buf.extend_from_slice(&(frac_part.len() as u16).to_be_bytes());
+    for digit in digits {
+        buf.extend_from_slice(&digit.to_be_bytes());
+    }
+}
+
+/// Converts a bound parameter into a SQL literal, which replaces the
+/// placeholder in the statement.
+pub fn param_to_literal(value: Option<&[u8]>, type_oid: u32, format: i16) -> Result<String> {
+    let Some(value) = value else {
+        return Ok("NULL".to_string());
+    };
+
+    if format == BINARY_FORMAT {
+        return match (type_oid, value.len()) {
+            (BOOL_OID, 1) => Ok(if value[0] != 0 { "TRUE" } else { "FALSE" }.to_string()),
+            (INT2_OID, 2) => Ok(i16::from_be_bytes([value[0], value[1]]).to_string()),
+            (INT4_OID, 4) => Ok(i32::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (INT8_OID, 8) => Ok(i64::from_be_bytes(value.try_into().unwrap()).to_string()),
+            (FLOAT4_OID, 4) => float_literal(f32::from_be_bytes(value.try_into().unwrap()) as f64),
+            (FLOAT8_OID, 8) => float_literal(f64::from_be_bytes(value.try_into().unwrap())),
+            (BYTEA_OID, _) => Ok(format!("FROM_HEX('{}')", hex::encode(value))),
+            (TEXT_OID | VARCHAR_OID | UNKNOWN_OID | JSON_OID, _) => {
+                Ok(quote_string(&utf8_param(value)?))
+            }
+            (type_oid, _) => Err(ErrorCode::BadArguments(format!(
+                "unsupported binary parameter of type {}",
+                type_oid
+            ))),
+        };
+    }
+
+    let value = utf8_param(value)?;
+    match type_oid {
+        BOOL_OID => match value.to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "invalid input syntax for type boolean: \"{}\"",
+                value
+            ))),
+        },
+        INT2_OID | INT4_OID | INT8_OID => value
+            .trim()
+            .parse::<i64>()
+            .map(|v| v.to_string())
+            .map_err(|_| invalid_number(&value)),
+        FLOAT4_OID | FLOAT8_OID => float_literal(
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| invalid_number(&value))?,
+        ),
+        NUMERIC_OID => {
+            let trimmed = value.trim();
+            if is_numeric_literal(trimmed) {
+                Ok(trimmed.to_string())
+            } else {
+                Err(invalid_number(&value))
+            }
+        }
+        BYTEA_OID => match value.strip_prefix("\\x") {
+            Some(hex) => Ok(format!("FROM_HEX({})", quote_string(hex))),
+            None => Ok(format!("{}::BINARY", quote_string(&value))),
+        },
+        DATE_OID => Ok(format!("{}::DATE", quote_string(&value))),
+        TIME_OID => Ok(format!("{}::TIME", quote_string(&value))),
+        TIMESTAMP_OID => Ok(format!("{}::TIMESTAMP", quote_string(&value))),
+        TIMESTAMPTZ_OID => Ok(format!("{}::TIMESTAMPTZ", quote_string(&value))),
+        _ => Ok(quote_string(&value)),
+    }
+}
+
+fn utf8_param(value: &[u8]) -> Result<String> {
+    String::from_utf8(value.to_vec())
+        .map_err(|_| ErrorCode::BadBytes("invalid UTF-8 string in parameter"))
+}
+
+fn float_literal(value: f64) -> Result<String> {
+    if value.is_finite() {
+        Ok(format!("{:?}", value))
+    } else {
+        Ok(format!("'{}'::DOUBLE", value))
+    }
+}
+
+// Matches `[+-]digits[.digits][e[+-]digits]`, with at least one digit before the exponent.
+fn is_numeric_literal(value: &str) -> bool {
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    let value = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (mantissa, exponent) = match value.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (value, None),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let valid_mantissa =
+        !(integer.is_empty() && fraction.is_empty()) && is_digits(integer) && is_digits(fraction);
+    let valid_exponent = exponent.is_none_or(|exponent| {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        !exponent.is_empty() && is_digits(exponent)
+    });
+    valid_mantissa && valid_exponent
+}
+
+fn invalid_number(value: &str) -> ErrorCode {
+    ErrorCode::BadArguments(format!(
+        "invalid input syntax for type numeric: \"{}\"",
+        value
+    ))
+}
+
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+}
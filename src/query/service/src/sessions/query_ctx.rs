@@ -66,6 +66,11 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
@@ -94,6 +99,8 @@ use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_pipeline_core::LockGuard;
+use databend_common_script::Client;
+use databend_common_script::ReturnValue;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
 use databend_common_storage::init_stage_operator;
@@ -136,6 +143,8 @@ use xorf::BinaryFuse16;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::clusters::ClusterHelper;
+use crate::interpreters::run_script;
+use crate::interpreters::ScriptClient;
 use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
@@ -373,6 +382,18 @@ impl QueryContext {
         self.shared.attach_stage(attachment);
     }
 
+    /// Marks the query as a statement of the refresh of a dynamic table, which is allowed
+    /// to write into a materialized view.
+    pub fn set_dynamic_table_refresh(&self) {
+        self.shared
+            .dynamic_table_refresh
+            .store(true, Ordering::Release);
+    }
+
+    pub fn is_dynamic_table_refresh(&self) -> bool {
+        self.shared.dynamic_table_refresh.load(Ordering::Acquire)
+    }
+
     pub fn set_ua(&self, ua: String) {
         *self.shared.user_agent.write() = ua;
     }
@@ -1878,6 +1899,36 @@ impl TableContext for QueryContext {
     fn reset_broadcast_id(&self) {
         self.shared.next_broadcast_id.store(0, Ordering::Release);
     }
+
+    #[async_backtrace::framed]
+    async fn call_procedure(
+        &self,
+        script: &str,
+        args: Vec<(String, Scalar)>,
+    ) -> Result<(DataSchemaRef, DataBlock)> {
+        let ctx = QueryContext::create_from(self);
+        let client = ScriptClient { ctx: ctx.clone() };
+        let args = args
+            .into_iter()
+            .map(|(name, arg)| Ok((name, client.var_to_ast(&arg)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let result = run_script(&ctx, script, args)
+            .await
+            .map_err(|err| err.display_with_sql(script))?;
+
+        match result {
+            Some(ReturnValue::Set(set)) => Ok((set.schema, set.block)),
+            Some(ReturnValue::Var(scalar)) => {
+                let data_type = scalar.as_ref().infer_data_type();
+                let column = ColumnBuilder::repeat(&scalar.as_ref(), 1, &data_type).build();
+                let schema = DataSchemaRefExt::create(vec![DataField::new("Result", data_type)]);
+                Ok((schema, DataBlock::new_from_columns(vec![column])))
+            }
+            None => Err(ErrorCode::ScriptExecutionError(
+                "The procedure does not return any value",
+            )),
+        }
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -140,6 +140,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) can_scan_from_agg_index: Arc<AtomicBool>,
     pub(in crate::sessions) num_fragmented_block_hint: Arc<Mutex<HashMap<String, u64>>>,
     pub(in crate::sessions) enable_sort_spill: Arc<AtomicBool>,
+    /// Set on the statements run by the refresh of a dynamic table.
+    pub(in crate::sessions) dynamic_table_refresh: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
 
@@ -227,6 +229,7 @@ impl QueryContextShared {
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             num_fragmented_block_hint: Default::default(),
             enable_sort_spill: Arc::new(AtomicBool::new(true)),
+            dynamic_table_refresh: Arc::new(AtomicBool::new(false)),
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             join_spill_progress: Arc::new(Progress::create()),
@@ -49,6 +49,8 @@ pub enum SpillerType {
     Window,
     OrderBy,
     Aggregation,
+    RangeJoin,
+    RecursiveCte,
 }
 
 impl Display for SpillerType {
@@ -59,6 +61,8 @@ impl Display for SpillerType {
             SpillerType::Window => write!(f, "Window"),
             SpillerType::OrderBy => write!(f, "OrderBy"),
             SpillerType::Aggregation => write!(f, "Aggregation"),
+            SpillerType::RangeJoin => write!(f, "RangeJoin"),
+            SpillerType::RecursiveCte => write!(f, "RecursiveCte"),
         }
     }
 }
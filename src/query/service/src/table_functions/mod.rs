@@ -21,6 +21,7 @@ mod list_stage;
 mod numbers;
 mod openai;
 mod others;
+mod procedure;
 mod show_grants;
 mod show_roles;
 mod show_variables;
@@ -36,6 +37,7 @@ pub use numbers::NumbersTable;
 pub use openai::GPT2SQLTable;
 pub use others::LicenseInfoTable;
 pub use others::TenantQuotaTable;
+pub use procedure::ProcedureTable;
 pub use system::TableStatisticsFunc;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableFunctionFactory;
@@ -0,0 +1,240 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::infer_schema_type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::ProcedureMeta;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_pipeline_transforms::processors::Transform;
+
+use crate::pipelines::processors::transforms::TransformCastSchema;
+
+const PROCEDURE: &str = "procedure";
+
+/// The table function that calls a procedure and returns its result,
+/// e.g. `SELECT * FROM TABLE(my_proc(1))`.
+pub struct ProcedureTable {
+    table_info: TableInfo,
+    script: String,
+    arg_names: Vec<String>,
+    args: Vec<Scalar>,
+}
+
+impl ProcedureTable {
+    pub fn create(
+        table_func_name: &str,
+        table_id: u64,
+        procedure_meta: ProcedureMeta,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.positioned;
+        if args.len() != procedure_meta.arg_names.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Procedure {} expects {} arguments, but got {}",
+                table_func_name,
+                procedure_meta.arg_names.len(),
+                args.len()
+            )));
+        }
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'system'.'{}'", table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(&procedure_meta)?,
+                engine: PROCEDURE.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(Self {
+            table_info,
+            script: procedure_meta.script,
+            arg_names: procedure_meta.arg_names,
+            args,
+        }))
+    }
+
+    /// The schema declared by `RETURNS`. A procedure that returns a scalar has a single
+    /// column `Result`, like the result of `CALL PROCEDURE`.
+    fn schema(procedure_meta: &ProcedureMeta) -> Result<TableSchemaRef> {
+        let return_types = &procedure_meta.return_types;
+        let return_names = &procedure_meta.return_names;
+        let fields = return_types
+            .iter()
+            .enumerate()
+            .map(|(i, data_type)| {
+                let name = match return_names.get(i) {
+                    Some(name) if return_names.len() == return_types.len() => name.clone(),
+                    _ if return_types.len() == 1 => "Result".to_string(),
+                    _ => format!("Result{}", i + 1),
+                };
+                Ok(TableField::new(&name, infer_schema_type(data_type)?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchemaRefExt::create(fields))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ProcedureTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(self.args.clone()))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        let args = self
+            .arg_names
+            .iter()
+            .cloned()
+            .zip(self.args.iter().cloned())
+            .collect::<Vec<_>>();
+        let schema = self.table_info.schema();
+        pipeline.add_source(
+            |output| {
+                ProcedureSource::create(
+                    ctx.clone(),
+                    output,
+                    self.script.clone(),
+                    args.clone(),
+                    schema.clone(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for ProcedureTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct ProcedureSource {
+    ctx: Arc<dyn TableContext>,
+    script: String,
+    args: Vec<(String, Scalar)>,
+    schema: TableSchemaRef,
+    finished: bool,
+}
+
+impl ProcedureSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        script: String,
+        args: Vec<(String, Scalar)>,
+        schema: TableSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, ProcedureSource {
+            ctx,
+            script,
+            args,
+            schema,
+            finished: false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for ProcedureSource {
+    const NAME: &'static str = "procedure";
+
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let (result_schema, block) = self
+            .ctx
+            .call_procedure(&self.script, std::mem::take(&mut self.args))
+            .await?;
+        if result_schema.num_fields() != self.schema.num_fields() {
+            return Err(ErrorCode::ScriptExecutionError(format!(
+                "The procedure returns {} columns, but {} columns are declared by RETURNS",
+                result_schema.num_fields(),
+                self.schema.num_fields()
+            )));
+        }
+
+        // Cast the result to the types declared by `RETURNS`.
+        let mut cast = TransformCastSchema::try_new(
+            result_schema,
+            Arc::new(DataSchema::from(&self.schema)),
+            self.ctx.get_function_context()?,
+        )?;
+        Ok(Some(cast.transform(block)?))
+    }
+}
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::ProcedureMeta;
 use databend_common_meta_types::MetaId;
 use databend_common_storages_fuse::table_functions::ClusteringStatisticsFunc;
 use databend_common_storages_fuse::table_functions::FuseAmendTable;
@@ -55,6 +56,7 @@ use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
 use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
+use crate::table_functions::procedure::ProcedureTable;
 use crate::table_functions::show_grants::ShowGrants;
 use crate::table_functions::show_roles::ShowRoles;
 use crate::table_functions::show_variables::ShowVariables;
@@ -94,6 +96,7 @@ where
 #[derive(Default)]
 pub struct TableFunctionFactory {
     creators: TableFunctionCreators,
+    procedure_table_id: MetaId,
 }
 
 impl TableFunctionFactory {
@@ -371,6 +374,7 @@ impl TableFunctionFactory {
 
         TableFunctionFactory {
             creators: RwLock::new(creators),
+            procedure_table_id: next_id(),
         }
     }
 
@@ -384,6 +388,15 @@ impl TableFunctionFactory {
         Ok(func)
     }
 
+    pub fn get_procedure(
+        &self,
+        func_name: &str,
+        procedure_meta: ProcedureMeta,
+        tbl_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        ProcedureTable::create(func_name, self.procedure_table_id, procedure_meta, tbl_args)
+    }
+
     pub fn exists(&self, func_name: &str) -> bool {
         let lock = self.creators.read();
         let func_name = func_name.to_lowercase();
@@ -0,0 +1,229 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::task::State;
+use databend_common_meta_app::principal::task::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+/// Runs a task and the graph of the tasks after it, and records each run as a `TaskRun`.
+///
+/// A task runs with the role that owns it and the session parameters of it. A task that
+/// comes after others runs once all of them have succeeded in the same graph run.
+pub struct TaskRunner {
+    tenant: Tenant,
+    cluster_id: String,
+}
+
+impl TaskRunner {
+    pub fn create(tenant: Tenant, cluster_id: String) -> Self {
+        Self { tenant, cluster_id }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run_graph(&self, root: Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let root_task_id = root.task_id;
+        let mut visited = HashSet::from([root.task_name.clone()]);
+        let mut succeeded = HashSet::new();
+        let mut queue = VecDeque::from([root]);
+        while let Some(task) = queue.pop_front() {
+            let name = task.task_name.clone();
+            if self.run(task, root_task_id, scheduled_at).await? != State::Succeeded {
+                continue;
+            }
+            succeeded.insert(name.clone());
+
+            let tasks = UserApiProvider::instance().get_tasks(&self.tenant).await?;
+            for task in tasks {
+                if task.status == Status::Started
+                    && task.after.contains(&name)
+                    && task.after.iter().all(|t| succeeded.contains(t))
+                    && visited.insert(task.task_name.clone())
+                {
+                    queue.push_back(task);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn run(
+        &self,
+        task: Task,
+        root_task_id: u64,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<State> {
+        let user_api = UserApiProvider::instance();
+        let run_id = user_api.next_task_run_id().await?;
+        let mut task_run = TaskRun {
+            task: task.clone(),
+            run_id: run_id.to_string(),
+            attempt_number: 1,
+            state: State::Executing,
+            scheduled_at,
+            completed_at: None,
+            error_code: 0,
+            error_message: None,
+            root_task_id,
+            query_id: "".to_string(),
+        };
+
+        let session = self.create_session(&task).await?;
+        if let Some(condition) = &task.when_condition {
+            let ctx = session.create_query_context().await?;
+            // The condition only needs to be true, wrap it as a filter so that any
+            // boolean expression can be used.
+            let sql = format!("SELECT 1 WHERE {}", condition);
+            if self.execute(ctx, &sql).await? == 0 {
+                info!("task {} skipped, condition is not met", task.task_name);
+                task_run.state = State::Cancelled;
+                task_run.completed_at = Some(Utc::now());
+                task_run.error_message = Some(format!("condition {} is not met", condition));
+                user_api.upsert_task_run(&self.tenant, task_run).await?;
+                return Ok(State::Cancelled);
+            }
+        }
+        user_api
+            .upsert_task_run(&self.tenant, task_run.clone())
+            .await?;
+
+        info!("task {} run {} started", task.task_name, run_id);
+        let res = self.execute_task(&session, &task, &mut task_run).await;
+        task_run.completed_at = Some(Utc::now());
+        match res {
+            Ok(_) => task_run.state = State::Succeeded,
+            Err(e) => {
+                task_run.state = State::Failed;
+                task_run.error_code = e.code() as i64;
+                task_run.error_message = Some(e.message());
+            }
+        }
+        let state = task_run.state;
+        info!("task {} run {} {}", task.task_name, run_id, state);
+        user_api.upsert_task_run(&self.tenant, task_run).await?;
+
+        if state == State::Failed {
+            self.suspend_if_failed_too_often(&task).await?;
+        }
+        Ok(state)
+    }
+
+    async fn execute_task(
+        &self,
+        session: &Arc<Session>,
+        task: &Task,
+        task_run: &mut TaskRun,
+    ) -> Result<()> {
+        let sqls = if task.script_sqls.is_empty() {
+            vec![task.query_text.clone()]
+        } else {
+            task.script_sqls.clone()
+        };
+        for sql in sqls {
+            let ctx = session.create_query_context().await?;
+            task_run.query_id = ctx.get_id();
+            self.execute(ctx, &sql).await?;
+        }
+        Ok(())
+    }
+
+    // Returns the number of rows of the result.
+    async fn execute(&self, ctx: Arc<QueryContext>, sql: &str) -> Result<usize> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        Ok(blocks.iter().map(|b| b.num_rows()).sum())
+    }
+
+    async fn suspend_if_failed_too_often(&self, task: &Task) -> Result<()> {
+        let Some(max_failures) = task.suspend_task_after_num_failures else {
+            return Ok(());
+        };
+        if max_failures == 0 {
+            return Ok(());
+        }
+        let user_api = UserApiProvider::instance();
+        let mut task_runs = user_api
+            .get_task_runs(&self.tenant)
+            .await?
+            .into_iter()
+            .filter(|r| r.task.task_id == task.task_id && r.state != State::Cancelled)
+            .collect::<Vec<_>>();
+        task_runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+        let failures = task_runs
+            .iter()
+            .take_while(|r| r.state == State::Failed)
+            .count() as u64;
+        if failures < max_failures {
+            return Ok(());
+        }
+
+        info!(
+            "task {} is suspended after {} consecutive failures",
+            task.task_name, failures
+        );
+        let now = Utc::now();
+        user_api
+            .update_task(&self.tenant, &task.task_name, |mut task| {
+                task.status = Status::Suspended;
+                task.next_scheduled_at = None;
+                task.last_suspended_at = Some(now);
+                task.updated_at = now;
+                task
+            })
+            .await
+    }
+
+    async fn create_session(&self, task: &Task) -> Result<Arc<Session>> {
+        let session_manager = SessionManager::instance();
+        let dummy_session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(dummy_session)?;
+        let user = UserInfo::new_no_auth(
+            &format!("{}-{}-task", self.tenant.tenant_name(), self.cluster_id),
+            "0.0.0.0",
+        );
+        // The task runs with the privileges of the role that owns it.
+        session
+            .set_authed_user(user, Some(task.owner.clone()))
+            .await?;
+        let settings = session.get_settings();
+        for (key, value) in task.session_params.iter() {
+            settings.set_setting(key.clone(), value.clone())?;
+        }
+        Ok(session)
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use chrono_tz::Tz;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::task::ScheduleOptions;
+use databend_common_meta_app::principal::task::ScheduleType;
+use databend_common_meta_app::principal::task::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use log::error;
+use log::info;
+
+use crate::task::TaskRunner;
+
+/// Returns the first time after `after` that matches the schedule of a task.
+pub fn next_scheduled_at(options: &ScheduleOptions, after: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    match options.schedule_type {
+        ScheduleType::IntervalType => {
+            let secs = options.interval.unwrap_or_default() as i64;
+            let millis = options.milliseconds_interval.unwrap_or_default() as i64;
+            let interval = chrono::Duration::seconds(secs) + chrono::Duration::milliseconds(millis);
+            if interval <= chrono::Duration::zero() {
+                return Err(ErrorCode::IllegalTask(format!(
+                    "illegal task schedule {}",
+                    options
+                )));
+            }
+            Ok(after + interval)
+        }
+        ScheduleType::CronType => {
+            let expr = options.cron.as_deref().unwrap_or_default();
+            let schedule = cron::Schedule::from_str(expr).map_err(|e| {
+                ErrorCode::IllegalTask(format!("invalid cron expression {}: {}", expr, e))
+            })?;
+            let tz = match options.time_zone.as_deref() {
+                Some(tz) if !tz.is_empty() => Tz::from_str(tz).map_err(|e| {
+                    ErrorCode::IllegalTask(format!("invalid time zone {}: {}", tz, e))
+                })?,
+                _ => Tz::UTC,
+            };
+            schedule
+                .after(&after.with_timezone(&tz))
+                .next()
+                .map(|t| t.with_timezone(&Utc))
+                .ok_or_else(|| {
+                    ErrorCode::IllegalTask(format!("cron expression {} never fires", expr))
+                })
+        }
+    }
+}
+
+/// Fires the scheduled tasks when the cloud control plane is not configured.
+///
+/// Every query node polls the tasks in the meta service, a due task is claimed by replacing
+/// its next scheduled time only if the task record is unchanged, so exactly one node of the
+/// cluster wins each run. The winner runs the task and then the tasks that come after it.
+pub struct TaskScheduler {
+    tenant: Tenant,
+    cluster_id: String,
+    interval: Duration,
+    initialized: AtomicBool,
+}
+
+impl TaskScheduler {
+    pub async fn init(cfg: &InnerConfig) -> Result<()> {
+        let instance = Arc::new(Self {
+            tenant: cfg.query.tenant_id.clone(),
+            cluster_id: cfg.query.cluster_id.clone(),
+            interval: Duration::from_secs(1),
+            initialized: AtomicBool::new(false),
+        });
+        GlobalInstance::set(instance);
+
+        // Tasks are scheduled by the cloud control plane if it is configured.
+        if cfg.query.cloud_control_grpc_server_address.is_some() {
+            return Ok(());
+        }
+        GlobalIORuntime::instance().try_spawn(
+            async move {
+                if let Err(e) = TaskScheduler::instance().work().await {
+                    error!("task scheduler exit {}", e);
+                }
+            },
+            Some("task-scheduler".to_string()),
+        )?;
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn initialized(&self) {
+        self.initialized.store(true, Ordering::SeqCst);
+    }
+
+    /// Runs a task and the tasks that come after it at once, regardless of its schedule.
+    pub fn execute(&self, task: Task) -> Result<()> {
+        if !self.initialized.load(Ordering::SeqCst) {
+            return Err(ErrorCode::IllegalTask("task scheduler is not initialized"));
+        }
+        self.spawn_run(task, Utc::now())
+    }
+
+    async fn work(&self) -> Result<()> {
+        // Wait all services to be initialized
+        while !self.initialized.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+
+        loop {
+            if let Err(e) = self.schedule().await {
+                error!("task scheduler failed: {}", e);
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+    }
+
+    async fn schedule(&self) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let now = Utc::now();
+        let tasks = user_api.get_tasks(&self.tenant).await?;
+        for task in tasks {
+            if !Self::is_due(&task, now) {
+                continue;
+            }
+            let name = task.task_name.clone();
+            match self.claim(&name, now).await {
+                Ok(Some(scheduled_at)) => {
+                    info!("task {} is scheduled at {}", name, scheduled_at);
+                    self.spawn_run(task, scheduled_at)?;
+                }
+                Ok(None) => {}
+                Err(e) => error!("claim task {} failed: {}", name, e),
+            }
+        }
+        Ok(())
+    }
+
+    // Only the started root tasks are scheduled, the others are run after their predecessors.
+    fn is_due(task: &Task, now: DateTime<Utc>) -> bool {
+        task.status == Status::Started
+            && task.after.is_empty()
+            && task.schedule_options.is_some()
+            && task.next_scheduled_at.is_none_or(|t| t <= now)
+    }
+
+    // Move the next scheduled time of a due task forward, returns the time of the run
+    // if this node wins it.
+    async fn claim(&self, name: &str, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        let user_api = UserApiProvider::instance();
+        let seq_v = user_api.get_task_seqv(&self.tenant, name).await?;
+        let mut task = seq_v.data;
+        if !Self::is_due(&task, now) {
+            return Ok(None);
+        }
+        let Some(options) = &task.schedule_options else {
+            return Ok(None);
+        };
+        let scheduled_at = task.next_scheduled_at.unwrap_or(now);
+        task.next_scheduled_at = Some(next_scheduled_at(options, now)?);
+        if user_api
+            .update_task_if_unchanged(&self.tenant, task, seq_v.seq)
+            .await?
+        {
+            Ok(Some(scheduled_at))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn spawn_run(&self, task: Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let runner = TaskRunner::create(self.tenant.clone(), self.cluster_id.clone());
+        GlobalIORuntime::instance().try_spawn(
+            async move {
+                let name = task.task_name.clone();
+                if let Err(e) = runner.run_graph(task, scheduled_at).await {
+                    error!("task {} run failed: {}", name, e);
+                }
+            },
+            Some("task-run".to_string()),
+        )?;
+        Ok(())
+    }
+}
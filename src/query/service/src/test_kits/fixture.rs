@@ -357,7 +357,9 @@ impl TestFixture {
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
@@ -385,7 +387,9 @@ impl TestFixture {
             cluster_key: None,
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
@@ -425,7 +429,9 @@ impl TestFixture {
             cluster_key: None,
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: None,
+            clone_source: None,
             table_partition: None,
         }
     }
@@ -464,7 +470,9 @@ impl TestFixture {
             cluster_key: None,
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
         }
     }
@@ -511,7 +519,9 @@ impl TestFixture {
             cluster_key: None,
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
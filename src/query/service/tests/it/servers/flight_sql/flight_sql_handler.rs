@@ -15,13 +15,29 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 use std::fs;
+use std::future::Future;
 use std::io::Write;
+use std::sync::Arc;
 
+use arrow_array::Array;
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
+use arrow_flight::sql::CommandGetPrimaryKeys;
+use arrow_flight::sql::CommandGetTables;
+use arrow_flight::sql::CommandStatementIngest;
+use arrow_flight::sql::TableDefinitionOptions;
+use arrow_flight::sql::TableExistsOption;
+use arrow_flight::sql::TableNotExistOption;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
 use databend_common_base::base::tokio;
 use databend_common_base::runtime::Runtime;
 use databend_common_config::InnerConfig;
@@ -98,34 +114,73 @@ fn prepare_config() -> InnerConfig {
         .build()
 }
 
+async fn with_client<F, Fut>(f: F)
+where
+    F: FnOnce(FlightSqlServiceClient<Channel>) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let file = NamedTempFile::new().unwrap();
+    let path = file.into_temp_path().to_str().unwrap().to_string();
+    let _ = fs::remove_file(path.clone());
+
+    let uds = UnixListener::bind(path.clone()).unwrap();
+    let stream = UnixListenerStream::new(uds);
+
+    // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
+    let service = FlightSqlServiceImpl::create();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let serve_future = Server::builder()
+        .add_service(FlightServiceServer::new(service))
+        .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
+
+    let request_future = async {
+        let mut client = client_with_uds(path).await;
+        let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
+        debug!("Auth succeeded with token: {:?}", token);
+        f(client).await
+    };
+    tokio::pin!(serve_future);
+
+    tokio::select! {
+        _ = &mut serve_future => panic!("server returned first"),
+        _ = request_future => {
+            debug!("Client finished!");
+        }
+    }
+    shutdown_tx.send(()).unwrap();
+    serve_future.await.unwrap();
+    debug!("Server shutdown!");
+}
+
+async fn fetch_all(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+) -> Vec<RecordBatch> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await.unwrap();
+    flight_data.try_collect().await.unwrap()
+}
+
+fn string_values(batches: &[RecordBatch], column: &str) -> Vec<String> {
+    let mut values = vec![];
+    for batch in batches {
+        let array = batch.column_by_name(column).unwrap();
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        values.extend(array.iter().map(|v| v.unwrap().to_string()));
+    }
+    values
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_query() -> Result<()> {
     let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
 
     let runtime = Runtime::with_default_worker_threads()?;
     runtime.block_on(async {
-        let file = NamedTempFile::new().unwrap();
-        let path = file.into_temp_path().to_str().unwrap().to_string();
-        let _ = fs::remove_file(path.clone());
-
-        let uds = UnixListener::bind(path.clone()).unwrap();
-        let stream = UnixListenerStream::new(uds);
-
-        // We would just listen on TCP, but it seems impossible to know when tonic is ready to serve
-        let service = FlightSqlServiceImpl::create();
-        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
-        let serve_future = Server::builder()
-            .add_service(FlightServiceServer::new(service))
-            .serve_with_incoming_shutdown(stream, async { shutdown_rx.await.unwrap() });
-
-        let request_future = async {
+        with_client(|mut client| async move {
             let mut mint = Mint::new("tests/it/servers/flight_sql/testdata");
             let mut file = mint.new_goldenfile("query.txt").unwrap();
 
-            let mut client = client_with_uds(path).await;
-            let token = client.handshake(TEST_USER, TEST_PASSWORD).await.unwrap();
-
-            debug!("Auth succeeded with token: {:?}", token);
             let cases = [
                 "select 1, 'abc', 1.1, 1.1::float32, 1::nullable(int)",
                 "select [1, 2]",
@@ -147,18 +202,137 @@ async fn test_query() -> Result<()> {
                 };
                 writeln!(file, "{}", res).unwrap();
             }
-        };
-        tokio::pin!(serve_future);
+        })
+        .await;
 
-        tokio::select! {
-            _ = &mut serve_future => panic!("server returned first"),
-            _ = request_future => {
-                debug!("Client finished!");
-            }
-        }
-        shutdown_tx.send(()).unwrap();
-        serve_future.await.unwrap();
-        debug!("Server shutdown!");
+        Ok(())
+    })
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_metadata_and_ingest() -> Result<()> {
+    let _fixture = TestFixture::setup_with_config(&prepare_config()).await?;
+
+    let runtime = Runtime::with_default_worker_threads()?;
+    runtime.block_on(async {
+        with_client(|mut client| async move {
+            client
+                .execute_update("drop table if exists ingest1".to_string(), None)
+                .await
+                .unwrap();
+            client
+                .execute_update("drop table if exists `ingest``2`".to_string(), None)
+                .await
+                .unwrap();
+
+            let info = client.get_catalogs().await.unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert!(string_values(&batches, "catalog_name").contains(&"default".to_string()));
+
+            let info = client
+                .get_db_schemas(CommandGetDbSchemas {
+                    catalog: Some("default".to_string()),
+                    db_schema_filter_pattern: Some("def%".to_string()),
+                })
+                .await
+                .unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(string_values(&batches, "db_schema_name"), vec!["default"]);
+
+            let info = client.get_table_types().await.unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(string_values(&batches, "table_type"), vec!["table", "view"]);
+
+            // Bulk ingest creates the table from the batch schema.
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ]));
+            let batch = RecordBatch::try_new(schema, vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+            ])
+            .unwrap();
+            let ingest = CommandStatementIngest {
+                table_definition_options: Some(TableDefinitionOptions {
+                    if_not_exist: TableNotExistOption::Create.into(),
+                    if_exists: TableExistsOption::Append.into(),
+                }),
+                table: "ingest1".to_string(),
+                schema: Some("default".to_string()),
+                ..Default::default()
+            };
+            let rows = client
+                .execute_ingest(
+                    ingest.clone(),
+                    futures::stream::iter(vec![Ok(batch.clone())]),
+                )
+                .await
+                .unwrap();
+            assert_eq!(rows, 3);
+            let rows = client
+                .execute_ingest(ingest, futures::stream::iter(vec![Ok(batch)]))
+                .await
+                .unwrap();
+            assert_eq!(rows, 3);
+
+            // The names from the client are quoted in the created table.
+            let schema = Arc::new(Schema::new(vec![Field::new("c`d", DataType::Int32, false)]));
+            let batch =
+                RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![4]))]).unwrap();
+            let ingest = CommandStatementIngest {
+                table_definition_options: Some(TableDefinitionOptions {
+                    if_not_exist: TableNotExistOption::Create.into(),
+                    if_exists: TableExistsOption::Fail.into(),
+                }),
+                table: "ingest`2".to_string(),
+                schema: Some("default".to_string()),
+                ..Default::default()
+            };
+            let rows = client
+                .execute_ingest(ingest, futures::stream::iter(vec![Ok(batch)]))
+                .await
+                .unwrap();
+            assert_eq!(rows, 1);
+            let info = client
+                .execute("select `c``d` as c from `ingest``2`".to_string(), None)
+                .await
+                .unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
+            let info = client
+                .get_tables(CommandGetTables {
+                    catalog: Some("default".to_string()),
+                    db_schema_filter_pattern: Some("default".to_string()),
+                    table_name_filter_pattern: Some("ingest_".to_string()),
+                    table_types: vec![],
+                    include_schema: false,
+                })
+                .await
+                .unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(string_values(&batches, "table_name"), vec!["ingest1"]);
+
+            let info = client
+                .execute("select b from ingest1 where a = 3".to_string(), None)
+                .await
+                .unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(string_values(&batches, "b"), vec!["z", "z"]);
+
+            let info = client
+                .get_primary_keys(CommandGetPrimaryKeys {
+                    catalog: None,
+                    db_schema: None,
+                    table: "ingest1".to_string(),
+                })
+                .await
+                .unwrap();
+            let batches = fetch_all(&mut client, info).await;
+            assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 0);
+        })
+        .await;
 
         Ok(())
     })
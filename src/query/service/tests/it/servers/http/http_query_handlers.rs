@@ -17,6 +17,10 @@ use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
 
+use arrow_array::Array;
+use arrow_array::RecordBatch;
+use arrow_array::UInt64Array;
+use arrow_ipc::reader::StreamReader;
 use base64::engine::general_purpose;
 use base64::prelude::*;
 use databend_common_base::base::get_free_tcp_port;
@@ -42,6 +46,7 @@ use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryResponseField;
 use databend_query::servers::http::v1::QueryStats;
+use databend_query::servers::http::v1::ARROW_RESPONSE_METADATA_KEY;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::sessions::QueryAffect;
@@ -1912,3 +1917,65 @@ async fn test_null_response() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_arrow_result_format() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let ep = create_endpoint()?;
+
+    let read_arrow = |body: Vec<u8>| -> Result<(serde_json::Value, Vec<RecordBatch>)> {
+        let reader = StreamReader::try_new(body.as_slice(), None)?;
+        let metadata = reader
+            .schema()
+            .metadata()
+            .get(ARROW_RESPONSE_METADATA_KEY)
+            .cloned()
+            .unwrap_or_default();
+        let batches = reader.collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok((serde_json::from_str(&metadata)?, batches))
+    };
+
+    // negotiated by the Accept header
+    let sql = "select number, number::string as s from numbers(3) order by number";
+    let json = serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 5}});
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::ACCEPT,
+        HeaderValue::from_static("application/vnd.apache.arrow.stream"),
+    );
+    let response = post_uri(&ep, "/v1/query", &json, headers).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.content_type(),
+        Some("application/vnd.apache.arrow.stream")
+    );
+    let body = response.into_body().into_vec().await.unwrap();
+    let (meta, batches) = read_arrow(body)?;
+    assert_eq!(meta["state"], "Succeeded", "{:?}", meta);
+    assert_eq!(meta["data"], json!([]));
+    let rows = batches.iter().map(|b| b.num_rows()).sum::<usize>();
+    assert_eq!(rows, 3);
+    let batch = &batches[0];
+    assert_eq!(batch.schema().field(0).name(), "number");
+    let numbers = batch
+        .column(0)
+        .as_any()
+        .downcast_ref::<UInt64Array>()
+        .unwrap();
+    assert_eq!(numbers.values().to_vec(), vec![0, 1, 2]);
+
+    // negotiated by the setting
+    let json = serde_json::json!({"sql": "select 1", "pagination": {"wait_time_secs": 5}, "session": {"settings": {"http_handler_result_format": "arrow"}}});
+    let response = post_uri(&ep, "/v1/query", &json, HeaderMap::default()).await?;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().into_vec().await.unwrap();
+    let (meta, batches) = read_arrow(body)?;
+    assert!(meta["error"].is_null(), "{:?}", meta);
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 1);
+
+    // JSON is still the default
+    let (status, result) = post_sql_to_endpoint(&ep, "select 1", 5).await?;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(result.data, vec![vec![Some("1".to_string())]]);
+    Ok(())
+}
@@ -17,3 +17,4 @@ mod flight;
 mod flight_sql;
 mod http;
 mod mysql;
+mod postgres;
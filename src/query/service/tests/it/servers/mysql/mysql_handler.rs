@@ -53,6 +53,72 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let statement = connection
+        .prep("SELECT ? + 1, ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    assert_eq!(statement.num_params(), 2);
+
+    let row: Option<(i64, String)> = connection
+        .exec_first(&statement, (41, "databend"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row, Some((42, "databend".to_string())));
+
+    let row: Option<(i64, String)> = connection
+        .exec_first(&statement, (1, "it's"))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row, Some((2, "it's".to_string())));
+
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close failed")?;
+
+    // The plan reused by the executions with the same parameters sees the new data.
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    connection
+        .query_drop("INSERT INTO t_prepared VALUES (1)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let statement = connection
+        .prep("SELECT count(*) FROM t_prepared WHERE a > ?")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare failed")?;
+    let row: Option<u64> = connection
+        .exec_first(&statement, (0,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row, Some(1));
+
+    connection
+        .query_drop("INSERT INTO t_prepared VALUES (2)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    let row: Option<u64> = connection
+        .exec_first(&statement, (0,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute failed")?;
+    assert_eq!(row, Some(2));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -0,0 +1,97 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::TestFixture;
+use sqlx::Connection;
+use sqlx::PgConnection;
+use sqlx::Row;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut connection = create_connection(listening.port()).await?;
+
+    let result = sqlx::raw_sql("SELECT 1; SELECT 'a;b'")
+        .fetch_all(&mut connection)
+        .await;
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().len(), 2);
+
+    // The connection is usable after an error.
+    let result = sqlx::raw_sql("SELECT * FROM not_exists")
+        .execute(&mut connection)
+        .await;
+    assert!(result.is_err());
+
+    let result = sqlx::raw_sql("SELECT 1").execute(&mut connection).await;
+    assert!(result.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut connection = create_connection(listening.port()).await?;
+
+    let row = sqlx::query("SELECT $1::BIGINT + 1 AS a, $2 AS b, $3::BOOLEAN AS c")
+        .bind(41i64)
+        .bind("it's databend")
+        .bind(true)
+        .fetch_one(&mut connection)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+
+    assert_eq!(row.get::<i64, _>("a"), 42);
+    assert_eq!(row.get::<String, _>("b"), "it's databend");
+    assert!(row.get::<bool, _>("c"));
+
+    let rows = sqlx::query("SELECT number FROM numbers(10) WHERE number < $1")
+        .bind(3i64)
+        .fetch_all(&mut connection)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(rows.len(), 3);
+
+    // A negative parameter after a minus sign is not bound as a comment.
+    let row = sqlx::query("SELECT (10-$1)::BIGINT AS a")
+        .bind(-5i64)
+        .fetch_one(&mut connection)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Query failed")?;
+    assert_eq!(row.get::<i64, _>("a"), 15);
+
+    Ok(())
+}
+
+async fn create_connection(port: u16) -> Result<PgConnection> {
+    let url = format!("postgres://root@127.0.0.1:{}/default?sslmode=disable", port);
+    PgConnection::connect(&url)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Reject connection")
+}
@@ -85,7 +85,9 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         cluster_key: None,
         inverted_indexes: None,
         ngram_indexes: None,
+        vector_indexes: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     }
@@ -152,6 +152,8 @@ fn sexpr_to_string(s_expr: &SExpr) -> String {
                     JoinType::RightMark => "RightMark",
                     JoinType::LeftSingle => "LeftSingle",
                     JoinType::RightSingle => "RightSingle",
+                    JoinType::Asof => "Asof",
+                    JoinType::LeftAsof => "LeftAsof",
                 };
 
                 let conditions: Vec<String> = join
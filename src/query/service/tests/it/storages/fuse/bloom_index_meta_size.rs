@@ -338,6 +338,8 @@ fn build_test_segment_info(
         virtual_block_meta: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector: None,
+        partition_value: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -354,6 +356,8 @@ fn build_test_segment_info(
         col_stats: col_stats.clone(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
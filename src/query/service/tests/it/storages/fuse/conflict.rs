@@ -99,6 +99,8 @@ fn test_resolvable_delete_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default(), None);
@@ -118,6 +120,8 @@ fn test_resolvable_delete_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let removed_statistics = Statistics {
@@ -130,6 +134,8 @@ fn test_resolvable_delete_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let merged_statistics = Statistics {
@@ -142,6 +148,8 @@ fn test_resolvable_delete_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -180,6 +188,8 @@ fn test_resolvable_delete_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
     assert_eq!(actual, expected);
 }
@@ -212,6 +222,8 @@ fn test_resolvable_replace_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let mut latest_snapshot = TableSnapshot::new_empty_snapshot(TableSchema::default(), None);
@@ -231,6 +243,8 @@ fn test_resolvable_replace_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let removed_statistics = Statistics {
@@ -243,6 +257,8 @@ fn test_resolvable_replace_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let merged_statistics = Statistics {
@@ -255,6 +271,8 @@ fn test_resolvable_replace_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -298,6 +316,8 @@ fn test_resolvable_replace_conflict() {
         col_stats: HashMap::new(),
         cluster_stats: None,
         virtual_block_count: None,
+        partition_value: None,
+        deleted_row_count: 0,
     };
     assert_eq!(actual, expected);
 }
@@ -54,7 +54,9 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         cluster_key: None,
         inverted_indexes: None,
         ngram_indexes: None,
+        vector_indexes: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
@@ -179,7 +179,9 @@ async fn test_snapshot_pruner() -> Result<()> {
         cluster_key: None,
         inverted_indexes: None,
         ngram_indexes: None,
+        vector_indexes: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
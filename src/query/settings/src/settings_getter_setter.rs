@@ -454,6 +454,12 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_http_handler_result_format(&self) -> Result<String> {
+        Ok(self
+            .try_get_string("http_handler_result_format")?
+            .to_lowercase())
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -482,6 +488,10 @@ impl Settings {
         Ok(self.try_get_u64("window_spill_unit_size_mb")? as usize)
     }
 
+    pub fn get_recursive_cte_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("recursive_cte_spilling_memory_ratio")? as usize)
+    }
+
     pub fn get_window_partition_sort_block_size(&self) -> Result<u64> {
         self.try_get_u64("window_partition_sort_block_size")
     }
@@ -586,6 +596,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_materialized_view_staleness_secs(&self) -> Result<u64> {
+        self.try_get_u64("materialized_view_staleness_secs")
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
@@ -929,6 +947,10 @@ impl Settings {
         Ok(self.try_get_u64("force_aggregate_data_spill")? == 1)
     }
 
+    pub fn get_force_recursive_cte_data_spill(&self) -> Result<bool> {
+        Ok(self.try_get_u64("force_recursive_cte_data_spill")? == 1)
+    }
+
     pub fn get_enable_auto_vacuum(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_auto_vacuum")? == 1)
     }
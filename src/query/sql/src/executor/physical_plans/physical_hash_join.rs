@@ -659,7 +659,9 @@ impl PhysicalPlanBuilder {
             | JoinType::LeftSingle
             | JoinType::Right
             | JoinType::RightSingle
-            | JoinType::Full => {
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => {
                 let mut result = probe_fields.clone();
                 result.extend(build_fields);
                 result
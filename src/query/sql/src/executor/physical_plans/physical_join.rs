@@ -34,6 +34,15 @@ pub enum PhysicalJoinType {
 
 // Choose physical join type by join conditions
 pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+    if join.join_type.is_asof_join() {
+        // The match condition is the only non-equi condition of asof join,
+        // equi conditions are handled by the range join itself.
+        return Ok(PhysicalJoinType::RangeJoin(
+            join.non_equi_conditions.clone(),
+            vec![],
+        ));
+    }
+
     if !join.equi_conditions.is_empty() {
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
@@ -173,7 +182,7 @@ impl PhysicalPlanBuilder {
                 .await
             }
             PhysicalJoinType::RangeJoin(range, other) => {
-                self.build_range_join(s_expr, left_required, right_required, range, other)
+                self.build_range_join(join, s_expr, left_required, right_required, range, other)
                     .await
             }
         }
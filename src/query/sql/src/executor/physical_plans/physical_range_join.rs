@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -28,6 +29,7 @@ use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ir::RelExpr;
 use crate::optimizer::ir::RelationalProperty;
 use crate::optimizer::ir::SExpr;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ColumnSet;
 use crate::ScalarExpr;
@@ -41,10 +43,11 @@ pub struct RangeJoin {
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
     // Condition's left/right side only contains one table's column
+    // For asof join, the first condition is the match condition, the rest are equi conditions.
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // Inner join, or asof/left asof join for `RangeJoinType::Asof`
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +58,17 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let right_fields = self.right.output_schema()?.fields().clone();
+        if self.join_type == JoinType::LeftAsof {
+            // Unmatched left rows are filled with nulls
+            fields.extend(
+                right_fields
+                    .into_iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_fields);
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,25 +77,39 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or "eq" for equi conditions of asof join
     pub operator: String,
 }
 
 impl PhysicalPlanBuilder {
     pub async fn build_range_join(
         &mut self,
+        join: &Join,
         s_expr: &SExpr,
         left_required: ColumnSet,
         right_required: ColumnSet,
         mut range_conditions: Vec<ScalarExpr>,
         mut other_conditions: Vec<ScalarExpr>,
     ) -> Result<PhysicalPlan> {
+        if join.join_type.is_asof_join() {
+            return self
+                .build_asof_join(
+                    join,
+                    s_expr,
+                    left_required,
+                    right_required,
+                    range_conditions,
+                )
+                .await;
+        }
+
         let left_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
         let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
 
@@ -140,6 +167,91 @@ impl PhysicalPlanBuilder {
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    // Asof join can't be commuted, the left child is always the probe side.
+    async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        range_conditions: Vec<ScalarExpr>,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        if range_conditions.len() != 1 {
+            return Err(ErrorCode::Internal(format!(
+                "Asof join expects exactly one match condition, but got {}",
+                range_conditions.len()
+            )));
+        }
+
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut conditions = Vec::with_capacity(join.equi_conditions.len() + 1);
+        conditions.push(resolve_range_condition(
+            &range_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?);
+        for condition in join.equi_conditions.iter() {
+            conditions.push(resolve_equi_condition(
+                &condition.left,
+                &condition.right,
+                &left_schema,
+                &right_schema,
+            )?);
+        }
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+fn resolve_equi_condition(
+    left: &ScalarExpr,
+    right: &ScalarExpr,
+    left_schema: &DataSchemaRef,
+    right_schema: &DataSchemaRef,
+) -> Result<RangeJoinCondition> {
+    let mut left = left.clone();
+    let mut right = right.clone();
+    let left_data_type = left.data_type()?;
+    let right_data_type = right.data_type()?;
+    if left_data_type.ne(&right_data_type) {
+        let common_type = common_super_type(
+            left_data_type.clone(),
+            right_data_type.clone(),
+            &BUILTIN_FUNCTIONS.default_cast_rules,
+        )
+        .ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!(
+                "Cannot find common type for {left_data_type} and {right_data_type}"
+            ))
+        })?;
+        left = wrap_cast(&left, &common_type);
+        right = wrap_cast(&right, &common_type);
+    }
+    Ok(RangeJoinCondition {
+        left_expr: resolve_scalar(&left, left_schema)?,
+        right_expr: resolve_scalar(&right, right_schema)?,
+        operator: "eq".to_string(),
+    })
 }
 
 fn resolve_range_condition(
@@ -26,6 +26,7 @@ use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::ir::SExpr;
+use crate::plans::UDFField;
 use crate::plans::UDFType;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -66,6 +67,8 @@ pub struct UdfFunctionDesc {
     pub headers: BTreeMap<String, String>,
 
     pub udf_type: UDFType,
+    // Set when the function is a table function.
+    pub table_fields: Option<Vec<UDFField>>,
 }
 
 impl PhysicalPlanBuilder {
@@ -138,6 +141,7 @@ impl PhysicalPlanBuilder {
                         data_type: func.return_type.clone(),
                         headers: func.headers.clone(),
                         udf_type: func.udf_type.clone(),
+                        table_fields: func.table_fields.clone(),
                     };
                     Ok(udf_func)
                 } else {
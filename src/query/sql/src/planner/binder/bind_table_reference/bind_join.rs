@@ -66,6 +66,15 @@ impl Binder {
         bind_context: &mut BindContext,
         join: &databend_common_ast::ast::Join,
     ) -> Result<(SExpr, BindContext)> {
+        let is_asof_join = matches!(join.op, JoinOperator::Asof | JoinOperator::LeftAsof);
+        if is_asof_join
+            && (join.right.is_lateral_subquery() || join.right.is_lateral_table_function())
+        {
+            return Err(ErrorCode::SemanticError(
+                "ASOF JOIN does not support lateral subquery or table function".to_string(),
+            ));
+        }
+
         let (left_child, mut left_context) = self.bind_table_reference(bind_context, &join.left)?;
         let mut left_column_bindings = left_context.columns.clone();
 
@@ -119,13 +128,22 @@ impl Binder {
             &mut right_column_bindings,
         );
 
-        let join_conditions = self.generate_join_condition(
+        let mut join_conditions = self.generate_join_condition(
             &mut bind_context,
             &join.op,
             &join.condition,
             &left_column_bindings,
             &right_column_bindings,
         )?;
+        if is_asof_join {
+            self.bind_asof_match_condition(
+                &mut bind_context,
+                join.match_condition.as_deref(),
+                &left_column_bindings,
+                &right_column_bindings,
+                &mut join_conditions,
+            )?;
+        }
 
         left_context.columns = left_column_bindings;
         right_context.columns = right_column_bindings;
@@ -238,7 +256,7 @@ impl Binder {
         right_column_bindings: &mut Vec<ColumnBinding>,
     ) {
         match join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 self.replace_column_binding(right_derived_scalars, right_column_bindings);
             }
             JoinOperator::RightOuter => {
@@ -351,6 +369,71 @@ impl Binder {
         })
     }
 
+    // The match condition of ASOF JOIN is kept as the only non-equi condition of the join,
+    // the equi-conditions in `ON` clause are used to group the rows of both sides.
+    fn bind_asof_match_condition(
+        &mut self,
+        bind_context: &mut BindContext,
+        match_condition: Option<&Expr>,
+        left_column_bindings: &[ColumnBinding],
+        right_column_bindings: &[ColumnBinding],
+        join_conditions: &mut JoinConditions,
+    ) -> Result<()> {
+        let Some(match_condition) = match_condition else {
+            return Err(ErrorCode::SemanticError(
+                "ASOF JOIN should contain a MATCH_CONDITION".to_string(),
+            ));
+        };
+        if let Some(condition) = join_conditions
+            .non_equi_conditions
+            .iter()
+            .chain(join_conditions.other_conditions.iter())
+            .next()
+        {
+            return Err(ErrorCode::SemanticError(
+                "ASOF JOIN only supports equi-conditions in ON clause".to_string(),
+            )
+            .set_span(condition.span()));
+        }
+
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(match_condition)?;
+
+        let left_columns: ColumnSet = left_column_bindings.iter().map(|c| c.index).collect();
+        let right_columns: ColumnSet = right_column_bindings.iter().map(|c| c.index).collect();
+        let is_valid = match &scalar {
+            ScalarExpr::FunctionCall(func)
+                if func.arguments.len() == 2
+                    && matches!(func.func_name.as_str(), "gt" | "lt" | "gte" | "lte") =>
+            {
+                let arg1_columns = func.arguments[0].used_columns();
+                let arg2_columns = func.arguments[1].used_columns();
+                !arg1_columns.is_empty()
+                    && !arg2_columns.is_empty()
+                    && ((arg1_columns.is_subset(&left_columns)
+                        && arg2_columns.is_subset(&right_columns))
+                        || (arg1_columns.is_subset(&right_columns)
+                            && arg2_columns.is_subset(&left_columns)))
+            }
+            _ => false,
+        };
+        if !is_valid {
+            return Err(ErrorCode::SemanticError(
+                "MATCH_CONDITION of ASOF JOIN should compare the left table with the right table by one of >, >=, <, <=".to_string(),
+            )
+            .set_span(match_condition.span()));
+        }
+
+        join_conditions.non_equi_conditions.push(scalar);
+        Ok(())
+    }
+
     pub(crate) fn bind_join_with_type(
         &mut self,
         mut join_type: JoinType,
@@ -496,7 +579,9 @@ impl Binder {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
-                    JoinType::Full => non_equi_conditions.push(predicate.clone()),
+                    JoinType::Full | JoinType::Asof | JoinType::LeftAsof => {
+                        non_equi_conditions.push(predicate.clone())
+                    }
                 },
                 JoinPredicate::Left(_) => {
                     need_push_down = true;
@@ -1008,6 +1093,8 @@ fn join_type(join_type: &JoinOperator) -> JoinType {
         JoinOperator::RightSemi => JoinType::RightSemi,
         JoinOperator::LeftAnti => JoinType::LeftAnti,
         JoinOperator::RightAnti => JoinType::RightAnti,
+        JoinOperator::Asof => JoinType::Asof,
+        JoinOperator::LeftAsof => JoinType::LeftAsof,
     }
 }
 
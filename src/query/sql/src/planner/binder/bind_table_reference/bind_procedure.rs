@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::Span;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::GetProcedureReq;
+use databend_common_meta_app::principal::ProcedureIdentity;
+use databend_common_meta_app::principal::ProcedureNameIdent;
+use databend_common_users::UserApiProvider;
+
+use crate::binder::Binder;
+
+impl Binder {
+    /// Bind a procedure call as a table function, e.g. `SELECT * FROM TABLE(my_proc(1))`.
+    ///
+    /// The procedure is looked up by the name and the types of the arguments, returns `None`
+    /// if there is no such procedure. The procedure is executed by the source of the table
+    /// function, the columns of the table are the ones declared by `RETURNS`.
+    pub(crate) fn try_bind_procedure(
+        &self,
+        span: &Span,
+        catalog: &dyn Catalog,
+        func_name: &Identifier,
+        table_args: &TableArgs,
+    ) -> Result<Option<Arc<dyn TableFunction>>> {
+        if !table_args.named.is_empty() {
+            return Ok(None);
+        }
+        let arg_types = table_args
+            .positioned
+            .iter()
+            .map(|arg| arg.as_ref().infer_data_type().to_string())
+            .collect::<Vec<_>>();
+
+        let tenant = self.ctx.get_tenant();
+        let procedure_ident = ProcedureIdentity::new(&func_name.name, arg_types.join(","));
+        let req = GetProcedureReq {
+            inner: ProcedureNameIdent::new(tenant.clone(), procedure_ident),
+        };
+        let procedure = databend_common_base::runtime::block_on(async move {
+            UserApiProvider::instance()
+                .procedure_api(&tenant)
+                .get_procedure(&req)
+                .await
+        })
+        .map_err(|err| err.set_span(*span))?;
+        let Some(procedure) = procedure else {
+            return Ok(None);
+        };
+
+        if !self
+            .ctx
+            .get_settings()
+            .get_enable_experimental_procedure()?
+        {
+            return Err(ErrorCode::SyntaxException(
+                "CALL PROCEDURE, set enable_experimental_procedure=1",
+            )
+            .set_span(*span));
+        }
+
+        let table_function = catalog
+            .get_procedure_table_function(
+                &func_name.name,
+                procedure.procedure_meta,
+                table_args.clone(),
+            )
+            .map_err(|err| err.set_span(*span))?;
+        Ok(Some(table_function))
+    }
+}
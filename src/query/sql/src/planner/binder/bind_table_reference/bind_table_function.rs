@@ -66,13 +66,17 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
-        if BUILTIN_FUNCTIONS
-            .get_property(&func_name.name)
-            .map(|p| p.kind == FunctionKind::SRF)
-            .unwrap_or(false)
-        {
+        let is_srf = is_builtin_srf(&func_name.name);
+        let udtf_fields = if is_srf {
+            None
+        } else {
+            self.get_udtf_fields(&func_name.name)?
+        };
+
+        if is_srf || udtf_fields.is_some() {
             // If it is a set-returning function, we bind it as a subquery.
             let args = parse_table_function_args(span, &func_name, params, named_params)?;
+            let srf = srf_function_call(span, &func_name, args, udtf_fields.is_some());
 
             let select_stmt = SelectStmt {
                 span: *span,
@@ -80,21 +84,7 @@ impl Binder {
                 distinct: false,
                 top_n: None,
                 select_list: vec![SelectTarget::AliasedExpr {
-                    expr: Box::new(databend_common_ast::ast::Expr::FunctionCall {
-                        span: *span,
-                        func: ASTFunctionCall {
-                            distinct: false,
-                            name: databend_common_ast::ast::Identifier::from_name(
-                                *span,
-                                &func_name.name,
-                            ),
-                            params: vec![],
-                            args,
-                            order_by: vec![],
-                            window: None,
-                            lambda: None,
-                        },
-                    }),
+                    expr: Box::new(srf),
                     alias: None,
                 }],
                 from: vec![],
@@ -107,10 +97,11 @@ impl Binder {
             let (srf_expr, mut bind_context) =
                 self.bind_select(bind_context, &select_stmt, &[], None)?;
 
+            let fields = udtf_fields.or_else(|| builtin_srf_fields(&func_name.name));
             return self.extract_srf_table_function_columns(
                 &mut bind_context,
                 span,
-                &func_name,
+                fields,
                 srf_expr,
                 alias,
             );
@@ -133,10 +124,18 @@ impl Binder {
             self.bind_result_scan(bind_context, span, alias, &table_args)
         } else {
             // Other table functions always reside is default catalog
-            let table_meta: Arc<dyn TableFunction> = self
+            let catalog = self
                 .catalogs
-                .get_default_catalog(self.ctx.session_state())?
-                .get_table_function(&func_name.name, table_args)?;
+                .get_default_catalog(self.ctx.session_state())?;
+            let procedure = if catalog.exists_table_function(&func_name.name) {
+                None
+            } else {
+                self.try_bind_procedure(span, catalog.as_ref(), &func_name, &table_args)?
+            };
+            let table_meta: Arc<dyn TableFunction> = match procedure {
+                Some(procedure) => procedure,
+                None => catalog.get_table_function(&func_name.name, table_args)?,
+            };
             let table = table_meta.as_table();
             let table_alias_name = if let Some(table_alias) = alias {
                 Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
@@ -227,30 +226,37 @@ impl Binder {
         })
     }
 
+    /// Get the output column names if the function is a table-valued UDF.
+    fn get_udtf_fields(&self, func_name: &str) -> Result<Option<Vec<String>>> {
+        let catalog = self
+            .catalogs
+            .get_default_catalog(self.ctx.session_state())?;
+        if catalog.exists_table_function(func_name) {
+            return Ok(None);
+        }
+
+        let udf = databend_common_base::runtime::block_on({
+            UserApiProvider::instance().get_udf(&self.ctx.get_tenant(), func_name)
+        })?;
+        Ok(udf.and_then(|udf| {
+            udf.definition.table_fields().map(|fields| {
+                fields
+                    .iter()
+                    .map(|field| field.name().to_string())
+                    .collect()
+            })
+        }))
+    }
+
     /// Extract the srf inner tuple fields as columns.
     fn extract_srf_table_function_columns(
         &mut self,
         bind_context: &mut BindContext,
         span: &Span,
-        func_name: &Identifier,
+        fields: Option<Vec<String>>,
         srf_expr: SExpr,
         alias: &Option<TableAlias>,
     ) -> Result<(SExpr, BindContext)> {
-        let fields = if func_name.name.eq_ignore_ascii_case("flatten") {
-            Some(vec![
-                "seq".to_string(),
-                "key".to_string(),
-                "path".to_string(),
-                "index".to_string(),
-                "value".to_string(),
-                "this".to_string(),
-            ])
-        } else if func_name.name.eq_ignore_ascii_case("json_each") {
-            Some(vec!["key".to_string(), "value".to_string()])
-        } else {
-            None
-        };
-
         if let Some(fields) = fields {
             if let RelOperator::EvalScalar(plan) = (*srf_expr.plan).clone() {
                 if plan.items.len() != 1 {
@@ -334,26 +340,18 @@ impl Binder {
                 let mut bind_context = BindContext::with_parent(parent_context.clone())?;
                 let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
-                if BUILTIN_FUNCTIONS
-                    .get_property(&func_name.name)
-                    .map(|p| p.kind == FunctionKind::SRF)
-                    .unwrap_or(false)
-                {
+                let is_srf = is_builtin_srf(&func_name.name);
+                let udtf_fields = if is_srf {
+                    None
+                } else {
+                    self.get_udtf_fields(&func_name.name)?
+                };
+
+                if is_srf || udtf_fields.is_some() {
                     let args = parse_table_function_args(span, &func_name, params, named_params)?;
 
                     // convert lateral join table function to srf function
-                    let srf = Expr::FunctionCall {
-                        span: *span,
-                        func: ASTFunctionCall {
-                            distinct: false,
-                            name: func_name.clone(),
-                            args,
-                            params: vec![],
-                            order_by: vec![],
-                            window: None,
-                            lambda: None,
-                        },
-                    };
+                    let srf = srf_function_call(span, &func_name, args, udtf_fields.is_some());
                     let select_list = vec![SelectTarget::AliasedExpr {
                         expr: Box::new(srf.clone()),
                         alias: None,
@@ -401,14 +399,17 @@ impl Binder {
                         let flatten_expr =
                             SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(srf_expr));
 
+                        let fields = udtf_fields.or_else(|| builtin_srf_fields(&func_name.name));
                         let (new_expr, mut bind_context) = self
                             .extract_srf_table_function_columns(
                                 &mut bind_context,
                                 span,
-                                &func_name,
+                                fields,
                                 flatten_expr,
                                 alias,
                             )?;
+                        // The table-valued UDF is evaluated below the ProjectSet.
+                        let new_expr = self.rewrite_udf(&mut bind_context, new_expr)?;
 
                         // add left table columns.
                         let mut new_columns = parent_context.columns.clone();
@@ -422,7 +423,7 @@ impl Binder {
                     }
                 } else {
                     Err(ErrorCode::InvalidArgument(format!(
-                        "The function '{}' is not supported for lateral joins. Lateral joins currently support only Set Returning Functions (SRFs).",
+                        "The function '{}' is not supported for lateral joins. Lateral joins currently support only Set Returning Functions (SRFs) and table-valued UDFs.",
                         func_name
                     ))
                     .set_span(*span))
@@ -433,6 +434,54 @@ impl Binder {
     }
 }
 
+fn is_builtin_srf(func_name: &str) -> bool {
+    BUILTIN_FUNCTIONS
+        .get_property(func_name)
+        .map(|p| p.kind == FunctionKind::SRF)
+        .unwrap_or(false)
+}
+
+/// The output column names of builtin set-returning functions that return tuples.
+fn builtin_srf_fields(func_name: &str) -> Option<Vec<String>> {
+    if func_name.eq_ignore_ascii_case("flatten") {
+        Some(vec![
+            "seq".to_string(),
+            "key".to_string(),
+            "path".to_string(),
+            "index".to_string(),
+            "value".to_string(),
+            "this".to_string(),
+        ])
+    } else if func_name.eq_ignore_ascii_case("json_each") {
+        Some(vec!["key".to_string(), "value".to_string()])
+    } else {
+        None
+    }
+}
+
+// A table-valued UDF returns an array of tuples, which is unnested into rows.
+fn srf_function_call(span: &Span, func_name: &Identifier, args: Vec<Expr>, udtf: bool) -> Expr {
+    let function_call = |name: Identifier, args: Vec<Expr>| Expr::FunctionCall {
+        span: *span,
+        func: ASTFunctionCall {
+            distinct: false,
+            name,
+            args,
+            params: vec![],
+            order_by: vec![],
+            window: None,
+            lambda: None,
+        },
+    };
+
+    let srf = function_call(func_name.clone(), args);
+    if udtf {
+        function_call(Identifier::from_name(*span, "unnest"), vec![srf])
+    } else {
+        srf
+    }
+}
+
 // parse flatten named params to arguments
 fn parse_table_function_args(
     span: &Span,
@@ -16,6 +16,7 @@ mod bind;
 mod bind_join;
 mod bind_location;
 mod bind_obfuscate;
+mod bind_procedure;
 mod bind_subquery;
 mod bind_table;
 mod bind_table_function;
@@ -165,6 +165,8 @@ impl<'a> Binder {
         let mut bind_context = BindContext::new();
         let plan = self.bind_statement(&mut bind_context, stmt).await?;
         self.bind_query_index(&mut bind_context, &plan).await?;
+        self.bind_query_materialized_views(&mut bind_context, &plan)
+            .await?;
         self.ctx.set_status_info(&format!(
             "bind stmt to plan done, time used: {:?}",
             start.elapsed()
@@ -375,6 +377,12 @@ impl<'a> Binder {
             Statement::DropNgramIndex(stmt) => {
                 self.bind_drop_ngram_index(bind_context, stmt).await?
             }
+            Statement::CreateVectorIndex(stmt) => {
+                self.bind_create_vector_index(bind_context, stmt).await?
+            }
+            Statement::DropVectorIndex(stmt) => {
+                self.bind_drop_vector_index(bind_context, stmt).await?
+            }
 
             // Virtual Columns
             Statement::RefreshVirtualColumn(stmt) => self.bind_refresh_virtual_column(stmt).await?,
@@ -680,19 +688,20 @@ impl<'a> Binder {
 
             // Dynamic Table
             Statement::CreateDynamicTable(stmt) => self.bind_create_dynamic_table(stmt).await?,
-
-            Statement::CreatePipe(_) => {
-                todo!()
+            Statement::RefreshDynamicTable(stmt) => self.bind_refresh_dynamic_table(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
             }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
             }
+            Statement::DropMaterializedView(stmt) => self.bind_drop_materialized_view(stmt).await?,
+
+            // Pipes
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
             Statement::CreateNotification(stmt) => self.bind_create_notification(stmt).await?,
             Statement::DropNotification(stmt) => self.bind_drop_notification(stmt).await?,
             Statement::AlterNotification(stmt) => self.bind_alter_notification(stmt).await?,
@@ -56,6 +56,12 @@ pub const DICT_OPT_KEY_REDIS_USERNAME: &str = "username";
 pub const DICT_OPT_KEY_REDIS_PASSWORD: &str = "password";
 pub const DICT_OPT_KEY_REDIS_DB_INDEX: &str = "db_index";
 
+pub const DICT_OPT_KEY_HTTP_URL: &str = "url";
+pub const DICT_OPT_KEY_HTTP_TIMEOUT: &str = "timeout";
+
+pub const DICT_OPT_KEY_DATABEND_DB: &str = "db";
+pub const DICT_OPT_KEY_DATABEND_TABLE: &str = "table";
+
 static DICT_REQUIRED_SQL_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     let mut r = HashSet::new();
     r.insert(DICT_OPT_KEY_SQL_HOST);
@@ -82,6 +88,30 @@ static DICT_OPTIONAL_REDIS_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLo
     r
 });
 
+static DICT_REQUIRED_HTTP_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert(DICT_OPT_KEY_HTTP_URL);
+    r
+});
+
+static DICT_OPTIONAL_HTTP_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert(DICT_OPT_KEY_HTTP_TIMEOUT);
+    r
+});
+
+static DICT_REQUIRED_DATABEND_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert(DICT_OPT_KEY_DATABEND_TABLE);
+    r
+});
+
+static DICT_OPTIONAL_DATABEND_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert(DICT_OPT_KEY_DATABEND_DB);
+    r
+});
+
 fn is_dict_required_sql_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     DICT_REQUIRED_SQL_OPTION_KEYS.contains(opt_key.as_ref())
 }
@@ -94,6 +124,16 @@ fn is_dict_optional_redis_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     DICT_OPTIONAL_REDIS_OPTION_KEYS.contains(opt_key.as_ref())
 }
 
+fn is_dict_http_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
+    DICT_REQUIRED_HTTP_OPTION_KEYS.contains(opt_key.as_ref())
+        || DICT_OPTIONAL_HTTP_OPTION_KEYS.contains(opt_key.as_ref())
+}
+
+fn is_dict_databend_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
+    DICT_REQUIRED_DATABEND_OPTION_KEYS.contains(opt_key.as_ref())
+        || DICT_OPTIONAL_DATABEND_OPTION_KEYS.contains(opt_key.as_ref())
+}
+
 fn insert_dictionary_sql_option_with_validation(
     options: &mut BTreeMap<String, String>,
     key: String,
@@ -149,13 +189,63 @@ fn insert_dictionary_redis_option_with_validation(
     Ok(())
 }
 
+fn insert_dictionary_http_option_with_validation(
+    options: &mut BTreeMap<String, String>,
+    key: String,
+    value: String,
+) -> Result<()> {
+    if is_dict_http_opt_key(&key) {
+        if key == DICT_OPT_KEY_HTTP_URL
+            && !(value.starts_with("http://") || value.starts_with("https://"))
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "dictionary option {key} must start with `http://` or `https://`",
+            )));
+        }
+        if key == DICT_OPT_KEY_HTTP_TIMEOUT && !value.parse::<u64>().is_ok_and(|v| v > 0) {
+            return Err(ErrorCode::BadArguments(format!(
+                "dictionary option {key} must be a positive integer",
+            )));
+        }
+        if options.insert(key.clone(), value).is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "dictionary option {key} duplicated",
+            )));
+        }
+    } else {
+        return Err(ErrorCode::BadArguments(format!(
+            "dictionary option {key} is not a valid option, required options are [`url`], optional options are [`timeout`]",
+        )));
+    }
+    Ok(())
+}
+
+fn insert_dictionary_databend_option_with_validation(
+    options: &mut BTreeMap<String, String>,
+    key: String,
+    value: String,
+) -> Result<()> {
+    if is_dict_databend_opt_key(&key) {
+        if options.insert(key.clone(), value).is_some() {
+            return Err(ErrorCode::BadArguments(format!(
+                "dictionary option {key} duplicated",
+            )));
+        }
+    } else {
+        return Err(ErrorCode::BadArguments(format!(
+            "dictionary option {key} is not a valid option, required options are [`table`], optional options are [`db`]",
+        )));
+    }
+    Ok(())
+}
+
 fn validate_dictionary_options(
     source: &str,
     source_options: &BTreeMap<String, String>,
 ) -> Result<BTreeMap<String, String>> {
     let mut options: BTreeMap<String, String> = BTreeMap::new();
     match source {
-        "mysql" => {
+        "mysql" | "postgresql" => {
             for (key, value) in source_options {
                 insert_dictionary_sql_option_with_validation(
                     &mut options,
@@ -195,6 +285,46 @@ fn validate_dictionary_options(
                 )));
             }
         }
+        "http" => {
+            for (key, value) in source_options {
+                insert_dictionary_http_option_with_validation(
+                    &mut options,
+                    key.to_lowercase(),
+                    value.to_string(),
+                )?;
+            }
+            let option_keys = options.keys().map(|k| k.as_str()).collect();
+            let diff_keys = DICT_REQUIRED_HTTP_OPTION_KEYS
+                .difference(&option_keys)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .join(", ");
+            if !diff_keys.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "dictionary miss options {diff_keys}, required options are [`url`], optional options are [`timeout`]",
+                )));
+            }
+        }
+        "databend" => {
+            for (key, value) in source_options {
+                insert_dictionary_databend_option_with_validation(
+                    &mut options,
+                    key.to_lowercase(),
+                    value.to_string(),
+                )?;
+            }
+            let option_keys = options.keys().map(|k| k.as_str()).collect();
+            let diff_keys = DICT_REQUIRED_DATABEND_OPTION_KEYS
+                .difference(&option_keys)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .join(", ");
+            if !diff_keys.is_empty() {
+                return Err(ErrorCode::BadArguments(format!(
+                    "dictionary miss options {diff_keys}, required options are [`table`], optional options are [`db`]",
+                )));
+            }
+        }
         _ => unreachable!(),
     }
 
@@ -215,6 +345,54 @@ fn validate_mysql_fields(schema: &TableSchema) -> Result<()> {
     Ok(())
 }
 
+fn validate_postgresql_fields(schema: &TableSchema) -> Result<()> {
+    for field in schema.fields() {
+        if !matches!(
+            field.data_type().remove_nullable(),
+            TableDataType::Boolean | TableDataType::String | TableDataType::Number(_)
+        ) {
+            return Err(ErrorCode::BadArguments(
+                "The type of PostgreSQL field must be in [`boolean`, `string`, `number`]",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_http_fields(schema: &TableSchema) -> Result<()> {
+    for field in schema.fields() {
+        if !matches!(
+            field.data_type().remove_nullable(),
+            TableDataType::Boolean | TableDataType::String | TableDataType::Number(_)
+        ) {
+            return Err(ErrorCode::BadArguments(
+                "The type of HTTP field must be in [`boolean`, `string`, `number`]",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_databend_fields(schema: &TableSchema, table_schema: &TableSchema) -> Result<()> {
+    for field in schema.fields() {
+        if !matches!(
+            field.data_type().remove_nullable(),
+            TableDataType::Boolean | TableDataType::String | TableDataType::Number(_)
+        ) {
+            return Err(ErrorCode::BadArguments(
+                "The type of Databend field must be in [`boolean`, `string`, `number`]",
+            ));
+        }
+        if table_schema.field_with_name(field.name()).is_err() {
+            return Err(ErrorCode::BadArguments(format!(
+                "The field `{}` does not exist in the source table",
+                field.name()
+            )));
+        }
+    }
+    Ok(())
+}
+
 fn validate_redis_fields(schema: &TableSchema) -> Result<()> {
     let fields_names: Vec<String> = schema.fields().iter().map(|f| f.name.clone()).collect();
     if fields_names.len() != 2 {
@@ -247,6 +425,7 @@ impl Binder {
             primary_keys,
             source_name,
             source_options,
+            lifetime,
             comment,
         } = stmt;
 
@@ -263,7 +442,10 @@ impl Binder {
 
         let source = self.normalize_object_identifier(source_name).to_lowercase();
 
-        if source != "mysql" && source != "redis" {
+        if !matches!(
+            source.as_str(),
+            "mysql" | "redis" | "postgresql" | "http" | "databend"
+        ) {
             return Err(ErrorCode::BadArguments(format!(
                 "The specified source '{}' is not currently supported",
                 source,
@@ -271,16 +453,42 @@ impl Binder {
         }
 
         // Check for options
-        let options = validate_dictionary_options(&source, source_options)?;
+        let mut options = validate_dictionary_options(&source, source_options)?;
 
         // Check for data source fields.
         let (schema, _) = self.analyze_create_table_schema_by_columns(columns).await?;
         match source.as_str() {
             "redis" => validate_redis_fields(&schema)?,
             "mysql" => validate_mysql_fields(&schema)?,
+            "postgresql" => validate_postgresql_fields(&schema)?,
+            "http" => validate_http_fields(&schema)?,
+            "databend" => {
+                // The source table is bound to the database when the dictionary is created.
+                let db = options
+                    .entry(DICT_OPT_KEY_DATABEND_DB.to_string())
+                    .or_insert_with(|| self.ctx.get_current_database())
+                    .clone();
+                let table_name = &options[DICT_OPT_KEY_DATABEND_TABLE];
+                let table = self.ctx.get_table(&catalog, &db, table_name).await?;
+                validate_databend_fields(&schema, &table.schema())?;
+            }
             _ => unreachable!(),
         }
 
+        // Check for lifetime.
+        if let Some(lifetime) = lifetime {
+            if source == "redis" {
+                return Err(ErrorCode::BadArguments(
+                    "LIFETIME is not supported by the redis source",
+                ));
+            }
+            if *lifetime == 0 {
+                return Err(ErrorCode::BadArguments(
+                    "LIFETIME must be a positive integer",
+                ));
+            }
+        }
+
         // Collect field_comments.
         let mut field_comments = BTreeMap::new();
         for column in columns {
@@ -312,6 +520,7 @@ impl Binder {
             field_comments,
             primary_column_ids,
             comment,
+            lifetime: *lifetime,
             ..Default::default()
         };
         Ok(Plan::CreateDictionary(Box::new(CreateDictionaryPlan {
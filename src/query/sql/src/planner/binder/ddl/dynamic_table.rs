@@ -16,6 +16,8 @@ use std::collections::BTreeMap;
 
 use databend_common_ast::ast::CreateDynamicTableStmt;
 use databend_common_ast::ast::CreateTableSource;
+use databend_common_ast::ast::RefreshDynamicTableStmt;
+use databend_common_ast::ast::RefreshMode;
 use databend_common_ast::ast::TypeName;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
@@ -27,15 +29,21 @@ use databend_common_meta_app::storage::StorageParams;
 use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_INITIALIZE;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TARGET_LAG;
+use derive_visitor::DriveMut;
 
 use crate::binder::ddl::table::AnalyzeCreateTableResult;
 use crate::plans::CreateDynamicTablePlan;
 use crate::plans::Plan;
+use crate::plans::RefreshDynamicTablePlan;
 use crate::BindContext;
 use crate::Binder;
+use crate::IncrementalRefreshChecker;
+use crate::ViewRewriter;
 
 impl Binder {
     pub(in crate::planner::binder) async fn bind_create_dynamic_table(
@@ -61,6 +69,20 @@ impl Binder {
         let (catalog_name, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        // The unqualified tables in the query are resolved in the database of the dynamic table,
+        // the same as views.
+        let mut as_query = as_query.clone();
+        as_query.drive_mut(&mut ViewRewriter {
+            current_database: database.clone(),
+        });
+        if *refresh_mode == RefreshMode::Incremental
+            && IncrementalRefreshChecker::check(&as_query).is_none()
+        {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "the query of dynamic table {database}.{table} can not be refreshed incrementally"
+            )));
+        }
+
         let mut options: BTreeMap<String, String> = BTreeMap::new();
         {
             // If table is TRANSIENT, set a flag in table option
@@ -70,6 +92,8 @@ impl Binder {
 
             options.insert(OPT_KEY_AS_QUERY.to_owned(), format!("{as_query}"));
             options.insert(OPT_KEY_TARGET_LAG.to_owned(), format!("{target_lag}"));
+            options.insert(OPT_KEY_REFRESH_MODE.to_owned(), format!("{refresh_mode}"));
+            options.insert(OPT_KEY_INITIALIZE.to_owned(), format!("{initialize}"));
 
             let catalog = self.ctx.get_catalog(&catalog_name).await?;
             let db = catalog
@@ -124,7 +148,13 @@ impl Binder {
         }
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, inverted_indexes, ngram_indexes)) = &source {
+        if let Some(CreateTableSource::Columns(
+            cols,
+            inverted_indexes,
+            ngram_indexes,
+            vector_indexes,
+        )) = &source
+        {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -146,10 +176,15 @@ impl Binder {
                     "dynamic table don't support ngram indexes".to_string(),
                 ));
             }
+            if vector_indexes.is_some() {
+                return Err(ErrorCode::SemanticError(
+                    "dynamic table don't support vector indexes".to_string(),
+                ));
+            }
         }
 
         let mut init_bind_context = BindContext::new();
-        let (_, bind_context) = self.bind_query(&mut init_bind_context, as_query)?;
+        let (_, bind_context) = self.bind_query(&mut init_bind_context, &as_query)?;
         let query_fields = bind_context
             .columns
             .iter()
@@ -213,4 +248,33 @@ impl Binder {
         };
         Ok(Plan::CreateDynamicTable(Box::new(plan)))
     }
+
+    pub(in crate::planner::binder) async fn bind_refresh_dynamic_table(
+        &mut self,
+        stmt: &RefreshDynamicTableStmt,
+    ) -> Result<Plan> {
+        let RefreshDynamicTableStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table_info.options().contains_key(OPT_KEY_TARGET_LAG) {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "{database}.{table} is not a dynamic table"
+            )));
+        }
+
+        Ok(Plan::RefreshDynamicTable(Box::new(
+            RefreshDynamicTablePlan {
+                catalog,
+                database,
+                table,
+            },
+        )))
+    }
 }
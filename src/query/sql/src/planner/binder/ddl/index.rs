@@ -20,9 +20,11 @@ use std::sync::LazyLock;
 use databend_common_ast::ast::CreateIndexStmt;
 use databend_common_ast::ast::CreateInvertedIndexStmt;
 use databend_common_ast::ast::CreateNgramIndexStmt;
+use databend_common_ast::ast::CreateVectorIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
 use databend_common_ast::ast::DropInvertedIndexStmt;
 use databend_common_ast::ast::DropNgramIndexStmt;
+use databend_common_ast::ast::DropVectorIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
@@ -94,6 +96,14 @@ static INDEX_RECORD_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
     r
 });
 
+// valid values for vector index option distance
+static VECTOR_INDEX_DISTANCE_VALUES: LazyLock<HashSet<&'static str>> = LazyLock::new(|| {
+    let mut r = HashSet::new();
+    r.insert("cosine");
+    r.insert("l2");
+    r
+});
+
 fn is_valid_tokenizer_values<S: AsRef<str>>(opt_val: S) -> bool {
     INDEX_TOKENIZER_VALUES.contains(opt_val.as_ref())
 }
@@ -636,6 +646,134 @@ impl Binder {
         Ok(options)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            create_option,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+
+        if table.is_read_only() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table {} is read-only, creating vector index not allowed",
+                table.name()
+            )));
+        }
+
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create vector index",
+                table.engine()
+            )));
+        }
+        if table.is_temp() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table {} is temporary table, creating vector index not allowed",
+                table.name()
+            )));
+        }
+        let table_schema = table.schema();
+        let table_id = table.get_id();
+        let index_name = self.normalize_object_identifier(index_name);
+        let column_id = self
+            .validate_vector_index_column(table_schema, column)
+            .await?;
+        let index_options = self.validate_vector_index_options(index_options).await?;
+
+        let plan = CreateTableIndexPlan {
+            index_type: TableIndexType::Vector,
+            create_option: create_option.clone().into(),
+            catalog,
+            index_name,
+            column_ids: vec![column_id],
+            table_id,
+            // vector indexes are always built together with the blocks.
+            sync_creation: true,
+            index_options,
+        };
+        Ok(Plan::CreateTableIndex(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) async fn validate_vector_index_column(
+        &self,
+        table_schema: TableSchemaRef,
+        column: &Identifier,
+    ) -> Result<ColumnId> {
+        match table_schema.field_with_name(&column.name) {
+            Ok(field) => {
+                if !matches!(field.data_type.remove_nullable(), TableDataType::Vector(_)) {
+                    return Err(ErrorCode::UnsupportedIndex(format!(
+                        "Vector index only support Vector type, but the type of column {} is {}",
+                        column, field.data_type
+                    )));
+                }
+                Ok(field.column_id)
+            }
+            Err(_) => Err(ErrorCode::UnsupportedIndex(format!(
+                "Table does not have column {}",
+                column
+            ))),
+        }
+    }
+
+    pub(in crate::planner::binder) async fn validate_vector_index_options(
+        &self,
+        index_options: &BTreeMap<String, String>,
+    ) -> Result<BTreeMap<String, String>> {
+        let mut options = BTreeMap::new();
+        for (opt, val) in index_options.iter() {
+            let key = opt.to_lowercase();
+            let value = val.to_lowercase();
+            match key.as_str() {
+                "distance" => {
+                    if !VECTOR_INDEX_DISTANCE_VALUES.contains(value.as_str()) {
+                        return Err(ErrorCode::IndexOptionInvalid(format!(
+                            "value `{value}` is invalid index distance",
+                        )));
+                    }
+                    options.insert("distance".to_string(), value);
+                }
+                "nlists" | "nprobe" => {
+                    match value.parse::<usize>() {
+                        Ok(num) => {
+                            if num == 0 {
+                                return Err(ErrorCode::IndexOptionInvalid(format!(
+                                    "`{key}` cannot be 0",
+                                )));
+                            }
+                        }
+                        Err(_) => {
+                            return Err(ErrorCode::IndexOptionInvalid(format!(
+                                "value `{value}` is not a legal number",
+                            )));
+                        }
+                    }
+                    options.insert(key, value);
+                }
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "index option `{key}` is invalid key for create vector index statement",
+                    )));
+                }
+            }
+        }
+        Ok(options)
+    }
+
     pub(in crate::planner::binder) async fn validate_inverted_index_columns(
         &self,
         table_schema: TableSchemaRef,
@@ -798,6 +936,43 @@ impl Binder {
         Ok(Plan::DropTableIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_vector_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &DropVectorIndexStmt,
+    ) -> Result<Plan> {
+        let DropVectorIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create vector index",
+                table.engine()
+            )));
+        }
+        let table_id = table.get_id();
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = DropTableIndexPlan {
+            index_type: TableIndexType::Vector,
+            if_exists: *if_exists,
+            catalog,
+            index_name,
+            table_id,
+        };
+        Ok(Plan::DropTableIndex(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
         &mut self,
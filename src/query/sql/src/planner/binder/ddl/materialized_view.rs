@@ -0,0 +1,433 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_ast::ast::quote::QuotedIdent;
+use databend_common_ast::ast::CreateDynamicTableStmt;
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::InitializeMode;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::WarehouseOptions;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::table::OPT_KEY_AS_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_SNAPSHOT_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_REFRESH_TIME;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use log::info;
+
+use crate::optimizer::ir::SExpr;
+use crate::plans::DropTablePlan;
+use crate::plans::Plan;
+use crate::plans::RefreshDynamicTablePlan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+use crate::Binder;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+use crate::TableEntry;
+
+// A materialized view is a dynamic table marked by `OPT_KEY_MATERIALIZED_VIEW`, the queries on
+// its sources may be rewritten to read it by the optimizer.
+impl Binder {
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            create_option,
+            catalog,
+            database,
+            view,
+            target_lag,
+            refresh_mode,
+            query,
+        } = stmt;
+
+        let stmt = CreateDynamicTableStmt {
+            create_option: create_option.clone(),
+            transient: false,
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            cluster_by: None,
+            target_lag: target_lag.clone(),
+            warehouse_opts: WarehouseOptions { warehouse: None },
+            refresh_mode: refresh_mode.clone(),
+            initialize: InitializeMode::OnCreate,
+            table_options: BTreeMap::new(),
+            as_query: query.clone(),
+        };
+        let mut plan = self.bind_create_dynamic_table(&stmt).await?;
+        if let Plan::CreateDynamicTable(plan) = &mut plan {
+            plan.options
+                .insert(OPT_KEY_MATERIALIZED_VIEW.to_owned(), "true".to_owned());
+        }
+        Ok(plan)
+    }
+
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        let table_info = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table_info.options().contains_key(OPT_KEY_MATERIALIZED_VIEW) {
+            return Err(ErrorCode::IllegalDynamicTable(format!(
+                "{database}.{table} is not a materialized view"
+            )));
+        }
+
+        Ok(Plan::RefreshDynamicTable(Box::new(
+            RefreshDynamicTablePlan {
+                catalog,
+                database,
+                table,
+            },
+        )))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+
+        match self.ctx.get_table(&catalog, &database, &table).await {
+            Ok(table_info) => {
+                if !table_info.options().contains_key(OPT_KEY_MATERIALIZED_VIEW) {
+                    return Err(ErrorCode::IllegalDynamicTable(format!(
+                        "{database}.{table} is not a materialized view"
+                    )));
+                }
+            }
+            Err(e) if *if_exists && e.code() == ErrorCode::UNKNOWN_TABLE => {}
+            Err(e) => return Err(e),
+        }
+
+        Ok(Plan::DropTable(Box::new(DropTablePlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            table,
+            all: false,
+        })))
+    }
+
+    /// Binds the materialized views on the tables of the query into its metadata, so that the
+    /// optimizer can rewrite the query to read them.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_query_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        plan: &Plan,
+    ) -> Result<()> {
+        match plan {
+            Plan::Query {
+                metadata,
+                bind_context: query_bind_context,
+                ..
+            } => {
+                self.do_bind_query_materialized_views(bind_context, query_bind_context, metadata)
+                    .await?;
+            }
+            Plan::Explain { kind, plan, .. }
+                if matches!(kind, ExplainKind::Plan) && matches!(**plan, Plan::Query { .. }) =>
+            {
+                match **plan {
+                    Plan::Query {
+                        ref metadata,
+                        bind_context: ref query_bind_context,
+                        ..
+                    } => {
+                        self.do_bind_query_materialized_views(
+                            bind_context,
+                            query_bind_context,
+                            metadata,
+                        )
+                        .await?;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_bind_query_materialized_views(
+        &mut self,
+        bind_context: &mut BindContext,
+        query_bind_context: &BindContext,
+        metadata: &MetadataRef,
+    ) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        if bind_context.planning_agg_index || !settings.get_enable_materialized_view_rewrite()? {
+            return Ok(());
+        }
+        let staleness_secs = settings.get_materialized_view_staleness_secs()?;
+
+        // The tables bound for the aggregating indexes are not read by the query.
+        let tables = metadata
+            .read()
+            .tables()
+            .iter()
+            .filter(|table| !table.is_source_of_index())
+            .cloned()
+            .collect::<Vec<_>>();
+        // The data of a materialized view is not masked, it can not stand for the masked columns.
+        if tables.iter().any(|table| {
+            table
+                .table()
+                .get_table_info()
+                .meta
+                .column_mask_policy
+                .as_ref()
+                .is_some_and(|policies| !policies.is_empty())
+        }) {
+            return Ok(());
+        }
+
+        metadata.write().set_query_output_columns(
+            query_bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect(),
+        );
+
+        let mut visited = HashSet::new();
+        for table_entry in tables.iter() {
+            let Some(view_ids) = table_entry
+                .table()
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEWS)
+            else {
+                continue;
+            };
+            for view_id in view_ids.split(',').filter_map(|id| id.parse::<u64>().ok()) {
+                if !visited.insert(view_id) {
+                    continue;
+                }
+                let Some((database, view)) =
+                    self.resolve_materialized_view(table_entry, view_id).await
+                else {
+                    continue;
+                };
+                if !is_materialized_view_fresh(view.as_ref(), &tables, staleness_secs) {
+                    info!(
+                        "materialized view {} is not used, it is not fresh enough",
+                        view.name()
+                    );
+                    continue;
+                }
+                if let Some(entry) = self.bind_materialized_view(
+                    bind_context,
+                    table_entry.catalog(),
+                    &database,
+                    view.as_ref(),
+                )? {
+                    metadata.write().add_materialized_view(entry);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the materialized view of the id and its database, `None` if it has been dropped
+    /// or replaced.
+    async fn resolve_materialized_view(
+        &self,
+        source: &TableEntry,
+        view_id: u64,
+    ) -> Option<(String, Arc<dyn Table>)> {
+        let catalog = self.ctx.get_catalog(source.catalog()).await.ok()?;
+        let table_meta = catalog.get_table_meta_by_id(view_id).await.ok()??;
+        let db_id = table_meta
+            .data
+            .options
+            .get(OPT_KEY_DATABASE_ID)?
+            .parse::<u64>()
+            .ok()?;
+        let database = catalog.get_db_name_by_id(db_id).await.ok()?;
+        let name = catalog.get_table_name_by_id(view_id).await.ok()??;
+        let view = self
+            .ctx
+            .get_table(source.catalog(), &database, &name)
+            .await
+            .ok()?;
+        (view.get_id() == view_id && view.options().contains_key(OPT_KEY_MATERIALIZED_VIEW))
+            .then_some((database, view))
+    }
+
+    /// Binds the query of the materialized view and the scan of it into the metadata.
+    fn bind_materialized_view(
+        &mut self,
+        bind_context: &BindContext,
+        catalog: &str,
+        database: &str,
+        view: &dyn Table,
+    ) -> Result<Option<MaterializedViewEntry>> {
+        let Some(as_query) = view.options().get(OPT_KEY_AS_QUERY) else {
+            return Ok(None);
+        };
+        let quote = self.dialect.default_ident_quote();
+        let name = format!(
+            "{}.{}.{}",
+            QuotedIdent(catalog, quote),
+            QuotedIdent(database, quote),
+            QuotedIdent(view.name(), quote)
+        );
+
+        let bind = |binder: &mut Binder, sql: &str| -> Result<Option<(SExpr, Vec<usize>)>> {
+            let tokens = tokenize_sql(sql)?;
+            let (stmt, _) = parse_sql(&tokens, binder.dialect)?;
+            let Statement::Query(query) = &stmt else {
+                return Ok(None);
+            };
+            let mut new_bind_context = BindContext::with_parent(bind_context.clone())?;
+            // The tables are bound as the sources of an index, they are not read by the query
+            // unless it is rewritten.
+            new_bind_context.planning_agg_index = true;
+            let (s_expr, new_bind_context) = binder.bind_query(&mut new_bind_context, query)?;
+            let columns = new_bind_context
+                .columns
+                .iter()
+                .map(|column| column.index)
+                .collect();
+            Ok(Some((s_expr, columns)))
+        };
+
+        let Some((query, output_columns)) = bind(self, as_query)? else {
+            return Ok(None);
+        };
+        let Some((scan, scan_columns)) = bind(self, &format!("SELECT * FROM {name}"))? else {
+            return Ok(None);
+        };
+        let mut scan = &scan;
+        while !matches!(scan.plan(), RelOperator::Scan(_)) {
+            match scan.children().next() {
+                Some(child) => scan = child,
+                None => return Ok(None),
+            }
+        }
+        if output_columns.len() != scan_columns.len() {
+            return Ok(None);
+        }
+
+        Ok(Some(MaterializedViewEntry {
+            table_id: view.get_id(),
+            name,
+            query,
+            output_columns,
+            scan: scan.clone(),
+            scan_columns,
+        }))
+    }
+}
+
+/// A materialized view is fresh if it has not been changed since its last refresh, and it has
+/// been refreshed to the snapshots of its sources read by the query or within `staleness_secs`.
+fn is_materialized_view_fresh(
+    view: &dyn Table,
+    tables: &[TableEntry],
+    staleness_secs: u64,
+) -> bool {
+    let options = view.options();
+    let Some(refresh_snapshot_id) = options.get(OPT_KEY_REFRESH_SNAPSHOT_ID) else {
+        return false;
+    };
+    // The file name of a snapshot contains its id.
+    let unchanged = match options.get(OPT_KEY_SNAPSHOT_LOCATION) {
+        Some(location) => {
+            !refresh_snapshot_id.is_empty()
+                && location
+                    .rsplit('/')
+                    .next()
+                    .is_some_and(|name| name.contains(refresh_snapshot_id.as_str()))
+        }
+        None => refresh_snapshot_id.is_empty(),
+    };
+    if !unchanged {
+        return false;
+    }
+
+    let (Some(source_ids), Some(locations)) = (
+        options.get(OPT_KEY_SOURCE_TABLE_ID),
+        options.get(OPT_KEY_SOURCE_SNAPSHOT_LOCATION),
+    ) else {
+        return false;
+    };
+    let up_to_date =
+        source_ids
+            .split(',')
+            .zip(locations.split(','))
+            .all(|(source_id, location)| {
+                tables.iter().any(|table| {
+                    let table = table.table();
+                    table.get_id().to_string() == source_id
+                        && table
+                            .options()
+                            .get(OPT_KEY_SNAPSHOT_LOCATION)
+                            .map_or("", String::as_str)
+                            == location
+                })
+            });
+    if up_to_date {
+        return true;
+    }
+
+    staleness_secs > 0
+        && options
+            .get(OPT_KEY_REFRESH_TIME)
+            .and_then(|time| time.parse::<i64>().ok())
+            .is_some_and(|time| {
+                Utc::now().timestamp_millis() - time <= staleness_secs as i64 * 1000
+            })
+}
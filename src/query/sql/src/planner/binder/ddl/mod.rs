@@ -21,9 +21,11 @@ mod database;
 mod dictionary;
 mod dynamic_table;
 mod index;
+mod materialized_view;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod pipe;
 mod procedure;
 mod role;
 mod sequence;
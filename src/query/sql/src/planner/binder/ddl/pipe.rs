@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::LiteralStringOrVariable;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::plans::AlterPipeAction;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        match &copy_stmt.src {
+            CopyIntoTableSource::Location(FileLocation::Stage(location))
+                if !location.starts_with('~') => {}
+            _ => {
+                return Err(ErrorCode::IllegalPipe(format!(
+                    "pipe {} must load from a named stage",
+                    name
+                )));
+            }
+        }
+        // The files are picked by the pipe, and the load history of COPY makes sure
+        // that each file is loaded only once.
+        if copy_stmt.files.is_some() || copy_stmt.options.force {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} does not support FILES or FORCE in the COPY statement",
+                name
+            )));
+        }
+
+        // Session variables are not available when the pipe is loaded.
+        if matches!(
+            copy_stmt.pattern,
+            Some(LiteralStringOrVariable::Variable(_))
+        ) {
+            return Err(ErrorCode::IllegalPipe(format!(
+                "pipe {} does not support variables in PATTERN",
+                name
+            )));
+        }
+
+        // The statement is executed later by other sessions, qualify the target table
+        // with the current database.
+        let mut copy_stmt = copy_stmt.clone();
+        if copy_stmt.dst.database.is_none() {
+            copy_stmt.dst.database =
+                Some(Identifier::from_name(None, self.ctx.get_current_database()));
+        }
+        // Make sure the statement is valid.
+        self.bind_copy_into_table(&mut BindContext::new(), &copy_stmt)
+            .await?;
+
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.to_string(),
+            copy_statement: copy_stmt.to_string(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let action = match options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => AlterPipeAction::Set {
+                execution_paused: *execution_paused,
+                comment: comments.clone(),
+            },
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => AlterPipeAction::Refresh {
+                prefix: prefix.clone(),
+                modified_after: modified_after
+                    .as_ref()
+                    .map(|v| parse_modified_after(v))
+                    .transpose()?,
+            },
+        };
+
+        Ok(Plan::AlterPipe(Box::new(AlterPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            action,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        Ok(Plan::DropPipe(Box::new(DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        Ok(Plan::DescPipe(Box::new(DescPipePlan {
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+        })))
+    }
+}
+
+/// Accepts RFC 3339 timestamps and `YYYY-MM-DD HH:MM:SS` in UTC.
+fn parse_modified_after(v: &str) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::<Utc>::from_str(v) {
+        return Ok(ts);
+    }
+    NaiveDateTime::parse_from_str(v, "%Y-%m-%d %H:%M:%S")
+        .map(|ts| ts.and_utc())
+        .map_err(|_| {
+            ErrorCode::IllegalPipe(format!(
+                "invalid MODIFIED_AFTER '{}', expect a timestamp like '2025-01-01 00:00:00'",
+                v
+            ))
+        })
+}
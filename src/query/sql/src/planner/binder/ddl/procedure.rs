@@ -197,15 +197,20 @@ impl Binder {
             }
         }
         let mut return_types = Vec::with_capacity(return_type.len());
+        let mut return_names = Vec::with_capacity(return_type.len());
         for arg_type in return_type {
             return_types.push(DataType::from(&resolve_type_name(
                 &arg_type.data_type,
                 true,
             )?));
+            if let Some(name) = &arg_type.name {
+                return_names.push(name.to_string());
+            }
         }
 
         Ok(ProcedureMeta {
             return_types,
+            return_names,
             arg_names,
             created_on: Utc::now(),
             updated_on: Utc::now(),
@@ -21,19 +21,25 @@ use databend_common_ast::ast::AlterTableAction;
 use databend_common_ast::ast::AlterTableStmt;
 use databend_common_ast::ast::AnalyzeTableStmt;
 use databend_common_ast::ast::AttachTableStmt;
+use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::ClusterOption;
 use databend_common_ast::ast::ClusterType as AstClusterType;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::CompactTarget;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::DeleteStmt;
 use databend_common_ast::ast::DescribeTableStmt;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InvertedIndexDefinition;
+use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::NgramIndexDefinition;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
@@ -55,6 +61,7 @@ use databend_common_ast::ast::UriLocation;
 use databend_common_ast::ast::VacuumDropTableStmt;
 use databend_common_ast::ast::VacuumTableStmt;
 use databend_common_ast::ast::VacuumTemporaryFiles;
+use databend_common_ast::ast::VectorIndexDefinition;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_base::base::uuid::Uuid;
@@ -62,6 +69,7 @@ use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -88,9 +96,16 @@ use databend_common_storage::init_operator;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_REFERENCES;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_PARTITION_BY;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
@@ -116,10 +131,12 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableTagPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableTagPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -137,6 +154,7 @@ use crate::plans::RevertTablePlan;
 use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
+use crate::plans::TableCloneSource;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::UnsetOptionsPlan;
@@ -155,6 +173,7 @@ pub(in crate::planner::binder) struct AnalyzeCreateTableResult {
     pub(in crate::planner::binder) field_comments: Vec<String>,
     pub(in crate::planner::binder) inverted_indexes: Option<BTreeMap<String, TableIndex>>,
     pub(in crate::planner::binder) ngram_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub(in crate::planner::binder) vector_indexes: Option<BTreeMap<String, TableIndex>>,
 }
 
 impl Binder {
@@ -454,7 +473,7 @@ impl Binder {
             table_type,
             engine,
             uri_location,
-            iceberg_table_partition,
+            table_partition: partition_by,
             table_properties,
         } = stmt;
 
@@ -499,12 +518,35 @@ impl Binder {
             None => None,
         };
 
-        let table_partition = iceberg_table_partition.as_ref().map(|partitions| {
-            partitions
-                .iter()
-                .map(|p| p.to_string())
-                .collect::<Vec<String>>()
-        });
+        let table_partition = match (engine, partition_by) {
+            (Engine::Iceberg, Some(partitions)) => Some(
+                partitions
+                    .iter()
+                    .map(|p| match p {
+                        Expr::ColumnRef {
+                            column:
+                                ColumnRef {
+                                    database: None,
+                                    table: None,
+                                    column: ColumnID::Name(column),
+                                },
+                            ..
+                        } => Ok(column.to_string()),
+                        _ => Err(ErrorCode::InvalidPartitionKeys(format!(
+                            "Partition by expression `{:#}` is invalid, iceberg table only supports partition by columns",
+                            p
+                        ))),
+                    })
+                    .collect::<Result<Vec<String>>>()?,
+            ),
+            (Engine::Fuse, _) | (_, None) => None,
+            (_, Some(_)) => {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "Table engine {} does not support partition by",
+                    engine
+                )));
+            }
+        };
 
         let mut storage_params = match (uri_location, engine) {
             (Some(uri), Engine::Fuse) => {
@@ -556,8 +598,74 @@ impl Binder {
             }
         };
 
+        let mut clone_source = None;
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = &source
+        {
+            if engine != Engine::Fuse
+                || as_query.is_some()
+                || uri_location.is_some()
+                || matches!(table_type, TableType::Temporary)
+            {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: CLONE is only supported for FUSE tables without AS SELECT or external location",
+                ));
+            }
+            let (source_catalog, source_database, source_table) = self
+                .normalize_object_identifier_triple(source_catalog, source_database, source_table);
+            let table = self
+                .ctx
+                .get_table(&source_catalog, &source_database, &source_table)
+                .await?;
+            let table_info = table.get_table_info();
+            if table.engine() != "FUSE"
+                || table.is_temp()
+                || table_info.options().contains_key(OPT_KEY_STORAGE_PREFIX)
+            {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "Table {}.{} can not be cloned, only FUSE tables which are neither temporary nor attached are supported",
+                    source_database, source_table
+                )));
+            }
+
+            // The cloned table reads the blocks of the source table, so they must be
+            // located by the same storage and written in the same format.
+            if let Some(storage_format) = options.get(OPT_KEY_STORAGE_FORMAT) {
+                if Some(storage_format) != table_info.options().get(OPT_KEY_STORAGE_FORMAT) {
+                    return Err(ErrorCode::BadArguments(
+                        "Incorrect CREATE query: the storage format of the cloned table can not be changed",
+                    ));
+                }
+            }
+            for (key, value) in table_info.options() {
+                if !CLONE_SKIPPED_OPTION_KEYS.contains(&key.to_lowercase().as_str())
+                    && !options.contains_key(key)
+                {
+                    options.insert(key.clone(), value.clone());
+                }
+            }
+            storage_params = table_info.meta.storage_params.clone();
+
+            let navigation = match travel_point {
+                Some(point) => {
+                    Some(self.resolve_data_travel_point(&mut BindContext::new(), point)?)
+                }
+                None => None,
+            };
+            clone_source = Some(TableCloneSource {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                navigation,
+            });
+        }
+
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols, _, _)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _, _, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry | TypeName::Geography))
@@ -578,6 +686,7 @@ impl Binder {
                 field_comments,
                 inverted_indexes,
                 ngram_indexes,
+                vector_indexes,
             },
             as_query_plan,
         ) = match (&source, &as_query) {
@@ -611,6 +720,7 @@ impl Binder {
                         field_comments: vec![],
                         inverted_indexes: None,
                         ngram_indexes: None,
+                        vector_indexes: None,
                     },
                     Some(Box::new(as_query_plan)),
                 )
@@ -658,6 +768,7 @@ impl Binder {
                             field_comments: vec![],
                             inverted_indexes: None,
                             ngram_indexes: None,
+                            vector_indexes: None,
                         }, as_query_plan)
                     }
                     Engine::Delta => {
@@ -675,6 +786,7 @@ impl Binder {
                             field_comments: vec![],
                             inverted_indexes: None,
                             ngram_indexes: None,
+                            vector_indexes: None,
                         }, as_query_plan)
                     }
                     _ => Err(ErrorCode::BadArguments(
@@ -768,6 +880,30 @@ impl Binder {
             }
         }
 
+        if let (Engine::Fuse, Some(partition_by)) = (engine, partition_by) {
+            let keys = self
+                .analyze_partition_keys(partition_by, schema.clone())
+                .await?;
+            options.insert(
+                OPT_KEY_PARTITION_BY.to_owned(),
+                format!("({})", keys.join(", ")),
+            );
+        }
+
+        if let Some(clone_source) = &clone_source {
+            if cluster_by.is_none() {
+                let table = self
+                    .ctx
+                    .get_table(
+                        &clone_source.catalog,
+                        &clone_source.database,
+                        &clone_source.table,
+                    )
+                    .await?;
+                cluster_key = table.cluster_key_meta().map(|(_, key)| key);
+            }
+        }
+
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
             tenant: self.ctx.get_tenant(),
@@ -786,7 +922,9 @@ impl Binder {
             as_select: as_query_plan,
             inverted_indexes,
             ngram_indexes,
+            vector_indexes,
             attached_columns: None,
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -855,7 +993,9 @@ impl Binder {
             as_select: None,
             inverted_indexes: None,
             ngram_indexes: None,
+            vector_indexes: None,
             attached_columns: stmt.columns_opt.clone(),
+            clone_source: None,
         })))
     }
 
@@ -1085,6 +1225,18 @@ impl Binder {
                     },
                 )))
             }
+            AlterTableAction::DropPartition { values } => {
+                let tbl = self.ctx.get_table(&catalog, &database, &table).await?;
+                let selection = self.bind_partition_predicate(tbl.as_ref(), values)?;
+                // Dropping a partition is a delete of all the rows that belong to it.
+                let stmt = DeleteStmt {
+                    hints: None,
+                    table: table_reference.clone(),
+                    selection: Some(selection),
+                    with: None,
+                };
+                self.bind_delete(bind_context, &stmt).await
+            }
             AlterTableAction::DropTableClusterKey => Ok(Plan::DropTableClusterKey(Box::new(
                 DropTableClusterKeyPlan {
                     tenant,
@@ -1115,6 +1267,35 @@ impl Binder {
                     point,
                 })))
             }
+            AlterTableAction::CreateTag {
+                tag,
+                travel_point,
+                retain,
+            } => {
+                let navigation = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point)?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableTag(Box::new(CreateTableTagPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                    navigation,
+                    retain: *retain,
+                })))
+            }
+            AlterTableAction::DropTag { if_exists, tag } => {
+                Ok(Plan::DropTableTag(Box::new(DropTableTagPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                    if_exists: *if_exists,
+                })))
+            }
             AlterTableAction::SetOptions { set_options } => {
                 Ok(Plan::SetOptions(Box::new(SetOptionsPlan {
                     set_options: set_options.clone(),
@@ -1628,13 +1809,53 @@ impl Binder {
         Ok(ngram_indexes)
     }
 
+    #[async_backtrace::framed]
+    async fn analyze_vector_indexes(
+        &self,
+        table_schema: TableSchemaRef,
+        vector_index_defs: &[VectorIndexDefinition],
+    ) -> Result<BTreeMap<String, TableIndex>> {
+        let mut vector_indexes = BTreeMap::new();
+        for vector_index_def in vector_index_defs {
+            let name = self.normalize_object_identifier(&vector_index_def.index_name);
+            if vector_indexes.contains_key(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated vector index name: {}",
+                    name
+                )));
+            }
+            let column_id = self
+                .validate_vector_index_column(table_schema.clone(), &vector_index_def.column)
+                .await?;
+            let options = self
+                .validate_vector_index_options(&vector_index_def.index_options)
+                .await?;
+
+            let vector_index = TableIndex {
+                index_type: TableIndexType::Vector,
+                name: name.clone(),
+                column_ids: vec![column_id],
+                sync_creation: true,
+                version: Uuid::new_v4().simple().to_string(),
+                options,
+            };
+            vector_indexes.insert(name, vector_index);
+        }
+        Ok(vector_indexes)
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn analyze_create_table_schema(
         &self,
         source: &CreateTableSource,
     ) -> Result<AnalyzeCreateTableResult> {
         match source {
-            CreateTableSource::Columns(columns, inverted_index_defs, ngram_index_defs) => {
+            CreateTableSource::Columns(
+                columns,
+                inverted_index_defs,
+                ngram_index_defs,
+                vector_index_defs,
+            ) => {
                 let (schema, comments) =
                     self.analyze_create_table_schema_by_columns(columns).await?;
                 let inverted_indexes = if let Some(inverted_index_defs) = inverted_index_defs {
@@ -1653,6 +1874,14 @@ impl Binder {
                 } else {
                     None
                 };
+                let vector_indexes = if let Some(vector_index_defs) = vector_index_defs {
+                    let vector_indexes = self
+                        .analyze_vector_indexes(schema.clone(), vector_index_defs)
+                        .await?;
+                    Some(vector_indexes)
+                } else {
+                    None
+                };
                 if let (Some(inverted_indexes), Some(ngram_indexes)) =
                     (&inverted_indexes, &ngram_indexes)
                 {
@@ -1665,11 +1894,45 @@ impl Binder {
                         }
                     }
                 }
+                if let Some(vector_indexes) = &vector_indexes {
+                    for key in vector_indexes.keys() {
+                        let exists_in_inverted = inverted_indexes
+                            .as_ref()
+                            .is_some_and(|indexes| indexes.contains_key(key));
+                        let exists_in_ngram = ngram_indexes
+                            .as_ref()
+                            .is_some_and(|indexes| indexes.contains_key(key));
+                        if exists_in_inverted || exists_in_ngram {
+                            return Err(ErrorCode::BadArguments(format!(
+                                "The index: {} exists between both vector index and other index",
+                                key
+                            )));
+                        }
+                    }
+                }
                 Ok(AnalyzeCreateTableResult {
                     schema,
                     field_comments: comments,
                     inverted_indexes,
                     ngram_indexes,
+                    vector_indexes,
+                })
+            }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                let (catalog, database, table) =
+                    self.normalize_object_identifier_triple(catalog, database, table);
+                let table = self.ctx.get_table(&catalog, &database, &table).await?;
+                Ok(AnalyzeCreateTableResult {
+                    schema: table.schema(),
+                    field_comments: table.field_comments().clone(),
+                    inverted_indexes: None,
+                    ngram_indexes: None,
+                    vector_indexes: None,
                 })
             }
             CreateTableSource::Like {
@@ -1690,6 +1953,7 @@ impl Binder {
                             field_comments: vec![],
                             inverted_indexes: None,
                             ngram_indexes: None,
+                            vector_indexes: None,
                         })
                     } else {
                         Err(ErrorCode::Internal(
@@ -1702,6 +1966,7 @@ impl Binder {
                         field_comments: table.field_comments().clone(),
                         inverted_indexes: None,
                         ngram_indexes: None,
+                        vector_indexes: None,
                     })
                 }
             }
@@ -1834,6 +2099,160 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    pub(in crate::planner::binder) async fn analyze_partition_keys(
+        &mut self,
+        partition_exprs: &[Expr],
+        schema: TableSchemaRef,
+    ) -> Result<Vec<String>> {
+        // Build a temporary BindContext to resolve the expr
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        // partition keys cannot be a udf expression.
+        scalar_binder.forbid_udf();
+
+        let mut partition_keys = Vec::with_capacity(partition_exprs.len());
+        for partition_expr in partition_exprs.iter() {
+            let (partition_key, _) = scalar_binder.bind(partition_expr)?;
+            if partition_key.used_columns().len() != 1 || !partition_key.evaluable() {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{:#}` is invalid",
+                    partition_expr
+                )));
+            }
+            // The virtual computed columns are not stored, can't be used to partition the data.
+            if partition_key.used_columns().iter().any(|index| {
+                matches!(
+                    schema.field(*index).computed_expr(),
+                    Some(ComputedExpr::Virtual(_))
+                )
+            }) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{:#}` can't use virtual computed column",
+                    partition_expr
+                )));
+            }
+
+            let expr = partition_key.as_expr()?;
+            if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{:#}` is not deterministic",
+                    partition_expr
+                )));
+            }
+
+            let data_type = expr.data_type();
+            if !Self::valid_cluster_key_type(data_type) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Unsupported data type '{}' for partition by expression `{:#}`",
+                    data_type, partition_expr
+                )));
+            }
+
+            let mut partition_expr = partition_expr.clone();
+            let mut normalizer = IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            };
+            partition_expr.drive_mut(&mut normalizer);
+            let partition_key = format!("{:#}", &partition_expr);
+            if partition_keys.contains(&partition_key) {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition by expression `{}` is duplicated",
+                    partition_key
+                )));
+            }
+            partition_keys.push(partition_key);
+        }
+
+        Ok(partition_keys)
+    }
+
+    /// Build the predicate `k1 = v1 AND k2 = v2 ...` that selects the rows of one partition.
+    pub(in crate::planner::binder) fn bind_partition_predicate(
+        &mut self,
+        table: &dyn Table,
+        values: &[Expr],
+    ) -> Result<Expr> {
+        let Some(keys) = table.resolve_partition_keys(self.ctx.clone())? else {
+            return Err(ErrorCode::UnpartitionedTable(format!(
+                "Table '{}' is not a partitioned table",
+                table.name()
+            )));
+        };
+        if keys.len() != values.len() {
+            return Err(ErrorCode::InvalidPartitionKeys(format!(
+                "Table '{}' has {} partition key(s), but {} partition value(s) are given",
+                table.name(),
+                keys.len(),
+                values.len()
+            )));
+        }
+
+        // Partition values must be constants.
+        let mut bind_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        scalar_binder.forbid_udf();
+        for value in values {
+            let (scalar, _) = scalar_binder.bind(value)?;
+            if !scalar.used_columns().is_empty() || !scalar.evaluable() {
+                return Err(ErrorCode::InvalidPartitionKeys(format!(
+                    "Partition value `{:#}` must be a constant",
+                    value
+                )));
+            }
+        }
+
+        let predicate = keys
+            .into_iter()
+            .zip(values.iter())
+            .map(|(key, value)| match value {
+                Expr::Literal {
+                    value: Literal::Null,
+                    ..
+                } => Expr::IsNull {
+                    span: None,
+                    expr: Box::new(key),
+                    not: false,
+                },
+                _ => Expr::BinaryOp {
+                    span: None,
+                    op: BinaryOperator::Eq,
+                    left: Box::new(key),
+                    right: Box::new(value.clone()),
+                },
+            })
+            .reduce(|left, right| Expr::BinaryOp {
+                span: None,
+                op: BinaryOperator::And,
+                left: Box::new(left),
+                right: Box::new(right),
+            })
+            .unwrap();
+        Ok(predicate)
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -1856,6 +2275,19 @@ impl Binder {
     }
 }
 
+/// Table options of the source table which are not inherited by `CREATE TABLE ... CLONE`.
+const CLONE_SKIPPED_OPTION_KEYS: &[&str] = &[
+    OPT_KEY_DATABASE_ID,
+    OPT_KEY_SNAPSHOT_LOCATION,
+    OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG,
+    OPT_KEY_LEGACY_SNAPSHOT_LOC,
+    OPT_KEY_CHANGE_TRACKING,
+    OPT_KEY_CHANGE_TRACKING_BEGIN_VER,
+    OPT_KEY_CLONE_REFERENCES,
+    OPT_KEY_TEMP_PREFIX,
+    "transient",
+];
+
 const VERIFICATION_KEY: &str = "_v_d77aa11285c22e0e1d4593a035c98c0d";
 const VERIFICATION_KEY_DEL: &str = "_v_d77aa11285c22e0e1d4593a035c98c0d_del";
 
@@ -14,10 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchema;
@@ -29,6 +31,7 @@ use crate::normalize_identifier;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
+use crate::plans::InsertOverwritePartitionPlan;
 use crate::plans::InsertValue;
 use crate::plans::Plan;
 use crate::BindContext;
@@ -81,6 +84,7 @@ impl Binder {
             columns,
             source,
             overwrite,
+            partition,
             ..
         } = stmt;
 
@@ -99,6 +103,27 @@ impl Binder {
             .await
             .map_err(|err| table_identifier.not_found_suggest_error(err))?;
 
+        if let Some(partition) = partition {
+            let predicate =
+                self.bind_overwrite_partition_predicate(stmt, table.as_ref(), partition)?;
+            // The new rows are appended by a plain insert, after the old rows of
+            // the partition are deleted.
+            let insert_stmt = InsertStmt {
+                overwrite: false,
+                partition: None,
+                ..stmt.clone()
+            };
+            return Ok(Plan::InsertOverwritePartition(Box::new(
+                InsertOverwritePartitionPlan {
+                    catalog: catalog_name,
+                    database: database_name,
+                    table: table_name,
+                    partition_predicate: format!("{:#}", predicate),
+                    insert_sql: insert_stmt.to_string(),
+                },
+            )));
+        }
+
         let schema = self.schema_project(&table.schema(), columns)?;
 
         let input_source: Result<InsertInputSource> = match source.clone() {
@@ -164,4 +189,23 @@ impl Binder {
 
         Ok(Plan::Insert(Box::new(plan)))
     }
+
+    fn bind_overwrite_partition_predicate(
+        &mut self,
+        stmt: &InsertStmt,
+        table: &dyn Table,
+        partition: &[Expr],
+    ) -> Result<Expr> {
+        if !stmt.overwrite {
+            return Err(ErrorCode::SemanticError(
+                "PARTITION clause is only supported by INSERT OVERWRITE",
+            ));
+        }
+        if self.ctx.get_stage_attachment().is_some() {
+            return Err(ErrorCode::SemanticError(
+                "INSERT OVERWRITE ... PARTITION does not support stage attachment",
+            ));
+        }
+        self.bind_partition_predicate(table, partition)
+    }
 }
@@ -322,6 +322,7 @@ impl Binder {
                         udf_type: udf.udf_type.clone(),
                         arg_types: udf.arg_types.clone(),
                         return_type: udf.return_type.clone(),
+                        table_fields: udf.table_fields.clone(),
                         arguments: new_args,
                     }
                     .into())
@@ -582,11 +582,47 @@ impl Binder {
                     Utc.timestamp_nanos(micros * 1000),
                 ))
             }
+            TimeTravelPoint::Version(expr) => {
+                let mut type_checker = TypeChecker::try_create(
+                    bind_context,
+                    self.ctx.clone(),
+                    &self.name_resolution_ctx,
+                    self.metadata.clone(),
+                    &[],
+                    false,
+                )?;
+                let box (scalar, _) = type_checker.resolve(expr)?;
+                let scalar_expr = scalar.as_expr()?;
+
+                let (new_expr, _) = ConstantFolder::fold(
+                    &scalar_expr,
+                    &self.ctx.get_function_context()?,
+                    &BUILTIN_FUNCTIONS,
+                );
+
+                let v: u64 = check_number(
+                    None,
+                    &FunctionContext::default(),
+                    &new_expr,
+                    &BUILTIN_FUNCTIONS,
+                )
+                .map_err(|_| {
+                    ErrorCode::InvalidArgument(format!(
+                        "TimeTravelPoint for 'Version' must resolve to a constant non-negative integer. \
+                        Provided expression '{}' does not meet this requirement",
+                        expr
+                    ))
+                })?;
+                Ok(NavigationPoint::Version(v))
+            }
             TimeTravelPoint::Stream {
                 catalog,
                 database,
                 name,
             } => self.resolve_stream_data_travel_point(catalog, database, name),
+            TimeTravelPoint::Tag(name) => Ok(NavigationPoint::Tag(
+                normalize_identifier(name, &self.name_resolution_ctx).name,
+            )),
         }
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use chrono::Utc;
@@ -21,6 +22,7 @@ use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UDAFStateField;
 use databend_common_ast::ast::UDFDefinition;
+use databend_common_ast::ast::UDTFReturnField;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -31,6 +33,8 @@ use databend_common_meta_app::principal::UDAFScript;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::principal::UDTFScript;
+use databend_common_meta_app::principal::UDTFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
 use databend_common_version::UDF_CLIENT_USER_AGENT;
 
@@ -45,6 +49,7 @@ use crate::plans::Plan;
 use crate::plans::UDFLanguage;
 use crate::BindContext;
 use crate::Binder;
+use crate::NameResolutionContext;
 use crate::UdfRewriter;
 
 impl Binder {
@@ -93,33 +98,9 @@ impl Binder {
                 }
                 let return_type = DataType::from(&resolve_type_name_udf(return_type)?);
 
-                let connect_timeout = self
-                    .ctx
-                    .get_settings()
-                    .get_external_server_connect_timeout_secs()?;
-                let request_timeout = self
-                    .ctx
-                    .get_settings()
-                    .get_external_server_request_timeout_secs()?;
-                let batch_rows =
-                    self.ctx
-                        .get_settings()
-                        .get_external_server_request_batch_rows()? as usize;
-
-                let endpoint = UDFFlightClient::build_endpoint(
-                    address,
-                    connect_timeout,
-                    request_timeout,
-                    UDF_CLIENT_USER_AGENT.as_str(),
-                )?;
-
-                let mut client = UDFFlightClient::connect(endpoint, connect_timeout, batch_rows)
-                    .await?
-                    .with_tenant(self.ctx.get_tenant().tenant_name())?
-                    .with_func_name(&name)?
-                    .with_handler_name(handler)?
-                    .with_query_id(&self.ctx.get_id())?
-                    .with_headers(headers.iter())?;
+                let mut client = self
+                    .connect_udf_server(&name, address, handler, headers)
+                    .await?;
                 client
                     .check_schema(handler, &arg_datatypes, &return_type)
                     .await?;
@@ -139,6 +120,89 @@ impl Binder {
                 })
             }
             UDFDefinition::UDAFServer { .. } => unimplemented!(),
+            UDFDefinition::UDTFServer {
+                arg_types,
+                return_fields,
+                address,
+                handler,
+                headers,
+                language,
+            } => {
+                UDFValidator::is_udf_server_allowed(address.as_str())?;
+
+                let arg_types = arg_types
+                    .iter()
+                    .map(|arg_type| Ok(DataType::from(&resolve_type_name_udf(arg_type)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                let return_fields =
+                    resolve_udtf_return_fields(return_fields, &self.name_resolution_ctx)?;
+
+                let mut client = self
+                    .connect_udf_server(&name, address, handler, headers)
+                    .await?;
+                let return_types = return_fields
+                    .iter()
+                    .map(|f| f.data_type().clone())
+                    .collect::<Vec<_>>();
+                client
+                    .check_table_schema(handler, &arg_types, &return_types)
+                    .await?;
+
+                Ok(UserDefinedFunction {
+                    name,
+                    description,
+                    definition: PlanUDFDefinition::UDTFServer(UDTFServer {
+                        address: address.clone(),
+                        handler: handler.clone(),
+                        headers: headers.clone(),
+                        language: language.clone(),
+                        arg_types,
+                        return_fields,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
+            UDFDefinition::UDTFScript {
+                arg_types,
+                return_fields,
+                code,
+                handler,
+                language,
+                runtime_version,
+            } => {
+                let Ok(language) = language.parse::<UDFLanguage>() else {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Unallowed UDF language {language:?}, must be python, javascript or wasm"
+                    )));
+                };
+                UDFValidator::is_udf_script_allowed(&language)?;
+
+                let arg_types = arg_types
+                    .iter()
+                    .map(|arg_type| Ok(DataType::from(&resolve_type_name_udf(arg_type)?)))
+                    .collect::<Result<Vec<_>>>()?;
+                let return_fields =
+                    resolve_udtf_return_fields(return_fields, &self.name_resolution_ctx)?;
+
+                let mut runtime_version = runtime_version.to_string();
+                if runtime_version.is_empty() && language == UDFLanguage::Python {
+                    runtime_version = "3.12.2".to_string();
+                }
+
+                Ok(UserDefinedFunction {
+                    name,
+                    description,
+                    definition: PlanUDFDefinition::UDTFScript(UDTFScript {
+                        code: code.clone(),
+                        handler: handler.clone(),
+                        language: language.to_string(),
+                        arg_types,
+                        return_fields,
+                        runtime_version,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
             UDFDefinition::UDFScript {
                 arg_types,
                 return_type,
@@ -191,6 +255,42 @@ impl Binder {
         }
     }
 
+    async fn connect_udf_server(
+        &self,
+        name: &str,
+        address: &str,
+        handler: &str,
+        headers: &BTreeMap<String, String>,
+    ) -> Result<UDFFlightClient> {
+        let connect_timeout = self
+            .ctx
+            .get_settings()
+            .get_external_server_connect_timeout_secs()?;
+        let request_timeout = self
+            .ctx
+            .get_settings()
+            .get_external_server_request_timeout_secs()?;
+        let batch_rows = self
+            .ctx
+            .get_settings()
+            .get_external_server_request_batch_rows()? as usize;
+
+        let endpoint = UDFFlightClient::build_endpoint(
+            address,
+            connect_timeout,
+            request_timeout,
+            UDF_CLIENT_USER_AGENT.as_str(),
+        )?;
+
+        UDFFlightClient::connect(endpoint, connect_timeout, batch_rows)
+            .await?
+            .with_tenant(self.ctx.get_tenant().tenant_name())?
+            .with_func_name(name)?
+            .with_handler_name(handler)?
+            .with_query_id(&self.ctx.get_id())?
+            .with_headers(headers.iter())
+    }
+
     pub(in crate::planner::binder) async fn bind_create_udf(
         &mut self,
         stmt: &CreateUDFStmt,
@@ -250,6 +350,29 @@ impl Binder {
     }
 }
 
+fn resolve_udtf_return_fields(
+    fields: &[UDTFReturnField],
+    name_resolution_ctx: &NameResolutionContext,
+) -> Result<Vec<DataField>> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            Ok(DataField::new(
+                &normalize_identifier(&field.name, name_resolution_ctx).name,
+                DataType::from(&resolve_type_name_udf(&field.type_name)?),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let names = fields.iter().map(|f| f.name()).collect::<HashSet<_>>();
+    if names.len() != fields.len() {
+        return Err(ErrorCode::InvalidArgument(
+            "Duplicate column name in the table returned by UDTF",
+        ));
+    }
+    Ok(fields)
+}
+
 fn create_udf_definition_script(
     arg_types: &[TypeName],
     state_fields: Option<&[UDAFStateField]>,
@@ -116,7 +116,7 @@ pub fn parse_exprs(
     parse_ast_exprs(ctx, table_meta, ast_exprs)
 }
 
-fn parse_ast_exprs(
+pub fn parse_ast_exprs(
     ctx: Arc<dyn TableContext>,
     table_meta: Arc<dyn Table>,
     ast_exprs: Vec<AExpr>,
@@ -0,0 +1,431 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::optimizer::ir::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::Exchange;
+use crate::plans::Filter;
+use crate::plans::Join;
+use crate::plans::Limit;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::plans::SortItem;
+use crate::plans::UnionAll;
+use crate::ColumnEntry;
+use crate::IndexType;
+use crate::Metadata;
+use crate::ScalarExpr;
+
+/// Functions whose arguments can be reordered without changing the result.
+const COMMUTATIVE_FUNCTIONS: [&str; 6] = ["eq", "noteq", "plus", "multiply", "and", "or"];
+
+/// Formats an optimized query plan into a canonical text, which is the same for
+/// queries that only differ in whitespace, aliases or the order of commutative
+/// operands and conjunctive predicates.
+///
+/// Columns are identified by the scanned table and column name, or renumbered in
+/// order of appearance if derived. The versions of the tables are not part of the
+/// text.
+///
+/// Returns `None` if the plan contains an operator or expression that can't be
+/// normalized, e.g. a subquery or a window function.
+pub fn format_normalized_plan(
+    metadata: &Metadata,
+    s_expr: &SExpr,
+    output_columns: &[IndexType],
+) -> Option<String> {
+    let mut normalizer = PlanNormalizer {
+        metadata,
+        tables: HashMap::new(),
+        columns: HashMap::new(),
+        derived_columns: 0,
+    };
+    let plan = normalizer.format_s_expr(s_expr)?;
+    let output = output_columns
+        .iter()
+        .map(|index| normalizer.column(*index))
+        .join(", ");
+    Some(format!("{plan}\nOutput: [{output}]"))
+}
+
+struct PlanNormalizer<'a> {
+    metadata: &'a Metadata,
+    // Table index -> ordinal of the scan.
+    tables: HashMap<IndexType, usize>,
+    // Column index -> canonical name.
+    columns: HashMap<IndexType, String>,
+    derived_columns: usize,
+}
+
+impl PlanNormalizer<'_> {
+    fn format_s_expr(&mut self, s_expr: &SExpr) -> Option<String> {
+        // Format the children first, so that columns are named by the operators
+        // producing them rather than the ones consuming them.
+        let children = s_expr
+            .children()
+            .map(|child| self.format_s_expr(child))
+            .collect::<Option<Vec<_>>>()?;
+        let op = match s_expr.plan() {
+            RelOperator::Scan(scan) => self.format_scan(scan)?,
+            RelOperator::Join(join) => self.format_join(join)?,
+            RelOperator::EvalScalar(eval_scalar) => {
+                format!("EvalScalar [{}]", self.format_items(&eval_scalar.items)?)
+            }
+            RelOperator::Filter(filter) => self.format_filter(filter)?,
+            RelOperator::Aggregate(aggregate) => self.format_aggregate(aggregate)?,
+            RelOperator::Sort(sort) => self.format_sort(sort)?,
+            RelOperator::Limit(limit) => format_limit(limit),
+            RelOperator::Exchange(exchange) => self.format_exchange(exchange)?,
+            RelOperator::UnionAll(union_all) => self.format_union_all(union_all)?,
+            RelOperator::DummyTableScan(_) => "DummyTableScan".to_string(),
+            _ => return None,
+        };
+        if children.is_empty() {
+            Some(op)
+        } else {
+            Some(format!("{op} ({})", children.join(", ")))
+        }
+    }
+
+    fn format_scan(&mut self, scan: &Scan) -> Option<String> {
+        if scan.agg_index.is_some()
+            || scan.change_type.is_some()
+            || scan.update_stream_columns
+            || scan.inverted_index.is_some()
+            || scan.vector_index.is_some()
+            || scan.sample.is_some()
+        {
+            return None;
+        }
+        let metadata = self.metadata;
+        let entry = metadata.table(scan.table_index);
+        let table = entry.table();
+        if table.is_temp() || table.is_stream() {
+            return None;
+        }
+        let alias = self.table(scan.table_index);
+        let mut text = format!("Scan {alias} = {}.{}", entry.catalog(), table.get_id());
+        let columns = scan
+            .columns
+            .iter()
+            .map(|index| self.column(*index))
+            .sorted()
+            .join(", ");
+        text.push_str(&format!(" columns: [{columns}]"));
+        if let Some(predicates) = &scan.push_down_predicates {
+            text.push_str(&format!(
+                " filters: [{}]",
+                self.format_conjuncts(predicates)?
+            ));
+        }
+        if let Some(prewhere) = &scan.prewhere {
+            text.push_str(&format!(
+                " prewhere: [{}]",
+                self.format_conjuncts(&prewhere.predicates)?
+            ));
+        }
+        if let Some(order_by) = &scan.order_by {
+            text.push_str(&format!(
+                " order by: [{}]",
+                self.format_sort_items(order_by)
+            ));
+        }
+        if let Some(limit) = scan.limit {
+            text.push_str(&format!(" limit: {limit}"));
+        }
+        Some(text)
+    }
+
+    fn format_join(&mut self, join: &Join) -> Option<String> {
+        if join.build_side_cache_info.is_some() {
+            return None;
+        }
+        let equi_conditions = join
+            .equi_conditions
+            .iter()
+            .map(|condition| {
+                let op = if condition.is_null_equal { "<=>" } else { "=" };
+                Some(format!(
+                    "{} {op} {}",
+                    self.format_scalar(&condition.left)?,
+                    self.format_scalar(&condition.right)?
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .sorted()
+            .join(", ");
+        let mut text = format!(
+            "Join {:?} equi: [{equi_conditions}] non-equi: [{}]",
+            join.join_type,
+            self.format_conjuncts(&join.non_equi_conditions)?
+        );
+        if let Some(marker_index) = join.marker_index {
+            text.push_str(&format!(" marker: {}", self.column(marker_index)));
+        }
+        if let Some(join_type) = &join.single_to_inner {
+            text.push_str(&format!(" single to inner: {join_type:?}"));
+        }
+        if join.is_lateral {
+            text.push_str(" lateral");
+        }
+        Some(text)
+    }
+
+    fn format_filter(&mut self, filter: &Filter) -> Option<String> {
+        Some(format!(
+            "Filter [{}]",
+            self.format_conjuncts(&filter.predicates)?
+        ))
+    }
+
+    fn format_aggregate(&mut self, aggregate: &Aggregate) -> Option<String> {
+        if aggregate.grouping_sets.is_some() || aggregate.rank_limit.is_some() {
+            return None;
+        }
+        Some(format!(
+            "Aggregate {:?} group: [{}] aggregates: [{}]",
+            aggregate.mode,
+            self.format_items(&aggregate.group_items)?,
+            self.format_items(&aggregate.aggregate_functions)?
+        ))
+    }
+
+    fn format_sort(&mut self, sort: &Sort) -> Option<String> {
+        if sort.window_partition.is_some() {
+            return None;
+        }
+        let mut text = format!("Sort [{}]", self.format_sort_items(&sort.items));
+        if let Some(limit) = sort.limit {
+            text.push_str(&format!(" limit: {limit}"));
+        }
+        Some(text)
+    }
+
+    fn format_exchange(&mut self, exchange: &Exchange) -> Option<String> {
+        Some(match exchange {
+            Exchange::Hash(keys) => format!(
+                "Exchange Hash [{}]",
+                keys.iter()
+                    .map(|key| self.format_scalar(key))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ")
+            ),
+            Exchange::Broadcast => "Exchange Broadcast".to_string(),
+            Exchange::Merge => "Exchange Merge".to_string(),
+            Exchange::MergeSort => "Exchange MergeSort".to_string(),
+        })
+    }
+
+    fn format_union_all(&mut self, union_all: &UnionAll) -> Option<String> {
+        if !union_all.cte_scan_names.is_empty() {
+            return None;
+        }
+        let left = self.format_union_outputs(&union_all.left_outputs)?;
+        let right = self.format_union_outputs(&union_all.right_outputs)?;
+        let outputs = union_all
+            .output_indexes
+            .iter()
+            .map(|index| self.column(*index))
+            .join(", ");
+        Some(format!(
+            "UnionAll left: [{left}] right: [{right}] outputs: [{outputs}]"
+        ))
+    }
+
+    fn format_union_outputs(
+        &mut self,
+        outputs: &[(IndexType, Option<ScalarExpr>)],
+    ) -> Option<String> {
+        Some(
+            outputs
+                .iter()
+                .map(|(index, cast)| match cast {
+                    Some(cast) => self.format_scalar(cast),
+                    None => Some(self.column(*index)),
+                })
+                .collect::<Option<Vec<_>>>()?
+                .join(", "),
+        )
+    }
+
+    fn format_items(&mut self, items: &[ScalarItem]) -> Option<String> {
+        Some(
+            items
+                .iter()
+                .map(|item| {
+                    let scalar = self.format_scalar(&item.scalar)?;
+                    Some(format!("{} := {scalar}", self.column(item.index)))
+                })
+                .collect::<Option<Vec<_>>>()?
+                .join(", "),
+        )
+    }
+
+    fn format_sort_items(&mut self, items: &[SortItem]) -> String {
+        items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} {} {}",
+                    self.column(item.index),
+                    if item.asc { "ASC" } else { "DESC" },
+                    if item.nulls_first {
+                        "NULLS FIRST"
+                    } else {
+                        "NULLS LAST"
+                    }
+                )
+            })
+            .join(", ")
+    }
+
+    /// Conjunctive predicates are evaluated independently, so they are sorted.
+    fn format_conjuncts(&mut self, predicates: &[ScalarExpr]) -> Option<String> {
+        Some(
+            predicates
+                .iter()
+                .map(|predicate| self.format_scalar(predicate))
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .sorted()
+                .join(", "),
+        )
+    }
+
+    fn format_scalar(&mut self, scalar: &ScalarExpr) -> Option<String> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => Some(self.column(column_ref.column.index)),
+            ScalarExpr::ConstantExpr(constant) => Some(format!("{:?}", constant.value)),
+            ScalarExpr::TypedConstantExpr(constant, data_type) => {
+                Some(format!("{:?}::{data_type}", constant.value))
+            }
+            ScalarExpr::FunctionCall(func) => {
+                let mut args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.format_scalar(arg))
+                    .collect::<Option<Vec<_>>>()?;
+                if COMMUTATIVE_FUNCTIONS.contains(&func.func_name.as_str()) {
+                    args.sort();
+                }
+                Some(format!(
+                    "{}{:?}({})",
+                    func.func_name,
+                    func.params,
+                    args.join(", ")
+                ))
+            }
+            ScalarExpr::CastExpr(cast) => Some(format!(
+                "{}({} AS {})",
+                if cast.is_try { "TRY_CAST" } else { "CAST" },
+                self.format_scalar(&cast.argument)?,
+                cast.target_type
+            )),
+            ScalarExpr::AggregateFunction(agg) => {
+                let args = agg
+                    .args
+                    .iter()
+                    .map(|arg| self.format_scalar(arg))
+                    .collect::<Option<Vec<_>>>()?
+                    .join(", ");
+                let mut text = format!(
+                    "{}{:?}({}{args})",
+                    agg.func_name,
+                    agg.params,
+                    if agg.distinct { "DISTINCT " } else { "" }
+                );
+                if !agg.sort_descs.is_empty() {
+                    let sort_descs = agg
+                        .sort_descs
+                        .iter()
+                        .map(|desc| {
+                            Some(format!(
+                                "{} {} {}",
+                                self.format_scalar(&desc.expr)?,
+                                if desc.asc { "ASC" } else { "DESC" },
+                                if desc.nulls_first {
+                                    "NULLS FIRST"
+                                } else {
+                                    "NULLS LAST"
+                                }
+                            ))
+                        })
+                        .collect::<Option<Vec<_>>>()?
+                        .join(", ");
+                    text.push_str(&format!(" WITHIN GROUP (ORDER BY {sort_descs})"));
+                }
+                Some(text)
+            }
+            _ => None,
+        }
+    }
+
+    fn table(&mut self, index: IndexType) -> String {
+        let ordinal = self.tables.len();
+        format!("t{}", self.tables.entry(index).or_insert(ordinal))
+    }
+
+    fn column(&mut self, index: IndexType) -> String {
+        if let Some(name) = self.columns.get(&index) {
+            return name.clone();
+        }
+        let metadata = self.metadata;
+        let name = match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(column) => {
+                let mut name = format!("{}.{}", self.table(column.table_index), column.column_name);
+                if let Some(column_id) = column.column_id {
+                    name.push_str(&format!("#{column_id}"));
+                }
+                if let Some(path_indices) = &column.path_indices {
+                    name.push_str(&format!("{path_indices:?}"));
+                }
+                if let Some(virtual_expr) = &column.virtual_expr {
+                    name.push_str(&format!("({virtual_expr})"));
+                }
+                name
+            }
+            ColumnEntry::InternalColumn(column) => format!(
+                "{}.{}",
+                self.table(column.table_index),
+                column.internal_column.column_name
+            ),
+            ColumnEntry::VirtualColumn(column) => format!(
+                "{}.{}#{}",
+                self.table(column.table_index),
+                column.column_name,
+                column.column_id
+            ),
+            ColumnEntry::DerivedColumn(_) => {
+                self.derived_columns += 1;
+                format!("${}", self.derived_columns - 1)
+            }
+        };
+        self.columns.insert(index, name.clone());
+        name
+    }
+}
+
+fn format_limit(limit: &Limit) -> String {
+    match limit.limit {
+        Some(n) => format!("Limit {n} offset: {}", limit.offset),
+        None => format!("Limit offset: {}", limit.offset),
+    }
+}
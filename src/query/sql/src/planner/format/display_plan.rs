@@ -111,6 +111,7 @@ impl Plan {
 
             // Dynamic Tables
             Plan::CreateDynamicTable(_) => Ok("CreateDynamicTable".to_string()),
+            Plan::RefreshDynamicTable(_) => Ok("RefreshDynamicTable".to_string()),
 
             // Indexes
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
@@ -126,6 +127,7 @@ impl Plan {
             // Insert
             Plan::Insert(_) => Ok("Insert".to_string()),
             Plan::InsertMultiTable(_) => Ok("InsertMultiTable".to_string()),
+            Plan::InsertOverwritePartition(_) => Ok("InsertOverwritePartition".to_string()),
             Plan::Replace(_) => Ok("Replace".to_string()),
             Plan::DataMutation {
                 s_expr, metadata, ..
@@ -172,6 +174,8 @@ impl Plan {
             Plan::Kill(_) => Ok("Kill".to_string()),
 
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::CreateTableTag(_) => Ok("CreateTableTag".to_string()),
+            Plan::DropTableTag(_) => Ok("DropTableTag".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
@@ -214,6 +218,12 @@ impl Plan {
             Plan::DescNotification(_) => Ok("DescNotification".to_string()),
             Plan::AlterNotification(_) => Ok("AlterNotification".to_string()),
 
+            // Pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+
             // Stored procedures
             Plan::ExecuteImmediate(_) => Ok("ExecuteImmediate".to_string()),
             Plan::CreateProcedure(_) => Ok("CreateProcedure".to_string()),
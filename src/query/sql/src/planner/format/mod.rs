@@ -13,9 +13,11 @@
 // limitations under the License.
 
 mod display;
+mod display_normalized;
 mod display_plan;
 mod display_rel_operator;
 
 pub use display::format_scalar;
 pub use display::FormatOptions;
 pub use display::MetadataIdHumanizer;
+pub use display_normalized::format_normalized_plan;
@@ -74,6 +74,11 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// Materialized views on the tables of the query that are fresh enough to answer it.
+    materialized_views: Vec<MaterializedViewEntry>,
+    /// The output columns of the query, which the materialized views have to provide when
+    /// they replace the joins of the query.
+    query_output_columns: ColumnSet,
     max_column_position: usize, // for CSV
 
     /// Scan id of each scan operator.
@@ -331,6 +336,22 @@ impl Metadata {
         !self.agg_indexes.is_empty()
     }
 
+    pub fn add_materialized_view(&mut self, view: MaterializedViewEntry) {
+        self.materialized_views.push(view);
+    }
+
+    pub fn materialized_views(&self) -> &[MaterializedViewEntry] {
+        self.materialized_views.as_slice()
+    }
+
+    pub fn set_query_output_columns(&mut self, columns: ColumnSet) {
+        self.query_output_columns = columns;
+    }
+
+    pub fn query_output_columns(&self) -> &ColumnSet {
+        &self.query_output_columns
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -555,6 +576,22 @@ impl Metadata {
     }
 }
 
+/// A materialized view bound in the metadata of the query it may be used to answer.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewEntry {
+    pub table_id: u64,
+    /// The full name of the materialized view.
+    pub name: String,
+    /// The plan of the query of the materialized view.
+    pub query: SExpr,
+    /// The output columns of `query`, in the order of the columns of the materialized view.
+    pub output_columns: Vec<IndexType>,
+    /// The plan reading the materialized view.
+    pub scan: SExpr,
+    /// The columns read by `scan`, in the order of the columns of the materialized view.
+    pub scan_columns: Vec<IndexType>,
+}
+
 #[derive(Clone)]
 pub struct TableEntry {
     catalog: String,
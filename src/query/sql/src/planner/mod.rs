@@ -44,6 +44,7 @@ pub use optimizer::optimize;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
 pub use planner::Planner;
+pub use planner_cache::PreparedPlan;
 pub use plans::InsertInputSource;
 pub use plans::ScalarExpr;
 pub use plans::DELETE_NAME;
@@ -27,6 +27,7 @@ use crate::optimizer::ir::Memo;
 use crate::optimizer::ir::SExpr;
 use crate::optimizer::optimizers::distributed::BroadcastToShuffleOptimizer;
 use crate::optimizer::optimizers::operator::DeduplicateJoinConditionOptimizer;
+use crate::optimizer::optimizers::operator::MaterializedViewRewriteOptimizer;
 use crate::optimizer::optimizers::operator::PullUpFilterOptimizer;
 use crate::optimizer::optimizers::operator::RuleNormalizeAggregateOptimizer;
 use crate::optimizer::optimizers::operator::RuleStatsAggregateOptimizer;
@@ -246,43 +247,45 @@ pub async fn optimize_query(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Re
         .await?
         // 2. Eliminate subqueries by rewriting them into more efficient form
         .add(SubqueryDecorrelatorOptimizer::new(opt_ctx.clone(), None))
-        // 3. Apply statistics aggregation to gather and propagate statistics
+        // 3. Rewrite aggregations to read materialized views
+        .add(MaterializedViewRewriteOptimizer::new(opt_ctx.clone()))
+        // 4. Apply statistics aggregation to gather and propagate statistics
         .add(RuleStatsAggregateOptimizer::new(opt_ctx.clone()))
-        // 4. Collect statistics for SExpr nodes to support cost estimation
+        // 5. Collect statistics for SExpr nodes to support cost estimation
         .add(CollectStatisticsOptimizer::new(opt_ctx.clone()))
-        // 5. Normalize aggregate, it should be executed before RuleSplitAggregate.
+        // 6. Normalize aggregate, it should be executed before RuleSplitAggregate.
         .add(RuleNormalizeAggregateOptimizer::new())
-        // 6. Pull up and infer filter.
+        // 7. Pull up and infer filter.
         .add(PullUpFilterOptimizer::new(opt_ctx.clone()))
-        // 7. Run default rewrite rules
+        // 8. Run default rewrite rules
         .add(RecursiveRuleOptimizer::new(
             opt_ctx.clone(),
             &DEFAULT_REWRITE_RULES,
         ))
-        // 8. Run post rewrite rules
+        // 9. Run post rewrite rules
         .add(RecursiveRuleOptimizer::new(opt_ctx.clone(), &[
             RuleID::SplitAggregate,
         ]))
-        // 9. Apply DPhyp algorithm for cost-based join reordering
+        // 10. Apply DPhyp algorithm for cost-based join reordering
         .add(DPhpyOptimizer::new(opt_ctx.clone()))
-        // 10. After join reorder, Convert some single join to inner join.
+        // 11. After join reorder, Convert some single join to inner join.
         .add(SingleToInnerOptimizer::new())
-        // 11. Deduplicate join conditions.
+        // 12. Deduplicate join conditions.
         .add(DeduplicateJoinConditionOptimizer::new())
-        // 12. Apply join commutativity to further optimize join ordering
+        // 13. Apply join commutativity to further optimize join ordering
         .add_if(
             opt_ctx.get_enable_join_reorder(),
             RecursiveRuleOptimizer::new(opt_ctx.clone(), [RuleID::CommuteJoin].as_slice()),
         )
-        // 13. Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
+        // 14. Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
         .add(CascadesOptimizer::new(opt_ctx.clone())?)
-        // 14. Eliminate unnecessary scalar calculations to clean up the final plan
+        // 15. Eliminate unnecessary scalar calculations to clean up the final plan
         .add_if(
             !opt_ctx.get_planning_agg_index(),
             RecursiveRuleOptimizer::new(opt_ctx.clone(), [RuleID::EliminateEvalScalar].as_slice()),
         );
 
-    // 15. Execute the pipeline
+    // 16. Execute the pipeline
     let s_expr = pipeline.execute().await?;
 
     Ok(s_expr)
@@ -116,6 +116,7 @@ impl SubqueryDecorrelatorOptimizer {
                     udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
+                    table_fields: udf.table_fields.clone(),
                     arguments,
                 }))
             }
@@ -0,0 +1,856 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use log::info;
+
+use crate::binder::split_conjunctions;
+use crate::binder::wrap_cast;
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::Optimizer;
+use crate::optimizer::OptimizerContext;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::MaterializedViewEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+// The MaterializedViewRewriteOptimizer rewrites aggregations and joins to read the materialized
+// views bound by the binder. An aggregation is answered by a materialized view if they join the
+// same tables, the view keeps all the rows the aggregation reads, and the group keys and
+// aggregate functions can be computed from the columns of the view. A join or filter of tables
+// is answered by a materialized view without aggregation in the same way, if the columns used
+// above it can be computed from the columns of the view.
+pub struct MaterializedViewRewriteOptimizer {
+    metadata: MetadataRef,
+    /// The materialized views not used yet.
+    views: Vec<ViewInfo>,
+}
+
+impl MaterializedViewRewriteOptimizer {
+    pub fn new(opt_ctx: Arc<OptimizerContext>) -> Self {
+        let metadata = opt_ctx.get_metadata();
+        let entries = metadata.read().materialized_views().to_vec();
+        let mut optimizer = MaterializedViewRewriteOptimizer {
+            metadata,
+            views: vec![],
+        };
+        optimizer.views = entries
+            .iter()
+            .filter_map(|entry| optimizer.parse_view(entry))
+            .collect();
+        optimizer
+    }
+
+    pub fn optimize_sync(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        if self.views.is_empty() {
+            return Ok(s_expr.clone());
+        }
+        let mut required = self.metadata.read().query_output_columns().clone();
+        if required.is_empty() {
+            required = s_expr.derive_relational_prop()?.output_columns.clone();
+        }
+        self.rewrite(s_expr, &required)
+    }
+
+    /// Rewrites the plan, `required` are the columns of the plan used by its parents.
+    #[recursive::recursive]
+    fn rewrite(&mut self, s_expr: &SExpr, required: &ColumnSet) -> Result<SExpr> {
+        // Each materialized view is read once, the columns of its scan are bound only once.
+        match s_expr.plan() {
+            RelOperator::Aggregate(agg) => {
+                for i in 0..self.views.len() {
+                    if let Some(result) = self.try_rewrite(agg, s_expr.child(0)?, &self.views[i])? {
+                        let view = self.views.remove(i);
+                        info!(
+                            "rewrite aggregation to read materialized view {}",
+                            view.name
+                        );
+                        return Ok(result);
+                    }
+                }
+            }
+            RelOperator::Join(_) | RelOperator::Filter(_) => {
+                for i in 0..self.views.len() {
+                    if let Some(result) = self.try_rewrite_join(s_expr, required, &self.views[i])? {
+                        let view = self.views.remove(i);
+                        info!("rewrite join to read materialized view {}", view.name);
+                        return Ok(result);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let used = used_columns(s_expr.plan())?;
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            // The columns of an unknown operator are all required.
+            let child_required = match &used {
+                Some(used) => required.union(used).cloned().collect(),
+                None => child.derive_relational_prop()?.output_columns.clone(),
+            };
+            let new_child = self.rewrite(child, &child_required)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+        if children_changed {
+            return Ok(s_expr.replace_children(children));
+        }
+        Ok(s_expr.clone())
+    }
+
+    fn parse_view(&self, entry: &MaterializedViewEntry) -> Option<ViewInfo> {
+        let mut core = Core::default();
+        let mut aggregated = false;
+        let mut s_expr = &entry.query;
+        loop {
+            match s_expr.plan() {
+                RelOperator::EvalScalar(eval) => core.add_items(&eval.items),
+                RelOperator::Sort(_) => {}
+                RelOperator::Aggregate(agg) if is_plain_aggregate(agg) => {
+                    core.add_items(&agg.group_items);
+                    core.add_items(&agg.aggregate_functions);
+                    aggregated = true;
+                    s_expr = s_expr.child(0).ok()?;
+                    break;
+                }
+                // A filter above the aggregation is rejected by `collect_input`.
+                RelOperator::Filter(_) | RelOperator::Join(_) | RelOperator::Scan(_) => break,
+                _ => return None,
+            }
+            s_expr = s_expr.child(0).ok()?;
+        }
+        if !self.collect_input(s_expr, &mut core) || !core.normalize_tables() {
+            return None;
+        }
+
+        let predicates = core
+            .predicates
+            .iter()
+            .map(|predicate| self.canonical(predicate, &core))
+            .collect::<Option<HashSet<_>>>()?;
+
+        let mut view = ViewInfo {
+            name: entry.name.clone(),
+            tables: core.tables.clone(),
+            predicates,
+            aggregated,
+            columns: HashMap::new(),
+            aggregations: HashMap::new(),
+            rollups: vec![],
+            scan: entry.scan.clone(),
+        };
+        for (output, scan_column) in entry.output_columns.iter().zip(&entry.scan_columns) {
+            let data_type = self.metadata.read().column(*scan_column).data_type();
+            let column = (*scan_column, data_type);
+            let scalar = core.resolve(*output);
+            let Some(canonical) = self.canonical_column(*output, &core) else {
+                continue;
+            };
+            match &scalar {
+                Some(ScalarExpr::AggregateFunction(_)) => {
+                    view.aggregations.insert(canonical, column);
+                }
+                Some(scalar) if core.contains_aggregate(scalar) => {}
+                Some(ScalarExpr::FunctionCall(func)) => {
+                    if let ([arg], Some(granularity)) = (
+                        func.arguments.as_slice(),
+                        Granularity::of_truncation(&func.func_name),
+                    ) {
+                        if let Some(arg) = self.canonical(arg, &core) {
+                            view.rollups.push((granularity, arg, column.clone()));
+                        }
+                    }
+                    view.columns.insert(canonical, column);
+                }
+                _ => {
+                    view.columns.insert(canonical, column);
+                }
+            }
+        }
+        Some(view)
+    }
+
+    /// Collects the tables, derived columns and predicates of a tree of inner joins.
+    #[recursive::recursive]
+    fn collect_input(&self, s_expr: &SExpr, core: &mut Core) -> bool {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(eval) => core.add_items(&eval.items),
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter() {
+                    core.predicates.extend(split_conjunctions(predicate));
+                }
+            }
+            RelOperator::Join(join)
+                if matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                    && join.marker_index.is_none()
+                    && !join.from_correlated_subquery
+                    && !join.is_lateral
+                    && !join.has_null_equi_condition()
+                    && join.single_to_inner.is_none() =>
+            {
+                for condition in join.equi_conditions.iter() {
+                    core.predicates.push(ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "eq".to_string(),
+                        params: vec![],
+                        arguments: vec![condition.left.clone(), condition.right.clone()],
+                    }));
+                }
+                for predicate in join.non_equi_conditions.iter() {
+                    core.predicates.extend(split_conjunctions(predicate));
+                }
+            }
+            RelOperator::Scan(scan) if is_plain_scan(scan) => {
+                let metadata = self.metadata.read();
+                core.tables
+                    .push(metadata.table(scan.table_index).table().get_id());
+                return true;
+            }
+            _ => return false,
+        }
+        s_expr
+            .children()
+            .all(|child| self.collect_input(child, core))
+    }
+
+    fn try_rewrite(
+        &self,
+        agg: &Aggregate,
+        input: &SExpr,
+        view: &ViewInfo,
+    ) -> Result<Option<SExpr>> {
+        if !is_plain_aggregate(agg) {
+            return Ok(None);
+        }
+        let mut core = Core::default();
+        if !self.collect_input(input, &mut core)
+            || !core.normalize_tables()
+            || core.tables != view.tables
+        {
+            return Ok(None);
+        }
+
+        let Some(compensations) = self.compensate(&core, view) else {
+            return Ok(None);
+        };
+
+        let mut items = vec![];
+        let mut group_items = Vec::with_capacity(agg.group_items.len());
+        for item in agg.group_items.iter() {
+            let Some(scalar) = self.express(&item.scalar, &core, view) else {
+                return Ok(None);
+            };
+            items.push(ScalarItem {
+                index: item.index,
+                scalar: scalar.clone(),
+            });
+            group_items.push(ScalarItem {
+                index: item.index,
+                scalar,
+            });
+        }
+
+        let mut aggregate_functions = vec![];
+        let mut casts = vec![];
+        for item in agg.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(func) = &item.scalar else {
+                return Ok(None);
+            };
+            if !func.sort_descs.is_empty() {
+                return Ok(None);
+            }
+
+            if !view.aggregated {
+                let mut args = Vec::with_capacity(func.args.len());
+                for arg in func.args.iter() {
+                    let Some(scalar) = self.express(arg, &core, view) else {
+                        return Ok(None);
+                    };
+                    let data_type = arg.data_type()?;
+                    let index = self.metadata.write().add_derived_column(
+                        format!("{}_arg", func.display_name),
+                        data_type.clone(),
+                        None,
+                    );
+                    items.push(ScalarItem { index, scalar });
+                    args.push(column_ref(index, &data_type));
+                }
+                aggregate_functions.push(ScalarItem {
+                    index: item.index,
+                    scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                        args,
+                        ..func.clone()
+                    }),
+                });
+                continue;
+            }
+
+            // The aggregate states of the view are merged by the aggregate functions below.
+            if func.distinct || !func.params.is_empty() {
+                return Ok(None);
+            }
+            let Some(args) = func
+                .args
+                .iter()
+                .map(|arg| self.canonical(arg, &core))
+                .collect::<Option<Vec<_>>>()
+            else {
+                return Ok(None);
+            };
+            let find = |name: &str| {
+                view.aggregations
+                    .get(&format_aggregate(name, false, &[], &args))
+            };
+            let result = match func.func_name.as_str() {
+                "sum" | "min" | "max" => match find(&func.func_name) {
+                    Some(column) => self.merge_aggregate(&func.func_name, func, column)?,
+                    None => return Ok(None),
+                },
+                // The sum of the counts is NULL rather than 0 without any rows.
+                "count" if !agg.group_items.is_empty() => match find("count") {
+                    Some(column) => self.merge_aggregate("sum", func, column)?,
+                    None => return Ok(None),
+                },
+                "avg" => match (find("sum"), find("count")) {
+                    (Some(sum), Some(count)) => {
+                        let sum = self.merge_aggregate("sum", func, sum)?;
+                        let count = self.merge_aggregate("sum", func, count)?;
+                        let divide = ScalarExpr::FunctionCall(FunctionCall {
+                            span: None,
+                            func_name: "divide".to_string(),
+                            params: vec![],
+                            arguments: vec![sum.1.clone(), count.1.clone()],
+                        });
+                        aggregate_functions.push(sum.0);
+                        aggregate_functions.push(count.0);
+                        casts.push(ScalarItem {
+                            index: item.index,
+                            scalar: wrap_cast(&divide, &func.return_type),
+                        });
+                        continue;
+                    }
+                    _ => return Ok(None),
+                },
+                _ => return Ok(None),
+            };
+
+            let (mut merged, column) = result;
+            if column.data_type()? == *func.return_type {
+                merged.index = item.index;
+            } else {
+                casts.push(ScalarItem {
+                    index: item.index,
+                    scalar: wrap_cast(&column, &func.return_type),
+                });
+            }
+            aggregate_functions.push(merged);
+        }
+
+        let mut s_expr = view.scan.clone();
+        if !compensations.is_empty() {
+            let filter = Filter {
+                predicates: compensations,
+            };
+            s_expr = SExpr::create_unary(Arc::new(filter.into()), Arc::new(s_expr));
+        }
+        if !items.is_empty() {
+            let eval_scalar = EvalScalar { items };
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+        let aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions,
+            from_distinct: agg.from_distinct,
+            rank_limit: None,
+            grouping_sets: None,
+        };
+        s_expr = SExpr::create_unary(Arc::new(aggregate.into()), Arc::new(s_expr));
+        if !casts.is_empty() {
+            let eval_scalar = EvalScalar { items: casts };
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+        Ok(Some(s_expr))
+    }
+
+    /// Rewrites a join or filter of tables to read a view without aggregation, the columns
+    /// in `required` are computed from the columns of the view.
+    fn try_rewrite_join(
+        &self,
+        s_expr: &SExpr,
+        required: &ColumnSet,
+        view: &ViewInfo,
+    ) -> Result<Option<SExpr>> {
+        if view.aggregated {
+            return Ok(None);
+        }
+        let mut core = Core::default();
+        if !self.collect_input(s_expr, &mut core)
+            || !core.normalize_tables()
+            || core.tables != view.tables
+        {
+            return Ok(None);
+        }
+        let Some(compensations) = self.compensate(&core, view) else {
+            return Ok(None);
+        };
+
+        let output_columns = s_expr.derive_relational_prop()?.output_columns.clone();
+        let mut items = vec![];
+        for index in output_columns.intersection(required) {
+            let data_type = self.metadata.read().column(*index).data_type();
+            let Some(scalar) = self.express(&column_ref(*index, &data_type), &core, view) else {
+                return Ok(None);
+            };
+            items.push(ScalarItem {
+                index: *index,
+                scalar,
+            });
+        }
+
+        let mut s_expr = view.scan.clone();
+        if !compensations.is_empty() {
+            let filter = Filter {
+                predicates: compensations,
+            };
+            s_expr = SExpr::create_unary(Arc::new(filter.into()), Arc::new(s_expr));
+        }
+        if !items.is_empty() {
+            let eval_scalar = EvalScalar { items };
+            s_expr = SExpr::create_unary(Arc::new(eval_scalar.into()), Arc::new(s_expr));
+        }
+        Ok(Some(s_expr))
+    }
+
+    /// Returns the predicates of the query to apply to the rows of the view, `None` if the
+    /// view does not keep all the rows of the query. The predicates of the view must be a
+    /// subset of the predicates of the query.
+    fn compensate(&self, core: &Core, view: &ViewInfo) -> Option<Vec<ScalarExpr>> {
+        let mut matched = HashSet::new();
+        let mut compensations = vec![];
+        for predicate in core.predicates.iter() {
+            match self.canonical(predicate, core) {
+                Some(canonical) if view.predicates.contains(&canonical) => {
+                    matched.insert(canonical);
+                }
+                _ => compensations.push(self.express(predicate, core, view)?),
+            }
+        }
+        (matched.len() == view.predicates.len()).then_some(compensations)
+    }
+
+    /// Returns the aggregate function `name` over a column of the view, with a new output
+    /// column, and the reference to the output column.
+    fn merge_aggregate(
+        &self,
+        name: &str,
+        func: &AggregateFunction,
+        (index, data_type): &(IndexType, DataType),
+    ) -> Result<(ScalarItem, ScalarExpr)> {
+        let return_type = AggregateFunctionFactory::instance()
+            .get(name, vec![], vec![data_type.clone()], vec![])?
+            .return_type()?;
+        let display_name = format!("{name}({})", func.display_name);
+        let output = self.metadata.write().add_derived_column(
+            display_name.clone(),
+            return_type.clone(),
+            None,
+        );
+        let item = ScalarItem {
+            index: output,
+            scalar: ScalarExpr::AggregateFunction(AggregateFunction {
+                span: None,
+                func_name: name.to_string(),
+                distinct: false,
+                params: vec![],
+                args: vec![column_ref(*index, data_type)],
+                return_type: Box::new(return_type.clone()),
+                sort_descs: vec![],
+                display_name,
+            }),
+        };
+        Ok((item, column_ref(output, &return_type)))
+    }
+
+    /// Computes the scalar of the query from the columns of the view, with the same data type.
+    fn express(&self, scalar: &ScalarExpr, core: &Core, view: &ViewInfo) -> Option<ScalarExpr> {
+        let data_type = scalar.data_type().ok()?;
+        let expressed = self.do_express(scalar, core, view)?;
+        if expressed.data_type().ok()? == data_type {
+            Some(expressed)
+        } else {
+            Some(wrap_cast(&expressed, &data_type))
+        }
+    }
+
+    #[recursive::recursive]
+    fn do_express(&self, scalar: &ScalarExpr, core: &Core, view: &ViewInfo) -> Option<ScalarExpr> {
+        if let Some(canonical) = self.canonical(scalar, core) {
+            if let Some((index, data_type)) = view.columns.get(&canonical) {
+                return Some(column_ref(*index, data_type));
+            }
+        }
+
+        match scalar {
+            ScalarExpr::BoundColumnRef(column) => {
+                let scalar = core.column_map.get(&column.column.index)?;
+                self.express(scalar, core, view)
+            }
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::TypedConstantExpr(_, _) => {
+                Some(scalar.clone())
+            }
+            ScalarExpr::FunctionCall(func) => {
+                // `F(x)` can be computed from `G(x)` of the view, if the values of `x` truncated
+                // by `G` fall into the same result of `F`, such as months from days.
+                if let ([arg], Some(granularity)) = (
+                    func.arguments.as_slice(),
+                    Granularity::of_function(&func.func_name),
+                ) {
+                    let arg = self.canonical(arg, core);
+                    for (view_granularity, view_arg, (index, data_type)) in view.rollups.iter() {
+                        if arg.as_ref() == Some(view_arg) && view_granularity.nests_in(granularity)
+                        {
+                            let rollup = ScalarExpr::FunctionCall(FunctionCall {
+                                arguments: vec![column_ref(*index, data_type)],
+                                ..func.clone()
+                            });
+                            if rollup.data_type().is_ok() {
+                                return Some(rollup);
+                            }
+                        }
+                    }
+                }
+
+                let arguments = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.express(arg, core, view))
+                    .collect::<Option<Vec<_>>>()?;
+                let scalar = ScalarExpr::FunctionCall(FunctionCall {
+                    arguments,
+                    ..func.clone()
+                });
+                scalar.data_type().ok()?;
+                Some(scalar)
+            }
+            ScalarExpr::CastExpr(cast) => Some(ScalarExpr::CastExpr(CastExpr {
+                argument: Box::new(self.express(&cast.argument, core, view)?),
+                ..cast.clone()
+            })),
+            _ => None,
+        }
+    }
+
+    /// Returns a string identifying the value of the scalar, with the derived columns expanded
+    /// and the columns of tables identified by the table ids. `None` if the scalar can not be
+    /// compared between queries.
+    #[recursive::recursive]
+    fn canonical(&self, scalar: &ScalarExpr, core: &Core) -> Option<String> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column) => self.canonical_column(column.column.index, core),
+            ScalarExpr::ConstantExpr(constant) => Some(format!("{:?}", constant.value)),
+            ScalarExpr::TypedConstantExpr(constant, data_type) => {
+                Some(format!("{:?}::{data_type}", constant.value))
+            }
+            ScalarExpr::FunctionCall(func) => {
+                let property = BUILTIN_FUNCTIONS.get_property(&func.func_name)?;
+                if property.non_deterministic {
+                    return None;
+                }
+                let mut args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.canonical(arg, core))
+                    .collect::<Option<Vec<_>>>()?;
+                if matches!(
+                    func.func_name.as_str(),
+                    "eq" | "noteq" | "plus" | "multiply" | "and" | "or"
+                ) {
+                    args.sort();
+                }
+                Some(format!(
+                    "{}{:?}({})",
+                    func.func_name,
+                    func.params,
+                    args.join(", ")
+                ))
+            }
+            ScalarExpr::CastExpr(cast) => Some(format!(
+                "{}({} AS {})",
+                if cast.is_try { "TRY_CAST" } else { "CAST" },
+                self.canonical(&cast.argument, core)?,
+                cast.target_type
+            )),
+            ScalarExpr::AggregateFunction(func) if func.sort_descs.is_empty() => {
+                let args = func
+                    .args
+                    .iter()
+                    .map(|arg| self.canonical(arg, core))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format_aggregate(
+                    &func.func_name,
+                    func.distinct,
+                    &func.params,
+                    &args,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn canonical_column(&self, index: IndexType, core: &Core) -> Option<String> {
+        if let Some(scalar) = core.column_map.get(&index) {
+            return self.canonical(scalar, core);
+        }
+        let metadata = self.metadata.read();
+        match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                ..
+            }) => Some(format!(
+                "{}.{column_name}",
+                metadata.table(*table_index).table().get_id()
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Optimizer for MaterializedViewRewriteOptimizer {
+    fn name(&self) -> String {
+        "MaterializedViewRewriteOptimizer".to_string()
+    }
+
+    async fn optimize(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        self.optimize_sync(s_expr)
+    }
+}
+
+/// The joined tables, derived columns and predicates below an aggregation.
+#[derive(Default)]
+struct Core {
+    /// The ids of the tables.
+    tables: Vec<u64>,
+    column_map: HashMap<IndexType, ScalarExpr>,
+    predicates: Vec<ScalarExpr>,
+}
+
+impl Core {
+    fn add_items(&mut self, items: &[ScalarItem]) {
+        for item in items {
+            // The group items of columns refer to themselves.
+            if let ScalarExpr::BoundColumnRef(column) = &item.scalar {
+                if column.column.index == item.index {
+                    continue;
+                }
+            }
+            self.column_map.insert(item.index, item.scalar.clone());
+        }
+    }
+
+    /// Sorts the tables, returns false if a table is joined more than once, whose columns
+    /// can not be told apart by the table id.
+    fn normalize_tables(&mut self) -> bool {
+        self.tables.sort();
+        self.tables.windows(2).all(|w| w[0] != w[1])
+    }
+
+    fn resolve(&self, mut index: IndexType) -> Option<ScalarExpr> {
+        loop {
+            match self.column_map.get(&index) {
+                Some(ScalarExpr::BoundColumnRef(column)) => index = column.column.index,
+                scalar => return scalar.cloned(),
+            }
+        }
+    }
+
+    #[recursive::recursive]
+    fn contains_aggregate(&self, scalar: &ScalarExpr) -> bool {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column) => self
+                .column_map
+                .get(&column.column.index)
+                .is_some_and(|scalar| self.contains_aggregate(scalar)),
+            ScalarExpr::ConstantExpr(_) | ScalarExpr::TypedConstantExpr(_, _) => false,
+            ScalarExpr::FunctionCall(func) => func
+                .arguments
+                .iter()
+                .any(|arg| self.contains_aggregate(arg)),
+            ScalarExpr::CastExpr(cast) => self.contains_aggregate(&cast.argument),
+            _ => true,
+        }
+    }
+}
+
+struct ViewInfo {
+    name: String,
+    tables: Vec<u64>,
+    predicates: HashSet<String>,
+    aggregated: bool,
+    /// The columns of the view that are not aggregations, by the canonical strings.
+    columns: HashMap<String, (IndexType, DataType)>,
+    /// The columns of the view that are aggregate functions, by the canonical strings.
+    aggregations: HashMap<String, (IndexType, DataType)>,
+    /// The columns of the view that truncate a date or timestamp, with the canonical strings
+    /// of the truncated values.
+    rollups: Vec<(Granularity, String, (IndexType, DataType))>,
+    scan: SExpr,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Granularity {
+    /// Units within a day, in seconds.
+    Seconds(u32),
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Granularity {
+    const DAY: Granularity = Granularity::Seconds(86400);
+
+    /// The granularity of the values kept by a truncation function.
+    fn of_truncation(name: &str) -> Option<Granularity> {
+        Some(match name {
+            "to_start_of_second" => Granularity::Seconds(1),
+            "to_start_of_minute" => Granularity::Seconds(60),
+            "to_start_of_five_minutes" => Granularity::Seconds(300),
+            "to_start_of_ten_minutes" => Granularity::Seconds(600),
+            "to_start_of_fifteen_minutes" => Granularity::Seconds(900),
+            "to_start_of_hour" => Granularity::Seconds(3600),
+            "to_start_of_day" | "to_date" => Granularity::DAY,
+            "to_monday" => Granularity::Week,
+            "to_start_of_month" => Granularity::Month,
+            "to_start_of_quarter" => Granularity::Quarter,
+            "to_start_of_year" => Granularity::Year,
+            _ => return None,
+        })
+    }
+
+    /// The granularity a function depends on, the function returns the same result for
+    /// the values in the same unit of the granularity.
+    fn of_function(name: &str) -> Option<Granularity> {
+        Some(match name {
+            "to_second" => Granularity::Seconds(1),
+            "to_minute" => Granularity::Seconds(60),
+            "to_hour" | "to_yyyymmddhh" => Granularity::Seconds(3600),
+            "to_yyyymmdd" | "to_day_of_month" | "to_day_of_week" | "to_day_of_year" => {
+                Granularity::DAY
+            }
+            "to_yyyymm" | "to_month" => Granularity::Month,
+            "to_quarter" => Granularity::Quarter,
+            "to_year" => Granularity::Year,
+            _ => return Self::of_truncation(name),
+        })
+    }
+
+    /// Returns true if each unit of `self` falls into one unit of `other`.
+    fn nests_in(self, other: Granularity) -> bool {
+        use Granularity::*;
+        match (self, other) {
+            (Seconds(a), Seconds(b)) => b % a == 0,
+            (Seconds(_), _) => true,
+            (Week, Week) => true,
+            (Month, Month | Quarter | Year) => true,
+            (Quarter, Quarter | Year) => true,
+            (Year, Year) => true,
+            _ => false,
+        }
+    }
+}
+
+fn is_plain_aggregate(agg: &Aggregate) -> bool {
+    agg.mode == AggregateMode::Initial && agg.grouping_sets.is_none() && agg.rank_limit.is_none()
+}
+
+/// Returns the columns used by the operator besides the columns it outputs, `None` if they are
+/// not known.
+fn used_columns(op: &RelOperator) -> Result<Option<ColumnSet>> {
+    Ok(Some(match op {
+        RelOperator::EvalScalar(eval) => eval.used_columns()?,
+        RelOperator::Filter(filter) => filter.used_columns()?,
+        RelOperator::Join(join) => join.used_columns()?,
+        RelOperator::Aggregate(agg) => agg.used_columns()?,
+        RelOperator::Sort(sort) => sort.used_columns(),
+        RelOperator::Window(window) => window.used_columns()?,
+        RelOperator::Limit(_) => ColumnSet::new(),
+        _ => return Ok(None),
+    }))
+}
+
+fn is_plain_scan(scan: &Scan) -> bool {
+    scan.push_down_predicates.is_none()
+        && scan.limit.is_none()
+        && scan.order_by.is_none()
+        && scan.prewhere.is_none()
+        && scan.agg_index.is_none()
+        && scan.change_type.is_none()
+        && !scan.update_stream_columns
+        && scan.inverted_index.is_none()
+        && scan.vector_index.is_none()
+        && scan.sample.is_none()
+}
+
+fn format_aggregate(name: &str, distinct: bool, params: &[Scalar], args: &[String]) -> String {
+    format!(
+        "{name}{}{params:?}({})",
+        if distinct { " DISTINCT" } else { "" },
+        args.join(", ")
+    )
+}
+
+fn column_ref(index: IndexType, data_type: &DataType) -> ScalarExpr {
+    ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column: ColumnBindingBuilder::new(
+            format!("_mv_{index}"),
+            index,
+            Box::new(data_type.clone()),
+            Visibility::Visible,
+        )
+        .build(),
+    })
+}
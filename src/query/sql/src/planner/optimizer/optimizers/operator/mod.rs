@@ -16,6 +16,7 @@ mod aggregate;
 mod decorrelate;
 mod filter;
 mod join;
+mod materialized_view;
 
 pub use aggregate::RuleNormalizeAggregateOptimizer;
 pub use aggregate::RuleStatsAggregateOptimizer;
@@ -28,3 +29,4 @@ pub use filter::JoinProperty;
 pub use filter::NormalizeDisjunctiveFilterOptimizer;
 pub use filter::PullUpFilterOptimizer;
 pub use join::SingleToInnerOptimizer;
+pub use materialized_view::MaterializedViewRewriteOptimizer;
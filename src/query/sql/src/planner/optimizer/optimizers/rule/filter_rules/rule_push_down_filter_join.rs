@@ -158,7 +158,10 @@ pub fn try_push_down_filter_join(s_expr: &SExpr, metadata: MetadataRef) -> Resul
                 }
             }
             JoinPredicate::Right(_) => {
-                if matches!(
+                if join.join_type.is_asof_join() {
+                    // Filtering the right side before an asof join changes which row is the closest one.
+                    original_predicates.push(predicate);
+                } else if matches!(
                     join.join_type,
                     JoinType::Left | JoinType::LeftSingle | JoinType::Full
                 ) {
@@ -200,7 +203,11 @@ pub fn try_push_down_filter_join(s_expr: &SExpr, metadata: MetadataRef) -> Resul
         return Ok((false, s_expr.clone()));
     }
 
-    if !matches!(join.join_type, JoinType::Full) && !join.has_null_equi_condition() {
+    if !matches!(
+        join.join_type,
+        JoinType::Full | JoinType::Asof | JoinType::LeftAsof
+    ) && !join.has_null_equi_condition()
+    {
         // Infer new predicate and push down filter.
         for equi_condition in join.equi_conditions.iter() {
             let left = equi_condition.left.clone();
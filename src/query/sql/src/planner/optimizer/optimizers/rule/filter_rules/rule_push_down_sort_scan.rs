@@ -15,16 +15,36 @@
 use std::cmp;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_exception::Result;
+use databend_common_expression::type_check;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::Constant;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIndexType;
 
 use crate::optimizer::ir::Matcher;
 use crate::optimizer::ir::SExpr;
 use crate::optimizer::optimizers::rule::Rule;
 use crate::optimizer::optimizers::rule::RuleID;
 use crate::optimizer::optimizers::rule::TransformResult;
+use crate::plans::CastExpr;
+use crate::plans::EvalScalar;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
+use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
 
 /// Input:  Sort
 ///           \
@@ -34,15 +54,20 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           Scan(padding order_by and limit)
+///
+/// If the table has a vector index on the column, `ORDER BY cosine_distance(column, query) LIMIT k`
+/// also pads the vector index info into Scan, which is used to prune the blocks.
 pub struct RulePushDownSortScan {
     id: RuleID,
     matchers: Vec<Matcher>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
+            metadata,
             matchers: vec![
                 Matcher::MatchOp {
                     op_type: RelOp::Sort,
@@ -64,6 +89,107 @@ impl RulePushDownSortScan {
             ],
         }
     }
+
+    fn try_build_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        get: &Scan,
+    ) -> Result<Option<VectorIndexInfo>> {
+        let (Some(limit), [item]) = (sort.limit, sort.items.as_slice()) else {
+            return Ok(None);
+        };
+        // Null vectors are not indexed, they must be sorted after the nearest rows.
+        if !item.asc || item.nulls_first || get.push_down_predicates.is_some() {
+            return Ok(None);
+        }
+        let Some(ScalarExpr::FunctionCall(func)) = eval_scalar
+            .items
+            .iter()
+            .find(|scalar_item| scalar_item.index == item.index)
+            .map(|scalar_item| &scalar_item.scalar)
+        else {
+            return Ok(None);
+        };
+        if !matches!(func.func_name.as_str(), "cosine_distance" | "l2_distance")
+            || func.arguments.len() != 2
+        {
+            return Ok(None);
+        }
+        let (column, query) = match (&func.arguments[0], &func.arguments[1]) {
+            (ScalarExpr::BoundColumnRef(column), query)
+            | (query, ScalarExpr::BoundColumnRef(column))
+                if query.used_columns().is_empty()
+                    && matches!(
+                        query,
+                        ScalarExpr::ConstantExpr(_)
+                            | ScalarExpr::CastExpr(_)
+                            | ScalarExpr::FunctionCall(_)
+                    ) =>
+            {
+                (column, query)
+            }
+            _ => return Ok(None),
+        };
+
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(base_column) = metadata.column(column.column.index) else {
+            return Ok(None);
+        };
+        let (Some(column_id), TableDataType::Vector(_)) = (
+            base_column.column_id,
+            base_column.data_type.remove_nullable(),
+        ) else {
+            return Ok(None);
+        };
+        if base_column.table_index != get.table_index {
+            return Ok(None);
+        }
+        let table = metadata.table(get.table_index).table();
+        let Some(index) = table.get_table_info().meta.indexes.values().find(|index| {
+            index.index_type == TableIndexType::Vector
+                && index.column_ids == [column_id]
+                && index
+                    .options
+                    .get("distance")
+                    .map_or("cosine", |v| v.as_str())
+                    .eq_ignore_ascii_case(func.func_name.trim_end_matches("_distance"))
+        }) else {
+            return Ok(None);
+        };
+
+        // Evaluate the query vector.
+        let query = ScalarExpr::CastExpr(CastExpr {
+            span: None,
+            is_try: false,
+            argument: Box::new(query.clone()),
+            target_type: Box::new(DataType::Array(Box::new(DataType::Number(
+                NumberDataType::Float32,
+            )))),
+        });
+        let Ok(expr) = type_check::check(&query.as_raw_expr(), &BUILTIN_FUNCTIONS) else {
+            return Ok(None);
+        };
+        let (expr, _) =
+            ConstantFolder::fold(&expr, &FunctionContext::default(), &BUILTIN_FUNCTIONS);
+        let Expr::Constant(Constant {
+            scalar: Scalar::Array(Column::Number(NumberColumn::Float32(values))),
+            ..
+        }) = expr
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(VectorIndexInfo {
+            index_name: index.name.clone(),
+            index_version: index.version.clone(),
+            index_options: index.options.clone(),
+            column_id,
+            func_name: func.func_name.clone(),
+            query_values: values.to_vec(),
+            limit,
+        }))
+    }
 }
 
 impl Rule for RulePushDownSortScan {
@@ -83,11 +209,16 @@ impl Rule for RulePushDownSortScan {
             _ => unreachable!(),
         };
         if get.order_by.is_none() {
-            get.order_by = Some(sort.items);
+            get.order_by = Some(sort.items.clone());
         }
         if let Some(limit) = sort.limit {
             get.limit = Some(get.limit.map_or(limit, |c| cmp::max(c, limit)));
         }
+        if get.vector_index.is_none() {
+            if let RelOperator::EvalScalar(eval_scalar) = child.plan() {
+                get.vector_index = self.try_build_vector_index(&sort, eval_scalar, &get)?;
+            }
+        }
 
         let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
 
@@ -109,9 +240,3 @@ impl Rule for RulePushDownSortScan {
         &self.matchers
     }
 }
-
-impl Default for RulePushDownSortScan {
-    fn default() -> Self {
-        Self::new()
-    }
-}
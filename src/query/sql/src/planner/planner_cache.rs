@@ -22,6 +22,7 @@ use databend_common_ast::ast::IdentifierType;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::is_cacheable_function;
@@ -33,9 +34,11 @@ use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
 use itertools::Itertools;
+use log::info;
 use sha2::Digest;
 use sha2::Sha256;
 
+use crate::get_query_kind;
 use crate::normalize_identifier;
 use crate::plans::Plan;
 use crate::NameResolutionContext;
@@ -48,6 +51,13 @@ pub struct PlanCacheItem {
     pub(crate) variables: HashMap<String, Scalar>,
 }
 
+/// The plan of a prepared statement, kept by the statement and reused by its executions.
+#[derive(Clone)]
+pub struct PreparedPlan {
+    key: String,
+    item: PlanCacheItem,
+}
+
 static PLAN_CACHE: LazyLock<InMemoryLruCache<PlanCacheItem>> =
     LazyLock::new(|| InMemoryLruCache::with_items_capacity("planner_cache".to_string(), 512));
 
@@ -69,8 +79,57 @@ impl Planner {
         key: &str,
         stmt: &Statement,
     ) -> (bool, Option<PlanCacheItem>) {
-        if !matches!(stmt, Statement::Query(_)) {
+        let Some(schema_snapshots) = self.schema_snapshots(name_resolution_ctx, stmt) else {
             return (false, None);
+        };
+
+        let cache = LazyLock::force(&PLAN_CACHE);
+        match cache.get(key) {
+            Some(plan_item) if self.is_cache_valid(&schema_snapshots, &plan_item) => {
+                (true, Some(plan_item.as_ref().clone()))
+            }
+            _ => (true, None),
+        }
+    }
+
+    /// Plan a prepared statement, the plan of the last execution is reused if the statement
+    /// is bound with the same parameters, and the tables, settings and variables are unchanged.
+    #[async_backtrace::framed]
+    pub async fn plan_prepared_stmt(
+        &mut self,
+        stmt: &Statement,
+        cached: &mut Option<PreparedPlan>,
+    ) -> Result<Plan> {
+        let name_resolution_ctx =
+            NameResolutionContext::try_from(self.ctx.get_settings().as_ref())?;
+        let key = Self::planner_cache_key(&stmt.to_string());
+        let schema_snapshots = self.schema_snapshots(name_resolution_ctx, stmt);
+        if let (Some(schema_snapshots), Some(prepared)) = (&schema_snapshots, cached.as_ref()) {
+            if prepared.key == key && self.is_cache_valid(schema_snapshots, &prepared.item) {
+                info!("logical plan from prepared statement");
+                self.ctx
+                    .attach_query_str(get_query_kind(stmt), stmt.to_mask_sql());
+                return Ok(prepared.item.plan.clone());
+            }
+        }
+
+        let plan = self.plan_stmt(stmt).await?;
+        *cached = schema_snapshots.map(|_| PreparedPlan {
+            key,
+            item: self.new_cache_item(plan.clone()),
+        });
+        Ok(plan)
+    }
+
+    // Returns the schemas and snapshots of the tables in the statement,
+    // or `None` if the plan of the statement can not be cached.
+    fn schema_snapshots(
+        &self,
+        name_resolution_ctx: NameResolutionContext,
+        stmt: &Statement,
+    ) -> Option<Vec<(TableSchemaRef, String)>> {
+        if !matches!(stmt, Statement::Query(_)) {
+            return None;
         }
 
         let mut visitor = TableRefVisitor {
@@ -82,48 +141,55 @@ impl Planner {
         stmt.drive(&mut visitor);
 
         if visitor.schema_snapshots.is_empty() || visitor.cache_miss {
-            return (false, None);
+            return None;
         }
+        Some(visitor.schema_snapshots)
+    }
 
-        let cache = LazyLock::force(&PLAN_CACHE);
-        if let Some(plan_item) = cache.get(key) {
-            let settings = self.ctx.get_settings();
-            if settings.changes().len() != plan_item.setting_changes.len() {
-                return (true, None);
-            }
+    // Checks the cached plan is planned with the current settings, variables and tables.
+    fn is_cache_valid(
+        &self,
+        schema_snapshots: &[(TableSchemaRef, String)],
+        plan_item: &PlanCacheItem,
+    ) -> bool {
+        let settings = self.ctx.get_settings();
+        if settings.changes().len() != plan_item.setting_changes.len() {
+            return false;
+        }
 
-            let setting_changes = settings
-                .changes()
-                .iter()
-                .map(|s| (s.key().clone(), s.value().clone()))
-                .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
-                .collect::<Vec<_>>();
-
-            if setting_changes != plan_item.setting_changes
-                || self.ctx.get_all_variables() != plan_item.variables
-            {
-                return (true, None);
-            }
+        let setting_changes = settings
+            .changes()
+            .iter()
+            .map(|s| (s.key().clone(), s.value().clone()))
+            .sorted_by(|a, b| Ord::cmp(&a.0, &b.0))
+            .collect::<Vec<_>>();
 
-            if let Plan::Query { metadata, .. } = &plan_item.plan {
-                let metadata = metadata.read();
-                if visitor.schema_snapshots.iter().all(|ss| {
-                    metadata.tables().iter().any(|table| {
-                        !table.table().is_temp()
-                            && table.table().options().get(OPT_KEY_SNAPSHOT_LOCATION) == Some(&ss.1)
-                            && table.table().schema().eq(&ss.0)
-                    })
-                }) {
-                    return (!visitor.cache_miss, Some(plan_item.as_ref().clone()));
-                }
-            }
-            (!visitor.cache_miss, None)
-        } else {
-            (!visitor.cache_miss, None)
+        if setting_changes != plan_item.setting_changes
+            || self.ctx.get_all_variables() != plan_item.variables
+        {
+            return false;
         }
+
+        if let Plan::Query { metadata, .. } = &plan_item.plan {
+            let metadata = metadata.read();
+            return schema_snapshots.iter().all(|ss| {
+                metadata.tables().iter().any(|table| {
+                    !table.table().is_temp()
+                        && table.table().options().get(OPT_KEY_SNAPSHOT_LOCATION) == Some(&ss.1)
+                        && table.table().schema().eq(&ss.0)
+                })
+            });
+        }
+        false
     }
 
     pub fn set_cache(&self, key: String, plan: Plan) {
+        let plan_item = self.new_cache_item(plan);
+        let cache = LazyLock::force(&PLAN_CACHE);
+        cache.insert(key, plan_item);
+    }
+
+    fn new_cache_item(&self, plan: Plan) -> PlanCacheItem {
         let setting_changes = self
             .ctx
             .get_settings()
@@ -135,13 +201,11 @@ impl Planner {
 
         let variables = self.ctx.get_all_variables();
 
-        let plan_item = PlanCacheItem {
+        PlanCacheItem {
             plan,
             setting_changes,
             variables,
-        };
-        let cache = LazyLock::force(&PLAN_CACHE);
-        cache.insert(key, plan_item);
+        }
     }
 }
 
@@ -21,6 +21,7 @@ mod dynamic_table;
 mod file_format;
 mod index;
 mod notification;
+mod pipe;
 mod procedure;
 mod sequence;
 mod stage;
@@ -41,6 +42,7 @@ pub use dynamic_table::*;
 pub use file_format::*;
 pub use index::*;
 pub use notification::*;
+pub use pipe::*;
 pub use procedure::*;
 pub use sequence::*;
 pub use stage::*;
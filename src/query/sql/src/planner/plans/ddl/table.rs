@@ -18,6 +18,7 @@ use std::time::Duration;
 
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::Identifier;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -59,8 +60,19 @@ pub struct CreateTablePlan {
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
     pub ngram_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub vector_indexes: Option<BTreeMap<String, TableIndex>>,
 
     pub attached_columns: Option<Vec<Identifier>>,
+    pub clone_source: Option<TableCloneSource>,
+}
+
+/// The source table of `CREATE TABLE ... CLONE`.
+#[derive(Clone, Debug)]
+pub struct TableCloneSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub navigation: Option<NavigationPoint>,
 }
 
 impl CreateTablePlan {
@@ -496,6 +508,41 @@ impl DropTableClusterKeyPlan {
     }
 }
 
+/// Create a tag on a snapshot of the table.
+#[derive(Clone, Debug)]
+pub struct CreateTableTagPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+    /// The snapshot being tagged, the current snapshot if not specified.
+    pub navigation: Option<NavigationPoint>,
+    pub retain: Option<Duration>,
+}
+
+impl CreateTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DropTableTagPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub tag: String,
+    pub if_exists: bool,
+}
+
+impl DropTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RefreshTableCachePlan {
     pub tenant: Tenant,
@@ -230,3 +230,16 @@ impl std::fmt::Debug for Insert {
             .finish()
     }
 }
+
+/// `INSERT OVERWRITE <table> PARTITION (...) <source>`, replaces the rows of one partition
+/// of a partitioned table with the rows of the source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsertOverwritePartitionPlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// SQL predicate which selects the rows of the partition.
+    pub partition_predicate: String,
+    /// SQL statement which appends the new rows of the partition.
+    pub insert_sql: String,
+}
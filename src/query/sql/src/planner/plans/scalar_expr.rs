@@ -945,6 +945,8 @@ pub struct UDFCall {
     pub return_type: Box<DataType>,
     pub arguments: Vec<ScalarExpr>,
     pub udf_type: UDFType,
+    // output columns of a table function, which returns an array of tuples
+    pub table_fields: Option<Vec<UDFField>>,
 }
 
 #[derive(Clone, Debug, Educe)]
@@ -1081,18 +1083,46 @@ pub struct SqlSource {
     pub value_field: String,
 }
 
+#[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct HttpSource {
+    // The url returns a JSON array of objects, each object is a row of the dictionary.
+    pub url: String,
+    pub timeout: Option<u64>,
+    pub key_field: String,
+    pub value_field: String,
+}
+
+#[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
+#[educe(PartialEq, Eq, Hash)]
+pub struct DatabendSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub key_field: String,
+    pub value_field: String,
+}
+
 #[derive(Clone, Debug, Educe, EnumAsInner, serde::Serialize, serde::Deserialize)]
 #[educe(PartialEq, Eq, Hash)]
 pub enum DictionarySource {
     Mysql(SqlSource),
     Redis(RedisSource),
+    Postgresql(SqlSource),
+    Http(HttpSource),
+    Databend(DatabendSource),
 }
 
 #[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct DictGetFunctionArgument {
+    pub dict_id: u64,
+    // Any change to the dictionary increments the seq, so the stale local snapshot can be detected.
+    pub dict_meta_seq: u64,
     pub dict_source: DictionarySource,
     pub default_value: Scalar,
+    // Seconds to keep the local snapshot of the dictionary, the source is read by each query if it is None.
+    pub lifetime: Option<u64>,
 }
 
 // Asynchronous functions are functions that need to call remote interfaces.
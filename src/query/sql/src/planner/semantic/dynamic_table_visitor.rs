@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableReference;
+use databend_common_expression::FunctionKind;
+use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+/// How a dynamic table is maintained by the changes of its source table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IncrementalRefreshKind {
+    /// Filter and projection of one table or inner joins of tables, the rows inserted into
+    /// one of the sources are joined with the other sources and appended.
+    Append,
+    /// Aggregation grouped by one column of one table, the changed groups are recomputed.
+    Aggregate {
+        /// The group by column of the source table.
+        source_key: Identifier,
+        /// The column of the dynamic table that holds the group by column.
+        target_key: Identifier,
+    },
+}
+
+#[derive(Debug, Clone, Default, Visitor)]
+#[visitor(FunctionCall(enter), Query(enter), TableReference(enter))]
+pub struct IncrementalRefreshChecker {
+    queries: usize,
+    tables: usize,
+    has_agg_function: bool,
+    not_support: bool,
+}
+
+impl IncrementalRefreshChecker {
+    /// Returns how the query of a dynamic table can be refreshed incrementally,
+    /// `None` if it can only be fully refreshed.
+    pub fn check(query: &Query) -> Option<IncrementalRefreshKind> {
+        let mut checker = Self::default();
+        query.drive(&mut checker);
+        // Subqueries and outer joins are not supported.
+        if checker.not_support || checker.queries != 1 || checker.tables == 0 {
+            return None;
+        }
+
+        let SetExpr::Select(stmt) = &query.body else {
+            return None;
+        };
+        if stmt.distinct
+            || stmt.top_n.is_some()
+            || stmt.window_list.is_some()
+            || stmt.qualify.is_some()
+        {
+            return None;
+        }
+
+        match &stmt.group_by {
+            None if !checker.has_agg_function && stmt.having.is_none() => {
+                Some(IncrementalRefreshKind::Append)
+            }
+            // The changed groups are found by the changes of the only source.
+            Some(GroupBy::Normal(keys)) if keys.len() == 1 && checker.tables == 1 => {
+                let Expr::ColumnRef {
+                    column:
+                        ColumnRef {
+                            column: ColumnID::Name(source_key),
+                            ..
+                        },
+                    ..
+                } = &keys[0]
+                else {
+                    return None;
+                };
+                // The group by column must be selected as it is,
+                // so that the changed groups can be found in the dynamic table.
+                stmt.select_list.iter().find_map(|target| {
+                    let SelectTarget::AliasedExpr { expr, alias } = target else {
+                        return None;
+                    };
+                    match expr.as_ref() {
+                        Expr::ColumnRef {
+                            column:
+                                ColumnRef {
+                                    column: ColumnID::Name(name),
+                                    ..
+                                },
+                            ..
+                        } if name.name == source_key.name => {
+                            Some(IncrementalRefreshKind::Aggregate {
+                                source_key: source_key.clone(),
+                                target_key: alias.clone().unwrap_or_else(|| name.clone()),
+                            })
+                        }
+                        _ => None,
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn enter_function_call(&mut self, func: &FunctionCall) {
+        let name = func.name.name.to_lowercase();
+        if AggregateFunctionFactory::instance().contains(&name) {
+            self.has_agg_function = true;
+            if func.window.is_some() {
+                self.not_support = true;
+            }
+        } else if let Some(property) = BUILTIN_FUNCTIONS.get_property(&name) {
+            // The results of set returning functions and non deterministic functions
+            // can not be maintained by the changes.
+            if property.kind == FunctionKind::SRF || property.non_deterministic {
+                self.not_support = true;
+            }
+        } else {
+            // Window functions, UDFs, etc.
+            self.not_support = true;
+        }
+    }
+
+    fn enter_query(&mut self, query: &Query) {
+        self.queries += 1;
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            self.not_support = true;
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        match table_ref {
+            TableReference::Table {
+                temporal: None,
+                with_options: None,
+                pivot: None,
+                unpivot: None,
+                sample: None,
+                ..
+            } => self.tables += 1,
+            // The changes of one side of an inner join can be joined with the other side.
+            TableReference::Join { join, .. }
+                if matches!(join.op, JoinOperator::Inner | JoinOperator::CrossJoin) => {}
+            _ => self.not_support = true,
+        }
+    }
+}
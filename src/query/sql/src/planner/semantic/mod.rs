@@ -17,6 +17,7 @@ mod aggregating_index_visitor;
 mod async_function_rewriter;
 mod count_set_ops;
 mod distinct_to_groupby;
+mod dynamic_table_visitor;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
@@ -32,6 +33,8 @@ pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub(crate) use async_function_rewriter::AsyncFunctionRewriter;
 pub use count_set_ops::CountSetOps;
 pub use distinct_to_groupby::DistinctToGroupBy;
+pub use dynamic_table_visitor::IncrementalRefreshChecker;
+pub use dynamic_table_visitor::IncrementalRefreshKind;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
 pub use name_resolution::compare_table_name;
@@ -102,6 +102,8 @@ use databend_common_meta_app::principal::UDAFScript;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::principal::UDTFScript;
+use databend_common_meta_app::principal::UDTFServer;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
 use databend_common_meta_app::schema::DictionaryIdentity;
 use databend_common_meta_app::schema::GetSequenceReq;
@@ -142,9 +144,11 @@ use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::ComparisonOp;
 use crate::plans::ConstantExpr;
+use crate::plans::DatabendSource;
 use crate::plans::DictGetFunctionArgument;
 use crate::plans::DictionarySource;
 use crate::plans::FunctionCall;
+use crate::plans::HttpSource;
 use crate::plans::LagLeadFunction;
 use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
@@ -4215,6 +4219,12 @@ impl<'a> TypeChecker<'a> {
             UDFDefinition::UDAFScript(udf_def) => Ok(Some(
                 self.resolve_udaf_script(span, name, arguments, udf_def)?,
             )),
+            UDFDefinition::UDTFServer(udf_def) => Ok(Some(
+                self.resolve_udtf_server(span, name, arguments, udf_def)?,
+            )),
+            UDFDefinition::UDTFScript(udf_def) => Ok(Some(
+                self.resolve_udtf_script(span, name, arguments, udf_def)?,
+            )),
         }
     }
 
@@ -4261,6 +4271,7 @@ impl<'a> TypeChecker<'a> {
                 arg_types: udf_definition.arg_types,
                 return_type: Box::new(udf_definition.return_type.clone()),
                 arguments: args,
+                table_fields: None,
             }
             .into(),
             udf_definition.return_type.clone(),
@@ -4379,12 +4390,136 @@ impl<'a> TypeChecker<'a> {
                 return_type: Box::new(return_type.clone()),
                 udf_type,
                 arguments,
+                table_fields: None,
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    fn resolve_udtf_server(
+        &mut self,
+        span: Span,
+        name: String,
+        args: &[Expr],
+        udf_definition: UDTFServer,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let UDTFServer {
+            address,
+            handler,
+            headers,
+            arg_types,
+            return_fields,
+            ..
+        } = udf_definition;
+
+        UDFValidator::is_udf_server_allowed(&address)?;
+        let arguments = self.resolve_udtf_arguments(span, args, &arg_types)?;
+
+        let arg_names = args.iter().map(|arg| format!("{arg}")).join(", ");
+        let display_name = format!("{}({})", &handler, arg_names);
+        let (table_fields, return_type) = udtf_return_type(&return_fields);
+
+        self.bind_context.have_udf_server = true;
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            UDFCall {
+                span,
+                name,
+                handler,
+                headers,
+                display_name,
+                udf_type: UDFType::Server(address),
+                arg_types,
+                return_type: Box::new(return_type.clone()),
+                arguments,
+                table_fields: Some(table_fields),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    fn resolve_udtf_script(
+        &mut self,
+        span: Span,
+        name: String,
+        args: &[Expr],
+        udf_definition: UDTFScript,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let UDTFScript {
+            code,
+            handler,
+            language,
+            arg_types,
+            return_fields,
+            runtime_version,
+        } = udf_definition;
+
+        let language = language.parse()?;
+        UDFValidator::is_udf_script_allowed(&language)?;
+        let arguments = self.resolve_udtf_arguments(span, args, &arg_types)?;
+
+        let code_blob = databend_common_base::runtime::block_on(self.resolve_udf_with_stage(code))?
+            .into_boxed_slice();
+        let udf_type = UDFType::Script(UDFScriptCode {
+            language,
+            runtime_version,
+            code: code_blob.into(),
+        });
+
+        let arg_names = args.iter().map(|arg| format!("{arg}")).join(", ");
+        let display_name = format!("{}({})", &handler, arg_names);
+        let (table_fields, return_type) = udtf_return_type(&return_fields);
+
+        self.bind_context.have_udf_script = true;
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            UDFCall {
+                span,
+                name,
+                handler,
+                headers: BTreeMap::default(),
+                display_name,
+                arg_types,
+                return_type: Box::new(return_type.clone()),
+                udf_type,
+                arguments,
+                table_fields: Some(table_fields),
             }
             .into(),
             return_type,
         )))
     }
 
+    fn resolve_udtf_arguments(
+        &mut self,
+        span: Span,
+        args: &[Expr],
+        arg_types: &[DataType],
+    ) -> Result<Vec<ScalarExpr>> {
+        if args.len() != arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Require {} parameters, but got: {}",
+                arg_types.len(),
+                args.len()
+            ))
+            .set_span(span));
+        }
+
+        args.iter()
+            .zip(arg_types.iter())
+            .map(|(argument, dest_type)| {
+                let box (arg, ty) = self.resolve(argument)?;
+                Ok(if ty == *dest_type {
+                    arg
+                } else {
+                    wrap_cast(&arg, dest_type)
+                })
+            })
+            .collect()
+    }
+
     fn resolve_udaf_script(
         &mut self,
         span: Span,
@@ -4651,8 +4786,8 @@ impl<'a> TypeChecker<'a> {
             DictionaryIdentity::new(db_id, dict_name.clone()),
         );
         let reply = databend_common_base::runtime::block_on(catalog.get_dictionary(req))?;
-        let dictionary = if let Some(r) = reply {
-            r.dictionary_meta
+        let (dict_id, dict_meta_seq, dictionary) = if let Some(r) = reply {
+            (r.dictionary_id, r.dictionary_meta_seq, r.dictionary_meta)
         } else {
             return Err(ErrorCode::UnknownDictionary(format!(
                 "Unknown dictionary {}",
@@ -4733,6 +4868,54 @@ impl<'a> TypeChecker<'a> {
                     db_index,
                 })
             }
+            "postgresql" => {
+                let connection_url = dictionary.build_sql_connection_url()?;
+                let table = dictionary
+                    .options
+                    .get("table")
+                    .ok_or_else(|| ErrorCode::BadArguments("Miss option `table`"))?;
+                DictionarySource::Postgresql(SqlSource {
+                    connection_url,
+                    table: table.to_string(),
+                    key_field: primary_field.name.clone(),
+                    value_field: attr_field.name.clone(),
+                })
+            }
+            "http" => {
+                let url = dictionary
+                    .options
+                    .get("url")
+                    .ok_or_else(|| ErrorCode::BadArguments("Miss option `url`"))?;
+                let timeout = dictionary
+                    .options
+                    .get("timeout")
+                    .map(|t| t.parse::<u64>())
+                    .transpose()
+                    .map_err(|_| ErrorCode::BadArguments("Invalid option `timeout`"))?;
+                DictionarySource::Http(HttpSource {
+                    url: url.to_string(),
+                    timeout,
+                    key_field: primary_field.name.clone(),
+                    value_field: attr_field.name.clone(),
+                })
+            }
+            "databend" => {
+                let database = dictionary
+                    .options
+                    .get("db")
+                    .ok_or_else(|| ErrorCode::BadArguments("Miss option `db`"))?;
+                let table = dictionary
+                    .options
+                    .get("table")
+                    .ok_or_else(|| ErrorCode::BadArguments("Miss option `table`"))?;
+                DictionarySource::Databend(DatabendSource {
+                    catalog: catalog.name(),
+                    database: database.to_string(),
+                    table: table.to_string(),
+                    key_field: primary_field.name.clone(),
+                    value_field: attr_field.name.clone(),
+                })
+            }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
                     "Unsupported source {}",
@@ -4742,8 +4925,11 @@ impl<'a> TypeChecker<'a> {
         };
 
         let dict_get_func_arg = DictGetFunctionArgument {
+            dict_id,
+            dict_meta_seq,
             dict_source,
             default_value,
+            lifetime: dictionary.lifetime,
         };
         let display_name = format!(
             "{}({}.{}, {}, {})",
@@ -5616,6 +5802,8 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         }
         TypeName::Bitmap => TableDataType::Bitmap,
         TypeName::Interval => TableDataType::Interval,
+        TypeName::Time => TableDataType::Time,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
         TypeName::Tuple {
             fields_type,
             fields_name,
@@ -5647,6 +5835,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
         TypeName::Geography => TableDataType::Geography,
+        TypeName::Vector(dimension) => TableDataType::Vector(*dimension),
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -5697,6 +5886,20 @@ pub fn validate_function_arg(
 }
 
 // Some check functions for like expression
+/// A table function returns the rows of each input row as an array of tuples,
+/// which is unnested into the output columns by the table function binder.
+pub fn udtf_return_type(fields: &[DataField]) -> (Vec<UDFField>, DataType) {
+    let table_fields = fields
+        .iter()
+        .map(|f| UDFField {
+            name: f.name().to_string(),
+            data_type: f.data_type().clone(),
+        })
+        .collect::<Vec<_>>();
+    let tuple = DataType::Tuple(fields.iter().map(|f| f.data_type().clone()).collect());
+    (table_fields, DataType::Array(Box::new(tuple)))
+}
+
 fn check_const(like_str: &str) -> bool {
     for char in like_str.chars() {
         if char == '_' || char == '%' {
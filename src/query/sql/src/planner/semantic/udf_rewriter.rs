@@ -97,6 +97,15 @@ impl UdfRewriter {
                 let new_expr = SExpr::create_unary(Arc::new(plan.into()), child_expr);
                 Ok(new_expr)
             }
+            RelOperator::ProjectSet(mut plan) => {
+                // Table functions are unnested by the set-returning functions.
+                for item in &mut plan.srfs {
+                    self.visit(&mut item.scalar)?;
+                }
+                let child_expr = self.create_udf_expr(s_expr.children[0].clone());
+                let new_expr = SExpr::create_unary(Arc::new(plan.into()), child_expr);
+                Ok(new_expr)
+            }
             RelOperator::Mutation(mut plan) => {
                 for matched_evaluator in plan.matched_evaluators.iter_mut() {
                     if let Some(condition) = matched_evaluator.condition.as_mut() {
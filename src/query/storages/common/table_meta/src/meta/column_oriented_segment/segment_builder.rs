@@ -361,6 +361,8 @@ impl ColumnOrientedSegmentBuilder {
             col_stats,
             cluster_stats,
             virtual_block_count: Some(virtual_block_count),
+            partition_value: None,
+            deleted_row_count: 0,
         })
     }
 }
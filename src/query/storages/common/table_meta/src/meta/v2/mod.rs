@@ -19,6 +19,7 @@ mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
+pub use segment::DeletionVectorMeta;
 pub use segment::DraftVirtualBlockMeta;
 pub use segment::DraftVirtualColumnMeta;
 pub use segment::ExtendedBlockMeta;
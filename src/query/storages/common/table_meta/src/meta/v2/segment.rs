@@ -22,6 +22,7 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::VariantDataType;
@@ -150,6 +151,24 @@ pub struct DraftVirtualBlockMeta {
     pub virtual_location: Location,
 }
 
+/// The deletion vector of a block.
+///
+/// Offsets of the rows deleted from the block, without rewriting the block, are
+/// persisted as a roaring bitmap at `location`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    /// The file location of the deletion bitmap.
+    pub location: Location,
+    /// Number of rows marked as deleted.
+    pub deleted_rows: u64,
+    /// The file size of the deletion bitmap.
+    pub size: u64,
+}
+
+impl DeletionVectorMeta {
+    pub const VERSION: u64 = 0;
+}
+
 /// Meta information of a block
 /// Part of and kept inside the [SegmentInfo]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -176,6 +195,15 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// Rows deleted from the block by a metadata-only mutation.
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+
+    /// The values of the partition keys, if the table is partitioned and
+    /// all the rows of the block belong to one partition.
+    #[serde(default)]
+    pub partition_value: Option<Vec<Scalar>>,
 }
 
 impl BlockMeta {
@@ -211,6 +239,8 @@ impl BlockMeta {
             virtual_block_meta,
             compression,
             create_on,
+            deletion_vector: None,
+            partition_value: None,
         }
     }
 
@@ -218,6 +248,16 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Get the number of rows that are still visible, i.e. excluding the rows
+    /// marked as deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count
+            - self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |dv| dv.deleted_rows)
+    }
+
     /// Get the page size of the block.
     ///
     /// - If the format is parquet, its page size is its row count.
@@ -371,6 +411,8 @@ impl BlockMeta {
             virtual_block_meta: None,
             create_on: None,
             ngram_filter_index_size: None,
+            deletion_vector: None,
+            partition_value: None,
         }
     }
 
@@ -397,6 +439,8 @@ impl BlockMeta {
             virtual_block_meta: None,
             create_on: None,
             ngram_filter_index_size: None,
+            deletion_vector: None,
+            partition_value: None,
         }
     }
 }
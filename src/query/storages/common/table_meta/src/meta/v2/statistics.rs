@@ -83,6 +83,15 @@ pub struct Statistics {
     pub col_stats: HashMap<ColumnId, ColumnStatistics>,
     pub cluster_stats: Option<ClusterStatistics>,
     pub virtual_block_count: Option<u64>,
+
+    /// The values of the partition keys, if all the blocks belong to one partition.
+    #[serde(default)]
+    pub partition_value: Option<Vec<Scalar>>,
+
+    /// Number of rows marked as deleted by deletion vectors. They are excluded from
+    /// `row_count`, but the column statistics are still computed over them.
+    #[serde(default)]
+    pub deleted_row_count: u64,
 }
 
 // conversions from old meta data
@@ -240,6 +249,8 @@ impl Statistics {
             col_stats,
             cluster_stats: None,
             virtual_block_count: None,
+            partition_value: None,
+            deleted_row_count: 0,
         }
     }
 }
@@ -66,6 +66,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             virtual_block_meta: None,
             compression: value.compression.into(),
             create_on: None,
+            deletion_vector: None,
+            partition_value: None,
         }
     }
 }
@@ -49,6 +49,8 @@ impl From<Statistics> for crate::meta::Statistics {
                 .collect(),
             cluster_stats: None,
             virtual_block_count: None,
+            partition_value: None,
+            deleted_row_count: 0,
         }
     }
 }
@@ -18,3 +18,16 @@ pub const OPT_KEY_REFRESH_MODE: &str = "refresh_mode";
 pub const OPT_KEY_INITIALIZE: &str = "initialize";
 pub const OPT_KEY_WAREHOUSE: &str = "warehouse";
 pub const OPT_KEY_LIFECYCLE: &str = "lifecycle";
+// Set on the dynamic tables created by `CREATE MATERIALIZED VIEW`, which can be used to
+// rewrite the queries on the source tables.
+pub const OPT_KEY_MATERIALIZED_VIEW: &str = "materialized_view";
+// The ids of the materialized views defined on a source table, separated by commas.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
+
+// The refresh state of a dynamic table, updated after every refresh.
+// The source keys hold one value per source table, separated by commas.
+pub const OPT_KEY_REFRESH_TIME: &str = "refresh_time";
+pub const OPT_KEY_REFRESH_SNAPSHOT_ID: &str = "refresh_snapshot_id";
+pub const OPT_KEY_SOURCE_TABLE_ID: &str = "source_table_id";
+pub const OPT_KEY_SOURCE_SNAPSHOT_ID: &str = "source_snapshot_id";
+pub const OPT_KEY_SOURCE_SNAPSHOT_LOCATION: &str = "source_snapshot_location";
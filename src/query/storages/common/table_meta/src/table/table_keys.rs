@@ -16,6 +16,19 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::sync::LazyLock;
+
+use crate::table::OPT_KEY_AS_QUERY;
+use crate::table::OPT_KEY_INITIALIZE;
+use crate::table::OPT_KEY_MATERIALIZED_VIEW;
+use crate::table::OPT_KEY_MATERIALIZED_VIEWS;
+use crate::table::OPT_KEY_REFRESH_MODE;
+use crate::table::OPT_KEY_REFRESH_SNAPSHOT_ID;
+use crate::table::OPT_KEY_REFRESH_TIME;
+use crate::table::OPT_KEY_SOURCE_SNAPSHOT_ID;
+use crate::table::OPT_KEY_SOURCE_SNAPSHOT_LOCATION;
+use crate::table::OPT_KEY_SOURCE_TABLE_ID;
+use crate::table::OPT_KEY_TARGET_LAG;
+
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_TEMP_PREFIX: &str = "temp_prefix";
@@ -29,6 +42,8 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// Locations of the reference files left by `CREATE TABLE ... CLONE` in the source tables.
+pub const OPT_KEY_CLONE_REFERENCES: &str = "clone_references";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -56,6 +71,8 @@ pub const OPT_KEY_RANDOM_MAX_STRING_LEN: &str = "max_string_len";
 pub const OPT_KEY_RANDOM_MAX_ARRAY_LEN: &str = "max_array_len";
 
 pub const OPT_KEY_CLUSTER_TYPE: &str = "cluster_type";
+// The partition keys of the table, set by `CREATE TABLE ... PARTITION BY (...)`.
+pub const OPT_KEY_PARTITION_BY: &str = "partition_by";
 pub const OPT_KEY_ENABLE_COPY_DEDUP_FULL_PATH: &str = "copy_dedup_full_path";
 pub const LINEAR_CLUSTER_TYPE: &str = "linear";
 pub const HILBERT_CLUSTER_TYPE: &str = "hilbert";
@@ -67,6 +84,19 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONE_REFERENCES);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_AS_QUERY);
+    r.insert(OPT_KEY_TARGET_LAG);
+    r.insert(OPT_KEY_REFRESH_MODE);
+    r.insert(OPT_KEY_INITIALIZE);
+    r.insert(OPT_KEY_REFRESH_TIME);
+    r.insert(OPT_KEY_REFRESH_SNAPSHOT_ID);
+    r.insert(OPT_KEY_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_ID);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
@@ -78,6 +108,19 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_CLONE_REFERENCES);
+    r.insert(OPT_KEY_PARTITION_BY);
+    r.insert(OPT_KEY_AS_QUERY);
+    r.insert(OPT_KEY_TARGET_LAG);
+    r.insert(OPT_KEY_REFRESH_MODE);
+    r.insert(OPT_KEY_INITIALIZE);
+    r.insert(OPT_KEY_REFRESH_TIME);
+    r.insert(OPT_KEY_REFRESH_SNAPSHOT_ID);
+    r.insert(OPT_KEY_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_ID);
+    r.insert(OPT_KEY_SOURCE_SNAPSHOT_LOCATION);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
     r
 });
 
@@ -16,6 +16,7 @@
 #![allow(clippy::diverging_sub_expression)]
 
 mod partition;
+mod sink;
 mod table;
 mod table_source;
 
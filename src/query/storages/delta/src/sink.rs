@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write path of delta tables.
+//!
+//! - [`DeltaDataFileWriter`] runs on every pipe and writes the incoming blocks as parquet files,
+//!   split by the partition values of the table.
+//! - [`DeltaCommitSink`] collects the `add` actions of all writers and commits them as a single
+//!   new version into `_delta_log`.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef;
+use async_trait::async_trait;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::AsyncAccumulatingTransform;
+use deltalake::kernel::Action;
+use deltalake::kernel::Add;
+use deltalake::operations::transaction::CommitBuilder;
+use deltalake::protocol::DeltaOperation;
+use deltalake::protocol::SaveMode;
+use deltalake::writer::DeltaWriter;
+use deltalake::writer::RecordBatchWriter;
+use log::info;
+
+use crate::table::DeltaTable;
+
+/// `add` actions of the files written by [`DeltaDataFileWriter`], waiting to be committed.
+#[derive(Debug)]
+pub struct DeltaAddActionsMeta {
+    pub adds: Vec<Add>,
+}
+
+local_block_meta_serde!(DeltaAddActionsMeta);
+
+#[typetag::serde(name = "delta_add_actions")]
+impl BlockMetaInfo for DeltaAddActionsMeta {}
+
+pub struct DeltaDataFileWriter {
+    storage_params: StorageParams,
+    table_schema: TableSchemaRef,
+
+    // Both are initialized on the first block, loading the delta log is async.
+    writer: Option<RecordBatchWriter>,
+    arrow_schema: Option<SchemaRef>,
+}
+
+impl DeltaDataFileWriter {
+    pub fn create(storage_params: StorageParams, table_schema: TableSchemaRef) -> Self {
+        Self {
+            storage_params,
+            table_schema,
+            writer: None,
+            arrow_schema: None,
+        }
+    }
+
+    async fn init_writer(&mut self) -> Result<()> {
+        let table = DeltaTable::load(&self.storage_params).await?;
+        let delta_schema = table.get_schema().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })?;
+        let arrow_schema: ArrowSchema = delta_schema.try_into().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot convert table metadata: {e:?}"))
+        })?;
+        let writer = RecordBatchWriter::for_table(&table).map_err(|e| {
+            ErrorCode::StorageOther(format!("Delta table create writer failed: {e:?}"))
+        })?;
+
+        self.arrow_schema = Some(Arc::new(arrow_schema));
+        self.writer = Some(writer);
+        Ok(())
+    }
+
+    fn to_record_batch(&self, block: DataBlock, arrow_schema: &SchemaRef) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.table_schema)?;
+        // The writer refuses batches whose schema differs from the one in the delta log,
+        // even if only the nullability or the string layout is different.
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(arrow_schema.fields())
+            .map(|(column, field)| {
+                if column.data_type() == field.data_type() {
+                    Ok(column.clone())
+                } else {
+                    arrow_cast::cast(column, field.data_type()).map_err(|err| {
+                        ErrorCode::Internal(format!(
+                            "Cannot cast column {} to delta type {}: {err:?}",
+                            field.name(),
+                            field.data_type()
+                        ))
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(arrow_schema.clone(), columns)
+            .map_err(|err| ErrorCode::Internal(format!("Cannot build delta record batch: {err:?}")))
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for DeltaDataFileWriter {
+    const NAME: &'static str = "DeltaDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        if self.writer.is_none() {
+            self.init_writer().await?;
+        }
+        let arrow_schema = self.arrow_schema.clone().unwrap();
+        let batch = self.to_record_batch(data, &arrow_schema)?;
+        if let Some(writer) = self.writer.as_mut() {
+            writer.write(batch).await.map_err(|e| {
+                ErrorCode::StorageOther(format!("Delta table write data failed: {e:?}"))
+            })?;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let adds = match self.writer.as_mut() {
+            Some(writer) => writer.flush().await.map_err(|e| {
+                ErrorCode::StorageOther(format!("Delta table flush data failed: {e:?}"))
+            })?,
+            None => vec![],
+        };
+        Ok(Some(DataBlock::empty_with_meta(
+            DeltaAddActionsMeta { adds }.boxed(),
+        )))
+    }
+}
+
+pub struct DeltaCommitSink {
+    storage_params: StorageParams,
+    partition_columns: Vec<String>,
+    adds: Vec<Add>,
+}
+
+impl DeltaCommitSink {
+    pub fn create(storage_params: StorageParams, partition_columns: Vec<String>) -> Self {
+        Self {
+            storage_params,
+            partition_columns,
+            adds: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for DeltaCommitSink {
+    const NAME: &'static str = "DeltaCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.adds.is_empty() {
+            return Ok(());
+        }
+
+        // Commit on top of the latest version, other writers may have committed in the meantime.
+        let table = DeltaTable::load(&self.storage_params).await?;
+        let snapshot = table.snapshot().map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot read delta table snapshot: {e:?}"))
+        })?;
+
+        let num_files = self.adds.len();
+        let actions = std::mem::take(&mut self.adds)
+            .into_iter()
+            .map(Action::Add)
+            .collect::<Vec<_>>();
+        let partition_by = if self.partition_columns.is_empty() {
+            None
+        } else {
+            Some(self.partition_columns.clone())
+        };
+        let operation = DeltaOperation::Write {
+            mode: SaveMode::Append,
+            partition_by,
+            predicate: None,
+        };
+
+        let commit = CommitBuilder::default()
+            .with_actions(actions)
+            .build(Some(snapshot), table.log_store(), operation)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("Delta table commit failed: {e:?}")))?;
+        info!(
+            "Delta table committed version {} with {} new files",
+            commit.version(),
+            num_files
+        );
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(DeltaAddActionsMeta::downcast_from)
+        {
+            self.adds.extend(meta.adds);
+        }
+        Ok(false)
+    }
+}
@@ -27,8 +27,11 @@ use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::DistributionLevel;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::AbortChecker;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -39,8 +42,13 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::TransformPipelineHelper;
 use databend_common_storage::init_operator;
 use databend_common_storages_parquet::ParquetFilePart;
 use databend_common_storages_parquet::ParquetPart;
@@ -48,6 +56,8 @@ use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::partition_prunner::FetchPartitionScalars;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
+use databend_storages_common_table_meta::meta::SnapshotId;
+use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
@@ -58,6 +68,8 @@ use tokio::sync::OnceCell;
 use url::Url;
 
 use crate::partition::DeltaPartInfo;
+use crate::sink::DeltaCommitSink;
+use crate::sink::DeltaDataFileWriter;
 use crate::table_source::DeltaTableSource;
 
 pub const DELTA_ENGINE: &str = "DELTA";
@@ -157,24 +169,63 @@ impl DeltaTable {
         Ok((schema, meta))
     }
 
-    #[async_backtrace::framed]
-    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+    fn build(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
         let op = init_operator(sp)?;
         let opendal_store = Arc::new(OpendalStore::new(op));
 
-        let mut table = DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
+        DeltaTableBuilder::from_uri(Url::from_directory_path("/").unwrap())
             .with_storage_backend(opendal_store, Url::from_directory_path("/").unwrap())
             .build()
             .map_err(|err| {
                 ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
-            })?;
+            })
+    }
 
+    #[async_backtrace::framed]
+    pub async fn load(sp: &StorageParams) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
         table.load().await.map_err(|err| {
             ErrorCode::ReadTableDataError(format!("Delta table load failed: {err:?}"))
         })?;
         Ok(table)
     }
 
+    /// Load the table as of the given point of the delta log.
+    ///
+    /// - `Version`: the table at exactly this version.
+    /// - `TimePoint`: the latest version committed before or at this time.
+    #[async_backtrace::framed]
+    pub async fn load_at(
+        sp: &StorageParams,
+        point: &NavigationPoint,
+    ) -> Result<deltalake::table::DeltaTable> {
+        let mut table = Self::build(sp)?;
+        let res = match point {
+            NavigationPoint::Version(version) => {
+                let version = i64::try_from(*version).map_err(|_| {
+                    ErrorCode::TableHistoricalDataNotFound(format!(
+                        "Version {version} of delta table is out of range"
+                    ))
+                })?;
+                table.load_version(version).await
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                table.load_with_datestring(&time_point.to_rfc3339()).await
+            }
+            _ => {
+                return Err(ErrorCode::Unimplemented(
+                    "Delta table only supports time travel by VERSION or TIMESTAMP",
+                ));
+            }
+        };
+        res.map_err(|err| {
+            ErrorCode::TableHistoricalDataNotFound(format!(
+                "No historical data found at {point:?} of delta table: {err:?}"
+            ))
+        })?;
+        Ok(table)
+    }
+
     #[async_backtrace::framed]
     async fn table(&self) -> Result<&deltalake::table::DeltaTable> {
         self.table
@@ -271,6 +322,37 @@ impl DeltaTable {
         )
     }
 
+    pub fn do_append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let sp = self.get_storage_params()?.clone();
+        let table_schema = self.schema();
+        pipeline.add_async_accumulating_transformer(|| {
+            DeltaDataFileWriter::create(sp.clone(), table_schema.clone())
+        });
+        Ok(())
+    }
+
+    pub fn do_commit_insertion(&self, pipeline: &mut Pipeline, overwrite: bool) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT OVERWRITE is not supported for delta table '{}'",
+                self.name()
+            )));
+        }
+
+        let sp = self.get_storage_params()?.clone();
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                DeltaCommitSink::create(sp.clone(), self.meta.partition_columns.clone()),
+            )))
+        })
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn do_read_partitions(
@@ -403,6 +485,61 @@ impl Table for DeltaTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _table_meta_timestamps: TableMetaTimestamps,
+    ) -> Result<()> {
+        self.do_append_data(ctx, pipeline)
+    }
+
+    fn commit_insertion(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+        _table_meta_timestamps: TableMetaTimestamps,
+    ) -> Result<()> {
+        self.do_commit_insertion(pipeline, overwrite)
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(
+        &self,
+        navigation: &TimeNavigation,
+        _abort_checker: AbortChecker,
+    ) -> Result<Arc<dyn Table>> {
+        let TimeNavigation::TimeTravel(point) = navigation else {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Time travel operation is not supported for the table '{}', which uses the '{}' engine.",
+                self.name(),
+                self.get_table_info().engine(),
+            )));
+        };
+
+        let sp = self.get_storage_params()?;
+        let table = Self::load_at(sp, point).await?;
+
+        // Partition columns may differ between versions, keep the ones of the target version.
+        let state = table.metadata().map_err(|_| {
+            ErrorCode::ReadTableDataError("bug: Delta table current_metadata is None.")
+        })?;
+        let meta = DeltaTableMeta {
+            partition_columns: state.partition_columns.clone(),
+        };
+
+        Ok(Arc::new(DeltaTable {
+            info: self.info.clone(),
+            table: OnceCell::new_with(Some(table)),
+            meta,
+        }))
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -23,6 +23,7 @@ pub const FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS: &str = "data_retention_pe
 pub const FUSE_OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP: &str =
     "data_retention_num_snapshots_to_keep";
 pub const FUSE_OPT_KEY_ENABLE_AUTO_VACUUM: &str = "enable_auto_vacuum";
+pub const FUSE_OPT_KEY_ENABLE_DELETION_VECTOR: &str = "enable_deletion_vector";
 pub const FUSE_OPT_KEY_ATTACH_COLUMN_IDS: &str = "attach_column_ids";
 
 pub const FUSE_TBL_BLOCK_PREFIX: &str = "_b";
@@ -36,6 +37,10 @@ pub const FUSE_TBL_LAST_SNAPSHOT_HINT_V2: &str = "last_snapshot_location_hint_v2
 pub const FUSE_TBL_VIRTUAL_BLOCK_PREFIX: &str = "_vb";
 pub const FUSE_TBL_AGG_INDEX_PREFIX: &str = "_i_a";
 pub const FUSE_TBL_INVERTED_INDEX_PREFIX: &str = "_i_i";
+pub const FUSE_TBL_VECTOR_INDEX_PREFIX: &str = "_i_v";
+pub const FUSE_TBL_CLONE_REFERENCE_PREFIX: &str = "_ref";
+pub const FUSE_TBL_TAG_PREFIX: &str = "_tag";
+pub const FUSE_TBL_DELETION_VECTOR_PREFIX: &str = "_dv";
 
 pub const DEFAULT_ROW_PER_PAGE: usize = 8192;
 pub const DEFAULT_ROW_PER_INDEX: usize = 100000;
@@ -56,6 +56,7 @@ use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
 use databend_common_expression::ROW_VERSION_COL_NAME;
 use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_COMPRESSED_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_PER_SEGMENT;
@@ -66,6 +67,7 @@ use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::binder::STREAM_COLUMN_FACTORY;
+use databend_common_sql::parse_ast_exprs;
 use databend_common_sql::parse_cluster_keys;
 use databend_common_sql::plans::TruncateMode;
 use databend_common_sql::BloomIndexColumns;
@@ -114,6 +116,7 @@ use crate::operations::load_last_snapshot_hint;
 use crate::operations::ChangesDesc;
 use crate::operations::SnapshotHint;
 use crate::statistics::reduce_block_statistics;
+use crate::statistics::PartitionValueGenerator;
 use crate::statistics::Trim;
 use crate::FuseSegmentFormat;
 use crate::FuseStorageFormat;
@@ -126,6 +129,7 @@ use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use crate::FUSE_OPT_KEY_FILE_SIZE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -294,6 +298,18 @@ impl FuseTable {
         }
     }
 
+    /// Whether the rows deleted by a mutation can be recorded in deletion vectors,
+    /// instead of rewriting the blocks.
+    ///
+    /// Deletion vectors are only supported by tables of parquet storage format and
+    /// row-oriented segments, and are disabled while change tracking is enabled.
+    pub fn deletion_vector_enabled(&self) -> bool {
+        self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR, 0u32) != 0
+            && matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && !self.is_column_oriented()
+            && !self.change_tracking_enabled()
+    }
+
     pub fn parse_storage_prefix_from_table_info(table_info: &TableInfo) -> Result<String> {
         parse_storage_prefix(table_info.options(), table_info.ident.table_id)
     }
@@ -434,6 +450,47 @@ impl FuseTable {
         cluster_keys
     }
 
+    /// The partition keys of the table, empty if the table is not partitioned.
+    pub fn partition_keys(&self, ctx: Arc<dyn TableContext>) -> Result<Vec<RemoteExpr<String>>> {
+        let Some(partition_key_exprs) = self.resolve_partition_keys(ctx.clone())? else {
+            return Ok(vec![]);
+        };
+
+        let table_meta = Arc::new(self.clone());
+        let exprs = parse_ast_exprs(ctx, table_meta.clone(), partition_key_exprs)?;
+        Ok(exprs
+            .iter()
+            .map(|k| {
+                k.project_column_ref(|index| table_meta.schema().field(*index).name().to_string())
+                    .as_remote_expr()
+            })
+            .collect())
+    }
+
+    /// Generator of the partition values of the blocks written with `schema`.
+    pub fn partition_value_gen(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        schema: &TableSchema,
+    ) -> Result<PartitionValueGenerator> {
+        let partition_keys = self
+            .partition_keys(ctx.clone())?
+            .iter()
+            .map(|k| {
+                let expr = k.as_expr(&BUILTIN_FUNCTIONS);
+                // The columns of the keys must be written.
+                for name in expr.column_refs().keys() {
+                    schema.index_of(name)?;
+                }
+                Ok(expr.project_column_ref(|name| schema.index_of(name).unwrap()))
+            })
+            .collect::<Result<_>>()?;
+        Ok(PartitionValueGenerator::new(
+            partition_keys,
+            ctx.get_function_context()?,
+        ))
+    }
+
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
@@ -941,18 +998,23 @@ impl Table for FuseTable {
         _ctx: Arc<dyn TableContext>,
     ) -> Result<Box<dyn ColumnStatisticsProvider>> {
         let provider = if let Some(snapshot) = self.read_table_snapshot().await? {
-            let stats = &snapshot.summary.col_stats;
+            let mut stats = snapshot.summary.col_stats.clone();
+            // The null counts also count the rows deleted by deletion vectors,
+            // they are exact only if there are no nulls at all.
+            if snapshot.summary.deleted_row_count > 0 {
+                stats.retain(|_, stat| stat.null_count == 0);
+            }
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
             if let Some(table_statistics) = table_statistics {
                 FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
+                    stats,
                     table_statistics.histograms.clone(),
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
-                    stats.clone(),
+                    stats,
                     HashMap::new(),
                     None,
                     snapshot.summary.row_count,
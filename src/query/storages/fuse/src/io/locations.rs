@@ -16,8 +16,10 @@ use std::marker::PhantomData;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_common_vector::IvfIndex;
 use databend_storages_common_table_meta::meta::trim_object_prefix;
 use databend_storages_common_table_meta::meta::uuid_from_date_time;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotVersion;
@@ -36,8 +38,10 @@ use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::index::InvertedIndexFile;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT_V2;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
 static SNAPSHOT_V1: SnapshotVersion = SnapshotVersion::V1(PhantomData);
@@ -63,6 +67,7 @@ pub struct TableMetaLocationGenerator {
     snapshot_location_prefix: String,
     agg_index_location_prefix: String,
     inverted_index_location_prefix: String,
+    deletion_vector_location_prefix: String,
 }
 
 impl TableMetaLocationGenerator {
@@ -75,6 +80,8 @@ impl TableMetaLocationGenerator {
         let agg_index_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_AGG_INDEX_PREFIX);
         let inverted_index_location_prefix =
             format!("{}/{}/", &prefix, FUSE_TBL_INVERTED_INDEX_PREFIX);
+        let deletion_vector_location_prefix =
+            format!("{}/{}/", &prefix, FUSE_TBL_DELETION_VECTOR_PREFIX);
         Self {
             prefix,
             block_location_prefix,
@@ -83,6 +90,7 @@ impl TableMetaLocationGenerator {
             snapshot_location_prefix,
             agg_index_location_prefix,
             inverted_index_location_prefix,
+            deletion_vector_location_prefix,
         }
     }
 
@@ -98,6 +106,10 @@ impl TableMetaLocationGenerator {
         &self.bloom_index_location_prefix
     }
 
+    pub fn deletion_vector_location_prefix(&self) -> &str {
+        &self.deletion_vector_location_prefix
+    }
+
     pub fn segment_location_prefix(&self) -> &str {
         &self.segment_info_location_prefix
     }
@@ -261,6 +273,28 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_vector_index_location_from_block_location(
+        loc: &str,
+        index_name: &str,
+        index_version: &str,
+    ) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        let short_ver: String = index_version.chars().take(7).collect();
+        format!(
+            "{}/{}/{}/{}/{}_v{}.index",
+            prefix,
+            FUSE_TBL_VECTOR_INDEX_PREFIX,
+            index_name,
+            short_ver,
+            id,
+            IvfIndex::VERSION,
+        )
+    }
+
     pub fn gen_bloom_index_location_from_block_location(loc: &str) -> String {
         let splits = loc.split('/').collect::<Vec<_>>();
         let len = splits.len();
@@ -275,6 +309,27 @@ impl TableMetaLocationGenerator {
             BlockFilter::VERSION,
         )
     }
+
+    /// Each deletion of a block produces a new deletion vector, so a random suffix is
+    /// appended to the block id to keep the locations of different versions apart.
+    pub fn gen_deletion_vector_location_from_block_location(loc: &str) -> Location {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = trim_object_prefix(splits[len - 1]);
+        let id: String = block_name.chars().take(32).collect();
+        (
+            format!(
+                "{}/{}/{}_{}_v{}.bin",
+                prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                id,
+                Uuid::new_v4().as_simple(),
+                DeletionVectorMeta::VERSION,
+            ),
+            DeletionVectorMeta::VERSION,
+        )
+    }
 }
 
 trait SnapshotLocationCreator {
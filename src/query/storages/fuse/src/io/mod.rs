@@ -19,6 +19,10 @@ mod snapshots;
 mod write;
 
 pub use locations::TableMetaLocationGenerator;
+pub use read::live_rows_bitmap;
+pub use read::read_deletion_vector;
+pub use read::read_live_rows;
+pub use read::sync_read_live_rows;
 pub use read::AggIndexReader;
 pub use read::BlockReadResult;
 pub use read::BlockReader;
@@ -39,8 +43,10 @@ pub use snapshots::SnapshotsIO;
 pub(crate) use write::create_index_schema;
 pub(crate) use write::create_inverted_index_builders;
 pub(crate) use write::create_tokenizer_manager;
+pub(crate) use write::create_vector_index_builders;
 pub use write::serialize_block;
 pub use write::write_data;
+pub use write::write_deletion_vector;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::BlockWriter;
@@ -52,6 +58,10 @@ pub use write::InvertedIndexWriter;
 pub use write::MetaWriter;
 pub(crate) use write::StreamBlockBuilder;
 pub(crate) use write::StreamBlockProperties;
+pub use write::VectorIndexBuilder;
 pub use write::VirtualColumnBuilder;
 pub use write::WriteSettings;
+pub use write::DEFAULT_VECTOR_INDEX_NPROBE;
 pub use write::MAX_BLOCK_UNCOMPRESSED_SIZE;
+pub use write::VECTOR_INDEX_DISTANCE_OPTION;
+pub use write::VECTOR_INDEX_NPROBE_OPTION;
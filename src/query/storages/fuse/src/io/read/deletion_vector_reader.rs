@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::MutableBitmap;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Read the offsets of the deleted rows of a block.
+pub async fn read_deletion_vector(
+    operator: &Operator,
+    meta: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = operator.read(&meta.location.0).await?;
+    Ok(RoaringBitmap::deserialize_from(data.to_vec().as_slice())?)
+}
+
+pub fn sync_read_deletion_vector(
+    operator: &Operator,
+    meta: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = operator.blocking().read(&meta.location.0)?;
+    Ok(RoaringBitmap::deserialize_from(data.to_vec().as_slice())?)
+}
+
+/// Read the deletion vector of a block and build the selection of the rows that are
+/// still visible, returns `None` if no row of the block has been deleted.
+pub async fn read_live_rows(
+    operator: &Operator,
+    meta: Option<&DeletionVectorMeta>,
+    num_rows: usize,
+) -> Result<Option<Bitmap>> {
+    match meta {
+        Some(meta) => {
+            let deleted = read_deletion_vector(operator, meta).await?;
+            Ok(Some(live_rows_bitmap(&deleted, num_rows)))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn sync_read_live_rows(
+    operator: &Operator,
+    meta: Option<&DeletionVectorMeta>,
+    num_rows: usize,
+) -> Result<Option<Bitmap>> {
+    match meta {
+        Some(meta) => {
+            let deleted = sync_read_deletion_vector(operator, meta)?;
+            Ok(Some(live_rows_bitmap(&deleted, num_rows)))
+        }
+        None => Ok(None),
+    }
+}
+
+pub fn live_rows_bitmap(deleted: &RoaringBitmap, num_rows: usize) -> Bitmap {
+    let mut bitmap = MutableBitmap::from_len_set(num_rows);
+    for row in deleted.iter() {
+        bitmap.set(row as usize, false);
+    }
+    bitmap.into()
+}
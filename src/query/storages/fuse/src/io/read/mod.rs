@@ -15,6 +15,7 @@
 mod agg_index;
 mod block;
 pub mod bloom;
+mod deletion_vector_reader;
 mod inverted_index;
 pub mod meta;
 mod segment_reader;
@@ -28,6 +29,10 @@ pub use block::BlockReader;
 pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
 pub use bloom::BloomBlockFilterReader;
+pub use deletion_vector_reader::live_rows_bitmap;
+pub use deletion_vector_reader::read_deletion_vector;
+pub use deletion_vector_reader::read_live_rows;
+pub use deletion_vector_reader::sync_read_live_rows;
 pub use inverted_index::InvertedIndexReader;
 pub use meta::CompactSegmentInfoReader;
 pub use meta::MetaReaders;
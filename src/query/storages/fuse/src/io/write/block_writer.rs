@@ -48,16 +48,20 @@ use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::table::TableCompression;
 use opendal::Operator;
 
+use crate::io::write::check_vector_dimensions;
 use crate::io::write::virtual_column_builder::VirtualColumnBuilder;
 use crate::io::write::virtual_column_builder::VirtualColumnState;
 use crate::io::write::InvertedIndexBuilder;
 use crate::io::write::InvertedIndexState;
+use crate::io::write::VectorIndexBuilder;
+use crate::io::write::VectorIndexState;
 use crate::io::write::WriteSettings;
 use crate::io::BloomIndexState;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::column_parquet_metas;
 use crate::statistics::gen_columns_statistics;
 use crate::statistics::ClusterStatsGenerator;
+use crate::statistics::PartitionValueGenerator;
 use crate::FuseStorageFormat;
 
 pub fn serialize_block(
@@ -129,6 +133,7 @@ pub struct BlockSerialization {
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
     pub inverted_index_states: Vec<InvertedIndexState>,
+    pub vector_index_states: Vec<VectorIndexState>,
     pub virtual_column_state: Option<VirtualColumnState>,
 }
 
@@ -139,9 +144,11 @@ pub struct BlockBuilder {
     pub source_schema: TableSchemaRef,
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
+    pub partition_gen: PartitionValueGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
     pub ngram_args: Vec<NgramArgs>,
     pub inverted_index_builders: Vec<InvertedIndexBuilder>,
+    pub vector_index_builders: Vec<VectorIndexBuilder>,
     pub virtual_column_builder: Option<VirtualColumnBuilder>,
     pub table_meta_timestamps: TableMetaTimestamps,
 }
@@ -151,6 +158,7 @@ impl BlockBuilder {
     where F: Fn(DataBlock, &ClusterStatsGenerator) -> Result<(Option<ClusterStatistics>, DataBlock)>
     {
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
+        check_vector_dimensions(&self.source_schema, &data_block)?;
         let (block_location, block_id) = self
             .meta_locations
             .gen_block_location(self.table_meta_timestamps);
@@ -178,6 +186,17 @@ impl BlockBuilder {
             inverted_index_states.push(inverted_index_state);
         }
 
+        let mut vector_index_states = Vec::with_capacity(self.vector_index_builders.len());
+        for vector_index_builder in &self.vector_index_builders {
+            let vector_index_state = VectorIndexState::from_data_block(
+                &self.source_schema,
+                &data_block,
+                &block_location,
+                vector_index_builder,
+            )?;
+            vector_index_states.push(vector_index_state);
+        }
+
         let virtual_column_state =
             if let Some(ref virtual_column_builder) = self.virtual_column_builder {
                 let virtual_column_state = virtual_column_builder.add_block(
@@ -193,6 +212,7 @@ impl BlockBuilder {
         let row_count = data_block.num_rows() as u64;
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        let partition_value = self.partition_gen.gen(&data_block)?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let block_size = data_block.estimate_block_size() as u64;
@@ -230,6 +250,8 @@ impl BlockBuilder {
             inverted_index_size,
             virtual_block_meta: None,
             create_on: Some(Utc::now()),
+            deletion_vector: None,
+            partition_value,
         };
 
         let serialized = BlockSerialization {
@@ -237,6 +259,7 @@ impl BlockBuilder {
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            vector_index_states,
             virtual_column_state,
         };
         Ok(serialized)
@@ -270,6 +293,7 @@ impl BlockWriter {
         Self::write_down_data_block(dal, serialized.block_raw_data, &block_meta.location.0).await?;
         Self::write_down_bloom_index_state(dal, serialized.bloom_index_state).await?;
         Self::write_down_inverted_index_state(dal, serialized.inverted_index_states).await?;
+        Self::write_down_vector_index_state(dal, serialized.vector_index_states).await?;
         Self::write_down_virtual_column_state(dal, serialized.virtual_column_state).await?;
 
         Ok(extended_block_meta)
@@ -326,6 +350,16 @@ impl BlockWriter {
         Ok(())
     }
 
+    pub async fn write_down_vector_index_state(
+        dal: &Operator,
+        vector_index_states: Vec<VectorIndexState>,
+    ) -> Result<()> {
+        for vector_index_state in vector_index_states {
+            write_data(vector_index_state.data, dal, &vector_index_state.location.0).await?;
+        }
+        Ok(())
+    }
+
     pub async fn write_down_virtual_column_state(
         dal: &Operator,
         virtual_column_state: Option<VirtualColumnState>,
@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+use crate::io::write_data;
+use crate::io::TableMetaLocationGenerator;
+
+/// Persist the offsets of the deleted rows of the block located at `block_location`.
+///
+/// `deleted` must contain all the deleted rows of the block, including the ones of the
+/// previous deletion vector, since the returned meta replaces the previous one.
+pub async fn write_deletion_vector(
+    operator: &Operator,
+    block_location: &str,
+    deleted: &RoaringBitmap,
+) -> Result<DeletionVectorMeta> {
+    let location = TableMetaLocationGenerator::gen_deletion_vector_location_from_block_location(
+        block_location,
+    );
+    let mut data = Vec::with_capacity(deleted.serialized_size());
+    deleted.serialize_into(&mut data)?;
+    let size = data.len() as u64;
+    write_data(data, operator, &location.0).await?;
+    Ok(DeletionVectorMeta {
+        location,
+        deleted_rows: deleted.len(),
+        size,
+    })
+}
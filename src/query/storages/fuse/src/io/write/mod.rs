@@ -14,9 +14,11 @@
 
 mod block_writer;
 mod bloom_index_writer;
+mod deletion_vector_writer;
 mod inverted_index_writer;
 mod meta_writer;
 mod stream;
+mod vector_index_writer;
 mod virtual_column_builder;
 mod write_settings;
 
@@ -27,6 +29,7 @@ pub use block_writer::BlockSerialization;
 pub use block_writer::BlockWriter;
 pub use bloom_index_writer::BloomIndexRebuilder;
 pub use bloom_index_writer::BloomIndexState;
+pub use deletion_vector_writer::write_deletion_vector;
 pub(crate) use inverted_index_writer::create_index_schema;
 pub(crate) use inverted_index_writer::create_inverted_index_builders;
 pub(crate) use inverted_index_writer::create_tokenizer_manager;
@@ -37,6 +40,14 @@ pub use meta_writer::CachedMetaWriter;
 pub use meta_writer::MetaWriter;
 pub(crate) use stream::StreamBlockBuilder;
 pub(crate) use stream::StreamBlockProperties;
+pub(crate) use vector_index_writer::check_vector_dimensions;
+pub(crate) use vector_index_writer::create_vector_index_builders;
+pub use vector_index_writer::VectorIndexBuilder;
+pub(crate) use vector_index_writer::VectorIndexState;
+pub(crate) use vector_index_writer::VectorIndexWriter;
+pub use vector_index_writer::DEFAULT_VECTOR_INDEX_NPROBE;
+pub use vector_index_writer::VECTOR_INDEX_DISTANCE_OPTION;
+pub use vector_index_writer::VECTOR_INDEX_NPROBE_OPTION;
 pub use virtual_column_builder::VirtualColumnBuilder;
 pub use write_settings::WriteSettings;
 pub use write_settings::MAX_BLOCK_UNCOMPRESSED_SIZE;
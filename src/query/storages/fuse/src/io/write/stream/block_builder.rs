@@ -50,15 +50,19 @@ use parquet::file::properties::EnabledStatistics;
 use parquet::file::properties::WriterProperties;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
+use crate::io::write::check_vector_dimensions;
 use crate::io::write::stream::cluster_statistics::ClusterStatisticsBuilder;
 use crate::io::write::stream::cluster_statistics::ClusterStatisticsState;
 use crate::io::write::stream::column_statistics::ColumnStatisticsState;
 use crate::io::write::InvertedIndexState;
+use crate::io::write::VectorIndexWriter;
 use crate::io::BlockSerialization;
 use crate::io::BloomIndexState;
 use crate::io::InvertedIndexBuilder;
 use crate::io::InvertedIndexWriter;
 use crate::io::TableMetaLocationGenerator;
+use crate::io::VectorIndexBuilder;
 use crate::io::WriteSettings;
 use crate::operations::column_parquet_metas;
 use crate::FuseStorageFormat;
@@ -147,6 +151,7 @@ pub struct StreamBlockBuilder {
     properties: Arc<StreamBlockProperties>,
     block_writer: BlockWriterImpl,
     inverted_index_writers: Vec<InvertedIndexWriter>,
+    vector_index_writers: Vec<VectorIndexWriter>,
     bloom_index_builder: BloomIndexBuilder,
 
     cluster_stats_state: ClusterStatisticsState,
@@ -204,6 +209,11 @@ impl StreamBlockBuilder {
                 InvertedIndexWriter::try_create(Arc::new(builder.schema.clone()), &builder.options)
             })
             .collect::<Result<Vec<_>>>()?;
+        let vector_index_writers = properties
+            .vector_index_builders
+            .iter()
+            .map(|builder| VectorIndexWriter::create(builder.clone()))
+            .collect();
 
         let bloom_index_builder = BloomIndexBuilder::create(
             properties.ctx.get_function_context()?,
@@ -220,6 +230,7 @@ impl StreamBlockBuilder {
             properties,
             block_writer,
             inverted_index_writers,
+            vector_index_writers,
             bloom_index_builder,
             row_count: 0,
             block_size: 0,
@@ -249,6 +260,7 @@ impl StreamBlockBuilder {
             self.block_writer.start()?;
         }
 
+        check_vector_dimensions(&self.properties.source_schema, &block)?;
         let block = self.cluster_stats_state.add_block(block)?;
         self.column_stats_state
             .add_block(&self.properties.source_schema, &block)?;
@@ -256,6 +268,9 @@ impl StreamBlockBuilder {
         for writer in self.inverted_index_writers.iter_mut() {
             writer.add_block(&self.properties.source_schema, &block)?;
         }
+        for writer in self.vector_index_writers.iter_mut() {
+            writer.add_block(&self.properties.source_schema, &block)?;
+        }
 
         self.row_count += block.num_rows();
         self.block_size += block.estimate_block_size();
@@ -301,6 +316,10 @@ impl StreamBlockBuilder {
                 InvertedIndexState::try_create(data, inverted_index_location)?;
             inverted_index_states.push(inverted_index_state);
         }
+        let vector_index_states = std::mem::take(&mut self.vector_index_writers)
+            .into_iter()
+            .map(|writer| writer.finalize(&block_location))
+            .collect::<Result<Vec<_>>>()?;
 
         let col_metas = self.block_writer.finish(&self.properties.source_schema)?;
         let block_raw_data = mem::take(self.block_writer.inner_mut());
@@ -334,12 +353,15 @@ impl StreamBlockBuilder {
             create_on: Some(Utc::now()),
             ngram_filter_index_size: None,
             virtual_block_meta: None,
+            deletion_vector: None,
+            partition_value: None,
         };
         let serialized = BlockSerialization {
             block_raw_data,
             block_meta,
             bloom_index_state,
             inverted_index_states,
+            vector_index_states,
             virtual_column_state: None,
         };
         Ok(serialized)
@@ -360,6 +382,7 @@ pub struct StreamBlockProperties {
     bloom_columns_map: BTreeMap<FieldIndex, TableField>,
     ngram_args: Vec<NgramArgs>,
     inverted_index_builders: Vec<InvertedIndexBuilder>,
+    vector_index_builders: Vec<VectorIndexBuilder>,
     table_meta_timestamps: TableMetaTimestamps,
 }
 
@@ -395,6 +418,7 @@ impl StreamBlockProperties {
             .collect::<HashSet<_>>();
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
 
         let cluster_stats_builder =
             ClusterStatisticsBuilder::try_create(table, ctx.clone(), &source_schema)?;
@@ -426,6 +450,7 @@ impl StreamBlockProperties {
             bloom_columns_map,
             ngram_args,
             inverted_index_builders,
+            vector_index_builders,
             table_meta_timestamps,
         }))
     }
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Buffer;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_vector::IvfIndex;
+use databend_common_vector::VectorDistance;
+use databend_storages_common_table_meta::meta::Location;
+
+use crate::io::TableMetaLocationGenerator;
+
+pub const VECTOR_INDEX_DISTANCE_OPTION: &str = "distance";
+pub const VECTOR_INDEX_NLISTS_OPTION: &str = "nlists";
+pub const VECTOR_INDEX_NPROBE_OPTION: &str = "nprobe";
+pub const DEFAULT_VECTOR_INDEX_NPROBE: usize = 8;
+
+#[derive(Clone)]
+pub struct VectorIndexBuilder {
+    pub(crate) name: String,
+    pub(crate) version: String,
+    pub(crate) column_name: String,
+    pub(crate) dimension: usize,
+    pub(crate) distance: VectorDistance,
+    pub(crate) nlists: Option<usize>,
+}
+
+pub fn create_vector_index_builders(table_meta: &TableMeta) -> Vec<VectorIndexBuilder> {
+    let mut vector_index_builders = Vec::new();
+    for index in table_meta.indexes.values() {
+        if !matches!(index.index_type, TableIndexType::Vector) {
+            continue;
+        }
+        let Some(field) = index.column_ids.first().and_then(|column_id| {
+            table_meta
+                .schema
+                .fields
+                .iter()
+                .find(|field| field.column_id() == *column_id)
+        }) else {
+            // ignore invalid index
+            continue;
+        };
+        let TableDataType::Vector(dimension) = field.data_type().remove_nullable() else {
+            continue;
+        };
+        let distance = index
+            .options
+            .get(VECTOR_INDEX_DISTANCE_OPTION)
+            .and_then(|v| VectorDistance::from_str(v).ok())
+            .unwrap_or(VectorDistance::Cosine);
+        let nlists = index
+            .options
+            .get(VECTOR_INDEX_NLISTS_OPTION)
+            .and_then(|v| v.parse::<usize>().ok());
+
+        vector_index_builders.push(VectorIndexBuilder {
+            name: index.name.clone(),
+            version: index.version.clone(),
+            column_name: field.name().clone(),
+            dimension: dimension as usize,
+            distance,
+            nlists,
+        });
+    }
+    vector_index_builders
+}
+
+pub struct VectorIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) size: u64,
+    pub(crate) location: Location,
+}
+
+impl VectorIndexState {
+    pub fn from_data_block(
+        source_schema: &TableSchemaRef,
+        block: &DataBlock,
+        block_location: &Location,
+        vector_index_builder: &VectorIndexBuilder,
+    ) -> Result<Self> {
+        let mut writer = VectorIndexWriter::create(vector_index_builder.clone());
+        writer.add_block(source_schema, block)?;
+        writer.finalize(block_location)
+    }
+}
+
+/// Collects the vectors of the blocks that make up a single fuse block, the rows are numbered
+/// in the order the blocks are added.
+pub struct VectorIndexWriter {
+    builder: VectorIndexBuilder,
+    vectors: Vec<(u32, Buffer<f32>)>,
+    num_rows: usize,
+}
+
+impl VectorIndexWriter {
+    pub fn create(builder: VectorIndexBuilder) -> Self {
+        Self {
+            builder,
+            vectors: vec![],
+            num_rows: 0,
+        }
+    }
+
+    pub fn add_block(&mut self, source_schema: &TableSchemaRef, block: &DataBlock) -> Result<()> {
+        let field_index = source_schema.index_of(&self.builder.column_name)?;
+        let column = block.get_by_offset(field_index).to_column(block.num_rows());
+        let offset = self.num_rows as u32;
+        self.vectors.extend(
+            vector_rows(&column)?
+                .into_iter()
+                .map(|(row, vector)| (row + offset, vector)),
+        );
+        self.num_rows += block.num_rows();
+        Ok(())
+    }
+
+    pub fn finalize(self, block_location: &Location) -> Result<VectorIndexState> {
+        let items = self
+            .vectors
+            .iter()
+            .map(|(row, vector)| (*row, vector.as_slice()))
+            .collect::<Vec<_>>();
+        let index = IvfIndex::build(
+            self.builder.dimension,
+            self.builder.distance,
+            &items,
+            self.builder.nlists,
+        )?;
+        let data = index.to_bytes();
+        let size = data.len() as u64;
+        let location = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            &block_location.0,
+            &self.builder.name,
+            &self.builder.version,
+        );
+        Ok(VectorIndexState {
+            data,
+            size,
+            location: (location, 0),
+        })
+    }
+}
+
+/// Check that all the non-null values of the vector columns have the declared dimension.
+pub fn check_vector_dimensions(source_schema: &TableSchemaRef, block: &DataBlock) -> Result<()> {
+    for (i, field) in source_schema.fields().iter().enumerate() {
+        let TableDataType::Vector(dimension) = field.data_type().remove_nullable() else {
+            continue;
+        };
+        if i >= block.num_columns() {
+            continue;
+        }
+        let column = block.get_by_offset(i).to_column(block.num_rows());
+        for (_, vector) in vector_rows(&column)? {
+            if vector.len() as u64 != dimension {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Column {} is VECTOR({}), but got a vector of dimension {}",
+                    field.name(),
+                    dimension,
+                    vector.len()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Returns the row numbers and the values of the non-null rows.
+fn vector_rows(column: &Column) -> Result<Vec<(u32, Buffer<f32>)>> {
+    let (column, validity) = match column {
+        Column::Nullable(box nullable) => (&nullable.column, Some(&nullable.validity)),
+        column => (column, None),
+    };
+    let array = ArrayType::<Float32Type>::try_downcast_column(column).ok_or_else(|| {
+        ErrorCode::Internal(format!(
+            "Vector column should be Array(Float32), but got {}",
+            column.data_type()
+        ))
+    })?;
+    Ok(array
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| validity.is_none_or(|validity| validity.get_bit(*row)))
+        .map(|(row, values)| {
+            let values = unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(values) };
+            (row as u32, values)
+        })
+        .collect())
+}
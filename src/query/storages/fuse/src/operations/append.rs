@@ -39,6 +39,7 @@ use databend_storages_common_table_meta::table::ClusterType;
 
 use crate::operations::TransformBlockWriter;
 use crate::operations::TransformSerializeBlock;
+use crate::operations::TransformSplitByPartition;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseStorageFormat;
 use crate::FuseTable;
@@ -50,8 +51,11 @@ impl FuseTable {
         pipeline: &mut Pipeline,
         table_meta_timestamps: TableMetaTimestamps,
     ) -> Result<()> {
+        // The blocks of a partitioned table are split by partition before serialization,
+        // which is not supported by the stream block writer.
         let enable_stream_block_write = ctx.get_settings().get_enable_block_stream_write()?
-            && matches!(self.storage_format, FuseStorageFormat::Parquet);
+            && matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && !self.is_partitioned();
         if enable_stream_block_write {
             pipeline.add_transform(|input, output| {
                 TransformBlockWriter::try_create(
@@ -70,6 +74,15 @@ impl FuseTable {
             let schema = DataSchema::from(self.schema()).into();
             let cluster_stats_gen =
                 self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, Some(schema))?;
+            if self.is_partitioned() {
+                let partition_gen = self.partition_value_gen(
+                    ctx.clone(),
+                    &self.schema().remove_virtual_computed_fields(),
+                )?;
+                pipeline.add_accumulating_transformer(|| {
+                    TransformSplitByPartition::new(partition_gen.clone())
+                });
+            }
             pipeline.add_transform(|input, output| {
                 let proc = TransformSerializeBlock::try_create(
                     ctx.clone(),
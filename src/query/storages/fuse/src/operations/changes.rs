@@ -306,6 +306,7 @@ impl FuseTable {
             &push_downs,
             cluster_key_meta,
             cluster_keys,
+            self.partition_keys(ctx.clone())?,
             bloom_index_cols,
             ngram_args,
             None,
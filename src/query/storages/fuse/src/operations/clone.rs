@@ -0,0 +1,168 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_REFERENCES;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use opendal::EntryMode;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::FuseTable;
+use crate::FUSE_TBL_CLONE_REFERENCE_PREFIX;
+use crate::FUSE_TBL_SEGMENT_PREFIX;
+
+/// Left by `CREATE TABLE ... CLONE` under `{owner_prefix}/_ref/{table_id}`, it lists the
+/// segments of the owner table which are referenced by the cloned table `table_id`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CloneReference {
+    pub table_id: u64,
+    pub segments: Vec<Location>,
+}
+
+impl FuseTable {
+    /// Commits the first snapshot of the table, which is cloned from `source`.
+    ///
+    /// The snapshot references the segments of `source` instead of copying them, and a
+    /// [`CloneReference`] is written for each table owning some of the segments. The
+    /// locations of the references are returned, they are removed if the clone fails.
+    #[async_backtrace::framed]
+    pub async fn do_clone_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<Vec<String>> {
+        let mut references = vec![];
+        if let Err(e) = self.clone_snapshot_from(ctx, source, &mut references).await {
+            self.remove_clone_references(&references).await;
+            return Err(e);
+        }
+        Ok(references)
+    }
+
+    /// Removes the references written by [`Self::do_clone_from`], used when the cloned
+    /// table is not created.
+    #[async_backtrace::framed]
+    pub async fn remove_clone_references(&self, references: &[String]) {
+        for reference in references {
+            if let Err(e) = self.operator.delete(reference).await {
+                warn!("clone: failed to remove reference {}: {}", reference, e);
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn clone_snapshot_from(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        source: &FuseTable,
+        references: &mut Vec<String>,
+    ) -> Result<()> {
+        let Some(source_snapshot) = source.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        // the source may be a clone itself, group the segments by the table owning them.
+        let mut segments_by_owner: BTreeMap<&str, Vec<Location>> = BTreeMap::new();
+        for segment in &source_snapshot.segments {
+            let owner = segment
+                .0
+                .rsplit_once(&format!("/{}/", FUSE_TBL_SEGMENT_PREFIX))
+                .map(|(owner, _)| owner)
+                .ok_or_else(|| {
+                    ErrorCode::StorageOther(format!(
+                        "clone: unexpected segment location {}",
+                        segment.0
+                    ))
+                })?;
+            segments_by_owner
+                .entry(owner)
+                .or_default()
+                .push(segment.clone());
+        }
+
+        let table_id = self.get_id();
+        for (owner, segments) in segments_by_owner {
+            let location = format!("{}/{}/{}", owner, FUSE_TBL_CLONE_REFERENCE_PREFIX, table_id);
+            let reference = CloneReference { table_id, segments };
+            let data = serde_json::to_vec(&reference)?;
+            // recorded before writing, a partially written reference is removed as well.
+            references.push(location.clone());
+            self.operator.write(&location, data).await?;
+        }
+        info!(
+            "clone: table {} references the segments of {}, reference files: {:?}",
+            self.table_info.desc, source.table_info.desc, references
+        );
+
+        // the references are removed when the cloned table is vacuumed after being dropped.
+        let mut table_info = self.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_CLONE_REFERENCES.to_owned(), references.join(","));
+
+        let table_meta_timestamps = ctx.get_table_meta_timestamps(self, None)?;
+        let snapshot = TableSnapshot::try_new(
+            Some(self.table_info.ident.seq),
+            None,
+            self.schema().as_ref().clone(),
+            source_snapshot.summary.clone(),
+            source_snapshot.segments.clone(),
+            None,
+            table_meta_timestamps,
+        )?;
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Reads the references left in the storage of this table by its clones.
+    #[async_backtrace::framed]
+    pub async fn read_clone_references(&self) -> Result<Vec<CloneReference>> {
+        let reference_prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_CLONE_REFERENCE_PREFIX
+        );
+
+        let mut references = vec![];
+        let mut lister = self.operator.lister_with(&reference_prefix).await?;
+        while let Some(entry) = lister.try_next().await? {
+            if entry.metadata().mode() != EntryMode::FILE {
+                continue;
+            }
+            let data = self.operator.read(entry.path()).await?;
+            references.push(serde_json::from_slice(&data.to_vec())?);
+        }
+        Ok(references)
+    }
+}
@@ -34,10 +34,12 @@ use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::ExtendedBlockMeta;
 use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use opendal::Operator;
 
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
@@ -154,6 +156,7 @@ impl TransformSerializeBlock {
         let ngram_args = FuseTable::create_ngram_index_args(&table.table_info.meta)?;
 
         let inverted_index_builders = create_inverted_index_builders(&table.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&table.table_info.meta);
         let virtual_column_builder = if ctx
             .get_settings()
             .get_enable_refresh_virtual_column_after_write()
@@ -164,15 +167,18 @@ impl TransformSerializeBlock {
             None
         };
 
+        let partition_gen = table.partition_value_gen(ctx.clone(), &source_schema)?;
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
             source_schema,
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
+            partition_gen,
             bloom_columns_map,
             ngram_args,
             inverted_index_builders,
+            vector_index_builders,
             virtual_column_builder,
             table_meta_timestamps,
         };
@@ -292,6 +298,27 @@ impl Processor for TransformSerializeBlock {
                     self.output.push_data(Ok(data_block));
                     Ok(Event::NeedConsume)
                 }
+                SerializeDataMeta::DeletionVector(deletion_vector_block) => {
+                    // the deleted rows are recorded in the deletion vector, block level
+                    let block_meta = Arc::unwrap_or_clone(deletion_vector_block.block_meta);
+                    let data_block = Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                        index: deletion_vector_block.index,
+                        block_meta: Arc::new(ExtendedBlockMeta {
+                            block_meta,
+                            draft_virtual_block_meta: None,
+                        }),
+                    });
+                    self.output.push_data(Ok(data_block));
+                    if !input_data.is_empty() {
+                        // append the rows produced by the mutation as a new block
+                        self.state = State::NeedSerialize {
+                            block: input_data,
+                            stats_type: ClusterStatsGenType::Generally,
+                            index: None,
+                        };
+                    }
+                    Ok(Event::NeedConsume)
+                }
             }
         } else if input_data.is_empty() {
             // do nothing
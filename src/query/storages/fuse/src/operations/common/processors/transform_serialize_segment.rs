@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -23,6 +24,8 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
@@ -47,7 +50,8 @@ use crate::FuseTable;
 
 enum State<B: SegmentBuilder> {
     None,
-    GenerateSegment,
+    // Generate the segment of the blocks with the partition value.
+    GenerateSegment(Option<Vec<Scalar>>),
     SerializedSegment {
         data: Vec<u8>,
         location: String,
@@ -64,6 +68,9 @@ pub struct TransformSerializeSegment<B: SegmentBuilder> {
     data_accessor: Operator,
     meta_locations: TableMetaLocationGenerator,
     segment_builder: B,
+    // The segments of a partitioned table are grouped by partition value.
+    partition_segment_builders: BTreeMap<Vec<Scalar>, B>,
+    table_schema: TableSchemaRef,
     virtual_column_accumulator: Option<VirtualColumnAccumulator>,
     state: State<B>,
 
@@ -104,6 +111,8 @@ impl<B: SegmentBuilder> TransformSerializeSegment<B> {
             meta_locations: table.meta_location_generator().clone(),
             state: State::None,
             segment_builder,
+            partition_segment_builders: BTreeMap::new(),
+            table_schema: table.schema(),
             virtual_column_accumulator,
             thresholds,
             default_cluster_key_id,
@@ -162,7 +171,7 @@ impl<B: SegmentBuilder> Processor for TransformSerializeSegment<B> {
     fn event(&mut self) -> Result<Event> {
         if matches!(
             &self.state,
-            State::GenerateSegment | State::PreCommitSegment { .. }
+            State::GenerateSegment(_) | State::PreCommitSegment { .. }
         ) {
             return Ok(Event::Sync);
         }
@@ -186,7 +195,11 @@ impl<B: SegmentBuilder> Processor for TransformSerializeSegment<B> {
 
         if self.input.is_finished() {
             if self.segment_builder.block_count() != 0 {
-                self.state = State::GenerateSegment;
+                self.state = State::GenerateSegment(None);
+                return Ok(Event::Sync);
+            }
+            if let Some(partition_value) = self.partition_segment_builders.keys().next() {
+                self.state = State::GenerateSegment(Some(partition_value.clone()));
                 return Ok(Event::Sync);
             }
 
@@ -223,6 +236,17 @@ impl<B: SegmentBuilder> Processor for TransformSerializeSegment<B> {
                 .ok_or_else(|| ErrorCode::Internal("No block meta. It's a bug"))?
                 .clone();
 
+            let partition_value = extended_block_meta.block_meta.partition_value.clone();
+            let segment_builder = match &partition_value {
+                None => &mut self.segment_builder,
+                Some(value) => self
+                    .partition_segment_builders
+                    .entry(value.clone())
+                    .or_insert_with(|| {
+                        B::new(self.table_schema.clone(), self.thresholds.block_per_segment)
+                    }),
+            };
+
             if let Some(draft_virtual_block_meta) = extended_block_meta.draft_virtual_block_meta {
                 let mut block_meta = extended_block_meta.block_meta.clone();
                 if let Some(ref mut virtual_column_accumulator) = self.virtual_column_accumulator {
@@ -238,14 +262,13 @@ impl<B: SegmentBuilder> Processor for TransformSerializeSegment<B> {
                     block_meta.virtual_block_meta = Some(virtual_block_meta);
                 }
 
-                self.segment_builder.add_block(block_meta)?;
+                segment_builder.add_block(block_meta)?;
             } else {
-                self.segment_builder
-                    .add_block(extended_block_meta.block_meta)?;
+                segment_builder.add_block(extended_block_meta.block_meta)?;
             }
 
-            if self.segment_builder.block_count() >= self.thresholds.block_per_segment {
-                self.state = State::GenerateSegment;
+            if segment_builder.block_count() >= self.thresholds.block_per_segment {
+                self.state = State::GenerateSegment(partition_value);
                 return Ok(Event::Sync);
             }
         }
@@ -256,10 +279,17 @@ impl<B: SegmentBuilder> Processor for TransformSerializeSegment<B> {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::None) {
-            State::GenerateSegment => {
-                let segment_info = self
-                    .segment_builder
-                    .build(self.thresholds, self.default_cluster_key_id)?;
+            State::GenerateSegment(partition_value) => {
+                let segment_info = match partition_value {
+                    None => self
+                        .segment_builder
+                        .build(self.thresholds, self.default_cluster_key_id)?,
+                    Some(value) => {
+                        let mut segment_builder =
+                            self.partition_segment_builders.remove(&value).unwrap();
+                        segment_builder.build(self.thresholds, self.default_cluster_key_id)?
+                    }
+                };
 
                 self.state = State::SerializedSegment {
                     data: segment_info.serialize()?,
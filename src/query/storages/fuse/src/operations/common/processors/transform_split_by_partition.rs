@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+
+use crate::statistics::PartitionValueGenerator;
+
+/// Splits the blocks to be written into a partitioned table, so that every
+/// block only contains the rows of one partition.
+pub struct TransformSplitByPartition {
+    partition_gen: PartitionValueGenerator,
+}
+
+impl TransformSplitByPartition {
+    pub fn new(partition_gen: PartitionValueGenerator) -> Self {
+        Self { partition_gen }
+    }
+}
+
+impl AccumulatingTransform for TransformSplitByPartition {
+    const NAME: &'static str = "TransformSplitByPartition";
+
+    fn transform(&mut self, data: DataBlock) -> Result<Vec<DataBlock>> {
+        let blocks = self.partition_gen.split(data)?;
+        Ok(blocks.into_iter().filter(|b| !b.is_empty()).collect())
+    }
+}
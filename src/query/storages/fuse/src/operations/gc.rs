@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::Utc;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -24,6 +25,7 @@ use databend_common_exception::Result;
 use databend_common_expression::ScalarRef;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CachedObject;
 use databend_storages_common_cache::LoadParams;
@@ -123,6 +125,9 @@ impl FuseTable {
 
         let inverted_indexes = &self.table_info.meta.indexes;
 
+        // Files shared with the cloned tables, or owned by the table this one is cloned from.
+        let protected_locations = self.read_protected_locations(ctx).await?;
+
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
@@ -201,6 +206,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &protected_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -216,6 +222,7 @@ impl FuseTable {
                         ctx,
                         counter,
                         &root_snapshot_info.referenced_locations,
+                        &protected_locations,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -257,6 +264,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &protected_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -268,6 +276,7 @@ impl FuseTable {
                     ctx,
                     counter,
                     &root_snapshot_info.referenced_locations,
+                    &protected_locations,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -290,6 +299,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &protected_locations,
                 &table_agg_index_ids,
                 inverted_indexes,
             )
@@ -355,6 +365,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        protected_locations: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -390,10 +401,21 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.retain(|loc| protected_locations.can_purge(loc));
 
         Ok(())
     }
@@ -404,6 +426,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        protected_locations: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -438,11 +461,10 @@ impl FuseTable {
 
                 for idx in inverted_indexes.values() {
                     inverted_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                            loc,
-                            idx.name.as_str(),
-                            idx.version.as_str(),
-                        ),
+                        match idx.index_type {
+TableIndexType::Vector => TableMetaLocationGenerator::gen_vector_index_location_from_block_location(loc, idx.name.as_str(), idx.version.as_str()),
+_ => TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(loc, idx.name.as_str(), idx.version.as_str()),
+},
                     );
                 }
             }
@@ -455,6 +477,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            let mut deletion_vectors_to_be_purged = HashSet::new();
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                deletion_vectors_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -477,19 +510,28 @@ impl FuseTable {
             self.purge_block_segments(
                 ctx,
                 counter,
+                protected_locations,
                 blocks_to_be_purged,
                 agg_indexes_to_be_purged,
                 inverted_indexes_to_be_purged,
                 blooms_to_be_purged,
+                deletion_vectors_to_be_purged,
                 segment_locations_to_be_purged,
             )
             .await?;
         }
 
-        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
-            .await
+        self.purge_ts_snapshots(
+            ctx,
+            counter,
+            protected_locations,
+            ts_to_be_purged,
+            snapshots_to_be_purged,
+        )
+        .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -497,6 +539,7 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        protected_locations: &ProtectedLocations,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
@@ -525,11 +568,10 @@ impl FuseTable {
         for idx in inverted_indexes.values() {
             inverted_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(
                 |loc| {
-                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
-                        loc,
-                        idx.name.as_str(),
-                        idx.version.as_str(),
-                    )
+                    match idx.index_type {
+TableIndexType::Vector => TableMetaLocationGenerator::gen_vector_index_location_from_block_location(loc, idx.name.as_str(), idx.version.as_str()),
+_ => TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(loc, idx.name.as_str(), idx.version.as_str()),
+}
                 },
             ));
         }
@@ -537,10 +579,12 @@ impl FuseTable {
         self.purge_block_segments(
             ctx,
             counter,
+            protected_locations,
             root_location_tuple.block_location,
             agg_indexes_to_be_purged,
             inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
+            root_location_tuple.deletion_vector_location,
             segment_locations_to_be_purged,
         )
         .await?;
@@ -552,22 +596,38 @@ impl FuseTable {
         self.purge_ts_snapshots(
             ctx,
             counter,
+            protected_locations,
             ts_to_be_purged,
             HashSet::from([root_snapshot_location]),
         )
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_block_segments(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
-        blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
-        inverted_indexes_to_be_purged: HashSet<String>,
-        blooms_to_be_purged: HashSet<String>,
-        segments_to_be_purged: HashSet<String>,
+        protected_locations: &ProtectedLocations,
+        mut blocks_to_be_purged: HashSet<String>,
+        mut agg_indexes_to_be_purged: HashSet<String>,
+        mut inverted_indexes_to_be_purged: HashSet<String>,
+        mut blooms_to_be_purged: HashSet<String>,
+        mut deletion_vectors_to_be_purged: HashSet<String>,
+        mut segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        // 0. Keep the files which are protected.
+        for locations in [
+            &mut blocks_to_be_purged,
+            &mut agg_indexes_to_be_purged,
+            &mut inverted_indexes_to_be_purged,
+            &mut blooms_to_be_purged,
+            &mut deletion_vectors_to_be_purged,
+            &mut segments_to_be_purged,
+        ] {
+            locations.retain(|loc| protected_locations.can_purge(loc));
+        }
+
         // 1. Try to purge block file chunks.
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
@@ -616,6 +676,13 @@ impl FuseTable {
             .await?;
         }
 
+        let deletion_vectors_count = deletion_vectors_to_be_purged.len();
+        if deletion_vectors_count > 0 {
+            counter.deletion_vectors += deletion_vectors_count;
+            self.try_purge_location_files(ctx.clone(), deletion_vectors_to_be_purged)
+                .await?;
+        }
+
         // 3. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
@@ -633,9 +700,13 @@ impl FuseTable {
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        protected_locations: &ProtectedLocations,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        ts_to_be_purged.retain(|loc| protected_locations.can_purge(loc));
+        snapshots_to_be_purged.retain(|loc| protected_locations.can_purge(loc));
+
         // 3. Purge table statistic files
         let ts_count = ts_to_be_purged.len();
         if ts_count > 0 {
@@ -714,6 +785,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -779,12 +851,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -804,10 +878,70 @@ struct RootSnapshotInfo {
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
+/// Files which must be kept by the gc of a table.
+///
+/// A table only purges the files located under its own storage prefix, so the files of the
+/// table it is cloned from are never purged. The files referenced by the clones of the table,
+/// and the files of the tagged snapshots which are not expired, are kept too.
+#[derive(Default)]
+pub struct ProtectedLocations {
+    prefix: String,
+    protected: HashSet<String>,
+}
+
+impl ProtectedLocations {
+    pub fn can_purge(&self, location: &str) -> bool {
+        location.starts_with(&self.prefix) && !self.protected.contains(location)
+    }
+}
+
+impl FuseTable {
+    #[async_backtrace::framed]
+    pub async fn read_protected_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<ProtectedLocations> {
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
+        let mut protected = HashSet::new();
+        let mut segments = HashSet::new();
+
+        for reference in self.read_clone_references().await? {
+            segments.extend(reference.segments);
+        }
+
+        let now = Utc::now();
+        for tag in self.list_tags().await? {
+            if tag.is_expired(now) {
+                continue;
+            }
+            let (snapshot, _) =
+                SnapshotsIO::read_snapshot(tag.snapshot_location.clone(), self.get_operator())
+                    .await?;
+            segments.extend(snapshot.segments.iter().cloned());
+            protected.extend(snapshot.table_statistics_location.clone());
+            protected.insert(tag.snapshot_location);
+        }
+
+        if !segments.is_empty() {
+            let segments = Vec::from_iter(segments);
+            // the protected blocks must all be known, errors are not ignored here.
+            let locations = self
+                .get_block_locations(ctx.clone(), &segments, false, false)
+                .await?;
+            protected.extend(segments.into_iter().map(|loc| loc.0));
+            protected.extend(locations.block_location);
+            protected.extend(locations.bloom_location);
+            protected.extend(locations.deletion_vector_location);
+        }
+        Ok(ProtectedLocations { prefix, protected })
+    }
+}
+
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -815,16 +949,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -859,9 +998,11 @@ impl TryFrom<Arc<ColumnOrientedSegment>> for LocationTuple {
             }
         }
 
+        // column-oriented segments never carry deletion vectors
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location: HashSet::new(),
         })
     }
 }
@@ -873,6 +1014,7 @@ struct PurgeCounter {
     agg_indexes: usize,
     inverted_indexes: usize,
     blooms: usize,
+    deletion_vectors: usize,
     segments: usize,
     table_statistics: usize,
     snapshots: usize,
@@ -886,6 +1028,7 @@ impl PurgeCounter {
             agg_indexes: 0,
             inverted_indexes: 0,
             blooms: 0,
+            deletion_vectors: 0,
             segments: 0,
             table_statistics: 0,
             snapshots: 0,
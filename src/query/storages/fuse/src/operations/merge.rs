@@ -27,6 +27,7 @@ use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use super::merge_into::MatchedAggregator;
 use super::mutation::SegmentIndex;
 use crate::io::create_inverted_index_builders;
+use crate::io::create_vector_index_builders;
 use crate::io::BlockBuilder;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -94,16 +95,20 @@ impl FuseTable {
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
         let ngram_args = FuseTable::create_ngram_index_args(&self.table_info.meta)?;
         let inverted_index_builders = create_inverted_index_builders(&self.table_info.meta);
+        let vector_index_builders = create_vector_index_builders(&self.table_info.meta);
 
+        let partition_gen = self.partition_value_gen(ctx.clone(), &new_schema)?;
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
             meta_locations: self.meta_location_generator().clone(),
             source_schema: new_schema,
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
+            partition_gen,
             bloom_columns_map,
             ngram_args,
             inverted_index_builders,
+            vector_index_builders,
             // todo
             virtual_column_builder: None,
             table_meta_timestamps,
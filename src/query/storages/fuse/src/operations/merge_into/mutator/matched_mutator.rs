@@ -48,6 +48,7 @@ use itertools::Itertools;
 use log::info;
 use opendal::Operator;
 
+use crate::io::read_live_rows;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
@@ -403,10 +404,18 @@ impl AggregationContext {
             origin_data_block.add_column(row_num);
         }
 
-        // apply delete
+        let live_rows = read_live_rows(
+            &self.data_accessor,
+            block_meta.deletion_vector.as_ref(),
+            origin_num_rows,
+        )
+        .await?;
+
+        // apply delete, the rows in the deletion vector are dropped as well
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            let live = live_rows.as_ref().is_none_or(|v| v.get_bit(row));
+            if modified_offsets.contains(&row) || !live {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
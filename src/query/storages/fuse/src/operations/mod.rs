@@ -16,6 +16,7 @@ mod agg_index_sink;
 mod analyze;
 mod append;
 mod changes;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -33,6 +34,7 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tag;
 mod truncate;
 mod util;
 
@@ -41,8 +43,10 @@ mod snapshot_hint;
 pub use agg_index_sink::AggIndexSink;
 pub use analyze::HistogramInfoSink;
 pub use changes::ChangesDesc;
+pub use clone::CloneReference;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use gc::ProtectedLocations;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
@@ -50,6 +54,7 @@ pub use read::need_reserve_block_info;
 pub use read::row_fetch_processor;
 pub use replace_into::*;
 pub use snapshot_hint::*;
+pub use tag::SnapshotTag;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::types::Bitmap;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_storages_common_table_meta::meta::BlockMeta;
@@ -30,6 +31,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(DeletionVectorBlock),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -61,9 +63,22 @@ impl SerializeBlock {
     }
 }
 
+/// A block whose deleted rows have been recorded in a new deletion vector.
+///
+/// If the data block carrying this meta is not empty, its rows are appended as a
+/// new block, e.g. the rows produced by an update.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletionVectorBlock {
+    pub index: BlockMetaIndex,
+    /// The meta of the mutated block, referencing the new deletion vector.
+    pub block_meta: Arc<BlockMeta>,
+}
+
 pub enum CompactSourceMeta {
     Concat {
         read_res: Vec<BlockReadResult>,
+        /// The selection of the rows not deleted by the deletion vector, per block.
+        live_rows: Vec<Option<Bitmap>>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
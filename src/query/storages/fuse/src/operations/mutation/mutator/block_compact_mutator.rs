@@ -511,6 +511,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // Fold the deletion vector into the block.
+        if block.deletion_vector.is_some() {
+            return true;
+        }
+
         // The snapshot schema does not contain stream columns,
         // so the stream columns need to be filtered out.
         let column_ids = block
@@ -579,9 +584,23 @@ impl CompactTaskBuilder {
                 sort_by_cluster_stats(&a.cluster_stats, &b.cluster_stats, default_cluster_key)
             });
         }
+        // The blocks of different partitions cannot be compacted together,
+        // keep the blocks of one partition adjacent.
+        if blocks.iter().any(|block| block.partition_value.is_some()) {
+            blocks.sort_by(|a, b| a.partition_value.cmp(&b.partition_value));
+        }
 
         let mut tasks = VecDeque::new();
         for block in blocks.iter() {
+            if self
+                .blocks
+                .last()
+                .is_some_and(|last| last.partition_value != block.partition_value)
+            {
+                let blocks = self.take_blocks();
+                latest_flag = self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, blocks);
+                block_idx += 1;
+            }
             let (unchanged, need_take) = self.add(block);
             if need_take {
                 let blocks = self.take_blocks();
@@ -597,8 +616,18 @@ impl CompactTaskBuilder {
 
         if !self.is_empty() {
             let tail = self.take_blocks();
-            if self.cluster_key_id.is_some() && latest_flag {
-                // The clustering table cannot compact different level blocks.
+            let last_partition_value = if latest_flag {
+                unchanged_blocks.last().map(|(_, v)| &v.partition_value)
+            } else {
+                tasks
+                    .back()
+                    .and_then(|(_, v)| v.last())
+                    .map(|v| &v.partition_value)
+            };
+            let same_partition = last_partition_value.is_none_or(|v| *v == tail[0].partition_value);
+            if (self.cluster_key_id.is_some() && latest_flag) || !same_partition {
+                // The clustering table cannot compact different level blocks,
+                // and the blocks of different partitions cannot be compacted together.
                 self.build_task(&mut tasks, &mut unchanged_blocks, block_idx, tail);
             } else {
                 let mut blocks = if latest_flag {
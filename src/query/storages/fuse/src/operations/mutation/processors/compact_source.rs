@@ -19,6 +19,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_metrics::storage::*;
@@ -28,6 +29,7 @@ use databend_common_pipeline_transforms::processors::UnknownMode;
 use databend_common_sql::StreamContext;
 use databend_storages_common_io::ReadSettings;
 
+use crate::io::read_live_rows;
 use crate::io::BlockReader;
 use crate::operations::ClusterStatsGenType;
 use crate::operations::CompactBlockPartInfo;
@@ -89,14 +91,21 @@ impl PrefetchAsyncSource for CompactSource {
                                 metrics_inc_compact_block_read_bytes(block.block_size);
                             }
 
-                            block_reader
+                            let read_res = block_reader
                                 .read_columns_data_by_merge_io(
                                     &settings,
                                     &block.location.0,
                                     &block.col_metas,
                                     &None,
                                 )
-                                .await
+                                .await?;
+                            let live_rows = read_live_rows(
+                                &block_reader.operator,
+                                block.deletion_vector.as_ref(),
+                                block.row_count as usize,
+                            )
+                            .await?;
+                            Ok::<_, ErrorCode>((read_res, live_rows))
                         })
                         .await
                         .unwrap()
@@ -105,13 +114,17 @@ impl PrefetchAsyncSource for CompactSource {
 
                 let start = Instant::now();
 
-                let read_res = futures::future::try_join_all(task_futures).await?;
+                let (read_res, live_rows) = futures::future::try_join_all(task_futures)
+                    .await?
+                    .into_iter()
+                    .unzip();
                 // Perf.
                 {
                     metrics_inc_compact_block_read_milliseconds(start.elapsed().as_millis() as u64);
                 }
                 Box::new(CompactSourceMeta::Concat {
                     read_res,
+                    live_rows,
                     metas: task.blocks.clone(),
                     index: task.index.clone(),
                 })
@@ -156,18 +169,23 @@ impl BlockMetaTransform<CompactSourceMeta> for CompactTransform {
         match meta {
             CompactSourceMeta::Concat {
                 read_res,
+                live_rows,
                 metas,
                 index,
             } => {
                 let blocks = read_res
                     .into_iter()
+                    .zip(live_rows.into_iter())
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, live_rows), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta.as_ref().into(),
                             &self.storage_format,
                             data,
                         )?;
+                        if let Some(live_rows) = live_rows {
+                            block = block.filter_with_bitmap(&live_rows)?;
+                        }
 
                         self.scan_progress.incr(&ProgressValues {
                             rows: block.num_rows(),
@@ -23,6 +23,7 @@ use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockEntry;
@@ -39,11 +40,16 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_io::ReadSettings;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use roaring::RoaringBitmap;
 
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::read_live_rows;
+use crate::io::write_deletion_vector;
 use crate::io::BlockReader;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletionVectorBlock;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
@@ -58,19 +64,25 @@ pub enum MutationAction {
 
 enum State {
     ReadData(Option<PartInfoPtr>),
-    FilterData(PartInfoPtr, BlockReadResult),
+    FilterData(PartInfoPtr, BlockReadResult, Option<Bitmap>),
     ReadRemain {
         part: PartInfoPtr,
         data_block: DataBlock,
+        live_rows: Option<Bitmap>,
         filter: Option<Value<BooleanType>>,
     },
     MergeRemain {
         part: PartInfoPtr,
         block_read_result: BlockReadResult,
         data_block: DataBlock,
+        live_rows: Option<Bitmap>,
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock, String),
+    WriteDeletionVector {
+        deleted: RoaringBitmap,
+        data_block: DataBlock,
+    },
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -89,6 +101,10 @@ pub struct MutationSource {
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // Only set if the mutation is allowed to produce deletion vectors.
+    block_meta: Option<Arc<BlockMeta>>,
+    // The rows to be marked as deleted once the updated rows are generated.
+    pending_deletion: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -115,6 +131,8 @@ impl MutationSource {
             action,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            block_meta: None,
+            pending_deletion: None,
         })))
     }
 }
@@ -161,7 +179,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector { .. }
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -170,13 +191,16 @@ impl Processor for MutationSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::FilterData(part, read_res) => {
+            State::FilterData(part, read_res, live_rows) => {
                 let chunks = read_res.columns_chunks()?;
                 let mut data_block = self.block_reader.deserialize_chunks_with_part_info(
                     part.clone(),
                     chunks,
                     &self.storage_format,
                 )?;
+                if let Some(live_rows) = &live_rows {
+                    data_block = data_block.filter_with_bitmap(live_rows)?;
+                }
                 let rows = data_block.num_rows();
                 self.ctx.get_scan_progress().incr(&ProgressValues {
                     rows,
@@ -210,9 +234,21 @@ impl Processor for MutationSource {
                     if affect_rows != 0 {
                         self.update_mutation_status(affect_rows);
 
+                        let deleted = if affect_rows < rows {
+                            self.deletion_vector_of(&fuse_part, live_rows.as_ref(), &predicates)
+                        } else {
+                            None
+                        };
+
                         match self.action {
                             MutationAction::Deletion => {
-                                if affect_rows == rows {
+                                if let Some(deleted) = deleted {
+                                    // only record the deleted rows, the block is kept as is.
+                                    self.state = State::WriteDeletionVector {
+                                        deleted,
+                                        data_block: DataBlock::empty(),
+                                    };
+                                } else if affect_rows == rows {
                                     // all the rows should be removed.
                                     let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                         SerializeBlock::create(
@@ -242,6 +278,7 @@ impl Processor for MutationSource {
                                         self.state = State::ReadRemain {
                                             part,
                                             data_block,
+                                            live_rows,
                                             filter: Some(Value::Column(filter)),
                                         }
                                     }
@@ -249,10 +286,24 @@ impl Processor for MutationSource {
                             }
 
                             MutationAction::Update => {
-                                data_block.add_column(BlockEntry::new(
-                                    DataType::Boolean,
-                                    Value::upcast(predicates),
-                                ));
+                                let mut filter = None;
+                                if let Some(deleted) = deleted {
+                                    // only the updated rows are rewritten into a new block,
+                                    // the origin rows are marked as deleted.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    data_block = data_block.filter_with_bitmap(&predicate_col)?;
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(Value::<BooleanType>::Scalar(true)),
+                                    ));
+                                    filter = Some(Value::Column(predicate_col));
+                                    self.pending_deletion = Some(deleted);
+                                } else {
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(predicates),
+                                    ));
+                                }
                                 if self.remain_reader.is_none() {
                                     self.state = State::PerformOperator(
                                         data_block,
@@ -262,7 +313,8 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
-                                        filter: None,
+                                        live_rows,
+                                        filter,
                                     };
                                 }
                             }
@@ -280,6 +332,7 @@ impl Processor for MutationSource {
                 part,
                 block_read_result,
                 mut data_block,
+                live_rows,
                 filter,
             } => {
                 let path = FuseBlockPartInfo::from_part(&part)?.location.clone();
@@ -297,6 +350,12 @@ impl Processor for MutationSource {
                         bytes: remain_block.memory_size(),
                     });
 
+                    let remain_block = if let Some(live_rows) = &live_rows {
+                        remain_block.filter_with_bitmap(live_rows)?
+                    } else {
+                        remain_block
+                    };
+
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
                         remain_block.filter_boolean_value(&filter)?
@@ -319,6 +378,13 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
+                if let Some(deleted) = self.pending_deletion.take() {
+                    self.state = State::WriteDeletionVector {
+                        deleted,
+                        data_block: block,
+                    };
+                    return Ok(());
+                }
                 let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
                     SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                 ));
@@ -350,6 +416,7 @@ impl Processor for MutationSource {
                         )
                     }
                     Mutation::MutationPartInfo(part) => {
+                        self.block_meta = part.block_meta.clone();
                         self.index = BlockMetaIndex {
                             segment_idx: part.index.segment_idx,
                             block_idx: part.index.block_idx,
@@ -366,7 +433,11 @@ impl Processor for MutationSource {
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
-                            self.update_mutation_status(fuse_part.nums_rows);
+                            let deleted_rows = fuse_part
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |v| v.deleted_rows as usize);
+                            self.update_mutation_status(fuse_part.nums_rows - deleted_rows);
                             let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                 SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                             ));
@@ -384,7 +455,13 @@ impl Processor for MutationSource {
                                     &None,
                                 )
                                 .await?;
-                            self.state = State::FilterData(inner_part, read_res);
+                            let live_rows = read_live_rows(
+                                &self.block_reader.operator,
+                                fuse_part.deletion_vector.as_ref(),
+                                fuse_part.nums_rows,
+                            )
+                            .await?;
+                            self.state = State::FilterData(inner_part, read_res, live_rows);
                         }
                     }
                 }
@@ -392,6 +469,7 @@ impl Processor for MutationSource {
             State::ReadRemain {
                 part,
                 data_block,
+                live_rows,
                 filter,
             } => {
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
@@ -410,12 +488,35 @@ impl Processor for MutationSource {
                         part,
                         block_read_result: read_res,
                         data_block,
+                        live_rows,
                         filter,
                     };
                 } else {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector {
+                deleted,
+                data_block,
+            } => {
+                let Some(block_meta) = self.block_meta.as_ref() else {
+                    return Err(ErrorCode::Internal("It's a bug. Need block meta"));
+                };
+                let deletion_vector = write_deletion_vector(
+                    &self.block_reader.operator,
+                    &block_meta.location.0,
+                    &deleted,
+                )
+                .await?;
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.deletion_vector = Some(deletion_vector);
+                let meta = Box::new(SerializeDataMeta::DeletionVector(DeletionVectorBlock {
+                    index: self.index.clone(),
+                    block_meta: Arc::new(new_block_meta),
+                }));
+                self.state =
+                    State::Output(self.ctx.get_partition(), data_block.add_meta(Some(meta))?);
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -423,6 +524,35 @@ impl Processor for MutationSource {
 }
 
 impl MutationSource {
+    /// Build the deletion vector of the block if the matched rows can be deleted
+    /// without rewriting the block, i.e. at most half of the block is deleted.
+    fn deletion_vector_of(
+        &self,
+        fuse_part: &FuseBlockPartInfo,
+        live_rows: Option<&Bitmap>,
+        predicates: &Value<BooleanType>,
+    ) -> Option<RoaringBitmap> {
+        self.block_meta.as_ref()?;
+        let Value::Column(matched) = predicates else {
+            return None;
+        };
+        let num_rows = fuse_part.nums_rows;
+        let deleted_rows = live_rows.map_or(0, |v| v.null_count());
+        if (deleted_rows + matched.len() - matched.null_count()) * 2 > num_rows {
+            return None;
+        }
+
+        let mut deleted = RoaringBitmap::new();
+        let mut matched = matched.iter();
+        for row in 0..num_rows {
+            let live = live_rows.is_none_or(|v| v.get_bit(row));
+            if !live || matched.next().unwrap_or(false) {
+                deleted.insert(row as u32);
+            }
+        }
+        Some(deleted)
+    }
+
     fn update_mutation_status(&self, num_rows: usize) {
         let (update_rows, deleted_rows) = if self.action == MutationAction::Update {
             (num_rows as u64, 0)
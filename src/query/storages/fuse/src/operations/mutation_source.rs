@@ -184,11 +184,14 @@ impl FuseTable {
             ..PushDownInfo::default()
         });
 
-        let mut pruner = FusePruner::create(
+        let mut pruner = FusePruner::create_with_pages(
             &ctx,
             self.operator.clone(),
             self.schema_with_stream(),
             &push_down,
+            None,
+            vec![],
+            self.partition_keys(ctx.clone())?,
             self.bloom_index_cols(),
             Self::create_ngram_index_args(&self.table_info.meta)?,
             None,
@@ -251,6 +254,7 @@ impl FuseTable {
             PruningStatistics::default(),
         )?;
 
+        let deletion_vector_enabled = self.deletion_vector_enabled();
         let mut parts = Partitions::create(
             PartitionsShuffleKind::Mod,
             block_metas
@@ -260,12 +264,16 @@ impl FuseTable {
                     let cluster_stats = block_meta.cluster_stats.clone();
                     let key = (index.segment_idx, index.block_idx);
                     let whole_block_mutation = whole_block_deletions.contains(&key);
+                    // The block meta is only needed to write new deletion vectors. The existing
+                    // deletion vector of the block is carried by the inner part and always applied.
+                    let block_meta = deletion_vector_enabled.then_some(block_meta);
                     let part_info_ptr: PartInfoPtr =
                         Arc::new(Box::new(Mutation::MutationPartInfo(MutationPartInfo {
                             index,
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
+                            block_meta,
                         })));
                     part_info_ptr
                 })
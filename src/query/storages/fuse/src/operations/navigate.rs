@@ -63,6 +63,10 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(info).await,
+            NavigationPoint::Tag(name) => self.navigate_to_tag(name).await,
+            NavigationPoint::Version(_) => Err(ErrorCode::Unimplemented(
+                "Time travel by version is not supported for fuse table",
+            )),
         }
     }
 
@@ -263,6 +267,9 @@ impl FuseTable {
                     Some(NavigationPoint::StreamInfo(info)) => {
                         self.list_by_stream(info, time_point).await
                     }
+                    Some(NavigationPoint::Version(_)) => Err(ErrorCode::Unimplemented(
+                        "Purge before a version is not supported for fuse table",
+                    )),
                     None => self.list_by_time_point(time_point).await,
                 }?;
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_expression::types::Bitmap;
 use databend_common_expression::BlockMetaInfo;
 
 use crate::io::BlockReadResult;
@@ -21,7 +22,15 @@ use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum ParquetDataSource {
     AggIndex((PartInfoPtr, BlockReadResult)),
-    Normal((BlockReadResult, Option<VirtualBlockReadResult>)),
+    /// The block data, the optional virtual columns data, and the selection of
+    /// the rows not marked as deleted by the deletion vector of the block.
+    Normal(
+        (
+            BlockReadResult,
+            Option<VirtualBlockReadResult>,
+            Option<Bitmap>,
+        ),
+    ),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
@@ -278,7 +278,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Normal((data, virtual_data, live_rows)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FuseBlockPartInfo::from_part(&part)?;
@@ -307,6 +307,23 @@ impl Processor for DeserializeDataTransform {
                             .deserialize_virtual_columns(data_block.clone(), virtual_data)?;
                     }
 
+                    // Remove the rows marked as deleted by the deletion vector.
+                    if let Some(live_rows) = live_rows {
+                        let (selection, remain) = match filter.take() {
+                            Some(selection) => {
+                                let remain = live_rows
+                                    .iter()
+                                    .zip(selection.iter())
+                                    .filter_map(|(live, selected)| selected.then_some(live))
+                                    .collect::<Bitmap>();
+                                (selection.bitand(&live_rows), remain)
+                            }
+                            None => (live_rows.clone(), live_rows),
+                        };
+                        data_block = data_block.filter_with_bitmap(&remain)?;
+                        filter = Some(selection);
+                    }
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -34,6 +34,8 @@ use log::debug;
 
 use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::read_live_rows;
+use crate::io::sync_read_live_rows;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::TableMetaLocationGenerator;
@@ -130,8 +132,15 @@ impl Transform for ReadParquetDataTransform<true> {
                     return Ok(DataBlock::empty());
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The aggregating index is built on all the rows of the block,
+                // it can not be used once some rows have been deleted.
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -151,7 +160,6 @@ impl Transform for ReadParquetDataTransform<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
                     let virtual_block_meta = fuse_part
                         .block_meta_index
                         .as_ref()
@@ -175,10 +183,19 @@ impl Transform for ReadParquetDataTransform<true> {
                     &part,
                     ignore_column_ids,
                 )?;
+                let live_rows = sync_read_live_rows(
+                    &self.block_reader.operator,
+                    fuse_part.deletion_vector.as_ref(),
+                    fuse_part.nums_rows,
+                )?;
 
                 return Ok(DataBlock::empty_with_meta(DataSourceWithMeta::create(
                     vec![part],
-                    vec![ParquetDataSource::Normal((source, virtual_source))],
+                    vec![ParquetDataSource::Normal((
+                        source,
+                        virtual_source,
+                        live_rows,
+                    ))],
                 )));
             }
         }
@@ -221,7 +238,13 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                             databend_common_base::runtime::spawn(async move {
                                 let part = FuseBlockPartInfo::from_part(&part)?;
 
-                                if let Some(index_reader) = index_reader.as_ref() {
+                                // The aggregating index is built on all the rows of the block,
+                                // it can not be used once some rows have been deleted.
+                                if let Some(index_reader) = index_reader
+                                    .as_ref()
+                                    .as_ref()
+                                    .filter(|_| part.deletion_vector.is_none())
+                                {
                                     let loc =
                                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                                             &part.location,
@@ -260,8 +283,14 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                                         ignore_column_ids,
                                     )
                                     .await?;
+                                let live_rows = read_live_rows(
+                                    &block_reader.operator,
+                                    part.deletion_vector.as_ref(),
+                                    part.nums_rows,
+                                )
+                                .await?;
 
-                                Ok(ParquetDataSource::Normal((source, virtual_source)))
+                                Ok(ParquetDataSource::Normal((source, virtual_source, live_rows)))
                             })
                                 .await
                                 .unwrap()
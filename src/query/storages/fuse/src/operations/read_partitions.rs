@@ -94,6 +94,7 @@ use crate::pruning_pipeline::SendPartInfoSink;
 use crate::pruning_pipeline::SendPartState;
 use crate::pruning_pipeline::SyncBlockPruneTransform;
 use crate::pruning_pipeline::TopNPruneTransform;
+use crate::pruning_pipeline::VectorIndexPruneTransform;
 use crate::segment_format_from_location;
 use crate::FuseLazyPartInfo;
 use crate::FuseSegmentFormat;
@@ -471,6 +472,14 @@ impl FuseTable {
             })?;
         }
 
+        if let Some(vector_index_pruner) = pruner.pruning_ctx.vector_index_pruner.clone() {
+            // the nearest rows are searched across all the blocks
+            prune_pipeline.resize(1, false)?;
+            prune_pipeline.add_transform(move |input, output| {
+                VectorIndexPruneTransform::create(input, output, vector_index_pruner.clone())
+            })?;
+        }
+
         let top_k = push_down
             .as_ref()
             .filter(|_| self.is_native()) // Only native format supports topk push down.
@@ -652,33 +661,29 @@ impl FuseTable {
             None
         };
 
-        let pruner =
+        let (cluster_key_meta, cluster_keys) =
             if !self.is_native() || self.cluster_type().is_none_or(|v| v != ClusterType::Linear) {
-                FusePruner::create(
-                    &ctx,
-                    dal,
-                    table_schema.clone(),
-                    &push_downs,
-                    self.bloom_index_cols(),
-                    ngram_args,
-                    bloom_index_builder,
-                )?
+                (None, vec![])
             } else {
-                let cluster_keys = self.linear_cluster_keys(ctx.clone());
-
-                FusePruner::create_with_pages(
-                    &ctx,
-                    dal,
-                    table_schema,
-                    &push_downs,
+                (
                     self.cluster_key_meta.clone(),
-                    cluster_keys,
-                    self.bloom_index_cols(),
-                    ngram_args,
-                    bloom_index_builder,
-                )?
+                    self.linear_cluster_keys(ctx.clone()),
+                )
             };
-        Ok(pruner)
+        let partition_keys = self.partition_keys(ctx.clone())?;
+
+        FusePruner::create_with_pages(
+            &ctx,
+            dal,
+            table_schema,
+            &push_downs,
+            cluster_key_meta,
+            cluster_keys,
+            partition_keys,
+            self.bloom_index_cols(),
+            ngram_args,
+            bloom_index_builder,
+        )
     }
 
     pub fn create_ngram_index_args(table_meta: &TableMeta) -> Result<Vec<NgramArgs>> {
@@ -864,7 +869,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -922,7 +927,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -1033,6 +1038,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -1081,6 +1087,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -248,6 +248,7 @@ impl FuseTable {
             push_down,
             None,
             vec![],
+            vec![],
             BloomIndexColumns::None,
             vec![],
             max_concurrency,
@@ -70,6 +70,8 @@ pub fn row_hash_of_columns(
                 NumberScalar::Float64(v) => sip.write_u64(v.to_bits()),
             },
             ScalarRef::Timestamp(v) => sip.write_i64(v),
+            ScalarRef::Time(v) => sip.write_i64(v),
+            ScalarRef::TimestampTz(v) => sip.write_i64(v.timestamp()),
             ScalarRef::String(v) => sip.write(v.as_bytes()),
             ScalarRef::Bitmap(v) => sip.write(v),
             ScalarRef::Decimal(v) => match v {
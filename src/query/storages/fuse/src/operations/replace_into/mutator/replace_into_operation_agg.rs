@@ -61,6 +61,7 @@ use log::warn;
 use opendal::Operator;
 
 use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::read_live_rows;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
@@ -468,19 +469,31 @@ impl AggregationContext {
                 .value);
         }
 
+        let live_rows = read_live_rows(
+            &self.data_accessor,
+            block_meta.deletion_vector.as_ref(),
+            num_rows,
+        )
+        .await?;
+
         let mut bitmap = MutableBitmap::new();
+        let mut delete_nums = 0;
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if live_rows.as_ref().is_some_and(|v| !v.get_bit(row)) {
+                // already deleted by the deletion vector
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
-                bitmap.push(!deleted_key_hashes.contains(&hash));
+                let deleted = deleted_key_hashes.contains(&hash);
+                delete_nums += deleted as usize;
+                bitmap.push(!deleted);
             } else {
                 // otherwise, keep this row
                 bitmap.push(true);
             }
         }
 
-        let delete_nums = bitmap.null_count();
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -492,12 +505,11 @@ impl AggregationContext {
         }
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if bitmap.null_count() == num_rows {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
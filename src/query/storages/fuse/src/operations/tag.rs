@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use futures_util::TryStreamExt;
+use log::info;
+use opendal::EntryMode;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::io::SnapshotsIO;
+use crate::FuseTable;
+use crate::FUSE_TBL_TAG_PREFIX;
+
+/// A named snapshot of the table, stored under `{table_prefix}/_tag/{name}`.
+///
+/// The gc keeps the tagged snapshot and the files it references until the tag is
+/// dropped or expired.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SnapshotTag {
+    pub name: String,
+    pub snapshot_location: String,
+    pub created_on: DateTime<Utc>,
+    pub expire_at: Option<DateTime<Utc>>,
+}
+
+impl SnapshotTag {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expire_at.is_some_and(|expire_at| expire_at <= now)
+    }
+}
+
+impl FuseTable {
+    fn tag_location(&self, name: &str) -> Result<String> {
+        // the name is a part of the storage path, e.g. `..` or `/` must not be accepted.
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ErrorCode::BadArguments(format!(
+                "Invalid tag name {:?}, only ASCII letters, digits and '_' are allowed",
+                name
+            )));
+        }
+        Ok(format!(
+            "{}/{}/{}",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_TAG_PREFIX,
+            name
+        ))
+    }
+
+    /// Tags the snapshot at `snapshot_location`, which is one of the snapshots of this table.
+    #[async_backtrace::framed]
+    pub async fn create_tag(
+        &self,
+        name: &str,
+        snapshot_location: Option<String>,
+        retain: Option<Duration>,
+    ) -> Result<()> {
+        let Some(snapshot_location) = snapshot_location else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "Empty Table has no snapshot to tag",
+            ));
+        };
+
+        let now = Utc::now();
+        if let Some(tag) = self.read_tag_opt(name).await? {
+            if !tag.is_expired(now) {
+                return Err(ErrorCode::TableTagAlreadyExists(format!(
+                    "Tag {} already exists on table {}",
+                    name, self.table_info.desc
+                )));
+            }
+        }
+
+        let expire_at = match retain {
+            Some(retain) => {
+                let retain = chrono::Duration::from_std(retain).map_err(|e| {
+                    ErrorCode::BadArguments(format!("Invalid retention of tag {}: {}", name, e))
+                })?;
+                Some(now + retain)
+            }
+            None => None,
+        };
+        let tag = SnapshotTag {
+            name: name.to_string(),
+            snapshot_location,
+            created_on: now,
+            expire_at,
+        };
+        info!("create tag {:?} on table {}", tag, self.table_info.desc);
+        self.operator
+            .write(&self.tag_location(name)?, serde_json::to_vec(&tag)?)
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_tag(&self, name: &str, if_exists: bool) -> Result<()> {
+        if self.read_tag_opt(name).await?.is_none() {
+            if if_exists {
+                return Ok(());
+            }
+            return Err(ErrorCode::UnknownTableTag(format!(
+                "Unknown tag {} on table {}",
+                name, self.table_info.desc
+            )));
+        }
+        info!("drop tag {} on table {}", name, self.table_info.desc);
+        self.operator.delete(&self.tag_location(name)?).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_tag_opt(&self, name: &str) -> Result<Option<SnapshotTag>> {
+        match self.operator.read(&self.tag_location(name)?).await {
+            Ok(data) => Ok(Some(serde_json::from_slice(&data.to_vec())?)),
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Lists the tags of the table, including the expired ones.
+    #[async_backtrace::framed]
+    pub async fn list_tags(&self) -> Result<Vec<SnapshotTag>> {
+        let tag_prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator.prefix(),
+            FUSE_TBL_TAG_PREFIX
+        );
+
+        let mut tags = vec![];
+        let mut lister = self.operator.lister_with(&tag_prefix).await?;
+        while let Some(entry) = lister.try_next().await? {
+            if entry.metadata().mode() != EntryMode::FILE {
+                continue;
+            }
+            let data = self.operator.read(entry.path()).await?;
+            tags.push(serde_json::from_slice(&data.to_vec())?);
+        }
+        Ok(tags)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_tag(&self, name: &str) -> Result<Arc<FuseTable>> {
+        let tag = match self.read_tag_opt(name).await? {
+            Some(tag) if !tag.is_expired(Utc::now()) => tag,
+            _ => {
+                return Err(ErrorCode::UnknownTableTag(format!(
+                    "Unknown tag {} on table {}",
+                    name, self.table_info.desc
+                )));
+            }
+        };
+        let (snapshot, format_version) =
+            SnapshotsIO::read_snapshot(tag.snapshot_location, self.get_operator()).await?;
+        self.load_table_by_snapshot(snapshot.as_ref(), format_version)
+    }
+}
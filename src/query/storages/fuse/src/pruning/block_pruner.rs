@@ -70,21 +70,23 @@ impl BlockPruner {
         &self,
         block_metas: &[Arc<BlockMeta>],
     ) -> Vec<(usize, Arc<BlockMeta>)> {
-        match &self.pruning_ctx.internal_column_pruner {
-            Some(pruner) => block_metas
-                .iter()
-                .enumerate()
-                .filter(|(_, block_meta)| {
+        let internal_column_pruner = &self.pruning_ctx.internal_column_pruner;
+        let partition_pruner = &self.pruning_ctx.partition_pruner;
+        block_metas
+            .iter()
+            .enumerate()
+            .filter(|(_, block_meta)| {
+                internal_column_pruner.as_ref().is_none_or(|pruner| {
                     pruner.should_keep(BLOCK_NAME_COL_NAME, &block_meta.location.0)
                 })
-                .map(|(index, block_meta)| (index, block_meta.clone()))
-                .collect(),
-            None => block_metas
-                .iter()
-                .enumerate()
-                .map(|(index, block_meta)| (index, block_meta.clone()))
-                .collect(),
-        }
+            })
+            .filter(|(_, block_meta)| {
+                partition_pruner
+                    .as_ref()
+                    .is_none_or(|pruner| pruner.should_keep(block_meta.partition_value.as_ref()))
+            })
+            .map(|(index, block_meta)| (index, block_meta.clone()))
+            .collect()
     }
 
     // async pruning with bloom index, inverted index or virtual columns.
@@ -63,7 +63,9 @@ use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
+use crate::pruning::PartitionPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 use crate::pruning::VirtualColumnPruner;
 
 const SMALL_DATASET_SAMPLE_THRESHOLD: usize = 100;
@@ -79,7 +81,9 @@ pub struct PruningContext {
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
+    pub partition_pruner: Option<Arc<PartitionPruner>>,
     pub inverted_index_pruner: Option<Arc<InvertedIndexPruner>>,
+    pub vector_index_pruner: Option<Arc<VectorIndexPruner>>,
     pub virtual_column_pruner: Option<Arc<VirtualColumnPruner>>,
 
     pub pruning_stats: Arc<FusePruningStatistics>,
@@ -94,6 +98,7 @@ impl PruningContext {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        partition_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
         ngram_args: Vec<NgramArgs>,
         max_concurrency: usize,
@@ -167,13 +172,21 @@ impl PruningContext {
         // inverted index pruner, used to search matched rows in block
         let inverted_index_pruner = InvertedIndexPruner::try_create(ctx, dal.clone(), push_down)?;
 
+        // vector index pruner, used to keep the blocks containing the nearest rows
+        let vector_index_pruner = VectorIndexPruner::try_create(dal.clone(), push_down)?;
+
         // virtual column pruner, used to read virtual column metas and ignore source columns.
         let virtual_column_pruner = VirtualColumnPruner::try_create(push_down)?;
 
         // Internal column pruner, if there are predicates using internal columns,
         // we can use them to prune segments and blocks.
         let internal_column_pruner =
-            InternalColumnPruner::try_create(func_ctx, filter_expr.as_ref());
+            InternalColumnPruner::try_create(func_ctx.clone(), filter_expr.as_ref());
+
+        // Partition pruner, if there are predicates using partition keys,
+        // we can use the partition values to prune segments and blocks.
+        let partition_pruner =
+            PartitionPruner::try_create(func_ctx, filter_expr.as_ref(), &partition_keys);
 
         // Constraint the degree of parallelism
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -196,7 +209,9 @@ impl PruningContext {
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
+            partition_pruner,
             inverted_index_pruner,
+            vector_index_pruner,
             virtual_column_pruner,
             pruning_stats,
         });
@@ -232,6 +247,7 @@ impl FusePruner {
             push_down,
             None,
             vec![],
+            vec![],
             bloom_index_cols,
             ngram_args,
             bloom_index_builder,
@@ -246,6 +262,7 @@ impl FusePruner {
         push_down: &Option<PushDownInfo>,
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
+        partition_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
         ngram_args: Vec<NgramArgs>,
         bloom_index_builder: Option<BloomIndexRebuilder>,
@@ -272,6 +289,7 @@ impl FusePruner {
             push_down,
             cluster_key_meta,
             cluster_keys,
+            partition_keys,
             bloom_index_cols,
             ngram_args,
             max_concurrency,
@@ -306,7 +324,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -444,7 +462,8 @@ impl FusePruner {
             // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
             // will get here, we can prevent other mutations like update and so on.
             // TopN pruner.
-            self.topn_pruning(metas)
+            let metas = self.topn_pruning(metas)?;
+            self.vector_index_pruning(metas).await
         }
     }
 
@@ -513,7 +532,8 @@ impl FusePruner {
             let res = worker?;
             metas.extend(res);
         }
-        self.topn_pruning(metas)
+        let metas = self.topn_pruning(metas)?;
+        self.vector_index_pruning(metas).await
     }
 
     // topn pruner:
@@ -538,6 +558,19 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if there are ordering by the distance of an indexed vector column + limit clause and no filters
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        match &self.pruning_ctx.vector_index_pruner {
+            Some(vector_index_pruner) => vector_index_pruner.prune(metas).await,
+            None => Ok(metas),
+        }
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::visit_expr;
+use databend_common_expression::Cast;
+use databend_common_expression::ColumnRef;
+use databend_common_expression::Constant;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::ExprVisitor;
+use databend_common_expression::FunctionCall;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+/// Prunes the segments and blocks of a partitioned table by the partition values.
+///
+/// The partition keys in the filter are replaced by the partition value of the segment or block,
+/// which can be pruned if the filter is folded to `false` or `NULL`.
+pub struct PartitionPruner {
+    func_ctx: FunctionContext,
+    filter: Expr<String>,
+    /// The partition keys and their display names, used to match the sub expressions of the filter.
+    partition_keys: Vec<(String, Expr<String>)>,
+}
+
+impl PartitionPruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        filter: Option<&Expr<String>>,
+        partition_keys: &[RemoteExpr<String>],
+    ) -> Option<Arc<Self>> {
+        let filter = filter?;
+        if partition_keys.is_empty() {
+            return None;
+        }
+
+        let partition_keys = partition_keys
+            .iter()
+            .map(|key| {
+                let key = key.as_expr(&BUILTIN_FUNCTIONS);
+                (key.sql_display(), key)
+            })
+            .collect::<Vec<_>>();
+        let column_refs = filter.column_refs();
+        if partition_keys.iter().all(|(_, key)| {
+            key.column_refs()
+                .keys()
+                .all(|c| !column_refs.contains_key(c))
+        }) {
+            return None;
+        }
+
+        Some(Arc::new(PartitionPruner {
+            func_ctx,
+            filter: filter.clone(),
+            partition_keys,
+        }))
+    }
+
+    pub fn should_keep(&self, partition_value: Option<&Vec<Scalar>>) -> bool {
+        let Some(partition_value) = partition_value else {
+            return true;
+        };
+        if partition_value.len() != self.partition_keys.len() {
+            return true;
+        }
+
+        let mut visitor = ReplacePartitionKeys {
+            partition_keys: &self.partition_keys,
+            partition_value,
+        };
+        let Some(filter) = visit_expr(&self.filter, &mut visitor).unwrap() else {
+            return true;
+        };
+
+        let (folded_filter, _) = ConstantFolder::fold(&filter, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        !matches!(
+            folded_filter,
+            Expr::Constant(Constant {
+                scalar: Scalar::Boolean(false) | Scalar::Null,
+                ..
+            })
+        )
+    }
+}
+
+struct ReplacePartitionKeys<'a> {
+    partition_keys: &'a [(String, Expr<String>)],
+    partition_value: &'a [Scalar],
+}
+
+impl ReplacePartitionKeys<'_> {
+    fn replace(&self, expr: &Expr<String>) -> Option<Expr<String>> {
+        let display = expr.sql_display();
+        self.partition_keys
+            .iter()
+            .zip(self.partition_value)
+            .find(|((name, key), _)| *name == display && key.data_type() == expr.data_type())
+            .map(|(_, value)| {
+                Expr::Constant(Constant {
+                    span: expr.span(),
+                    scalar: value.clone(),
+                    data_type: expr.data_type().clone(),
+                })
+            })
+    }
+}
+
+impl ExprVisitor<String> for ReplacePartitionKeys<'_> {
+    fn enter_column_ref(
+        &mut self,
+        col: &ColumnRef<String>,
+    ) -> Result<Option<Expr<String>>, Self::Error> {
+        Ok(self.replace(&Expr::ColumnRef(col.clone())))
+    }
+
+    fn enter_cast(&mut self, cast: &Cast<String>) -> Result<Option<Expr<String>>, Self::Error> {
+        match self.replace(&Expr::Cast(cast.clone())) {
+            Some(expr) => Ok(Some(expr)),
+            None => Self::visit_cast(cast, self),
+        }
+    }
+
+    fn enter_function_call(
+        &mut self,
+        call: &FunctionCall<String>,
+    ) -> Result<Option<Expr<String>>, Self::Error> {
+        match self.replace(&Expr::FunctionCall(call.clone())) {
+            Some(expr) => Ok(Some(expr)),
+            None => Self::visit_function_call(call, self),
+        }
+    }
+}
@@ -59,6 +59,7 @@ impl SegmentPruner {
 
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let partition_pruner = self.pruning_ctx.partition_pruner.clone();
 
         for segment_location in segment_locs {
             let info = T::SegmentReader::read_compact_segment_through_cache(
@@ -78,7 +79,13 @@ impl SegmentPruner {
                 pruning_stats.set_segments_range_pruning_before(1);
             }
 
-            if range_pruner.should_keep(&info.summary().col_stats, None) {
+            // The segments of a partitioned table are pruned by the partition values
+            // before the block metas are loaded.
+            let keep = partition_pruner
+                .as_ref()
+                .is_none_or(|pruner| pruner.should_keep(info.summary().partition_value.as_ref()))
+                && range_pruner.should_keep(&info.summary().col_stats, None);
+            if keep {
                 // Perf.
                 {
                     metrics_inc_segments_range_pruning_after(1);
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_vector::IvfIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::future;
+use opendal::ErrorKind;
+use opendal::Operator;
+
+use crate::io::read_deletion_vector;
+use crate::io::TableMetaLocationGenerator;
+use crate::io::DEFAULT_VECTOR_INDEX_NPROBE;
+use crate::io::VECTOR_INDEX_NPROBE_OPTION;
+
+// Each block file has a corresponding vector index file.
+// The index of every block returns the nearest rows of the query vector,
+// only the blocks that contain one of the global `limit` nearest rows are kept.
+// The rows are not filtered, the sort and limit above the scan still compute
+// the exact distances of the kept blocks. The rows deleted by deletion vectors
+// are excluded from the nearest rows.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    index_name: String,
+    index_version: String,
+    query_values: Vec<f32>,
+    limit: usize,
+    nprobe: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        dal: Operator,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Option<Arc<VectorIndexPruner>>> {
+        let Some(push_down) = push_down else {
+            return Ok(None);
+        };
+        let Some(vector_index) = &push_down.vector_index else {
+            return Ok(None);
+        };
+        // Rows filtered out can't be counted into the nearest rows.
+        if push_down.filters.is_some() || push_down.prewhere.is_some() {
+            return Ok(None);
+        }
+
+        let nprobe = vector_index
+            .index_options
+            .get(VECTOR_INDEX_NPROBE_OPTION)
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(DEFAULT_VECTOR_INDEX_NPROBE);
+        Ok(Some(Arc::new(VectorIndexPruner {
+            dal,
+            index_name: vector_index.index_name.clone(),
+            index_version: vector_index.index_version.clone(),
+            query_values: vector_index.query_values.iter().map(|v| v.0).collect(),
+            limit: vector_index.limit,
+            nprobe,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let results = future::try_join_all(
+            metas
+                .iter()
+                .map(|(_, block_meta)| self.search_block(block_meta)),
+        )
+        .await?;
+
+        // Blocks without index file can't be pruned.
+        let mut keep = HashSet::new();
+        let mut candidates = Vec::new();
+        for (i, result) in results.into_iter().enumerate() {
+            match result {
+                Some(rows) => {
+                    candidates.extend(rows.into_iter().map(|(_, distance)| (distance, i)));
+                }
+                None => {
+                    keep.insert(i);
+                }
+            }
+        }
+        // Less indexed rows than `limit`, the rows without vector are also needed.
+        if candidates.len() < self.limit {
+            return Ok(metas);
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        keep.extend(candidates.into_iter().take(self.limit).map(|(_, i)| i));
+
+        Ok(metas
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| keep.contains(i))
+            .map(|(_, meta)| meta)
+            .collect())
+    }
+
+    // Returns `None` if the index file of the block is not found.
+    async fn search_block(&self, block_meta: &BlockMeta) -> Result<Option<Vec<(u32, f32)>>> {
+        let location = TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+            &block_meta.location.0,
+            &self.index_name,
+            &self.index_version,
+        );
+        let data = match self.dal.read(&location).await {
+            Ok(data) => data,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let index = IvfIndex::from_bytes(&data.to_vec())?;
+        let Some(deletion_vector) = &block_meta.deletion_vector else {
+            let rows = index.search(&self.query_values, self.limit, self.nprobe)?;
+            return Ok(Some(rows));
+        };
+
+        // Search for more rows, so that the nearest live rows are kept after
+        // the deleted rows are filtered out.
+        let deleted = read_deletion_vector(&self.dal, deletion_vector).await?;
+        let limit = self.limit + deletion_vector.deleted_rows as usize;
+        let rows = index.search(&self.query_values, limit, self.nprobe)?;
+        Ok(Some(
+            rows.into_iter()
+                .filter(|(row, _)| !deleted.contains(*row))
+                .take(self.limit)
+                .collect(),
+        ))
+    }
+}
@@ -268,6 +268,8 @@ impl AsyncSink for ColumnOrientedBlockPruneSink {
                         None, // TODO(Sky): sort_min_max
                         Some(block_meta_index),
                         create_on,
+                        // column-oriented segments never carry deletion vectors
+                        None,
                     );
 
                     let _ = sender.send(Ok(part_info)).await;
@@ -234,7 +234,7 @@ impl SendPartInfoSink {
         let mut parts = Vec::with_capacity(block_metas.len());
 
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             let previous_limit = self.send_part_state.limit.fetch_sub(
                 rows.min(self.send_part_state.limit.load(Ordering::SeqCst)),
                 Ordering::SeqCst,
@@ -281,7 +281,7 @@ impl SendPartInfoSink {
         };
 
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             let previous_limit = self.send_part_state.limit.fetch_sub(
                 rows.min(self.send_part_state.limit.load(Ordering::SeqCst)),
                 Ordering::SeqCst,
@@ -0,0 +1,83 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::AsyncAccumulatingTransform;
+use databend_common_pipeline_transforms::AsyncAccumulatingTransformer;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+
+use crate::pruning::VectorIndexPruner;
+use crate::pruning_pipeline::block_prune_result_meta::BlockPruneResult;
+
+// VectorIndexPruneTransform is a processor that will accumulate the block meta and not push to
+// downstream until all data is received and pruned, the nearest rows are searched across all the blocks.
+pub struct VectorIndexPruneTransform {
+    vector_index_pruner: Arc<VectorIndexPruner>,
+    metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for VectorIndexPruneTransform {
+    const NAME: &'static str = "VectorIndexPruneTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data: DataBlock) -> Result<Option<DataBlock>> {
+        let meta = data
+            .take_meta()
+            .and_then(BlockPruneResult::downcast_from)
+            .ok_or_else(|| ErrorCode::Internal("Cannot downcast meta to BlockPruneResult"))?;
+        self.metas.extend(meta.block_metas);
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        let metas = std::mem::take(&mut self.metas);
+        let pruned = self.vector_index_pruner.prune(metas).await?;
+        if pruned.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(DataBlock::empty_with_meta(BlockPruneResult::create(
+                pruned,
+            ))))
+        }
+    }
+}
+
+impl VectorIndexPruneTransform {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        vector_index_pruner: Arc<VectorIndexPruner>,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+            input,
+            output,
+            VectorIndexPruneTransform {
+                vector_index_pruner,
+                metas: vec![],
+            },
+        )))
+    }
+}
@@ -15,6 +15,7 @@
 pub mod accumulator;
 mod cluster_statistics;
 mod column_statistic;
+mod partition_value;
 pub mod reducers;
 mod traverse;
 
@@ -28,6 +29,7 @@ pub use column_statistic::scalar_min_max;
 pub use column_statistic::Trim;
 pub use column_statistic::STATS_REPLACEMENT_CHAR;
 pub use column_statistic::STATS_STRING_PREFIX_LEN;
+pub use partition_value::PartitionValueGenerator;
 pub use reducers::merge_statistics;
 pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_statistics;
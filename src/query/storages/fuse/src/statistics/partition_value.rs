@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::Result;
+use databend_common_expression::AnyType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+/// Evaluates the partition keys of a partitioned table over the blocks to be written.
+#[derive(Clone, Default)]
+pub struct PartitionValueGenerator {
+    /// The partition keys, the column refs are offsets of the block columns.
+    partition_keys: Vec<Expr>,
+    func_ctx: FunctionContext,
+}
+
+impl PartitionValueGenerator {
+    pub fn new(partition_keys: Vec<Expr>, func_ctx: FunctionContext) -> Self {
+        Self {
+            partition_keys,
+            func_ctx,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.partition_keys.is_empty()
+    }
+
+    fn eval(&self, data_block: &DataBlock) -> Result<Vec<Value<AnyType>>> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        self.partition_keys
+            .iter()
+            .map(|expr| evaluator.run(expr))
+            .collect()
+    }
+
+    /// Returns the partition value of the block, if all the rows of it belong to one partition.
+    pub fn gen(&self, data_block: &DataBlock) -> Result<Option<Vec<Scalar>>> {
+        if self.is_empty() || data_block.is_empty() {
+            return Ok(None);
+        }
+
+        let mut partition_value = Vec::with_capacity(self.partition_keys.len());
+        for value in self.eval(data_block)? {
+            let first = value.index(0).unwrap();
+            if (1..data_block.num_rows()).any(|row| value.index(row).unwrap() != first) {
+                return Ok(None);
+            }
+            partition_value.push(first.to_owned());
+        }
+        Ok(Some(partition_value))
+    }
+
+    /// Splits the block into blocks whose rows belong to one partition.
+    pub fn split(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if self.is_empty() || data_block.num_rows() <= 1 {
+            return Ok(vec![data_block]);
+        }
+
+        let values = self.eval(&data_block)?;
+        let mut partitions = BTreeMap::new();
+        let mut indices = Vec::with_capacity(data_block.num_rows());
+        for row in 0..data_block.num_rows() {
+            let partition_value = values
+                .iter()
+                .map(|value| value.index(row).unwrap().to_owned())
+                .collect::<Vec<_>>();
+            let next = partitions.len();
+            indices.push(*partitions.entry(partition_value).or_insert(next) as u32);
+        }
+
+        if partitions.len() == 1 {
+            return Ok(vec![data_block]);
+        }
+        data_block.scatter(&indices, partitions.len())
+    }
+}
@@ -154,12 +154,16 @@ pub fn merge_statistics_mut(
     if l.row_count == 0 {
         l.col_stats = r.col_stats.clone();
         l.cluster_stats = r.cluster_stats.clone();
+        l.partition_value = r.partition_value.clone();
     } else {
         l.col_stats = reduce_block_statistics(&[&l.col_stats, &r.col_stats]);
         l.cluster_stats = reduce_cluster_statistics(
             &[&l.cluster_stats, &r.cluster_stats],
             default_cluster_key_id,
         );
+        if l.partition_value != r.partition_value {
+            l.partition_value = None;
+        }
     }
 
     l.row_count += r.row_count;
@@ -168,6 +172,7 @@ pub fn merge_statistics_mut(
     l.uncompressed_byte_size += r.uncompressed_byte_size;
     l.compressed_byte_size += r.compressed_byte_size;
     l.index_size += r.index_size;
+    l.deleted_row_count += r.deleted_row_count;
     let virtual_block_count =
         l.virtual_block_count.unwrap_or_default() + r.virtual_block_count.unwrap_or_default();
     l.virtual_block_count = if virtual_block_count > 0 {
@@ -192,6 +197,7 @@ pub fn deduct_statistics_mut(l: &mut Statistics, r: &Statistics) {
     l.uncompressed_byte_size -= r.uncompressed_byte_size;
     l.compressed_byte_size -= r.compressed_byte_size;
     l.index_size -= r.index_size;
+    l.deleted_row_count = l.deleted_row_count.saturating_sub(r.deleted_row_count);
     for (id, col_stats) in &mut l.col_stats {
         if let Some(r_col_stats) = r.col_stats.get(id) {
             // The MinMax of a column cannot be recalculated by the right statistics,
@@ -221,6 +227,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let mut index_size: u64 = 0;
     let mut perfect_block_count: u64 = 0;
     let mut virtual_block_count: u64 = 0;
+    let mut deleted_row_count: u64 = 0;
 
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
@@ -228,7 +235,8 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
+        deleted_row_count += b.deletion_vector.as_ref().map_or(0, |dv| dv.deleted_rows);
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
@@ -238,11 +246,14 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
             index_size += virtual_block_meta.virtual_column_size;
             virtual_block_count += 1;
         }
-        if thresholds.check_perfect_block(
-            b.row_count as usize,
-            b.block_size as usize,
-            b.file_size as usize,
-        ) || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // Blocks with deleted rows are never perfect, so that compaction will
+        // pick them up and fold the deletion vectors in.
+        if b.deletion_vector.is_none()
+            && (thresholds.check_perfect_block(
+                b.row_count as usize,
+                b.block_size as usize,
+                b.file_size as usize,
+            ) || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
@@ -257,6 +268,14 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     } else {
         None
     };
+    // The blocks of a segment written into a partitioned table belong to one partition.
+    let partition_value = block_metas.first().and_then(|first| {
+        let partition_value = first.borrow().partition_value.as_ref()?;
+        block_metas
+            .iter()
+            .all(|b| b.borrow().partition_value.as_ref() == Some(partition_value))
+            .then(|| partition_value.clone())
+    });
 
     Statistics {
         row_count,
@@ -268,5 +287,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         col_stats: merged_col_stats,
         cluster_stats: merged_cluster_stats,
         virtual_block_count: merged_virtual_block_count,
+        partition_value,
+        deleted_row_count,
     }
 }
@@ -198,7 +198,7 @@ impl Database for IcebergDatabase {
                             table_id: 0,
                             table_id_seq: None,
                             db_id: 0,
-                            new_table: true,
+                            new_table: false,
                             spec_vec: None,
                             prev_table_id: None,
                             orphan_table_name: None,
@@ -25,6 +25,7 @@ mod database;
 mod iceberg_inspect;
 mod partition;
 mod predicate;
+mod sink;
 mod statistics;
 pub mod table;
 pub use catalog::IcebergCatalog;
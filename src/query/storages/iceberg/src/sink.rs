@@ -0,0 +1,307 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write path of iceberg tables.
+//!
+//! Inserting into an iceberg table is split into two stages:
+//!
+//! - [`IcebergDataFileWriter`] runs on every pipe, converts the incoming blocks into arrow
+//!   record batches and writes them out as parquet data files under the table location.
+//! - [`IcebergCommitSink`] collects the [`DataFile`]s produced by all the writers and commits
+//!   them into a new snapshot through a `fast_append` transaction of the iceberg catalog.
+//!
+//! Only appends to unpartitioned tables are supported. The iceberg client pinned in the
+//! workspace only provides the `fast_append` action, so INSERT OVERWRITE (replace) and
+//! writing partitioned data files are rejected with `ErrorCode::Unimplemented`.
+//!
+//! DELETE through position and equality delete files is NOT implemented. Committing delete
+//! files needs a row delta action, which the pinned client does not have. Whether to add it
+//! to our iceberg-rust fork or to wait for upstream has not been agreed on yet. Until then,
+//! DELETE, UPDATE and MERGE INTO iceberg tables are rejected, because only fuse tables can
+//! be mutated.
+
+use std::sync::Arc;
+
+use arrow_array::RecordBatch;
+use arrow_schema::SchemaRef;
+use async_trait::async_trait;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::AsyncAccumulatingTransform;
+use iceberg::arrow::schema_to_arrow_schema;
+use iceberg::spec::DataFile;
+use iceberg::spec::DataFileFormat;
+use iceberg::transaction::Transaction;
+use iceberg::writer::base_writer::data_file_writer::DataFileWriter;
+use iceberg::writer::base_writer::data_file_writer::DataFileWriterBuilder;
+use iceberg::writer::file_writer::location_generator::DefaultFileNameGenerator;
+use iceberg::writer::file_writer::location_generator::DefaultLocationGenerator;
+use iceberg::writer::file_writer::ParquetWriterBuilder;
+use iceberg::writer::IcebergWriter;
+use iceberg::writer::IcebergWriterBuilder;
+use log::info;
+use parquet::file::properties::WriterProperties;
+
+use crate::IcebergCatalog;
+use crate::IcebergTable;
+
+/// Roll over to a new data file once the current one holds this many rows.
+const MAX_ROWS_PER_DATA_FILE: usize = 1_000_000;
+
+type ParquetDataFileWriter =
+    DataFileWriter<ParquetWriterBuilder<DefaultLocationGenerator, DefaultFileNameGenerator>>;
+
+/// Data files written by [`IcebergDataFileWriter`], waiting to be committed.
+#[derive(Debug)]
+pub struct IcebergDataFilesMeta {
+    pub data_files: Vec<DataFile>,
+}
+
+local_block_meta_serde!(IcebergDataFilesMeta);
+
+#[typetag::serde(name = "iceberg_data_files")]
+impl BlockMetaInfo for IcebergDataFilesMeta {}
+
+pub struct IcebergDataFileWriter {
+    table: iceberg::table::Table,
+    table_schema: TableSchemaRef,
+    arrow_schema: SchemaRef,
+
+    // The writer is created lazily, so that pipes without any input won't leave empty files.
+    writer: Option<ParquetDataFileWriter>,
+    data_files: Vec<DataFile>,
+    rows_in_file: usize,
+}
+
+impl IcebergDataFileWriter {
+    pub fn try_create(table: &IcebergTable, table_schema: TableSchemaRef) -> Result<Self> {
+        let arrow_schema = schema_to_arrow_schema(table.table.metadata().current_schema())
+            .map_err(|err| {
+                ErrorCode::Internal(format!("Cannot convert iceberg schema to arrow: {err:?}"))
+            })?;
+        Ok(Self {
+            table: table.table.clone(),
+            table_schema,
+            arrow_schema: Arc::new(arrow_schema),
+            writer: None,
+            data_files: vec![],
+            rows_in_file: 0,
+        })
+    }
+
+    async fn build_writer(&self) -> Result<ParquetDataFileWriter> {
+        let metadata = self.table.metadata();
+        let location_generator =
+            DefaultLocationGenerator::new(metadata.clone()).map_err(|err| {
+                ErrorCode::Internal(format!("Iceberg build location generator failed: {err:?}"))
+            })?;
+        // Every writer gets its own prefix, file names generated by different
+        // writers (or nodes) must never collide.
+        let file_name_generator = DefaultFileNameGenerator::new(
+            format!("databend-{}", uuid::Uuid::now_v7().simple()),
+            None,
+            DataFileFormat::Parquet,
+        );
+        let parquet_writer_builder = ParquetWriterBuilder::new(
+            WriterProperties::default(),
+            metadata.current_schema().clone(),
+            self.table.file_io().clone(),
+            location_generator,
+            file_name_generator,
+        );
+        DataFileWriterBuilder::new(parquet_writer_builder, None)
+            .build()
+            .await
+            .map_err(|err| ErrorCode::Internal(format!("Iceberg build writer failed: {err:?}")))
+    }
+
+    async fn close_writer(&mut self) -> Result<()> {
+        if let Some(mut writer) = self.writer.take() {
+            let data_files = writer.close().await.map_err(|err| {
+                ErrorCode::Internal(format!("Iceberg close data file failed: {err:?}"))
+            })?;
+            self.data_files.extend(data_files);
+        }
+        self.rows_in_file = 0;
+        Ok(())
+    }
+
+    fn to_record_batch(&self, block: DataBlock) -> Result<RecordBatch> {
+        let batch = block.to_record_batch(&self.table_schema)?;
+        // The column layout is the same, but the iceberg schema carries the field ids
+        // which are required by the parquet writer.
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(self.arrow_schema.fields())
+            .map(|(column, field)| {
+                if column.data_type() == field.data_type() {
+                    Ok(column.clone())
+                } else {
+                    arrow_cast::cast(column, field.data_type()).map_err(|err| {
+                        ErrorCode::Internal(format!(
+                            "Cannot cast column {} to iceberg type {}: {err:?}",
+                            field.name(),
+                            field.data_type()
+                        ))
+                    })
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(self.arrow_schema.clone(), columns).map_err(|err| {
+            ErrorCode::Internal(format!("Cannot build iceberg record batch: {err:?}"))
+        })
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for IcebergDataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let num_rows = data.num_rows();
+        let batch = self.to_record_batch(data)?;
+        if self.writer.is_none() {
+            self.writer = Some(self.build_writer().await?);
+        }
+        if let Some(writer) = self.writer.as_mut() {
+            writer.write(batch).await.map_err(|err| {
+                ErrorCode::Internal(format!("Iceberg write data file failed: {err:?}"))
+            })?;
+        }
+
+        self.rows_in_file += num_rows;
+        if self.rows_in_file >= MAX_ROWS_PER_DATA_FILE {
+            self.close_writer().await?;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.close_writer().await?;
+        let data_files = std::mem::take(&mut self.data_files);
+        Ok(Some(DataBlock::empty_with_meta(
+            IcebergDataFilesMeta { data_files }.boxed(),
+        )))
+    }
+}
+
+pub struct IcebergCommitSink {
+    ctx: Arc<dyn TableContext>,
+    table: IcebergTable,
+    data_files: Vec<DataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn create(ctx: Arc<dyn TableContext>, table: IcebergTable) -> Self {
+        Self {
+            ctx,
+            table,
+            data_files: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.data_files.is_empty() {
+            return Ok(());
+        }
+
+        let catalog = self
+            .ctx
+            .get_catalog(self.table.get_table_info().catalog())
+            .await?;
+        let catalog = catalog
+            .as_any()
+            .downcast_ref::<IcebergCatalog>()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "expects catalog of type iceberg, but got {}",
+                    catalog.name()
+                ))
+            })?;
+
+        let identifier = self.table.table.identifier();
+        let db_name = identifier.namespace().to_url_string();
+        let table_name = identifier.name().to_string();
+
+        // Always commit on top of the latest metadata, other engines may have
+        // committed new snapshots while we were writing data files.
+        let latest =
+            IcebergTable::load_iceberg_table(catalog.iceberg_catalog(), &db_name, &table_name)
+                .await?;
+
+        let data_files = std::mem::take(&mut self.data_files);
+        let num_data_files = data_files.len();
+        let tx = Transaction::new(&latest);
+        let mut action = tx.fast_append(None, vec![]).map_err(|err| {
+            ErrorCode::Internal(format!("Iceberg create append action failed: {err:?}"))
+        })?;
+        action.add_data_files(data_files).map_err(|err| {
+            ErrorCode::Internal(format!("Iceberg add data files failed: {err:?}"))
+        })?;
+        let tx = action.apply().await.map_err(|err| {
+            ErrorCode::Internal(format!("Iceberg apply append action failed: {err:?}"))
+        })?;
+        tx.commit(catalog.iceberg_catalog().as_ref())
+            .await
+            .map_err(|err| {
+                ErrorCode::StorageOther(format!(
+                    "Iceberg commit table {db_name}.{table_name} failed: {err:?}"
+                ))
+            })?;
+        info!(
+            "Iceberg table {}.{} committed {} data files",
+            db_name, table_name, num_data_files
+        );
+
+        // Refresh the cached table so that the new snapshot is visible to the following queries.
+        catalog
+            .get_database(&self.ctx.get_tenant(), &db_name)
+            .await?
+            .refresh_table(&table_name)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFilesMeta::downcast_from)
+        {
+            self.data_files.extend(meta.data_files);
+        }
+        Ok(false)
+    }
+}
@@ -44,10 +44,17 @@ use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::TransformPipelineHelper;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_common_storages_parquet::ParquetSource;
 use databend_common_storages_parquet::ParquetSourceType;
+use databend_storages_common_table_meta::meta::SnapshotId;
+use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::table::ChangeType;
 use futures::TryStreamExt;
 use iceberg::arrow::schema_to_arrow_schema;
@@ -55,6 +62,8 @@ use iceberg::io::FileIOBuilder;
 
 use crate::partition::convert_file_scan_task;
 use crate::predicate::PredicateBuilder;
+use crate::sink::IcebergCommitSink;
+use crate::sink::IcebergDataFileWriter;
 use crate::statistics;
 use crate::statistics::IcebergStatistics;
 
@@ -322,6 +331,63 @@ impl IcebergTable {
         )
     }
 
+    /// Writes are only allowed on the latest snapshot of an unpartitioned table.
+    fn check_writable(&self) -> Result<()> {
+        if self.snapshot_id.is_some() {
+            return Err(ErrorCode::InvalidOperation(format!(
+                "Cannot write to table '{}' at a historical snapshot",
+                self.name()
+            )));
+        }
+        if !self
+            .table
+            .metadata()
+            .default_partition_spec()
+            .is_unpartitioned()
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Writing to partitioned iceberg table '{}' is not supported yet, only unpartitioned tables can be appended to",
+                self.name()
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn do_append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.check_writable()?;
+
+        let table_schema = self.schema();
+        pipeline.try_add_async_accumulating_transformer(|| {
+            IcebergDataFileWriter::try_create(self, table_schema.clone())
+        })
+    }
+
+    pub fn do_commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        overwrite: bool,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT OVERWRITE is not supported for iceberg table '{}', only appends can be committed",
+                self.name()
+            )));
+        }
+
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                IcebergCommitSink::create(ctx.clone(), self.clone()),
+            )))
+        })
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn do_read_partitions(
@@ -450,6 +516,29 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _table_meta_timestamps: TableMetaTimestamps,
+    ) -> Result<()> {
+        self.do_append_data(ctx, pipeline)
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+        _table_meta_timestamps: TableMetaTimestamps,
+    ) -> Result<()> {
+        self.do_commit_insertion(ctx, pipeline, overwrite)
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -251,6 +251,7 @@ impl AvroDecoder {
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
             ColumnBuilder::Interval(c) => self.read_interval(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value, matched_schema),
             ColumnBuilder::Map(c) => self.read_map(c, value, matched_schema),
@@ -348,6 +349,16 @@ impl AvroDecoder {
         Ok(())
     }
 
+    fn read_time(&self, column: &mut Vec<i64>, value: Value) -> ReadFieldResult {
+        let v = match value {
+            Value::TimeMillis(v) => v as i64 * 1000,
+            Value::TimeMicros(v) => v,
+            _ => return Err(Error::default()),
+        };
+        column.push(v);
+        Ok(())
+    }
+
     fn read_decimal<D: Decimal>(
         &self,
         column: &mut Vec<D>,
@@ -122,6 +122,7 @@ impl SchemaMatcher {
             | (TableDataType::Boolean, Schema::Boolean)
             | (TableDataType::Date, Schema::Date)
             | (TableDataType::Interval, Schema::Duration)
+            | (TableDataType::Time, Schema::TimeMillis | Schema::TimeMicros)
             | (
                 TableDataType::Timestamp,
                 Schema::TimestampNanos
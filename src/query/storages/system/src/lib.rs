@@ -46,6 +46,7 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod pipes_table;
 mod procedures_table;
 mod processes_table;
 mod queries_profiling;
@@ -100,6 +101,8 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use pipes_table::parse_pipes_to_datablock;
+pub use pipes_table::PipesTable;
 pub use procedures_table::ProceduresTable;
 pub use processes_table::ProcessesTable;
 pub use queries_profiling::ProfilesLogElement;
@@ -124,8 +127,12 @@ pub use tables_table::TablesTableWithoutHistory;
 pub use tables_table::ViewsTableWithHistory;
 pub use tables_table::ViewsTableWithoutHistory;
 pub use task_history_table::parse_task_runs_to_datablock;
+pub use task_history_table::task_run_to_pb;
 pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
+pub use tasks_table::schedule_options_to_pb;
+pub use tasks_table::task_to_pb;
+pub use tasks_table::warehouse_options_to_pb;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
 pub use temporary_tables_table::TemporaryTablesTable;
@@ -0,0 +1,142 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub fn parse_pipes_to_datablock(pipes: Vec<PipeInfo>) -> DataBlock {
+    let mut names = Vec::with_capacity(pipes.len());
+    let mut definitions = Vec::with_capacity(pipes.len());
+    let mut auto_ingests = Vec::with_capacity(pipes.len());
+    let mut execution_pauseds = Vec::with_capacity(pipes.len());
+    let mut comments = Vec::with_capacity(pipes.len());
+    let mut created_ons = Vec::with_capacity(pipes.len());
+    let mut updated_ons = Vec::with_capacity(pipes.len());
+    let mut last_load_ons = Vec::with_capacity(pipes.len());
+    let mut last_load_files = Vec::with_capacity(pipes.len());
+    let mut last_load_errors = Vec::with_capacity(pipes.len());
+    let mut owners = Vec::with_capacity(pipes.len());
+    for pipe in pipes {
+        names.push(pipe.name);
+        definitions.push(pipe.copy_statement);
+        auto_ingests.push(pipe.auto_ingest);
+        execution_pauseds.push(pipe.execution_paused);
+        comments.push(pipe.comment);
+        created_ons.push(pipe.created_on.timestamp_micros());
+        updated_ons.push(pipe.updated_on.timestamp_micros());
+        last_load_ons.push(pipe.last_load_on.map(|t| t.timestamp_micros()));
+        last_load_files.push(pipe.last_load_files);
+        last_load_errors.push(pipe.last_load_error);
+        owners.push(pipe.owner);
+    }
+
+    DataBlock::new_from_columns(vec![
+        StringType::from_data(names),
+        StringType::from_data(definitions),
+        BooleanType::from_data(auto_ingests),
+        BooleanType::from_data(execution_pauseds),
+        StringType::from_data(comments),
+        TimestampType::from_data(created_ons),
+        TimestampType::from_data(updated_ons),
+        TimestampType::from_opt_data(last_load_ons),
+        UInt64Type::from_data(last_load_files),
+        StringType::from_opt_data(last_load_errors),
+        StringType::from_data(owners),
+    ])
+}
+
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let pipes = UserApiProvider::instance().get_pipes(&tenant).await?;
+        Ok(parse_pipes_to_datablock(pipes))
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("auto_ingest", TableDataType::Boolean),
+            TableField::new("execution_paused", TableDataType::Boolean),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("updated_on", TableDataType::Timestamp),
+            TableField::new(
+                "last_load_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_load_files",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "last_load_error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("owner", TableDataType::String),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipesTable { table_info })
+    }
+}
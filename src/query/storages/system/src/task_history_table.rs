@@ -22,7 +22,6 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::infer_table_schema;
@@ -36,17 +35,21 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal::task;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 use jiff::tz::TimeZone;
 
+use crate::schedule_options_to_pb;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
 use crate::util::find_gt_filter;
 use crate::util::find_lt_filter;
+use crate::warehouse_options_to_pb;
 
 pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock> {
     let mut name: Vec<String> = Vec::with_capacity(task_runs.len());
@@ -112,6 +115,32 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
     ]))
 }
 
+/// Convert a task run kept in the meta service to the cloud control message,
+/// so that both sources share the same output format.
+pub fn task_run_to_pb(task_run: &task::TaskRun) -> TaskRun {
+    let task = &task_run.task;
+    TaskRun {
+        task_id: task.task_id,
+        task_name: task.task_name.clone(),
+        query_text: task.query_text.clone(),
+        comment: task.comment.clone(),
+        owner: task.owner.clone(),
+        schedule_options: task.schedule_options.as_ref().map(schedule_options_to_pb),
+        run_id: task_run.run_id.clone(),
+        attempt_number: task_run.attempt_number,
+        warehouse_options: task.warehouse_options.as_ref().map(warehouse_options_to_pb),
+        state: task_run.state as i32,
+        error_code: task_run.error_code,
+        error_message: task_run.error_message.clone(),
+        scheduled_time: task_run.scheduled_at.to_rfc3339(),
+        completed_time: task_run.completed_at.map(|t| t.to_rfc3339()),
+        query_id: task_run.query_id.clone(),
+        condition_text: task.when_condition.clone().unwrap_or_default(),
+        root_task_id: task_run.root_task_id.to_string(),
+        session_parameters: task.session_params.clone(),
+    }
+}
+
 pub struct TaskHistoryTable {
     table_info: TableInfo,
 }
@@ -131,12 +160,6 @@ impl AsyncSystemTable for TaskHistoryTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
-        }
-
         let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
@@ -178,6 +201,27 @@ impl AsyncSystemTable for TaskHistoryTable {
                 });
             }
         }
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            // Task runs are recorded in the meta service by the built-in scheduler.
+            let start = scheduled_time_start.and_then(|s| s.parse::<i64>().ok());
+            let end = scheduled_time_end.and_then(|s| s.parse::<i64>().ok());
+            let mut task_runs = UserApiProvider::instance()
+                .get_task_runs(&tenant)
+                .await?
+                .into_iter()
+                .filter(|r| available_roles.iter().any(|x| x.identity() == r.task.owner))
+                .filter(|r| task_name.as_ref().is_none_or(|n| n == &r.task.task_name))
+                .filter(|r| start.is_none_or(|s| r.scheduled_at.timestamp() > s))
+                .filter(|r| end.is_none_or(|e| r.scheduled_at.timestamp() < e))
+                .collect::<Vec<_>>();
+            task_runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+            if let Some(limit) = result_limit {
+                task_runs.truncate(limit as usize);
+            }
+            let trs = task_runs.iter().map(task_run_to_pb).collect();
+            return parse_task_runs_to_datablock(trs);
+        }
+
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.tenant_name().to_string(),
             scheduled_time_start: scheduled_time_start.unwrap_or("".to_string()),
@@ -20,10 +20,10 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
+use databend_common_cloud_control::pb;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -32,10 +32,12 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal::task;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -102,6 +104,47 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     ]))
 }
 
+pub fn schedule_options_to_pb(options: &task::ScheduleOptions) -> pb::ScheduleOptions {
+    pb::ScheduleOptions {
+        interval: options.interval,
+        cron: options.cron.clone(),
+        time_zone: options.time_zone.clone(),
+        schedule_type: options.schedule_type as i32,
+        milliseconds_interval: options.milliseconds_interval,
+    }
+}
+
+pub fn warehouse_options_to_pb(options: &task::WarehouseOptions) -> pb::WarehouseOptions {
+    pb::WarehouseOptions {
+        warehouse: options.warehouse.clone(),
+        using_warehouse_size: options.using_warehouse_size.clone(),
+    }
+}
+
+/// Convert a task kept in the meta service to the cloud control message,
+/// so that both sources share the same output format.
+pub fn task_to_pb(task: &task::Task) -> Task {
+    Task {
+        task_id: task.task_id,
+        task_name: task.task_name.clone(),
+        query_text: task.query_text.clone(),
+        comment: task.comment.clone(),
+        owner: task.owner.clone(),
+        schedule_options: task.schedule_options.as_ref().map(schedule_options_to_pb),
+        warehouse_options: task.warehouse_options.as_ref().map(warehouse_options_to_pb),
+        next_scheduled_at: task.next_scheduled_at.map(|t| t.to_rfc3339()),
+        suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|v| v as i32),
+        status: task.status as i32,
+        created_at: task.created_at.to_rfc3339(),
+        updated_at: task.updated_at.to_rfc3339(),
+        last_suspended_at: task.last_suspended_at.map(|t| t.to_rfc3339()),
+        after: task.after.clone(),
+        when_condition: task.when_condition.clone(),
+        session_parameters: task.session_params.clone(),
+        error_integration: task.error_integration.clone(),
+    }
+}
+
 pub struct TasksTable {
     table_info: TableInfo,
 }
@@ -121,16 +164,21 @@ impl AsyncSystemTable for TasksTable {
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
         let config = GlobalConfig::instance();
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_all_available_roles().await?;
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are kept in the meta service by the built-in scheduler.
+            let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+            let tasks = tasks
+                .iter()
+                .filter(|t| available_roles.iter().any(|r| r.identity() == t.owner))
+                .map(task_to_pb)
+                .collect();
+            return parse_tasks_to_datablock(tasks);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
-        let available_roles = ctx.get_all_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.tenant_name().to_string(),
             name_like: "".to_string(),
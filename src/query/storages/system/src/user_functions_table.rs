@@ -27,6 +27,7 @@ use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::utils::FromData;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRefExt;
@@ -204,8 +205,30 @@ impl UserFunctionsTable {
                             .map(|f| (f.name().to_string(), f.data_type().to_string()))
                             .collect(),
                     },
+                    UDFDefinition::UDTFServer(x) => UserFunctionArguments {
+                        arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
+                        return_type: Some(table_return_type(&x.return_fields)),
+                        server: Some(x.address.to_string()),
+                        parameters: vec![],
+                        states: BTreeMap::new(),
+                    },
+                    UDFDefinition::UDTFScript(x) => UserFunctionArguments {
+                        arg_types: x.arg_types.iter().map(ToString::to_string).collect(),
+                        return_type: Some(table_return_type(&x.return_fields)),
+                        server: None,
+                        parameters: vec![],
+                        states: BTreeMap::new(),
+                    },
                 },
             })
             .collect())
     }
 }
+
+fn table_return_type(fields: &[DataField]) -> String {
+    let columns = fields
+        .iter()
+        .map(|f| format!("{} {}", f.name(), f.data_type()))
+        .collect::<Vec<_>>();
+    format!("TABLE ({})", columns.join(", "))
+}
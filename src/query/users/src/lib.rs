@@ -31,8 +31,10 @@ mod visibility_checker;
 pub mod builtin;
 pub mod connection;
 pub mod file_format;
+pub mod pipe;
 pub mod role_cache_mgr;
 pub mod role_util;
+pub mod task;
 
 pub use jwt::*;
 pub use password_policy::*;
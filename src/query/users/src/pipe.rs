@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe(
+        &self,
+        tenant: &Tenant,
+        pipe: PipeInfo,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let pipe_api_provider = self.pipe_api(tenant);
+        pipe_api_provider.add(pipe, create_option).await?;
+        Ok(())
+    }
+
+    // Get one pipe by name.
+    #[async_backtrace::framed]
+    pub async fn get_pipe(&self, tenant: &Tenant, pipe_name: &str) -> Result<PipeInfo> {
+        let pipe_api_provider = self.pipe_api(tenant);
+        let get_pipe = pipe_api_provider.get(pipe_name, MatchSeq::GE(0));
+        Ok(get_pipe.await?.data)
+    }
+
+    // Get the tenant all pipe list.
+    #[async_backtrace::framed]
+    pub async fn get_pipes(&self, tenant: &Tenant) -> Result<Vec<PipeInfo>> {
+        let pipe_api_provider = self.pipe_api(tenant);
+        match pipe_api_provider.list().await {
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while get pipes)")),
+            Ok(pipes) => Ok(pipes),
+        }
+    }
+
+    // Update a pipe by the function `f`, retry if the pipe is changed concurrently.
+    #[async_backtrace::framed]
+    pub async fn update_pipe(
+        &self,
+        tenant: &Tenant,
+        pipe_name: &str,
+        f: impl Fn(PipeInfo) -> PipeInfo + Send,
+    ) -> Result<()> {
+        let pipe_api_provider = self.pipe_api(tenant);
+        pipe_api_provider
+            .cas_with(pipe_name, MatchSeq::GE(1), |seq_v| f(seq_v.data))
+            .await?;
+        Ok(())
+    }
+
+    // Drop a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn drop_pipe(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let pipe_api_provider = self.pipe_api(tenant);
+        match pipe_api_provider.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                if if_exists && e.code() == ErrorCode::UNKNOWN_PIPE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop pipe)"))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+use crate::UserApiProvider;
+
+/// How long a task run record is kept in the meta service.
+const TASK_RUN_RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// task operations.
+impl UserApiProvider {
+    // Add a new task, returns the id assigned to it.
+    #[async_backtrace::framed]
+    pub async fn create_task(
+        &self,
+        tenant: &Tenant,
+        mut task: Task,
+        create_option: &CreateOption,
+    ) -> Result<u64> {
+        let task_id = fetch_id(self.client.as_ref(), IdGenerator::task_id()).await?;
+        task.task_id = task_id;
+
+        let task_api_provider = self.task_api(tenant);
+        task_api_provider.add(task, create_option).await?;
+        Ok(task_id)
+    }
+
+    // Get one task by name.
+    #[async_backtrace::framed]
+    pub async fn get_task(&self, tenant: &Tenant, task_name: &str) -> Result<Task> {
+        Ok(self.get_task_seqv(tenant, task_name).await?.data)
+    }
+
+    // Get one task by name, along with the seq of the record.
+    #[async_backtrace::framed]
+    pub async fn get_task_seqv(&self, tenant: &Tenant, task_name: &str) -> Result<SeqV<Task>> {
+        let task_api_provider = self.task_api(tenant);
+        let get_task = task_api_provider.get(task_name, MatchSeq::GE(0));
+        Ok(get_task.await?)
+    }
+
+    // Get the tenant all task list.
+    #[async_backtrace::framed]
+    pub async fn get_tasks(&self, tenant: &Tenant) -> Result<Vec<Task>> {
+        let task_api_provider = self.task_api(tenant);
+        match task_api_provider.list().await {
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while get tasks)")),
+            Ok(tasks) => Ok(tasks),
+        }
+    }
+
+    // Update a task by the function `f`, retry if the task is changed concurrently.
+    #[async_backtrace::framed]
+    pub async fn update_task(
+        &self,
+        tenant: &Tenant,
+        task_name: &str,
+        f: impl Fn(Task) -> Task + Send,
+    ) -> Result<()> {
+        let task_api_provider = self.task_api(tenant);
+        task_api_provider
+            .cas_with(task_name, MatchSeq::GE(1), |seq_v| f(seq_v.data))
+            .await?;
+        Ok(())
+    }
+
+    // Replace a task only if its seq is still `seq`.
+    //
+    // Returns false if another writer changed the task first, this is how
+    // query nodes agree on which one fires a scheduled run.
+    #[async_backtrace::framed]
+    pub async fn update_task_if_unchanged(
+        &self,
+        tenant: &Tenant,
+        task: Task,
+        seq: u64,
+    ) -> Result<bool> {
+        let task_api_provider = self.task_api(tenant);
+        match task_api_provider.update(task, MatchSeq::Exact(seq)).await {
+            Ok(_) => Ok(true),
+            Err(CrudError::Business(_)) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // Drop a task by name.
+    #[async_backtrace::framed]
+    pub async fn drop_task(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let task_api_provider = self.task_api(tenant);
+        match task_api_provider.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                let e = ErrorCode::from(e);
+                if if_exists && e.code() == ErrorCode::UNKNOWN_TASK {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop task)"))
+                }
+            }
+        }
+    }
+
+    // Allocate an id for a new task run.
+    #[async_backtrace::framed]
+    pub async fn next_task_run_id(&self) -> Result<u64> {
+        Ok(fetch_id(self.client.as_ref(), IdGenerator::task_run_id()).await?)
+    }
+
+    // Insert or replace a task run record, it expires after `TASK_RUN_RETENTION`.
+    #[async_backtrace::framed]
+    pub async fn upsert_task_run(&self, tenant: &Tenant, task_run: TaskRun) -> Result<()> {
+        let task_run_api_provider = self.task_run_api(tenant);
+        task_run_api_provider
+            .add_with_ttl(
+                task_run,
+                Some(TASK_RUN_RETENTION),
+                &CreateOption::CreateOrReplace,
+            )
+            .await?;
+        Ok(())
+    }
+
+    // Get the tenant all task run list.
+    #[async_backtrace::framed]
+    pub async fn get_task_runs(&self, tenant: &Tenant) -> Result<Vec<TaskRun>> {
+        let task_run_api_provider = self.task_run_api(tenant);
+        match task_run_api_provider.list().await {
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while get task runs)")),
+            Ok(task_runs) => Ok(task_runs),
+        }
+    }
+}
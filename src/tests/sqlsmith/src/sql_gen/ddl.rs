@@ -102,7 +102,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                 uri_location: None,
                 cluster_by: None,
                 table_options: table_options.clone(),
-                iceberg_table_partition: None,
+                table_partition: None,
                 as_query: None,
                 table_type: TableType::Normal,
                 table_properties: Default::default(),
@@ -281,7 +281,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs, None, None)
+        CreateTableSource::Columns(column_defs, None, None, None)
     }
 }
 
@@ -330,7 +330,7 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("".to_string()),
         },
-        TypeName::Array(_) => Expr::Array {
+        TypeName::Array(_) | TypeName::Vector(_) => Expr::Array {
             span: None,
             exprs: vec![],
         },
@@ -362,6 +362,14 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("1 month 1 hour".to_string()),
         },
+        TypeName::Time => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
+        TypeName::TimestampTz => Expr::Literal {
+            span: None,
+            value: Literal::String("1970-01-01 00:00:00 +00:00".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
@@ -91,6 +91,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 source,
                 // TODO
                 overwrite: false,
+                partition: None,
             };
             insert_stmts.push(insert_stmt);
         }
@@ -532,6 +533,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                partition: None,
             })
         } else {
             None
@@ -0,0 +1,57 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=owner --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+export TEST_OTHER_CONNECT="bendsql --user=other --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+stmt "drop user if exists 'owner'"
+stmt "drop user if exists 'other'"
+stmt "drop role if exists role1"
+stmt "create user 'owner' IDENTIFIED BY '$TEST_USER_PASSWORD' with DEFAULT_ROLE='role1'"
+stmt "create user 'other' IDENTIFIED BY '$TEST_USER_PASSWORD'"
+stmt 'create role role1'
+stmt 'grant role role1 to owner'
+
+stmt 'drop pipe if exists pipe_p'
+stmt 'drop stage if exists pipe_src'
+stmt 'drop table if exists pipe_t'
+stmt 'drop table if exists pipe_dst'
+stmt 'create stage pipe_src'
+stmt 'create table pipe_t(id int)'
+stmt 'insert into pipe_t values(1),(2)'
+echo "copy into @pipe_src/data/ from pipe_t file_format = (type = 'csv')" | $BENDSQL_CLIENT_CONNECT > /dev/null
+stmt 'create table pipe_dst(id int)'
+
+echo 'need failed: with 1063, no read on the stage'
+echo "create pipe pipe_p as copy into pipe_dst from @pipe_src/data/ file_format = (type = 'csv')" | $TEST_USER_CONNECT
+
+stmt 'grant read on stage pipe_src to role role1'
+echo 'need failed: with 1063, no insert on the table'
+echo "create pipe pipe_p as copy into pipe_dst from @pipe_src/data/ file_format = (type = 'csv')" | $TEST_USER_CONNECT
+
+stmt 'grant insert on default.pipe_dst to role role1'
+echo "create pipe pipe_p as copy into pipe_dst from @pipe_src/data/ file_format = (type = 'csv')" | $TEST_USER_CONNECT
+query "select name, owner from system.pipes where name = 'pipe_p'"
+echo 'alter pipe pipe_p refresh' | $TEST_USER_CONNECT
+query 'select count(*) from pipe_dst'
+
+echo 'need failed: with 1063, the other user does not own the pipe'
+echo 'alter pipe pipe_p set pipe_execution_paused = true' | $TEST_OTHER_CONNECT
+echo 'drop pipe pipe_p' | $TEST_OTHER_CONNECT
+
+echo 'need failed: with 1063, the owner can no longer insert into the table'
+stmt 'revoke insert on default.pipe_dst from role role1'
+echo 'alter pipe pipe_p refresh' | $TEST_USER_CONNECT
+
+echo 'drop pipe pipe_p' | $TEST_USER_CONNECT
+query "select count(*) from system.pipes where name = 'pipe_p'"
+
+stmt 'drop stage if exists pipe_src'
+stmt 'drop table if exists pipe_t'
+stmt 'drop table if exists pipe_dst'
+stmt 'drop user if exists owner'
+stmt 'drop user if exists other'
+stmt 'drop role if exists role1'
@@ -0,0 +1,43 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=owner --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+export TEST_OTHER_CONNECT="bendsql --user=other --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+stmt "drop user if exists 'owner'"
+stmt "drop user if exists 'other'"
+stmt "drop role if exists role1"
+stmt "create user 'owner' IDENTIFIED BY '$TEST_USER_PASSWORD' with DEFAULT_ROLE='role1'"
+stmt "create user 'other' IDENTIFIED BY '$TEST_USER_PASSWORD'"
+stmt 'create role role1'
+stmt 'grant role role1 to owner'
+stmt 'drop task if exists task_18_0017'
+
+stmt 'grant super on *.* to role role1'
+echo "create task task_18_0017 schedule = using cron '0 0 0 1 1 ? 2100' as select 1" | $TEST_USER_CONNECT
+stmt 'revoke super on *.* from role role1'
+query "select name, owner from system.tasks where name = 'task_18_0017'"
+
+echo 'the owner role manages the task without the super privilege'
+echo "alter task task_18_0017 set comment = 'by owner'" | $TEST_USER_CONNECT
+
+echo 'need failed: with 1063, the other user does not own the task'
+echo 'alter task task_18_0017 suspend' | $TEST_OTHER_CONNECT
+echo 'execute task task_18_0017' | $TEST_OTHER_CONNECT
+echo 'desc task task_18_0017' | $TEST_OTHER_CONNECT
+echo 'drop task task_18_0017' | $TEST_OTHER_CONNECT
+
+echo 'the role that modifies the sql of the task becomes the owner'
+stmt 'alter task task_18_0017 modify as select 2'
+query "select name, owner from system.tasks where name = 'task_18_0017'"
+
+echo 'need failed: with 1063, the task is no longer owned by role1'
+echo 'drop task task_18_0017' | $TEST_USER_CONNECT
+
+stmt 'drop task if exists task_18_0017'
+stmt 'drop user if exists owner'
+stmt 'drop user if exists other'
+stmt 'drop role if exists role1'
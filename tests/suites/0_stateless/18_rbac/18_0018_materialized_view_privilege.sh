@@ -0,0 +1,32 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_NAME="owner"
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=owner --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+stmt "drop user if exists 'owner'"
+stmt "drop role if exists role1"
+stmt "create user 'owner' IDENTIFIED BY '$TEST_USER_PASSWORD' with DEFAULT_ROLE='role1'"
+stmt 'create role role1'
+
+stmt 'grant role role1 to owner'
+stmt 'grant create on default.* to role role1'
+stmt 'drop table if exists t'
+stmt 'drop table if exists mv_t'
+stmt 'create table t(id int)'
+stmt 'insert into t values(1)'
+
+echo 'need failed: with 1063'
+echo 'create materialized view mv_t as select id from t' | $TEST_USER_CONNECT
+
+stmt 'grant select on default.t to role role1'
+echo 'create materialized view mv_t as select id from t' | $TEST_USER_CONNECT
+echo 'select * from mv_t order by id' | $TEST_USER_CONNECT
+
+stmt 'drop table if exists t'
+stmt 'drop materialized view if exists mv_t'
+stmt 'drop user if exists owner'
+stmt 'drop role if exists role1'
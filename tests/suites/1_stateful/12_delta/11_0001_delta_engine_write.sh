@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Work on a copy, the write path commits new versions into _delta_log.
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/delta/simple/. "$ROOT"/
+
+stmt "drop table if exists test_delta_write;"
+echo "create table test_delta_write engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_delta_write values (5), (6);"
+query "select * from test_delta_write order by id;"
+
+stmt "insert into test_delta_write select number + 7 from numbers(2);"
+query "select count(*) from test_delta_write;"
+
+query "select * from test_delta_write at (version => 0) order by id;"
+query "select count(*) from test_delta_write at (version => 1);"
+
+stmt "drop table test_delta_write;"
+rm -rf "$ROOT"
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP CATALOG IF EXISTS iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
+
+cat <<EOF | $BENDSQL_CLIENT_CONNECT
+CREATE CATALOG iceberg_write_ctl
+TYPE=ICEBERG
+CONNECTION=(
+    TYPE='rest'
+    ADDRESS='http://127.0.0.1:8181'
+    WAREHOUSE='s3://icebergdata/demo'
+);
+EOF
+
+echo "CREATE DATABASE IF NOT EXISTS iceberg_write_ctl.write_db;" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE IF EXISTS iceberg_write_ctl.write_db.t1;" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE IF EXISTS iceberg_write_ctl.write_db.t2;" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE iceberg_write_ctl.write_db.t1 (id INT, name STRING);" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO iceberg_write_ctl.write_db.t1 VALUES (1, 'a'), (2, 'b');" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO iceberg_write_ctl.write_db.t1 SELECT number + 3, to_string(number) FROM numbers(2);" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_write_ctl.write_db.t1 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE iceberg_write_ctl.write_db.t2 AS SELECT * FROM iceberg_write_ctl.write_db.t1 WHERE id > 2;" | $BENDSQL_CLIENT_CONNECT
+echo "SELECT * FROM iceberg_write_ctl.write_db.t2 ORDER BY id;" | $BENDSQL_CLIENT_CONNECT
+
+echo "INSERT OVERWRITE iceberg_write_ctl.write_db.t2 VALUES (1, 'a');" | $BENDSQL_CLIENT_CONNECT 2>&1 | grep -o "INSERT OVERWRITE is not supported"
+
+echo "DROP TABLE iceberg_write_ctl.write_db.t1;" | $BENDSQL_CLIENT_CONNECT
+echo "DROP TABLE iceberg_write_ctl.write_db.t2;" | $BENDSQL_CLIENT_CONNECT
+echo "DROP CATALOG iceberg_write_ctl" | $BENDSQL_CLIENT_CONNECT
@@ -17,6 +17,7 @@ import datetime
 from decimal import Decimal
 import time
 from typing import List, Dict, Any, Tuple, Optional
+import pyarrow as pa
 from pyarrow import flight
 
 # https://github.com/datafuselabs/databend-udf
@@ -42,7 +43,50 @@ class HeadersMiddleware(flight.ServerMiddleware):
         return self._headers.get(key)
 
 
+class TableFunction:
+    """
+    A table function, `func` yields the output rows of one input row.
+
+    Every output batch starts with the `row` column, the index of the input row
+    that produced the output row, followed by the output columns.
+    """
+
+    def __init__(self, name, input_fields, output_fields, func):
+        self.name = name
+        self.func = func
+        row = pa.field("row", pa.int32(), nullable=False)
+        self.output_fields = output_fields
+        self.schema = pa.schema(input_fields + [row] + output_fields)
+        self.result_schema = pa.schema([row] + output_fields)
+
+    def eval_batch(self, batch):
+        inputs = [column.to_pylist() for column in batch.columns]
+        rows = []
+        outputs = [[] for _ in self.output_fields]
+        for i in range(batch.num_rows):
+            for output in self.func(*[column[i] for column in inputs]):
+                rows.append(i)
+                for j, value in enumerate(output):
+                    outputs[j].append(value)
+        arrays = [pa.array(rows, pa.int32())] + [
+            pa.array(values, type=field.type)
+            for values, field in zip(outputs, self.output_fields)
+        ]
+        return pa.RecordBatch.from_arrays(arrays, schema=self.result_schema)
+
+
 class CheckHeadersServer(UDFServer):
+    _table_functions: Dict[str, TableFunction] = {}
+
+    def add_table_function(self, func: TableFunction):
+        self._table_functions[func.name] = func
+
+    def get_flight_info(self, context, descriptor):
+        func = self._table_functions.get(descriptor.path[0].decode("utf-8"))
+        if func is not None:
+            return flight.FlightInfo(func.schema, descriptor, [], -1, -1)
+        return super().get_flight_info(context, descriptor)
+
     def do_exchange(self, context, descriptor, reader, writer):
         headers = context.get_middleware("headers")
 
@@ -60,6 +104,13 @@ class CheckHeadersServer(UDFServer):
                     f"Wrong token(expect: {expect_token}): {token}"
                 )
 
+        func = self._table_functions.get(descriptor.path[0].decode("utf-8"))
+        if func is not None:
+            writer.begin(func.result_schema)
+            for chunk in reader:
+                writer.write_batch(func.eval_batch(chunk.data))
+            return
+
         return super().do_exchange(context, descriptor, reader, writer)
 
 
@@ -415,6 +466,21 @@ def check_headers() -> str:
     return "success"
 
 
+def split_words(s):
+    if s is None:
+        return
+    for i, word in enumerate(s.split()):
+        yield i, word
+
+
+split_words_udtf = TableFunction(
+    "split_words",
+    [pa.field("s", pa.string())],
+    [pa.field("idx", pa.int32()), pa.field("word", pa.string())],
+    split_words,
+)
+
+
 if __name__ == "__main__":
     udf_server = CheckHeadersServer(
         location="0.0.0.0:8815", middleware={"headers": HeadersMiddlewareFactory()}
@@ -444,6 +510,7 @@ if __name__ == "__main__":
     udf_server.add_function(wait_concurrent)
     udf_server.add_function(url_len)
     udf_server.add_function(check_headers)
+    udf_server.add_table_function(split_words_udtf)
 
     # Built-in function
     udf_server.add_function(ping)